@@ -48,6 +48,15 @@ fn bench_signal_operations(c: &mut Criterion) {
     let sig = Signal::new(0);
     group.bench_function("signal_update", |b| b.iter(|| sig.update(|v| *v += 1)));
 
+    // Reading a large vec: clone vs borrow
+    let big = Signal::new((0..10_000u64).collect::<Vec<_>>());
+    group.bench_function("signal_get_vec_10k", |b| {
+        b.iter(|| black_box(big.get().len()))
+    });
+    group.bench_function("signal_with_vec_10k", |b| {
+        b.iter(|| black_box(big.with(|v| v.len())))
+    });
+
     // Derived computation
     let a = Signal::new(2);
     let b = Signal::new(3);
@@ -207,6 +216,45 @@ fn bench_focus_management(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_frame_diff(c: &mut Criterion) {
+    use rsdrav::render::compute_diff;
+
+    let mut group = c.benchmark_group("frame_diff");
+
+    // What the app does each frame: clear, redraw the same mostly-blank
+    // screen, diff against the previous frame
+    fn draw(buffer: &mut Buffer) {
+        buffer.clear();
+        for y in (0..60).step_by(4) {
+            for (x, ch) in "static status line".chars().enumerate() {
+                buffer.set(x as u16, y, Cell::new(ch));
+            }
+        }
+    }
+
+    group.bench_function("static_200x60", |b| {
+        let mut prev = Buffer::new(200, 60);
+        let mut next = Buffer::new(200, 60);
+        draw(&mut prev);
+        b.iter(|| {
+            draw(&mut next);
+            let dirty = compute_diff(black_box(&prev), black_box(&next));
+            std::mem::swap(&mut prev, &mut next);
+            dirty
+        })
+    });
+
+    group.bench_function("unchanged_200x60", |b| {
+        let mut prev = Buffer::new(200, 60);
+        let mut next = Buffer::new(200, 60);
+        draw(&mut prev);
+        draw(&mut next);
+        b.iter(|| compute_diff(black_box(&prev), black_box(&next)))
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_component_rendering,
@@ -216,5 +264,6 @@ criterion_group!(
     bench_table_operations,
     bench_animation,
     bench_focus_management,
+    bench_frame_diff,
 );
 criterion_main!(benches);
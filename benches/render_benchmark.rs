@@ -1,6 +1,8 @@
 use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
 use rsdrav::prelude::*;
-use rsdrav::render::{Buffer, Cell};
+use rsdrav::render::{compute_diff, Backend, Buffer, Cell, Renderer};
+use std::io::Write;
+use std::time::Duration;
 
 fn bench_buffer_creation(c: &mut Criterion) {
     c.bench_function("buffer_new_80x24", |b| {
@@ -116,6 +118,125 @@ fn bench_layout_calculations(c: &mut Criterion) {
     });
 }
 
+/// Backend that throws output away, so the benchmark times the renderer
+/// rather than a terminal
+struct NullBackend {
+    bytes: usize,
+}
+
+impl Backend for NullBackend {
+    fn enter_raw_mode(&mut self) -> rsdrav::Result<()> {
+        Ok(())
+    }
+    fn leave_raw_mode(&mut self) -> rsdrav::Result<()> {
+        Ok(())
+    }
+    fn enter_alt_screen(&mut self) -> rsdrav::Result<()> {
+        Ok(())
+    }
+    fn leave_alt_screen(&mut self) -> rsdrav::Result<()> {
+        Ok(())
+    }
+    fn enable_mouse(&mut self) -> rsdrav::Result<()> {
+        Ok(())
+    }
+    fn disable_mouse(&mut self) -> rsdrav::Result<()> {
+        Ok(())
+    }
+    fn size(&self) -> rsdrav::Result<(u16, u16)> {
+        Ok((200, 60))
+    }
+    fn clear(&mut self) -> rsdrav::Result<()> {
+        Ok(())
+    }
+    fn flush(&mut self) -> rsdrav::Result<()> {
+        Ok(())
+    }
+    fn write(&mut self, content: &[u8]) -> rsdrav::Result<()> {
+        self.bytes += content.len();
+        Ok(())
+    }
+    fn read_event(&mut self, _timeout: Duration) -> rsdrav::Result<Option<Event>> {
+        Ok(None)
+    }
+    fn cursor_goto(&mut self, x: u16, y: u16) -> rsdrav::Result<()> {
+        self.write(format!("\x1b[{};{}H", y + 1, x + 1).as_bytes())
+    }
+    fn cursor_show(&mut self) -> rsdrav::Result<()> {
+        Ok(())
+    }
+    fn cursor_hide(&mut self) -> rsdrav::Result<()> {
+        Ok(())
+    }
+}
+
+/// The old approach: a cursor move and a full style escape for every
+/// changed cell
+fn render_per_cell(backend: &mut NullBackend, prev: &Buffer, next: &Buffer) {
+    for region in compute_diff(prev, next) {
+        let rect = region.rect;
+        for y in rect.y..rect.y + rect.height {
+            for x in rect.x..rect.x + rect.width {
+                let cell = next.get(x, y).unwrap();
+                backend.cursor_goto(x, y).unwrap();
+                let mut out = Vec::new();
+                write!(out, "\x1b[0m").unwrap();
+                if let Some(fg) = cell.style.fg {
+                    write!(out, "\x1b[38;2;{};{};{}m", fg.r, fg.g, fg.b).unwrap();
+                }
+                if let Some(bg) = cell.style.bg {
+                    write!(out, "\x1b[48;2;{};{};{}m", bg.r, bg.g, bg.b).unwrap();
+                }
+                write!(out, "{}\x1b[0m", cell.ch).unwrap();
+                backend.write(&out).unwrap();
+            }
+        }
+    }
+}
+
+fn bench_renderer(c: &mut Criterion) {
+    let mut group = c.benchmark_group("renderer");
+
+    // A scrolled list: every row changes, in two alternating styles
+    let draw = |buffer: &mut Buffer, offset: usize| {
+        let even = Style::new().fg(Color::WHITE).bg(Color::gray(30));
+        let odd = Style::new().fg(Color::CYAN);
+        for y in 0..60u16 {
+            let style = if (y as usize + offset) % 2 == 0 {
+                even
+            } else {
+                odd
+            };
+            let line = format!("item {:>4} ", y as usize + offset).repeat(20);
+            for (x, ch) in line.chars().take(200).enumerate() {
+                buffer.set(x as u16, y, Cell::with_style(ch, style));
+            }
+        }
+    };
+    let mut prev = Buffer::new(200, 60);
+    let mut next = Buffer::new(200, 60);
+    draw(&mut prev, 0);
+    draw(&mut next, 1);
+
+    group.bench_function("per_cell_200x60", |b| {
+        let mut backend = NullBackend { bytes: 0 };
+        b.iter(|| render_per_cell(&mut backend, black_box(&prev), black_box(&next)))
+    });
+
+    group.bench_function("coalesced_200x60", |b| {
+        let mut backend = NullBackend { bytes: 0 };
+        let mut renderer = Renderer::new();
+        renderer.render(&mut backend, None, &prev).unwrap();
+        b.iter(|| {
+            renderer
+                .render(&mut backend, Some(black_box(&prev)), black_box(&next))
+                .unwrap()
+        })
+    });
+
+    group.finish();
+}
+
 criterion_group!(
     benches,
     bench_buffer_creation,
@@ -123,6 +244,7 @@ criterion_group!(
     bench_diff_algorithm,
     bench_signal_operations,
     bench_component_render,
-    bench_layout_calculations
+    bench_layout_calculations,
+    bench_renderer
 );
 criterion_main!(benches);
@@ -7,10 +7,13 @@
 //! - Background async tasks
 //! - Async data fetching simulation
 //! - Signal updates from async context
+//! - An async command that reports back through a signal
 
 #[cfg(feature = "tokio")]
 use rsdrav::async_support::AsyncRuntime;
 #[cfg(feature = "tokio")]
+use rsdrav::command::{Command, CommandContext, CommandRegistry};
+#[cfg(feature = "tokio")]
 use rsdrav::prelude::*;
 #[cfg(feature = "tokio")]
 use std::time::Duration;
@@ -39,32 +42,44 @@ fn main() -> rsdrav::Result<()> {
         }
     });
 
-    app.root(AsyncDemo::new(counter)).run()
+    app.root(AsyncDemo::new(counter, &runtime)).run()
 }
 
 #[cfg(feature = "tokio")]
 struct AsyncDemo {
     counter: Signal<i32>,
     data: Signal<String>,
+    commands: CommandRegistry,
+    command_ctx: CommandContext,
 }
 
 #[cfg(feature = "tokio")]
 impl AsyncDemo {
-    fn new(counter: Signal<i32>) -> Self {
+    fn new(counter: Signal<i32>, runtime: &AsyncRuntime) -> Self {
+        let data = Signal::new("Waiting...".to_string());
+
+        // Runs off the main thread; the UI keeps updating meanwhile
+        let mut commands = CommandRegistry::new();
+        commands.set_runtime(runtime);
+        commands.register_async("fetch", {
+            let data = data.clone();
+            move |_cmd: Command| {
+                let data = data.clone();
+                async move {
+                    data.set("Fetching...".to_string());
+                    tokio::time::sleep(Duration::from_secs(2)).await;
+                    data.set("Data loaded!".to_string());
+                }
+            }
+        });
+
         Self {
             counter,
-            data: Signal::new("Waiting...".to_string()),
+            data,
+            commands,
+            command_ctx: CommandContext::new(Store::new()),
         }
     }
-
-    #[allow(dead_code)]
-    async fn fetch_data(&self) {
-        use tokio::time::{sleep, Duration};
-
-        self.data.set("Fetching...".to_string());
-        sleep(Duration::from_secs(2)).await;
-        self.data.set("Data loaded!".to_string());
-    }
 }
 
 #[cfg(feature = "tokio")]
@@ -90,6 +105,7 @@ impl Component for AsyncDemo {
             .push(Text::new(""))
             .push(Text::new("This example shows async background tasks").fg(Color::GRAY))
             .push(Text::new("The counter increments every second via async task").fg(Color::GRAY))
+            .push(Text::new("Press 'f' to run the async fetch command").fg(Color::GRAY))
             .push(Text::new(""))
             .push(Text::new("Press 'q' to quit").fg(Color::GRAY));
 
@@ -103,7 +119,15 @@ impl Component for AsyncDemo {
             .render(_ctx)
     }
 
-    fn handle_event(&mut self, _event: &Event, _ctx: &mut EventContext) -> EventResult {
-        EventResult::Ignored
+    fn handle_event(&mut self, event: &Event, _ctx: &mut EventContext) -> EventResult {
+        match event {
+            Event::Key(key) if key.code == KeyCode::Char('f') => {
+                if let Err(e) = self.commands.execute_line("fetch", &mut self.command_ctx) {
+                    self.data.set(format!("Error: {}", e));
+                }
+                EventResult::Consumed
+            }
+            _ => EventResult::Ignored,
+        }
     }
 }
@@ -3,18 +3,22 @@
 //! Demonstrates:
 //! - Input widget for command entry
 //! - List widget for filtered commands
-//! - Command execution
+//! - Command execution through a `CommandRegistry`
+//! - Asking for input when a command returns an `OpenInput` follow-up
 //! - Fuzzy matching (simple)
 //! - Modal overlay
+//! - Recalling earlier commands from history
 //!
 //! Controls:
 //! - Ctrl+P - Open command palette
 //! - Type to filter commands
 //! - ↑/↓ - Navigate commands
-//! - Enter - Execute command
+//! - Ctrl+↑/↓ - Recall earlier commands
+//! - Enter - Execute command (or submit the answer to a prompt)
 //! - Esc - Close palette
 //! - q - Quit (when palette closed)
 
+use rsdrav::command::InputCallback;
 use rsdrav::prelude::*;
 
 fn main() -> rsdrav::Result<()> {
@@ -22,7 +26,7 @@ fn main() -> rsdrav::Result<()> {
 }
 
 #[derive(Clone, Debug)]
-struct Command {
+struct PaletteEntry {
     name: String,
     description: String,
     category: String,
@@ -31,17 +35,63 @@ struct Command {
 struct CommandPaletteDemo {
     palette_visible: Signal<bool>,
     search_query: Signal<String>,
-    all_commands: Vec<Command>,
-    filtered_commands: Signal<Vec<Command>>,
+    all_commands: Vec<PaletteEntry>,
+    filtered_commands: Signal<Vec<PaletteEntry>>,
     selected: Signal<Option<usize>>,
     last_executed: Signal<Option<String>>,
     status_message: Signal<String>,
+    history: CommandHistory,
+    registry: CommandRegistry,
+    command_ctx: CommandContext,
+    /// Prompt text and callback of an `OpenInput` follow-up waiting for
+    /// the user's answer
+    pending_input: Option<(String, InputCallback)>,
+}
+
+/// Runs any palette entry that needs nothing more than a status message
+struct ExecuteHandler;
+
+impl CommandHandler for ExecuteHandler {
+    fn execute(&mut self, cmd: Command, _ctx: &mut CommandContext) -> Result<CommandResult> {
+        Ok(CommandResult::success_with_message(format!(
+            "Executed: {}",
+            cmd.name
+        )))
+    }
+}
+
+/// Asks for the new name, then hands it to `rename_to`
+struct RenameHandler;
+
+impl CommandHandler for RenameHandler {
+    fn execute(&mut self, _cmd: Command, _ctx: &mut CommandContext) -> Result<CommandResult> {
+        Ok(CommandResult::success()
+            .open_input("New name:", |name| Command::new("rename_to").arg(name)))
+    }
+}
+
+struct RenameToHandler;
+
+impl CommandHandler for RenameToHandler {
+    fn execute(&mut self, cmd: Command, _ctx: &mut CommandContext) -> Result<CommandResult> {
+        Ok(CommandResult::success_with_message(format!(
+            "Renamed to {}",
+            cmd.args.join(" ")
+        )))
+    }
 }
 
 impl CommandPaletteDemo {
     fn new() -> Self {
         let all_commands = Self::create_commands();
 
+        let mut registry = CommandRegistry::new();
+        for entry in &all_commands {
+            registry.register(&entry.name, ExecuteHandler);
+        }
+        registry.register("File: Rename", RenameHandler);
+        registry.register("rename_to", RenameToHandler);
+
         Self {
             palette_visible: Signal::new(false),
             search_query: Signal::new(String::new()),
@@ -50,67 +100,76 @@ impl CommandPaletteDemo {
             selected: Signal::new(Some(0)),
             last_executed: Signal::new(None),
             status_message: Signal::new("Press Ctrl+P to open command palette".into()),
+            history: CommandHistory::new(50),
+            registry,
+            command_ctx: CommandContext::new(Store::new()),
+            pending_input: None,
         }
     }
 
-    fn create_commands() -> Vec<Command> {
+    fn create_commands() -> Vec<PaletteEntry> {
         vec![
-            Command {
+            PaletteEntry {
                 name: "File: New".into(),
                 description: "Create a new file".into(),
                 category: "File".into(),
             },
-            Command {
+            PaletteEntry {
                 name: "File: Open".into(),
                 description: "Open an existing file".into(),
                 category: "File".into(),
             },
-            Command {
+            PaletteEntry {
                 name: "File: Save".into(),
                 description: "Save current file".into(),
                 category: "File".into(),
             },
-            Command {
+            PaletteEntry {
+                name: "File: Rename".into(),
+                description: "Rename current file".into(),
+                category: "File".into(),
+            },
+            PaletteEntry {
                 name: "Edit: Copy".into(),
                 description: "Copy selection to clipboard".into(),
                 category: "Edit".into(),
             },
-            Command {
+            PaletteEntry {
                 name: "Edit: Paste".into(),
                 description: "Paste from clipboard".into(),
                 category: "Edit".into(),
             },
-            Command {
+            PaletteEntry {
                 name: "View: Toggle Theme".into(),
                 description: "Switch between light and dark theme".into(),
                 category: "View".into(),
             },
-            Command {
+            PaletteEntry {
                 name: "View: Zoom In".into(),
                 description: "Increase font size".into(),
                 category: "View".into(),
             },
-            Command {
+            PaletteEntry {
                 name: "View: Zoom Out".into(),
                 description: "Decrease font size".into(),
                 category: "View".into(),
             },
-            Command {
+            PaletteEntry {
                 name: "Terminal: New".into(),
                 description: "Open a new terminal".into(),
                 category: "Terminal".into(),
             },
-            Command {
+            PaletteEntry {
                 name: "Terminal: Split".into(),
                 description: "Split terminal pane".into(),
                 category: "Terminal".into(),
             },
-            Command {
+            PaletteEntry {
                 name: "Help: Documentation".into(),
                 description: "Open documentation".into(),
                 category: "Help".into(),
             },
-            Command {
+            PaletteEntry {
                 name: "Help: Shortcuts".into(),
                 description: "View keyboard shortcuts".into(),
                 category: "Help".into(),
@@ -128,6 +187,21 @@ impl CommandPaletteDemo {
     fn close_palette(&mut self) {
         self.palette_visible.set(false);
         self.search_query.set(String::new());
+        self.history.reset();
+        self.pending_input = None;
+    }
+
+    /// Put an earlier (or, going forward, later) command in the search box
+    fn recall(&mut self, older: bool) {
+        let recalled = if older {
+            self.history.prev(&self.search_query.get())
+        } else {
+            self.history.next()
+        };
+        if let Some(line) = recalled.map(String::from) {
+            self.search_query.set(line);
+            self.filter_commands();
+        }
     }
 
     fn filter_commands(&mut self) {
@@ -137,7 +211,7 @@ impl CommandPaletteDemo {
             self.filtered_commands.set(self.all_commands.clone());
         } else {
             // Simple substring matching
-            let filtered: Vec<Command> = self
+            let filtered: Vec<PaletteEntry> = self
                 .all_commands
                 .iter()
                 .filter(|cmd| {
@@ -156,14 +230,43 @@ impl CommandPaletteDemo {
     }
 
     fn execute_selected(&mut self) {
+        // Enter answers the prompt when there is one
+        if let Some((_, on_submit)) = self.pending_input.take() {
+            let answer = self.search_query.get();
+            self.run(on_submit(answer));
+            return;
+        }
+
         let commands = self.filtered_commands.get();
         if let Some(idx) = self.selected.get() {
-            if let Some(cmd) = commands.get(idx) {
-                self.last_executed.set(Some(cmd.name.clone()));
-                self.status_message.set(format!("Executed: {}", cmd.name));
-                self.close_palette();
+            if let Some(entry) = commands.get(idx) {
+                self.last_executed.set(Some(entry.name.clone()));
+                self.history.push(entry.name.clone());
+                self.run(Command::new(entry.name.clone()));
+            }
+        }
+    }
+
+    /// Execute a command through the registry
+    ///
+    /// The registry runs chained commands itself but can't show a prompt,
+    /// so an `OpenInput` follow-up keeps the palette open as an input box
+    /// until the user submits an answer.
+    fn run(&mut self, cmd: Command) {
+        match self.registry.execute(cmd, &mut self.command_ctx) {
+            Ok(result) => {
+                if let Some(message) = result.message {
+                    self.status_message.set(message);
+                }
+                if let Some(FollowUp::OpenInput { prompt, on_submit }) = result.follow_up {
+                    self.pending_input = Some((prompt, on_submit));
+                    self.search_query.set(String::new());
+                    return;
+                }
             }
+            Err(e) => self.status_message.set(format!("Error: {}", e)),
         }
+        self.close_palette();
     }
 }
 
@@ -210,7 +313,7 @@ impl Component for CommandPaletteDemo {
 
             let command_list = List::new(self.filtered_commands.clone(), self.selected.clone())
                 .visible_height(8)
-                .render_item(|cmd: &Command, is_selected| {
+                .render_item(|cmd: &PaletteEntry, is_selected| {
                     let icon = match cmd.category.as_str() {
                         "File" => "📄",
                         "Edit" => "✏️",
@@ -230,18 +333,33 @@ impl Component for CommandPaletteDemo {
                     ViewNode::text_styled(text, style)
                 });
 
-            let palette_content = VStack::new()
-                .push(
-                    Text::new("Command Palette")
-                        .fg(Color::YELLOW)
-                        .add_modifier(Modifier::BOLD),
-                )
-                .push(Text::new(""))
-                .push(search_input)
-                .push(Text::new(""))
-                .push(command_list)
-                .push(Text::new(""))
-                .push(Text::new("↑/↓ Navigate  Enter Execute  Esc Close").fg(Color::GRAY));
+            let palette_content = match &self.pending_input {
+                Some((prompt, _)) => VStack::new()
+                    .push(
+                        Text::new(prompt.clone())
+                            .fg(Color::YELLOW)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                    .push(Text::new(""))
+                    .push(Input::new(self.search_query.clone()).focused(true))
+                    .push(Text::new(""))
+                    .push(Text::new("Enter Submit  Esc Cancel").fg(Color::GRAY)),
+                None => VStack::new()
+                    .push(
+                        Text::new("Command Palette")
+                            .fg(Color::YELLOW)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                    .push(Text::new(""))
+                    .push(search_input)
+                    .push(Text::new(""))
+                    .push(command_list)
+                    .push(Text::new(""))
+                    .push(
+                        Text::new("↑/↓ Navigate  Ctrl+↑/↓ History  Enter Execute  Esc Close")
+                            .fg(Color::GRAY),
+                    ),
+            };
 
             let palette = Panel::new()
                 .title("🔍 Commands")
@@ -295,10 +413,19 @@ impl Component for CommandPaletteDemo {
                         let mut input = Input::new(self.search_query.clone()).focused(true);
                         let result = input.handle_event(event, ctx);
                         if result == EventResult::Handled {
-                            self.filter_commands();
+                            // An answer to a prompt isn't a search
+                            if self.pending_input.is_none() {
+                                self.filter_commands();
+                            }
                             return result;
                         }
                     }
+                    KeyCode::Up | KeyCode::Down
+                        if key.modifiers.contains(KeyModifiers::CONTROL) =>
+                    {
+                        self.recall(key.code == KeyCode::Up);
+                        return EventResult::Consumed;
+                    }
                     KeyCode::Up | KeyCode::Down => {
                         // Let list handle navigation
                         let mut list =
@@ -313,3 +440,33 @@ impl Component for CommandPaletteDemo {
         EventResult::Ignored
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_open_input_runs_callback() {
+        let mut demo = CommandPaletteDemo::new();
+        demo.open_palette();
+        demo.search_query.set("rename".into());
+        demo.filter_commands();
+
+        // The rename command asks for a name instead of finishing
+        demo.execute_selected();
+        assert!(demo.palette_visible.get());
+        assert_eq!(
+            demo.pending_input
+                .as_ref()
+                .map(|(prompt, _)| prompt.as_str()),
+            Some("New name:")
+        );
+
+        // Submitting the answer runs the command built from it
+        demo.search_query.set("notes.txt".into());
+        demo.execute_selected();
+        assert!(demo.pending_input.is_none());
+        assert!(!demo.palette_visible.get());
+        assert_eq!(demo.status_message.get(), "Renamed to notes.txt");
+    }
+}
@@ -4,12 +4,14 @@
 //! - Table widget with multiple columns
 //! - Row selection and navigation
 //! - Sorting indicators
+//! - Status cells colored by value
 //! - Styled table with colors
 //! - Real-world data display
 //!
 //! Controls:
 //! - ↑/↓ - Navigate rows
-//! - s - Toggle sort
+//! - ←/→ - Pick the column to sort by
+//! - s - Toggle sort (or click a sortable header)
 //! - r - Refresh data
 //! - q - Quit
 
@@ -171,13 +173,27 @@ impl Component for DataTableDemo {
         // Employee table
         let table = Table::new(self.employees.clone(), self.selected.clone())
             .column(TableColumn::new("ID", 8).render(|e: &Employee| e.id.to_string()))
-            .column(TableColumn::new("Name", 18).render(|e: &Employee| e.name.clone()))
+            .column(
+                TableColumn::new("Name", 18)
+                    .render(|e: &Employee| e.name.clone())
+                    .sortable(),
+            )
             .column(TableColumn::new("Department", 14).render(|e: &Employee| e.department.clone()))
             .column(
-                TableColumn::new("Salary", 12).render(|e: &Employee| format!("${:.0}", e.salary)),
+                TableColumn::new("Salary", 12)
+                    .render(|e: &Employee| format!("${:.0}", e.salary))
+                    .sort_cmp(|a: &Employee, b: &Employee| a.salary.total_cmp(&b.salary)),
             )
             .column(TableColumn::new("Years", 7).render(|e: &Employee| e.years.to_string()))
-            .column(TableColumn::new("Status", 10).render(|e: &Employee| e.status.to_string()))
+            .column(
+                TableColumn::new("Status", 10)
+                    .render(|e: &Employee| e.status.to_string())
+                    .style(|e: &Employee| match e.status {
+                        EmployeeStatus::Active => Style::default().fg(Color::GREEN),
+                        EmployeeStatus::Remote => Style::default().fg(Color::CYAN),
+                        EmployeeStatus::OnLeave => Style::default().fg(Color::YELLOW),
+                    }),
+            )
             .visible_height(10);
 
         let table_panel = Panel::new()
@@ -218,6 +234,7 @@ impl Component for DataTableDemo {
             .push(Text::new(""))
             .push(Text::new("Controls:").fg(Color::YELLOW))
             .push(Text::new("  ↑/↓ - Navigate employees").fg(Color::GRAY))
+            .push(Text::new("  ←/→ - Pick sort column").fg(Color::GRAY))
             .push(Text::new("  s - Toggle sort").fg(Color::GRAY))
             .push(Text::new("  r - Refresh data").fg(Color::GRAY))
             .push(Text::new("  q - Quit").fg(Color::GRAY));
@@ -1,19 +1,33 @@
 //! Animation system for smooth transitions
 //!
-//! Provides easing functions and tween support for animated UI transitions.
+//! Provides easing functions, tweens and springs for animated UI transitions.
 
 use std::time::Duration;
 
 mod easing;
+mod spring;
 pub use easing::*;
+pub use spring::Spring;
+
+/// Callback run once when an animation in a `Timeline` finishes
+pub type OnComplete = Box<dyn FnOnce() + Send + Sync>;
 
 /// Animation tween for interpolating values over time
+///
+/// Runs once from `start` to `end` by default. `delay` holds it at `start`
+/// before the first run, `repeat` plays it again, and `yoyo` makes every
+/// other run go backwards from `end` to `start`.
 pub struct Tween<T> {
     start: T,
     end: T,
     duration: Duration,
     elapsed: Duration,
     easing: EasingFunction,
+    delay: Duration,
+    /// Extra runs after the first, or `None` to repeat forever
+    repeats: Option<u32>,
+    yoyo: bool,
+    on_complete: Option<OnComplete>,
 }
 
 impl<T: Animatable> Tween<T> {
@@ -25,6 +39,10 @@ impl<T: Animatable> Tween<T> {
             duration,
             elapsed: Duration::ZERO,
             easing: EasingFunction::Linear,
+            delay: Duration::ZERO,
+            repeats: Some(0),
+            yoyo: false,
+            on_complete: None,
         }
     }
 
@@ -34,26 +52,82 @@ impl<T: Animatable> Tween<T> {
         self
     }
 
+    /// Wait this long at `start` before running
+    pub fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    /// Play `count` more times after the first run
+    ///
+    /// A count too large for the total time to fit in a `Duration`
+    /// repeats forever.
+    pub fn repeat(mut self, count: u32) -> Self {
+        self.repeats = Some(count);
+        self
+    }
+
+    /// Keep playing until the tween is dropped; it never completes
+    pub fn repeat_forever(mut self) -> Self {
+        self.repeats = None;
+        self
+    }
+
+    /// Run every other repeat backwards, ping-ponging between the ends
+    pub fn yoyo(mut self, yoyo: bool) -> Self {
+        self.yoyo = yoyo;
+        self
+    }
+
+    /// Run `callback` when the tween finishes in a `Timeline`
+    pub fn on_complete(mut self, callback: impl FnOnce() + Send + Sync + 'static) -> Self {
+        self.on_complete = Some(Box::new(callback));
+        self
+    }
+
     /// Update the animation
     pub fn update(&mut self, delta: Duration) {
-        self.elapsed = (self.elapsed + delta).min(self.duration);
+        self.elapsed = self.elapsed.saturating_add(delta);
+        if let Some(total) = self.total() {
+            self.elapsed = self.elapsed.min(total);
+        }
     }
 
     /// Get the current interpolated value
     pub fn value(&self) -> T {
-        let t = if self.duration.as_secs_f32() > 0.0 {
-            self.elapsed.as_secs_f32() / self.duration.as_secs_f32()
+        if self.elapsed < self.delay {
+            return self.start.clone();
+        }
+        let run_time = self.elapsed - self.delay;
+        let duration = self.duration.as_nanos();
+
+        let (run, t) = if self.is_complete() || duration == 0 {
+            (self.repeats.unwrap_or(0), 1.0)
+        } else {
+            let run = run_time.as_nanos() / duration;
+            let within = run_time.as_nanos() % duration;
+            (run as u32, within as f32 / duration as f32)
+        };
+        let t = if self.yoyo && run % 2 == 1 {
+            1.0 - t
         } else {
-            1.0
+            t
         };
 
         let eased = self.easing.apply(t);
         self.start.lerp(&self.end, eased)
     }
 
-    /// Check if animation is complete
+    /// Check if animation is complete, including any repeats
     pub fn is_complete(&self) -> bool {
-        self.elapsed >= self.duration
+        self.total().is_some_and(|total| self.elapsed >= total)
+    }
+
+    /// Delay plus every run, or `None` when repeating forever (or for
+    /// longer than a `Duration` can hold)
+    fn total(&self) -> Option<Duration> {
+        let runs = self.repeats?.checked_add(1)?;
+        self.duration.checked_mul(runs)?.checked_add(self.delay)
     }
 }
 
@@ -69,6 +143,12 @@ impl Animatable for f32 {
     }
 }
 
+impl Animatable for f64 {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        self + (other - self) * t as f64
+    }
+}
+
 impl Animatable for i32 {
     fn lerp(&self, other: &Self, t: f32) -> Self {
         ((*self as f32) + ((*other as f32) - (*self as f32)) * t) as i32
@@ -82,8 +162,15 @@ impl Animatable for u16 {
 }
 
 /// Animation timeline for managing multiple tweens
+///
+/// Advanced by real elapsed time rather than per frame, so animations run
+/// at the same speed however often the app renders. A `max_delta` caps a
+/// single step, so waking up from a suspend or a long stall doesn't jump
+/// every animation to its end.
 pub struct Timeline {
     animations: Vec<Box<dyn Animation>>,
+    elapsed: Duration,
+    max_delta: Option<Duration>,
 }
 
 impl Timeline {
@@ -91,20 +178,46 @@ impl Timeline {
     pub fn new() -> Self {
         Self {
             animations: Vec::new(),
+            elapsed: Duration::ZERO,
+            max_delta: None,
         }
     }
 
+    /// Cap how far a single `update` can move animations (no cap by default)
+    pub fn max_delta(mut self, max: Duration) -> Self {
+        self.max_delta = Some(max);
+        self
+    }
+
+    /// Total time the timeline has advanced, after capping
+    pub fn elapsed(&self) -> Duration {
+        self.elapsed
+    }
+
     /// Add an animation to the timeline
     pub fn add<A: Animation + 'static>(&mut self, animation: A) {
         self.animations.push(Box::new(animation));
     }
 
-    /// Update all animations
+    /// Update all animations by the time since the last update
     pub fn update(&mut self, delta: Duration) {
-        // Update all animations and remove completed ones
+        let delta = match self.max_delta {
+            Some(max) => delta.min(max),
+            None => delta,
+        };
+        self.elapsed += delta;
+
+        // Update all animations and remove completed ones, letting them
+        // know on the way out
         self.animations.retain_mut(|anim| {
             anim.update(delta);
-            !anim.is_complete()
+            if !anim.is_complete() {
+                return true;
+            }
+            if let Some(callback) = anim.take_on_complete() {
+                callback();
+            }
+            false
         });
     }
 
@@ -137,6 +250,13 @@ pub trait Animation: Send + Sync {
 
     /// Check if animation is complete
     fn is_complete(&self) -> bool;
+
+    /// Hand over the completion callback, if there is one
+    ///
+    /// Called by `Timeline` once the animation completes.
+    fn take_on_complete(&mut self) -> Option<OnComplete> {
+        None
+    }
 }
 
 impl<T: Animatable + Send + Sync + 'static> Animation for Tween<T> {
@@ -147,6 +267,10 @@ impl<T: Animatable + Send + Sync + 'static> Animation for Tween<T> {
     fn is_complete(&self) -> bool {
         Tween::is_complete(self)
     }
+
+    fn take_on_complete(&mut self) -> Option<OnComplete> {
+        self.on_complete.take()
+    }
 }
 
 #[cfg(test)]
@@ -178,6 +302,67 @@ mod tests {
         assert!((45..=55).contains(&mid));
     }
 
+    #[test]
+    fn test_delay_holds_at_start() {
+        let mut tween =
+            Tween::new(0.0_f32, 10.0, Duration::from_millis(100)).delay(Duration::from_millis(50));
+
+        tween.update(Duration::from_millis(40));
+        assert_eq!(tween.value(), 0.0);
+        tween.update(Duration::from_millis(60));
+        assert_eq!(tween.value(), 5.0);
+        tween.update(Duration::from_millis(50));
+        assert!(tween.is_complete());
+    }
+
+    #[test]
+    fn test_yoyo_returns_to_start() {
+        let mut tween = Tween::new(0.0_f32, 10.0, Duration::from_millis(100))
+            .repeat(1)
+            .yoyo(true);
+
+        tween.update(Duration::from_millis(100));
+        assert_eq!(tween.value(), 10.0);
+        tween.update(Duration::from_millis(50));
+        assert_eq!(tween.value(), 5.0);
+        tween.update(Duration::from_millis(50));
+        assert_eq!(tween.value(), 0.0);
+        assert!(tween.is_complete());
+    }
+
+    #[test]
+    fn test_repeat_until_count_exhausted() {
+        let mut tween = Tween::new(0.0_f32, 10.0, Duration::from_millis(100)).repeat(2);
+
+        tween.update(Duration::from_millis(150));
+        assert!(!tween.is_complete());
+        assert_eq!(tween.value(), 5.0);
+        tween.update(Duration::from_millis(100));
+        assert!(!tween.is_complete());
+        tween.update(Duration::from_millis(50));
+        assert!(tween.is_complete());
+        assert_eq!(tween.value(), 10.0);
+
+        let mut forever = Tween::new(0.0_f32, 1.0, Duration::from_millis(10)).repeat_forever();
+        forever.update(Duration::from_secs(60));
+        assert!(!forever.is_complete());
+    }
+
+    #[test]
+    fn test_huge_repeat_counts_repeat_forever() {
+        let mut tween = Tween::new(0.0_f32, 10.0, Duration::from_millis(100)).repeat(u32::MAX);
+        tween.update(Duration::from_millis(150));
+        assert!(!tween.is_complete());
+        assert_eq!(tween.value(), 5.0);
+
+        // The runs alone don't fit in a Duration
+        let mut tween = Tween::new(0.0_f32, 10.0, Duration::MAX / 2).repeat(3);
+        tween.update(Duration::from_secs(1));
+        assert!(!tween.is_complete());
+        tween.update(Duration::MAX);
+        assert!(!tween.is_complete());
+    }
+
     #[test]
     fn test_timeline() {
         let mut timeline = Timeline::new();
@@ -195,4 +380,57 @@ mod tests {
 
         assert!(timeline.is_complete());
     }
+
+    #[test]
+    fn test_timeline_runs_on_complete() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let done = Arc::new(AtomicBool::new(false));
+        let mut timeline = Timeline::new();
+        let flag = done.clone();
+        timeline.add(
+            Tween::new(1.0_f32, 0.0, Duration::from_millis(300))
+                .on_complete(move || flag.store(true, Ordering::SeqCst)),
+        );
+
+        timeline.update(Duration::from_millis(200));
+        assert!(!done.load(Ordering::SeqCst));
+        timeline.update(Duration::from_millis(200));
+        assert!(done.load(Ordering::SeqCst));
+        assert!(timeline.is_complete());
+    }
+
+    #[test]
+    fn test_timeline_irregular_deltas() {
+        use std::sync::{Arc, Mutex};
+
+        struct Recorder(Arc<Mutex<Vec<Duration>>>);
+
+        impl Animation for Recorder {
+            fn update(&mut self, delta: Duration) {
+                self.0.lock().unwrap().push(delta);
+            }
+
+            fn is_complete(&self) -> bool {
+                false
+            }
+        }
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let mut timeline = Timeline::new().max_delta(Duration::from_millis(250));
+        timeline.add(Recorder(seen.clone()));
+        let mut tween = Tween::new(0.0_f32, 1.0_f32, Duration::from_secs(1));
+
+        // Uneven frames add up exactly; the 5s stall counts as 250ms
+        for ms in [16, 40, 3, 5000, 0, 41] {
+            timeline.update(Duration::from_millis(ms));
+        }
+        for delta in seen.lock().unwrap().iter() {
+            tween.update(*delta);
+        }
+        assert_eq!(timeline.elapsed(), Duration::from_millis(350));
+        assert_eq!(seen.lock().unwrap()[3], Duration::from_millis(250));
+        assert!((tween.value() - 0.35).abs() < 1e-6);
+    }
 }
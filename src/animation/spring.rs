@@ -0,0 +1,206 @@
+use super::{Animatable, Animation, OnComplete};
+use std::time::Duration;
+
+/// Physics step; `update` runs as many of these as the delta covers
+const STEP: Duration = Duration::from_millis(1);
+
+/// Spring animation that settles on its target instead of running for a
+/// fixed duration
+///
+/// The value is pulled toward the target by a spring with the given
+/// stiffness, slowed by damping, and weighed down by mass. With low damping
+/// it overshoots and wobbles before settling; with high damping it eases in
+/// without overshooting. It's complete once both the distance to the target
+/// and the speed drop below the rest threshold.
+///
+/// Progress is tracked from the start value to the target (0.0 to 1.0) and
+/// mapped through `Animatable::lerp`, so overshoot shows up as a `t` past
+/// 1.0.
+///
+/// ## Example
+/// ```
+/// use rsdrav::animation::Spring;
+/// use std::time::Duration;
+///
+/// let mut scroll = Spring::new(0.0_f32, 40.0).stiffness(200.0).damping(12.0);
+/// while !scroll.is_complete() {
+///     scroll.update(Duration::from_millis(16));
+/// }
+/// assert_eq!(scroll.value(), 40.0);
+/// ```
+pub struct Spring<T> {
+    start: T,
+    target: T,
+    stiffness: f32,
+    damping: f32,
+    mass: f32,
+    rest_threshold: f32,
+    /// Progress from `start` to `target`, and its rate per second
+    position: f32,
+    velocity: f32,
+    /// Time not yet simulated, less than one step
+    pending: Duration,
+    on_complete: Option<OnComplete>,
+}
+
+impl<T: Animatable> Spring<T> {
+    /// Create a spring moving from `start` to `target`
+    ///
+    /// Defaults to stiffness 170, damping 26 and mass 1, which settles
+    /// quickly with barely any overshoot.
+    pub fn new(start: T, target: T) -> Self {
+        Self {
+            start,
+            target,
+            stiffness: 170.0,
+            damping: 26.0,
+            mass: 1.0,
+            rest_threshold: 0.001,
+            position: 0.0,
+            velocity: 0.0,
+            pending: Duration::ZERO,
+            on_complete: None,
+        }
+    }
+
+    /// Set how hard the spring pulls toward the target
+    pub fn stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness.max(0.0);
+        self
+    }
+
+    /// Set how much motion is resisted; lower values wobble longer
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping.max(0.0);
+        self
+    }
+
+    /// Set the mass on the spring; heavier springs move slower
+    pub fn mass(mut self, mass: f32) -> Self {
+        self.mass = mass.max(f32::EPSILON);
+        self
+    }
+
+    /// Set how close to still the spring must be to count as settled,
+    /// as a fraction of the start-to-target distance (default 0.001)
+    pub fn rest_threshold(mut self, threshold: f32) -> Self {
+        self.rest_threshold = threshold.max(0.0);
+        self
+    }
+
+    /// Run `callback` when the spring settles in a `Timeline`
+    pub fn on_complete(mut self, callback: impl FnOnce() + Send + Sync + 'static) -> Self {
+        self.on_complete = Some(Box::new(callback));
+        self
+    }
+
+    /// Aim at a new target, starting from the current value
+    ///
+    /// The motion restarts from rest at the current value.
+    pub fn set_target(&mut self, target: T) {
+        self.start = self.value();
+        self.target = target;
+        self.position = 0.0;
+        self.velocity = 0.0;
+        self.pending = Duration::ZERO;
+    }
+
+    /// Advance the simulation
+    pub fn update(&mut self, delta: Duration) {
+        if self.is_complete() {
+            return;
+        }
+        self.pending += delta;
+        let dt = STEP.as_secs_f32();
+        while self.pending >= STEP {
+            self.pending -= STEP;
+            // Semi-implicit Euler: velocity first, then position with it
+            let force = -self.stiffness * (self.position - 1.0) - self.damping * self.velocity;
+            self.velocity += force / self.mass * dt;
+            self.position += self.velocity * dt;
+
+            if self.at_rest() {
+                self.position = 1.0;
+                self.velocity = 0.0;
+                self.pending = Duration::ZERO;
+                break;
+            }
+        }
+    }
+
+    /// Get the current value, which may be past the target while
+    /// overshooting
+    pub fn value(&self) -> T {
+        self.start.lerp(&self.target, self.position)
+    }
+
+    /// Check if the spring has settled on its target
+    pub fn is_complete(&self) -> bool {
+        self.position == 1.0 && self.velocity == 0.0
+    }
+
+    fn at_rest(&self) -> bool {
+        (1.0 - self.position).abs() < self.rest_threshold
+            && self.velocity.abs() < self.rest_threshold
+    }
+}
+
+impl<T: Animatable + Send + Sync + 'static> Animation for Spring<T> {
+    fn update(&mut self, delta: Duration) {
+        Spring::update(self, delta);
+    }
+
+    fn is_complete(&self) -> bool {
+        Spring::is_complete(self)
+    }
+
+    fn take_on_complete(&mut self) -> Option<OnComplete> {
+        self.on_complete.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animation::Timeline;
+
+    #[test]
+    fn test_underdamped_overshoots_then_settles() {
+        let mut spring = Spring::new(0.0_f32, 100.0).stiffness(170.0).damping(8.0);
+        let mut peak = 0.0_f32;
+        let mut frames = 0;
+
+        while !spring.is_complete() {
+            spring.update(Duration::from_millis(16));
+            peak = peak.max(spring.value());
+            frames += 1;
+            assert!(frames < 1000, "spring never settled");
+        }
+
+        assert!(peak > 110.0, "expected overshoot, peaked at {}", peak);
+        assert_eq!(spring.value(), 100.0);
+    }
+
+    #[test]
+    fn test_heavy_damping_does_not_overshoot() {
+        let mut spring = Spring::new(0.0_f32, 100.0).damping(60.0);
+        for _ in 0..200 {
+            spring.update(Duration::from_millis(16));
+            assert!(spring.value() <= 100.0);
+        }
+        assert!(spring.is_complete());
+    }
+
+    #[test]
+    fn test_runs_in_a_timeline() {
+        let mut timeline = Timeline::new();
+        timeline.add(Spring::new(0_u16, 20));
+
+        timeline.update(Duration::from_millis(50));
+        assert_eq!(timeline.count(), 1);
+        for _ in 0..100 {
+            timeline.update(Duration::from_millis(50));
+        }
+        assert!(timeline.is_complete());
+    }
+}
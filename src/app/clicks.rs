@@ -0,0 +1,89 @@
+//! Double-click recognition
+
+use crate::event::{MouseButton, MouseEvent, MouseEventKind};
+use std::time::Duration;
+
+/// Default longest gap between the two clicks of a double click
+pub(super) const DOUBLE_CLICK_THRESHOLD: Duration = Duration::from_millis(400);
+
+/// Spots a second press of the same button on the same cell soon after
+/// the first
+///
+/// Time comes from the app's frame deltas rather than the clock, so a
+/// replayed event log sees the same double clicks.
+pub(super) struct ClickTracker {
+    pub(super) threshold: Duration,
+    /// Last press that could start a double click, and the time since
+    last: Option<(MouseButton, u16, u16, Duration)>,
+}
+
+impl ClickTracker {
+    pub(super) fn new() -> Self {
+        Self {
+            threshold: DOUBLE_CLICK_THRESHOLD,
+            last: None,
+        }
+    }
+
+    /// Let time pass
+    pub(super) fn advance(&mut self, delta: Duration) {
+        if let Some((.., since)) = &mut self.last {
+            *since += delta;
+        }
+    }
+
+    /// Note a mouse event, returning the double click it completes
+    ///
+    /// The double click is reported once; a third quick press starts over.
+    pub(super) fn register(&mut self, mouse: &MouseEvent) -> Option<MouseEvent> {
+        let MouseEventKind::Down(button) = mouse.kind else {
+            return None;
+        };
+        match self.last.take() {
+            Some((b, x, y, since))
+                if b == button && x == mouse.x && y == mouse.y && since <= self.threshold =>
+            {
+                Some(MouseEvent {
+                    kind: MouseEventKind::DoubleClick(button),
+                    ..*mouse
+                })
+            }
+            _ => {
+                self.last = Some((button, mouse.x, mouse.y, Duration::ZERO));
+                None
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::KeyModifiers;
+
+    fn down(x: u16, y: u16) -> MouseEvent {
+        MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            x,
+            y,
+            modifiers: KeyModifiers::empty(),
+        }
+    }
+
+    #[test]
+    fn test_needs_same_cell_and_quick_succession() {
+        let mut clicks = ClickTracker::new();
+        assert_eq!(clicks.register(&down(3, 4)), None);
+        clicks.advance(Duration::from_millis(500));
+        assert_eq!(clicks.register(&down(3, 4)), None);
+        clicks.advance(Duration::from_millis(100));
+        assert_eq!(clicks.register(&down(5, 4)), None);
+
+        clicks.advance(Duration::from_millis(100));
+        let double = clicks.register(&down(5, 4)).unwrap();
+        assert_eq!(double.kind, MouseEventKind::DoubleClick(MouseButton::Left));
+
+        // A third click is a new first click
+        assert_eq!(clicks.register(&down(5, 4)), None);
+    }
+}
@@ -0,0 +1,251 @@
+//! Keys the app handles once the components have passed on them
+
+use crate::config::Bindings;
+use crate::event::{ChordMatcher, ChordResult, KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// What a key in the `Keymap` does
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Action {
+    Quit,
+    FocusNext,
+    FocusPrev,
+    /// Application-defined, run by the handler registered with
+    /// `Keymap::on_action`
+    Custom(String),
+}
+
+impl Action {
+    /// The action's name in `Bindings` and config files
+    pub fn name(&self) -> &str {
+        match self {
+            Action::Quit => "quit",
+            Action::FocusNext => "focus_next",
+            Action::FocusPrev => "focus_prev",
+            Action::Custom(name) => name,
+        }
+    }
+
+    /// The action with this name; unknown names are custom actions
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "quit" => Action::Quit,
+            "focus_next" => Action::FocusNext,
+            "focus_prev" => Action::FocusPrev,
+            name => Action::Custom(name.to_string()),
+        }
+    }
+}
+
+/// Key to action table the `App` falls back on
+///
+/// The app consults it for key events the root component ignores. Each
+/// key does at most one thing; binding a key again replaces what it did.
+/// Multi-key sequences like `g g` go through a `ChordMatcher` first; while
+/// one is half typed, its keys don't trigger their single-key actions.
+/// The default matches `Bindings::default()`: `q` and Ctrl+C quit, Tab and
+/// Shift+Tab move focus.
+///
+/// ## Example
+/// ```
+/// use rsdrav::app::{Action, Keymap};
+/// use rsdrav::event::{KeyCode, KeyModifiers};
+///
+/// let mut keymap = Keymap::default();
+/// keymap.unbind_action(&Action::Quit);
+/// keymap.bind(KeyCode::Char('q'), KeyModifiers::CONTROL, Action::Quit);
+///
+/// keymap.bind(KeyCode::F(5), KeyModifiers::empty(), Action::Custom("refresh".into()));
+/// keymap.on_action("refresh", || println!("refreshing"));
+/// ```
+pub struct Keymap {
+    /// In the order they were bound, so `to_bindings` keeps each
+    /// action's keys in order
+    keys: Vec<(KeyEvent, Action)>,
+    chords: ChordMatcher,
+    handlers: HashMap<String, Box<dyn FnMut() + Send>>,
+}
+
+impl Keymap {
+    /// A keymap with nothing bound
+    pub fn new() -> Self {
+        Self {
+            keys: Vec::new(),
+            chords: ChordMatcher::new(),
+            handlers: HashMap::new(),
+        }
+    }
+
+    /// A keymap with the keys from `bindings`
+    pub fn from_bindings(bindings: &Bindings) -> Self {
+        let mut keymap = Self::new();
+        keymap.load_bindings(bindings);
+        keymap
+    }
+
+    /// Replace every key with the ones from `bindings`
+    ///
+    /// Handlers for custom actions are kept.
+    pub fn load_bindings(&mut self, bindings: &Bindings) {
+        self.keys.clear();
+        for (name, keys) in bindings.iter() {
+            for key in keys {
+                self.bind(key.code, key.modifiers, Action::from_name(name));
+            }
+        }
+    }
+
+    /// The keys as `Bindings`, e.g. to show in a help screen
+    pub fn to_bindings(&self) -> Bindings {
+        let mut by_action: HashMap<&str, Vec<KeyEvent>> = HashMap::new();
+        for (key, action) in &self.keys {
+            by_action.entry(action.name()).or_default().push(*key);
+        }
+        let mut bindings = Bindings::empty();
+        for (name, keys) in by_action {
+            bindings.bind(name, keys);
+        }
+        bindings
+    }
+
+    /// Make a key do `action`, replacing anything it did before
+    pub fn bind(&mut self, code: KeyCode, modifiers: KeyModifiers, action: Action) {
+        let key = KeyEvent::new(code, modifiers);
+        match self.keys.iter_mut().find(|(bound, _)| *bound == key) {
+            Some((_, bound)) => *bound = action,
+            None => self.keys.push((key, action)),
+        }
+    }
+
+    /// Make a sequence of keys do `action`, e.g. `g g` to go to the top
+    pub fn bind_sequence(&mut self, keys: Vec<KeyEvent>, action: Action) {
+        self.chords.register(keys, action.name());
+    }
+
+    /// Set the longest wait between two keys of a sequence (default 1s)
+    pub fn sequence_timeout(&mut self, timeout: Duration) {
+        self.chords = std::mem::take(&mut self.chords).timeout(timeout);
+    }
+
+    /// Make a key do nothing
+    pub fn unbind(&mut self, code: KeyCode, modifiers: KeyModifiers) {
+        let key = KeyEvent::new(code, modifiers);
+        self.keys.retain(|(bound, _)| *bound != key);
+    }
+
+    /// Remove every key bound to `action`
+    pub fn unbind_action(&mut self, action: &Action) {
+        self.keys.retain(|(_, bound)| bound != action);
+    }
+
+    /// What a key does, if anything
+    pub fn action(&self, code: KeyCode, modifiers: KeyModifiers) -> Option<&Action> {
+        let key = normalize(KeyEvent::new(code, modifiers));
+        self.keys
+            .iter()
+            .find(|(bound, _)| normalize(*bound) == key)
+            .map(|(_, action)| action)
+    }
+
+    /// What a key press does, taking sequences into account
+    ///
+    /// None for keys bound to nothing and for keys that start or continue
+    /// a sequence without finishing it.
+    pub(crate) fn action_for_key(&mut self, key: KeyEvent) -> Option<Action> {
+        let key = normalize(key);
+        match self.chords.feed(key) {
+            ChordResult::Matched(name) => Some(Action::from_name(&name)),
+            ChordResult::Pending => None,
+            ChordResult::Unmatched => self.action(key.code, key.modifiers).cloned(),
+        }
+    }
+
+    /// Let time pass for the sequence timeout
+    pub(crate) fn advance(&mut self, delta: Duration) {
+        self.chords.advance(delta);
+    }
+
+    /// Run `handler` whenever a key bound to `Action::Custom(name)` is
+    /// pressed, replacing any earlier handler for it
+    pub fn on_action(&mut self, name: impl Into<String>, handler: impl FnMut() + Send + 'static) {
+        self.handlers.insert(name.into(), Box::new(handler));
+    }
+
+    /// Run the handler for a custom action, returning whether it had one
+    pub(crate) fn run_custom(&mut self, name: &str) -> bool {
+        match self.handlers.get_mut(name) {
+            Some(handler) => {
+                handler();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Self::from_bindings(&Bindings::default())
+    }
+}
+
+/// Terminals report Shift+Tab as BackTab, some with SHIFT set and some
+/// without, so BackTab matches whether or not SHIFT is held
+fn normalize(mut key: KeyEvent) -> KeyEvent {
+    if key.code == KeyCode::BackTab {
+        key.modifiers.remove(KeyModifiers::SHIFT);
+    }
+    key
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matches_bindings() {
+        let keymap = Keymap::default();
+        assert_eq!(
+            keymap.action(KeyCode::Char('c'), KeyModifiers::CONTROL),
+            Some(&Action::Quit)
+        );
+        assert_eq!(
+            keymap.action(KeyCode::BackTab, KeyModifiers::empty()),
+            Some(&Action::FocusPrev)
+        );
+        assert_eq!(keymap.to_bindings(), Bindings::default());
+    }
+
+    #[test]
+    fn test_backtab_ignores_shift() {
+        let mut keymap = Keymap::default();
+        assert_eq!(
+            keymap.action(KeyCode::BackTab, KeyModifiers::SHIFT),
+            Some(&Action::FocusPrev)
+        );
+        assert_eq!(
+            keymap.action_for_key(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT)),
+            Some(Action::FocusPrev)
+        );
+    }
+
+    #[test]
+    fn test_rebinding_replaces_the_key() {
+        let mut keymap = Keymap::default();
+        keymap.bind(
+            KeyCode::Char('q'),
+            KeyModifiers::empty(),
+            Action::Custom("search".into()),
+        );
+        keymap.unbind(KeyCode::Tab, KeyModifiers::empty());
+
+        let bindings = keymap.to_bindings();
+        assert_eq!(
+            bindings.keys("quit"),
+            [KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)]
+        );
+        assert_eq!(bindings.keys("search").len(), 1);
+        assert!(bindings.keys("focus_next").is_empty());
+    }
+}
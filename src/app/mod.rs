@@ -1,16 +1,60 @@
+mod clicks;
+mod keymap;
+mod modal_stack;
+mod poller;
+mod progress;
+#[cfg(feature = "tokio")]
+mod prompt;
+
+pub use keymap::{Action, Keymap};
+pub use modal_stack::ModalStack;
+pub use poller::Poller;
+pub use progress::ProgressReporter;
+
 use crate::animation::Timeline;
-use crate::error::Result;
-use crate::event::{Event, KeyCode, KeyModifiers};
-use crate::focus::FocusManager;
-use crate::layout::Rect;
+use crate::config::{Bindings, Config};
+use crate::error::{Error, Result};
+use crate::event::{Event, KeyCode, KeyEvent, KeyModifiers, MouseEventKind};
+use crate::focus::{ComponentId, FocusManager};
+use crate::layout::{LayoutCache, Rect};
 use crate::render::{Backend, Buffer, Renderer};
-use crate::state::Store;
-use crate::view::{Component, EventContext, MountContext, RenderContext, UpdateContext};
+use crate::state::{Signal, Store};
+use crate::theme::{Modifier, Theme};
+use crate::view::{
+    Component, ContainerDirection, EventContext, MountContext, RenderBudget, RenderContext,
+    RenderMode, UpdateContext, ViewNode, BELL_REQUEST_KEY, CLOSE_MODAL_REQUEST_KEY,
+    FRAME_STATS_KEY, REDRAW_REQUEST_KEY,
+};
+use std::cell::RefCell;
 use std::time::{Duration, Instant};
 
 #[cfg(feature = "tokio")]
 use crate::async_support::AsyncRuntime;
 
+/// Target frame time (~60 FPS)
+const FRAME_TIME: Duration = Duration::from_millis(16);
+
+/// Longest step animations take in one frame, so they don't leap ahead
+/// after the process was suspended or stalled
+const MAX_ANIMATION_DELTA: Duration = Duration::from_millis(250);
+
+/// Bells closer together than this are merged into one
+const BELL_DEBOUNCE: Duration = Duration::from_millis(100);
+
+/// An event the app processed, tagged with the frame it arrived in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LoggedEvent {
+    pub frame: u64,
+    pub event: Event,
+}
+
+/// Callback fired once after a stretch without user input
+struct IdleHandler {
+    after: Duration,
+    fired: bool,
+    callback: Box<dyn FnMut() + Send>,
+}
+
 pub struct App {
     backend: Box<dyn Backend>,
     buffer: Buffer,
@@ -18,10 +62,41 @@ pub struct App {
     renderer: Renderer,
     should_quit: bool,
     root: Option<Box<dyn Component>>,
+    modals: ModalStack,
     store: Store,
     focus: FocusManager,
+    keymap: Keymap,
+    /// Focus as last reported to the component tree
+    last_focus: Option<ComponentId>,
+    theme: Theme,
+    render_mode: RenderMode,
     timeline: Timeline,
     last_tick: Instant,
+    frame: u64,
+    mounted: bool,
+    event_log: Option<Vec<LoggedEvent>>,
+    /// Time since the last user input (advanced by frame deltas)
+    idle_time: Duration,
+    idle_handlers: Vec<IdleHandler>,
+    layout_cache: RefCell<LayoutCache>,
+    pollers: Vec<Box<dyn poller::PollTask>>,
+    visual_bell: bool,
+    focus_follows_mouse: bool,
+    clicks: clicks::ClickTracker,
+    /// Invert the next rendered frame
+    flash_pending: bool,
+    /// The last frame was inverted, so the next one has to draw it back
+    restore_pending: bool,
+    /// Time left before another bell is allowed
+    bell_cooldown: Duration,
+    frame_budget: Option<Duration>,
+    render_budget: Option<Duration>,
+    /// Low-priority subtrees were skipped last frame; draw them next
+    catch_up: bool,
+    error_hook: Option<Box<dyn FnMut(&Error) + Send>>,
+    resize_handlers: Vec<Box<dyn FnMut(u16, u16) + Send>>,
+    /// View tree from the last rendered frame and the area it filled
+    last_tree: Option<(ViewNode, Rect)>,
     #[cfg(feature = "tokio")]
     async_runtime: Option<AsyncRuntime>,
 }
@@ -41,25 +116,54 @@ impl App {
         #[cfg(not(any(feature = "crossterm", feature = "termion")))]
         compile_error!("No backend feature enabled! Enable 'crossterm' or 'termion'");
 
+        Ok(Self::with_backend(backend))
+    }
+
+    /// Create an app on a specific backend (e.g. `TestBackend` in tests)
+    pub fn with_backend(backend: Box<dyn Backend>) -> Self {
         // Start with a default size, will resize on first frame
         let buffer = Buffer::new(80, 24);
         let prev_buffer = Buffer::new(80, 24);
         let renderer = Renderer::new();
 
-        Ok(Self {
+        Self {
             backend,
             buffer,
             prev_buffer,
             renderer,
             should_quit: false,
             root: None,
+            modals: ModalStack::new(),
             store: Store::new(),
             focus: FocusManager::new(),
-            timeline: Timeline::new(),
+            keymap: Keymap::default(),
+            last_focus: None,
+            theme: Theme::default(),
+            render_mode: RenderMode::Unicode,
+            timeline: Timeline::new().max_delta(MAX_ANIMATION_DELTA),
             last_tick: Instant::now(),
+            frame: 0,
+            mounted: false,
+            event_log: None,
+            idle_time: Duration::ZERO,
+            idle_handlers: Vec::new(),
+            layout_cache: RefCell::new(LayoutCache::new()),
+            pollers: Vec::new(),
+            visual_bell: false,
+            focus_follows_mouse: false,
+            clicks: clicks::ClickTracker::new(),
+            flash_pending: false,
+            restore_pending: false,
+            bell_cooldown: Duration::ZERO,
+            frame_budget: None,
+            render_budget: None,
+            catch_up: false,
+            error_hook: None,
+            resize_handlers: Vec::new(),
+            last_tree: None,
             #[cfg(feature = "tokio")]
             async_runtime: None,
-        })
+        }
     }
 
     /// Set the root component for the app
@@ -68,6 +172,90 @@ impl App {
         self
     }
 
+    /// Show a modal over everything else, including other modals
+    ///
+    /// While any modal is open only the topmost one gets events, and
+    /// focus is taken away from the components underneath: the modal gets
+    /// its own focus scope, so Tab only moves between components
+    /// registered while it's on top. Escape (if the modal doesn't handle
+    /// it) or `pop_modal` closes it.
+    pub fn push_modal(&mut self, component: impl Component + 'static) {
+        let mut component: Box<dyn Component> = Box::new(component);
+        let mut mount_ctx = MountContext {
+            store: &mut self.store,
+        };
+        component.mount(&mut mount_ctx);
+
+        self.modals.push(component);
+        self.focus.push_scope();
+        self.store.set(REDRAW_REQUEST_KEY, true);
+    }
+
+    /// Close the topmost modal, unregistering the components in its focus
+    /// scope and giving focus back to whatever had it before that modal
+    /// opened. Returns false if none was open.
+    pub fn pop_modal(&mut self) -> bool {
+        let Some(mut component) = self.modals.pop() else {
+            return false;
+        };
+        let mut mount_ctx = MountContext {
+            store: &mut self.store,
+        };
+        component.unmount(&mut mount_ctx);
+
+        self.focus.pop_scope();
+        self.store.set(REDRAW_REQUEST_KEY, true);
+        true
+    }
+
+    /// Open modals, bottom to top
+    pub fn modals(&self) -> &ModalStack {
+        &self.modals
+    }
+
+    /// Show a confirmation dialog and get a future of the answer
+    ///
+    /// Resolves to `true` if the user confirms, `false` if they decline
+    /// or the dialog is closed any other way (Escape, `pop_modal`, quit).
+    /// The future doesn't borrow the app, so async work can hold on to it
+    /// and pause until the user has answered.
+    ///
+    /// ## Example
+    /// ```no_run
+    /// use rsdrav::prelude::*;
+    ///
+    /// let mut app = App::new()?;
+    /// let answer = app.prompt(ConfirmDialog::new("Overwrite the file?"));
+    /// rsdrav::async_support::spawn_task(async move {
+    ///     if answer.await {
+    ///         // write it
+    ///     }
+    /// });
+    /// # Ok::<(), rsdrav::Error>(())
+    /// ```
+    #[cfg(feature = "tokio")]
+    pub fn prompt(
+        &mut self,
+        dialog: crate::view::ConfirmDialog,
+    ) -> impl std::future::Future<Output = bool> + Send + 'static {
+        let answer = self.prompt_dialog(dialog);
+        async move { answer.await.unwrap_or(false) }
+    }
+
+    /// Show any `Dialog` as a modal and get a future of its result
+    ///
+    /// Resolves to `None` if the modal is closed before the dialog
+    /// produced a result.
+    #[cfg(feature = "tokio")]
+    pub fn prompt_dialog<D: crate::view::Dialog + 'static>(
+        &mut self,
+        dialog: D,
+    ) -> impl std::future::Future<Output = Option<D::Output>> + Send + 'static {
+        let (layer, receiver) = prompt::PromptLayer::new(dialog);
+        self.push_modal(layer);
+        async move { receiver.await.ok() }
+    }
+
     /// Get access to the store for registering signals
     pub fn store(&self) -> &Store {
         &self.store
@@ -88,7 +276,90 @@ impl App {
         &mut self.focus
     }
 
+    /// Use a custom theme
+    pub fn with_theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self.store.set(REDRAW_REQUEST_KEY, true);
+        self
+    }
+
+    /// Draw with Unicode symbols (the default) or plain ASCII
+    ///
+    /// Use `RenderMode::detect()` to pick based on the locale.
+    pub fn render_mode(mut self, mode: RenderMode) -> Self {
+        self.render_mode = mode;
+        self.store.set(REDRAW_REQUEST_KEY, true);
+        self
+    }
+
+    /// Get access to the theme
+    pub fn theme(&self) -> &Theme {
+        &self.theme
+    }
+
+    /// Get mutable access to the theme
+    ///
+    /// The next frame is redrawn with whatever was changed.
+    pub fn theme_mut(&mut self) -> &mut Theme {
+        self.store.set(REDRAW_REQUEST_KEY, true);
+        &mut self.theme
+    }
+
+    /// Key bindings for the app's actions, as currently in the keymap
+    pub fn bindings(&self) -> Bindings {
+        self.keymap.to_bindings()
+    }
+
+    /// Replace the key bindings
+    pub fn with_bindings(mut self, bindings: Bindings) -> Self {
+        self.keymap.load_bindings(&bindings);
+        self
+    }
+
+    /// Get the keys the app handles when the root component doesn't
+    pub fn keymap(&self) -> &Keymap {
+        &self.keymap
+    }
+
+    /// Get mutable access to the keymap, to rebind keys or add actions
+    pub fn keymap_mut(&mut self) -> &mut Keymap {
+        &mut self.keymap
+    }
+
+    /// Apply a user config's bindings and theme
+    ///
+    /// A section that fails to apply (an unknown key name, a bad color) is
+    /// reported through `on_error` and left at its current value.
+    pub fn apply_config(&mut self, config: &Config) {
+        match config.bindings() {
+            Ok(bindings) => self.keymap.load_bindings(&bindings),
+            Err(e) => self.report_error(&e),
+        }
+        match config.theme() {
+            Ok(theme) => {
+                self.theme = theme;
+                self.store.set(REDRAW_REQUEST_KEY, true);
+            }
+            Err(e) => self.report_error(&e),
+        }
+    }
+
+    /// Parse a JSON config and apply it
+    ///
+    /// A config that doesn't parse is reported through `on_error` and the
+    /// app keeps its defaults.
+    #[cfg(feature = "serde")]
+    pub fn load_config(&mut self, source: &str) {
+        match Config::from_json(source) {
+            Ok(config) => self.apply_config(&config),
+            Err(e) => self.report_error(&e),
+        }
+    }
+
     /// Get access to the animation timeline
+    ///
+    /// It advances by the real time between frames, whether or not a frame
+    /// renders, with each step capped at 250ms.
     pub fn timeline(&self) -> &Timeline {
         &self.timeline
     }
@@ -98,6 +369,184 @@ impl App {
         &mut self.timeline
     }
 
+    /// Set the terminal window title
+    ///
+    /// Control characters in the title are stripped.
+    pub fn set_title(&mut self, title: &str) -> Result<()> {
+        self.backend.set_title(title)
+    }
+
+    /// Record every processed event so the session can be replayed later
+    pub fn with_event_log(mut self) -> Self {
+        self.event_log = Some(Vec::new());
+        self
+    }
+
+    /// Events recorded so far (empty unless `with_event_log` was used)
+    pub fn event_log(&self) -> &[LoggedEvent] {
+        self.event_log.as_deref().unwrap_or(&[])
+    }
+
+    /// Flash the screen instead of beeping on `bell()`
+    pub fn visual_bell(mut self, visual: bool) -> Self {
+        self.visual_bell = visual;
+        self
+    }
+
+    /// Focus whatever focusable component the mouse moves over
+    ///
+    /// Hit-tests the rects components reported to the `FocusManager` on
+    /// the last frame. Moving over anything else leaves focus alone.
+    pub fn focus_follows_mouse(mut self, enabled: bool) -> Self {
+        self.focus_follows_mouse = enabled;
+        self
+    }
+
+    /// Set the longest gap between two presses that makes them a
+    /// `MouseEventKind::DoubleClick` (default 400ms)
+    pub fn double_click_threshold(mut self, threshold: Duration) -> Self {
+        self.clicks.threshold = threshold;
+        self
+    }
+
+    /// Ring the terminal bell
+    ///
+    /// Beeps (`BEL`), or with `visual_bell` set, shows the next frame with
+    /// inverted colors. Bells within 100ms of each other count as one.
+    /// Widgets can request one with `EventContext::bell`.
+    pub fn bell(&mut self) -> Result<()> {
+        if !self.bell_cooldown.is_zero() {
+            return Ok(());
+        }
+        self.bell_cooldown = BELL_DEBOUNCE;
+
+        if self.visual_bell {
+            self.flash_pending = true;
+            Ok(())
+        } else {
+            self.backend.write(b"\x07")?;
+            self.backend.flush()
+        }
+    }
+
+    /// Warn (through `on_error`) about frames that take longer than `budget`
+    ///
+    /// Only the frame's own work is timed - waiting for input and the
+    /// frame-pacing sleep don't count. A stuck frame can't be interrupted,
+    /// but this at least tells you which frame was slow.
+    pub fn with_frame_budget(mut self, budget: Duration) -> Self {
+        self.frame_budget = Some(budget);
+        self
+    }
+
+    /// Let frames that run past `budget` skip their low-priority subtrees
+    ///
+    /// Subtrees marked with `ViewNode::low_priority` that come up after
+    /// the deadline keep last frame's content, and the next frame renders
+    /// everything with no deadline so they catch up. The budget is
+    /// measured from the start of the frame.
+    pub fn with_render_budget(mut self, budget: Duration) -> Self {
+        self.render_budget = Some(budget);
+        self
+    }
+
+    /// Hook for non-fatal problems, like `Error::SlowFrame`
+    pub fn on_error(&mut self, hook: impl FnMut(&Error) + Send + 'static) {
+        self.error_hook = Some(Box::new(hook));
+    }
+
+    /// Terminal size (width, height) as of the last frame
+    ///
+    /// 80x24 until the first frame has run.
+    pub fn dimensions(&self) -> (u16, u16) {
+        (self.buffer.width, self.buffer.height)
+    }
+
+    /// The last rendered view tree, one node per line with its type, area
+    /// and content, indented by depth
+    ///
+    /// For debugging layout; empty before the first frame. Very deep trees
+    /// are cut off at `DEBUG_TREE_MAX_DEPTH`.
+    pub fn debug_tree(&self) -> String {
+        match self.last_tree {
+            Some((ref tree, area)) => tree.debug_tree(area),
+            None => String::new(),
+        }
+    }
+
+    /// Run a callback with the new (width, height) whenever the terminal
+    /// size changes, before that frame renders
+    ///
+    /// Also called on the first frame with the initial size.
+    pub fn on_resize(&mut self, callback: impl FnMut(u16, u16) + Send + 'static) {
+        self.resize_handlers.push(Box::new(callback));
+    }
+
+    /// Fetch data every `interval` and store it in `target`
+    ///
+    /// The fetch runs on the UI thread between frames, so keep it quick -
+    /// use `add_poller` with `Poller::background` for anything blocking.
+    pub fn poll<T: Clone + Send + Sync + 'static>(
+        &mut self,
+        interval: Duration,
+        target: Signal<T>,
+        fetch: impl FnMut() -> T + Send + 'static,
+    ) {
+        self.add_poller(Poller::new(interval, target, fetch));
+    }
+
+    /// Drive a configured poller from the frame clock
+    pub fn add_poller<T: Clone + Send + Sync + 'static>(&mut self, poller: Poller<T>) {
+        self.pollers.push(Box::new(poller));
+    }
+
+    /// Get a reporter that background work can use to drive `progress`
+    /// (e.g. a `ProgressBar`'s signal) and optionally a status `message`
+    ///
+    /// Updates are applied at the start of each frame.
+    pub fn progress_reporter(
+        &mut self,
+        progress: Signal<f32>,
+        message: Option<Signal<String>>,
+    ) -> ProgressReporter {
+        let (reporter, task) = progress::progress_channel(progress, message);
+        self.pollers.push(Box::new(task));
+        reporter
+    }
+
+    /// Run a callback once the user has been idle for `after`
+    ///
+    /// Fires once per idle stretch - any key, mouse or paste event resets
+    /// the timer. Animations and resizes don't count as activity.
+    pub fn on_idle(&mut self, after: Duration, callback: impl FnMut() + Send + 'static) {
+        self.idle_handlers.push(IdleHandler {
+            after,
+            fired: false,
+            callback: Box::new(callback),
+        });
+    }
+
+    /// Forget a component's cached layout so it gets re-measured, and
+    /// render the next frame
+    ///
+    /// Call this when something that affects its child sizes changed
+    /// outside what the root's `update` reports.
+    pub fn invalidate_layout(&mut self, id: ComponentId) {
+        self.layout_cache.get_mut().invalidate(id);
+        self.store.set(REDRAW_REQUEST_KEY, true);
+    }
+
+    /// Index of the next frame to run
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+
+    /// The most recently rendered frame
+    pub fn last_frame(&self) -> &Buffer {
+        // Buffers get swapped after each render
+        &self.prev_buffer
+    }
+
     /// Enable async support (requires tokio feature)
     #[cfg(feature = "tokio")]
     pub fn with_async(mut self) -> Result<Self> {
@@ -105,20 +554,25 @@ impl App {
         Ok(self)
     }
 
+    /// Run the event loop until quit
+    ///
+    /// Fails with `Error::NotATerminal` straight away if stdin or stdout is
+    /// redirected (piped input, output to a file, CI), instead of erroring
+    /// out of raw mode or blocking on input that never comes.
     pub fn run(mut self) -> Result<()> {
+        if !self.backend.is_terminal() {
+            return Err(Error::NotATerminal);
+        }
+
         // Setup terminal
         self.backend.enter_raw_mode()?;
         self.backend.enter_alt_screen()?;
+        self.backend.enable_focus_reporting()?;
         self.backend.cursor_hide()?;
         self.backend.clear()?;
 
         // Mount the root component if present
-        if let Some(ref mut root) = self.root {
-            let mut mount_ctx = MountContext {
-                store: &mut self.store,
-            };
-            root.mount(&mut mount_ctx);
-        }
+        self.ensure_mounted();
 
         // Install panic hook to restore terminal
         // This is important - if we panic without cleanup, the terminal stays messed up
@@ -130,6 +584,7 @@ impl App {
                 let _ = crossterm::terminal::disable_raw_mode();
                 let _ = crossterm::execute!(
                     std::io::stdout(),
+                    crossterm::event::DisableFocusChange,
                     crossterm::terminal::LeaveAlternateScreen,
                     crossterm::cursor::Show
                 );
@@ -138,45 +593,22 @@ impl App {
         }));
 
         // Main loop
-        let tick_rate = Duration::from_millis(16); // ~60 FPS
         while !self.should_quit {
             let frame_start = Instant::now();
 
-            // Check terminal size and resize buffer if needed
-            let (w, h) = self.backend.size()?;
-            if self.buffer.width != w || self.buffer.height != h {
-                self.buffer.resize(w, h);
-                self.prev_buffer.resize(w, h);
-            }
-
-            // Update animations
-            let delta = frame_start.duration_since(self.last_tick);
-            self.timeline.update(delta);
-            self.last_tick = frame_start;
-
-            // Poll for events
-            if let Some(event) = self.backend.read_event(tick_rate)? {
-                self.handle_event(event)?;
-            }
-
-            // Render frame
-            if self.root.is_some() {
-                self.render_component_frame()?;
-            } else {
-                // Fallback to test pattern if no root component
-                self.render_test_frame()?;
-            }
+            self.tick()?;
 
             // Sleep to maintain frame rate
             let elapsed = frame_start.elapsed();
-            if elapsed < tick_rate {
-                std::thread::sleep(tick_rate - elapsed);
+            if elapsed < FRAME_TIME {
+                std::thread::sleep(FRAME_TIME - elapsed);
             }
         }
 
         // Cleanup
+        while self.pop_modal() {}
         // Unmount root component
-        if let Some(ref mut root) = self.root {
+        if let Some(ref mut root) = self.root.as_mut().filter(|_| self.mounted) {
             let mut mount_ctx = MountContext {
                 store: &mut self.store,
             };
@@ -187,7 +619,256 @@ impl App {
         Ok(())
     }
 
+    /// Run a single frame: poll one event, update animations, render
+    ///
+    /// `run` calls this in a loop. Handy for driving the app by hand in tests.
+    pub fn tick(&mut self) -> Result<()> {
+        let now = Instant::now();
+        let delta = now.duration_since(self.last_tick);
+        self.last_tick = now;
+
+        let event = self.backend.read_event(FRAME_TIME)?;
+        self.step(delta, event)
+    }
+
+    /// Feed a recorded event log back through the app
+    ///
+    /// Each frame advances time by a fixed 16ms so animations play out the
+    /// same way every run. Frames without events are still ticked.
+    pub fn replay(&mut self, log: &[LoggedEvent]) -> Result<()> {
+        self.replay_with_delta(log, FRAME_TIME)
+    }
+
+    /// Like `replay`, with a custom per-frame time step
+    pub fn replay_with_delta(&mut self, log: &[LoggedEvent], delta: Duration) -> Result<()> {
+        let Some(last) = log.last() else {
+            return Ok(());
+        };
+
+        let mut events = log.iter().peekable();
+        for frame in 0..=last.frame {
+            if self.should_quit {
+                break;
+            }
+
+            // Only one event per frame, same as the live loop
+            let event = events
+                .next_if(|logged| logged.frame <= frame)
+                .map(|logged| logged.event.clone());
+            self.step(delta, event)?;
+        }
+        Ok(())
+    }
+
+    /// One frame with an explicit time step and (optional) event
+    fn step(&mut self, delta: Duration, event: Option<Event>) -> Result<()> {
+        let frame_start = Instant::now();
+        self.ensure_mounted();
+
+        // Check terminal size and resize buffer if needed
+        let (w, h) = self.backend.size()?;
+        let resized = self.buffer.width != w || self.buffer.height != h;
+        if resized {
+            self.buffer.resize(w, h);
+            self.prev_buffer.resize(w, h);
+        }
+        if resized || self.frame == 0 {
+            for handler in &mut self.resize_handlers {
+                handler(w, h);
+            }
+        }
+        self.layout_cache.get_mut().set_screen_size(w, h);
+
+        // Update animations - every frame, even ones that end up not
+        // rendering, so they keep wall-clock pace
+        self.timeline.update(delta);
+        self.bell_cooldown = self.bell_cooldown.saturating_sub(delta);
+
+        for poller in &mut self.pollers {
+            poller.tick(delta);
+        }
+        self.clicks.advance(delta);
+        self.keymap.advance(delta);
+
+        if let Some(event) = event {
+            if matches!(event, Event::Key(_) | Event::Mouse(_) | Event::Paste(_)) {
+                self.idle_time = Duration::ZERO;
+                for handler in &mut self.idle_handlers {
+                    handler.fired = false;
+                }
+            }
+
+            if let Some(ref mut log) = self.event_log {
+                log.push(LoggedEvent {
+                    frame: self.frame,
+                    event: event.clone(),
+                });
+            }
+            let double_click = match &event {
+                Event::Mouse(mouse) => self.clicks.register(mouse),
+                _ => None,
+            };
+            self.handle_event(event)?;
+            if let Some(double_click) = double_click {
+                self.handle_event(Event::Mouse(double_click))?;
+            }
+
+            if self.store.remove(BELL_REQUEST_KEY) {
+                self.bell()?;
+            }
+        }
+
+        self.check_idle(delta);
+        let focus_moved = self.sync_focus();
+
+        // Render frame, unless the root says nothing changed and nothing
+        // else forces one
+        let redraw_requested = self.store.remove(REDRAW_REQUEST_KEY);
+        let forced = self.frame == 0
+            || resized
+            || focus_moved
+            || redraw_requested
+            || self.flash_pending
+            || std::mem::take(&mut self.restore_pending)
+            || self.catch_up;
+        if self.root.is_some() {
+            // Components' own cached layouts can't outlive a change in the
+            // tree; container node layouts don't depend on it
+            let dirty = self.root_needs_update(delta) || redraw_requested;
+            if dirty {
+                self.layout_cache.get_mut().invalidate_components();
+            }
+            if dirty || forced {
+                // A frame that follows a deferral draws everything
+                let deadline = match self.render_budget {
+                    Some(budget) if !self.catch_up => Some(frame_start + budget),
+                    _ => None,
+                };
+                self.render_component_frame(deadline)?;
+                self.layout_cache.get_mut().end_frame();
+                self.publish_frame_stats(frame_start.elapsed(), delta);
+            }
+        } else {
+            // Fallback to test pattern if no root component
+            self.render_test_frame()?;
+        }
+
+        self.check_frame_budget(frame_start.elapsed());
+        self.frame += 1;
+        Ok(())
+    }
+
+    /// Put the renderer's stats for this frame in the store for widgets
+    /// like `StatsOverlay`, with whole-frame timing filled in
+    fn publish_frame_stats(&mut self, frame_time: Duration, interval: Duration) {
+        if let Some(mut stats) = self.renderer.last_frame_stats() {
+            stats.frame_time = frame_time;
+            stats.interval = interval;
+            self.store.set(FRAME_STATS_KEY, stats);
+        }
+    }
+
+    fn check_frame_budget(&mut self, elapsed: Duration) {
+        let Some(budget) = self.frame_budget else {
+            return;
+        };
+        if elapsed > budget {
+            self.report_error(&Error::SlowFrame {
+                frame: self.frame,
+                elapsed,
+                budget,
+            });
+        }
+    }
+
+    /// Pass a non-fatal error to the `on_error` hook, if there is one
+    fn report_error(&mut self, error: &Error) {
+        if let Some(ref mut hook) = self.error_hook {
+            hook(error);
+        }
+    }
+
+    fn check_idle(&mut self, delta: Duration) {
+        self.idle_time += delta;
+        for handler in &mut self.idle_handlers {
+            if !handler.fired && self.idle_time >= handler.after {
+                handler.fired = true;
+                (handler.callback)();
+            }
+        }
+    }
+
+    /// Ask the root whether it needs re-rendering (`Component::update`)
+    ///
+    /// Always called, even when the frame is forced anyway, so components
+    /// can rely on it running once per frame.
+    fn root_needs_update(&mut self, delta: Duration) -> bool {
+        let Some(ref mut root) = self.root else {
+            return false;
+        };
+        let mut update_ctx = UpdateContext {
+            store: &self.store,
+            delta,
+        };
+        let mut needs_update = root.update(&mut update_ctx);
+        for modal in self.modals.iter_mut() {
+            needs_update |= modal.update(&mut update_ctx);
+        }
+        needs_update
+    }
+
+    /// Tell the tree about focus changes since the last frame
+    ///
+    /// Focus can move through Tab handling or through `focus_mut()`, so
+    /// this compares against what was last delivered rather than hooking
+    /// each of those. Returns whether focus moved.
+    fn sync_focus(&mut self) -> bool {
+        let current = self.focus.current();
+        if current == self.last_focus {
+            return false;
+        }
+        let previous = std::mem::replace(&mut self.last_focus, current);
+
+        if let Some(ref mut root) = self.root {
+            let area = Rect::new(0, 0, self.buffer.width, self.buffer.height);
+            let mut event_ctx = EventContext {
+                store: &mut self.store,
+                area,
+            };
+            root.focus_changed(previous, current, &mut event_ctx);
+            for modal in self.modals.iter_mut() {
+                modal.focus_changed(previous, current, &mut event_ctx);
+            }
+        }
+        true
+    }
+
+    fn ensure_mounted(&mut self) {
+        if self.mounted {
+            return;
+        }
+        if let Some(ref mut root) = self.root {
+            let mut mount_ctx = MountContext {
+                store: &mut self.store,
+            };
+            root.mount(&mut mount_ctx);
+        }
+        self.mounted = true;
+    }
+
     fn handle_event(&mut self, event: Event) -> Result<()> {
+        if !self.modals.is_empty() {
+            return self.handle_modal_event(event);
+        }
+
+        if let Event::Mouse(mouse) = event {
+            if self.focus_follows_mouse && mouse.kind == MouseEventKind::Moved {
+                if let Some(id) = self.focus.focusable_at(mouse.x, mouse.y) {
+                    self.focus.focus(id);
+                }
+            }
+        }
+
         // Give root component first chance to handle the event
         if let Some(ref mut root) = self.root {
             let w = self.buffer.width;
@@ -210,41 +891,63 @@ impl App {
             }
         }
 
-        // Handle focus navigation with Tab/Shift+Tab
-        match event {
-            Event::Key(key) => match key.code {
-                KeyCode::Tab => {
-                    if key.modifiers.contains(KeyModifiers::SHIFT) {
-                        self.focus.focus_prev();
-                    } else {
-                        self.focus.focus_next();
-                    }
-                    return Ok(());
-                }
-                _ => {}
-            },
-            _ => {}
+        if let Event::Key(key) = event {
+            self.run_key_action(key);
         }
+        Ok(())
+    }
 
-        // Default event handling - quit on 'q' or Ctrl+C
-        match event {
-            Event::Key(key) => match key.code {
-                KeyCode::Char('q') => {
-                    self.should_quit = true;
-                }
-                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    self.should_quit = true;
-                }
-                _ => {}
-            },
-            _ => {}
+    /// Default handling from the keymap: focus navigation (Tab/Shift+Tab)
+    /// and quit ('q' or Ctrl+C), unless it's been rebound
+    fn run_key_action(&mut self, key: KeyEvent) {
+        match self.keymap.action_for_key(key) {
+            Some(Action::FocusPrev) => {
+                self.focus.focus_prev();
+            }
+            Some(Action::FocusNext) => {
+                self.focus.focus_next();
+            }
+            Some(Action::Quit) => self.should_quit = true,
+            Some(Action::Custom(name)) => {
+                self.keymap.run_custom(&name);
+            }
+            None => {}
+        }
+    }
+
+    /// With modals open, the topmost one gets the event and no other
+    /// component does. Escape it ignores closes it; other keys it ignores
+    /// go to the keymap, so Tab moves focus within its scope and Ctrl+C
+    /// still quits
+    fn handle_modal_event(&mut self, event: Event) -> Result<()> {
+        let area = Rect::new(0, 0, self.buffer.width, self.buffer.height);
+        let Some(modal) = self.modals.top_mut() else {
+            return Ok(());
+        };
+        let mut event_ctx = EventContext {
+            store: &mut self.store,
+            area,
+        };
+
+        use crate::event::EventResult;
+        let result = modal.handle_event(&event, &mut event_ctx);
+        if self.store.remove(CLOSE_MODAL_REQUEST_KEY) {
+            self.pop_modal();
+        } else if let (EventResult::Ignored, Event::Key(key)) = (result, &event) {
+            if key.code == KeyCode::Esc {
+                self.pop_modal();
+            } else {
+                self.run_key_action(*key);
+            }
         }
         Ok(())
     }
 
-    fn render_component_frame(&mut self) -> Result<()> {
+    fn render_component_frame(&mut self, deadline: Option<Instant>) -> Result<()> {
         // Clear buffer
         self.buffer.clear();
+        let focus_rects = RefCell::new(Vec::new());
+        let budget = deadline.map(RenderBudget::new);
 
         if let Some(ref root) = self.root {
             let w = self.buffer.width;
@@ -252,12 +955,44 @@ impl App {
             let area = Rect::new(0, 0, w, h);
 
             // Render component to view tree
-            let render_ctx = RenderContext::new(&mut self.buffer, area, &self.store);
-            let view_tree = root.render(&render_ctx);
+            let mut render_ctx = RenderContext::new(&mut self.buffer, area, &self.store);
+            render_ctx.focused = self.focus.current();
+            render_ctx.theme = self.theme;
+            render_ctx.mode = self.render_mode;
+            render_ctx.layout_cache = Some(&self.layout_cache);
+            render_ctx.focus_rects = Some(&focus_rects);
+            let mut view_tree = render_ctx.render_child(root.as_ref());
+            if !self.modals.is_empty() {
+                // Modals draw over the root, newest last (on top)
+                let layers = std::iter::once(view_tree)
+                    .chain(self.modals.iter().map(|m| render_ctx.render_child(m)))
+                    .collect();
+                view_tree = ViewNode::container_with_direction(layers, ContainerDirection::Stacked);
+            }
 
             // Render view tree to buffer
             let mut render_ctx = RenderContext::new(&mut self.buffer, area, &self.store);
+            render_ctx.focused = self.focus.current();
+            render_ctx.theme = self.theme;
+            render_ctx.mode = self.render_mode;
+            render_ctx.layout_cache = Some(&self.layout_cache);
+            render_ctx.focus_rects = Some(&focus_rects);
+            render_ctx.budget = budget.as_ref();
+            render_ctx.previous_frame = Some(&self.prev_buffer);
             view_tree.render(&mut render_ctx);
+            self.last_tree = Some((view_tree, area));
+        }
+        self.catch_up = budget.is_some_and(|b| b.deferred() > 0);
+
+        // Rects are where components were drawn this frame
+        self.focus.clear_rects();
+        for (id, rect) in focus_rects.into_inner() {
+            self.focus.set_rect(id, rect);
+        }
+
+        if std::mem::take(&mut self.flash_pending) {
+            self.invert_buffer();
+            self.restore_pending = true;
         }
 
         // Render using the efficient diff-based renderer
@@ -270,6 +1005,17 @@ impl App {
         Ok(())
     }
 
+    /// Visual bell: flip every cell's colors for this frame
+    fn invert_buffer(&mut self) {
+        for y in 0..self.buffer.height {
+            for x in 0..self.buffer.width {
+                if let Some(cell) = self.buffer.get_mut(x, y) {
+                    cell.style.modifiers.toggle(Modifier::REVERSE);
+                }
+            }
+        }
+    }
+
     fn render_test_frame(&mut self) -> Result<()> {
         // Simple test pattern so we know it's working
         // Clear buffer
@@ -328,6 +1074,7 @@ impl App {
 
     fn cleanup(&mut self) -> Result<()> {
         self.backend.cursor_show()?;
+        self.backend.disable_focus_reporting()?;
         self.backend.leave_alt_screen()?;
         self.backend.leave_raw_mode()?;
         Ok(())
@@ -340,3 +1087,912 @@ impl Drop for App {
         let _ = self.cleanup();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::render::TestBackend;
+
+    use crate::event::{EventResult, KeyEvent};
+    use crate::state::Signal;
+    use crate::view::{Component, ViewNode};
+
+    struct Counter {
+        count: Signal<i32>,
+    }
+
+    impl Component for Counter {
+        fn render(&self, _ctx: &RenderContext) -> ViewNode {
+            ViewNode::text(format!("Count: {}", self.count.get()))
+        }
+
+        fn handle_event(&mut self, event: &Event, _ctx: &mut EventContext) -> EventResult {
+            match event {
+                Event::Key(key) if key.code == KeyCode::Char('+') => {
+                    self.count.update(|c| *c += 1);
+                    EventResult::Handled
+                }
+                Event::Key(key) if key.code == KeyCode::Char('-') => {
+                    self.count.update(|c| *c -= 1);
+                    EventResult::Handled
+                }
+                _ => EventResult::Ignored,
+            }
+        }
+    }
+
+    fn key(c: char) -> Event {
+        Event::Key(KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty()))
+    }
+
+    fn counter_app(backend: &TestBackend) -> App {
+        App::with_backend(Box::new(backend.clone())).root(Counter {
+            count: Signal::new(0),
+        })
+    }
+
+    #[test]
+    fn test_app_is_send() {
+        fn assert_send<T: Send>() {}
+        assert_send::<App>();
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let backend = TestBackend::new(20, 3);
+        let mut recorded = counter_app(&backend).with_event_log();
+
+        backend.push_event(key('+'));
+        recorded.tick().unwrap();
+        recorded.tick().unwrap(); // Idle frame
+        backend.push_event(key('+'));
+        recorded.tick().unwrap();
+        backend.push_event(key('-'));
+        backend.push_event(key('+'));
+        recorded.tick().unwrap();
+        recorded.tick().unwrap();
+
+        let log = recorded.event_log().to_vec();
+        assert_eq!(log.len(), 4);
+        assert_eq!(log[0].frame, 0);
+        assert_eq!(log[1].frame, 2);
+
+        let mut replayed = counter_app(&TestBackend::new(20, 3)).with_event_log();
+        replayed.replay(&log).unwrap();
+
+        assert_eq!(replayed.last_frame(), recorded.last_frame());
+        assert_eq!(replayed.last_frame().get(7, 0).unwrap().ch, '2');
+        assert_eq!(replayed.event_log().len(), log.len());
+    }
+
+    #[test]
+    fn test_event_log_disabled_by_default() {
+        let backend = TestBackend::new(20, 3);
+        let mut app = counter_app(&backend);
+
+        backend.push_event(key('+'));
+        app.tick().unwrap();
+        assert!(app.event_log().is_empty());
+        assert_eq!(app.frame(), 1);
+    }
+
+    #[test]
+    fn test_static_layout_measured_once() {
+        use crate::layout::{Length, Row};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        struct Measured {
+            measures: Arc<AtomicUsize>,
+        }
+
+        impl Component for Measured {
+            fn update(&mut self, _ctx: &mut UpdateContext) -> bool {
+                false
+            }
+
+            fn render(&self, ctx: &RenderContext) -> ViewNode {
+                let rects = ctx.cached_layout(ComponentId::new(1), ctx.area, || {
+                    self.measures.fetch_add(1, Ordering::SeqCst);
+                    Row::new().layout(ctx.area, &[Length::Fill(1), Length::Fill(1)])
+                });
+                ViewNode::text(format!("{}", rects.len()))
+            }
+        }
+
+        let measures = Arc::new(AtomicUsize::new(0));
+        let backend = TestBackend::new(20, 3);
+        let mut app = App::with_backend(Box::new(backend.clone())).root(Measured {
+            measures: measures.clone(),
+        });
+
+        for _ in 0..5 {
+            app.step(FRAME_TIME, None).unwrap();
+        }
+        assert_eq!(measures.load(Ordering::SeqCst), 1);
+
+        // Resizing invalidates everything
+        backend.resize(30, 5);
+        app.step(FRAME_TIME, None).unwrap();
+        app.step(FRAME_TIME, None).unwrap();
+        assert_eq!(measures.load(Ordering::SeqCst), 2);
+
+        app.invalidate_layout(ComponentId::new(1));
+        app.step(FRAME_TIME, None).unwrap();
+        assert_eq!(measures.load(Ordering::SeqCst), 3);
+
+        // A redraw request means something changed, so it's re-measured
+        app.store.set(REDRAW_REQUEST_KEY, true);
+        app.step(FRAME_TIME, None).unwrap();
+        assert_eq!(measures.load(Ordering::SeqCst), 4);
+    }
+
+    #[test]
+    fn test_static_subtree_layout_computed_once() {
+        use crate::view::{Text, VStack};
+
+        let backend = TestBackend::new(20, 4);
+        let count = Signal::new(0);
+        let mut app = App::with_backend(Box::new(backend.clone())).root(
+            VStack::new()
+                .push(VStack::new().push(Text::new("a")).push(Text::new("b")))
+                .push(Counter {
+                    count: count.clone(),
+                }),
+        );
+
+        app.step(FRAME_TIME, None).unwrap();
+        let misses = app.layout_cache.borrow().misses();
+        assert_eq!(misses, 2);
+
+        // Every frame re-renders the counter, but the containers are
+        // laid out in the same areas and aren't measured again
+        for _ in 0..5 {
+            app.step(FRAME_TIME, Some(key('+'))).unwrap();
+        }
+        assert_eq!(count.get(), 5);
+        assert_eq!(app.layout_cache.borrow().misses(), misses);
+        assert_eq!(app.layout_cache.borrow().len(), 2);
+    }
+
+    #[test]
+    fn test_nested_cached_layout() {
+        struct Nested;
+
+        impl Component for Nested {
+            fn render(&self, ctx: &RenderContext) -> ViewNode {
+                let rects = ctx.cached_layout(ComponentId::new(1), ctx.area, || {
+                    ctx.cached_layout(ComponentId::new(2), ctx.area, || vec![ctx.area])
+                });
+                ViewNode::text(format!("{}", rects.len()))
+            }
+        }
+
+        let backend = TestBackend::new(20, 3);
+        let mut app = App::with_backend(Box::new(backend)).root(Nested);
+        app.step(FRAME_TIME, None).unwrap();
+        assert_eq!(app.last_frame().get(0, 0).unwrap().ch, '1');
+    }
+
+    #[test]
+    fn test_slow_frame_warning() {
+        use std::sync::{Arc, Mutex};
+
+        struct Slow;
+
+        impl Component for Slow {
+            fn render(&self, _ctx: &RenderContext) -> ViewNode {
+                std::thread::sleep(Duration::from_millis(30));
+                ViewNode::text("slow")
+            }
+        }
+
+        let backend = TestBackend::new(20, 3);
+        let mut app = App::with_backend(Box::new(backend))
+            .root(Slow)
+            .with_frame_budget(Duration::from_millis(10));
+
+        let warnings = Arc::new(Mutex::new(Vec::new()));
+        let w = warnings.clone();
+        app.on_error(move |err| {
+            if let Error::SlowFrame { frame, elapsed, .. } = err {
+                w.lock().unwrap().push((*frame, *elapsed));
+            }
+        });
+
+        app.step(FRAME_TIME, None).unwrap();
+        app.step(FRAME_TIME, None).unwrap();
+
+        let warnings = warnings.lock().unwrap();
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].0, 0);
+        assert_eq!(warnings[1].0, 1);
+        assert!(warnings[0].1 >= Duration::from_millis(30));
+    }
+
+    #[test]
+    fn test_fast_frames_dont_warn() {
+        let backend = TestBackend::new(20, 3);
+        let mut app = counter_app(&backend).with_frame_budget(Duration::from_secs(5));
+
+        let warned = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let w = warned.clone();
+        app.on_error(move |_| w.store(true, std::sync::atomic::Ordering::SeqCst));
+
+        app.step(FRAME_TIME, None).unwrap();
+        assert!(!warned.load(std::sync::atomic::Ordering::SeqCst));
+    }
+
+    #[test]
+    fn test_poll_follows_frame_time() {
+        let backend = TestBackend::new(20, 3);
+        let mut app = counter_app(&backend);
+
+        let value = Signal::new(0);
+        let mut fetches = 0;
+        app.poll(Duration::from_millis(50), value.clone(), move || {
+            fetches += 1;
+            fetches
+        });
+
+        // First fetch right away, then one every 50ms of frame time
+        let frame = Duration::from_millis(10);
+        let mut seen = Vec::new();
+        for _ in 0..12 {
+            app.step(frame, None).unwrap();
+            seen.push(value.get());
+        }
+        assert_eq!(seen, vec![1, 1, 1, 1, 2, 2, 2, 2, 2, 3, 3, 3]);
+    }
+
+    #[test]
+    fn test_visual_bell_inverts_one_frame() {
+        let backend = TestBackend::new(10, 2);
+        let mut app = counter_app(&backend).visual_bell(true);
+
+        app.step(FRAME_TIME, None).unwrap();
+        app.bell().unwrap();
+        assert!(app.flash_pending);
+
+        app.step(FRAME_TIME, None).unwrap();
+        let cell = app.last_frame().get(0, 0).unwrap();
+        assert!(cell.style.modifiers.contains(Modifier::REVERSE));
+
+        app.step(FRAME_TIME, None).unwrap();
+        let cell = app.last_frame().get(0, 0).unwrap();
+        assert!(!cell.style.modifiers.contains(Modifier::REVERSE));
+        // Nothing audible in visual mode
+        assert!(!backend.output_str().contains('\x07'));
+    }
+
+    #[test]
+    fn test_visual_bell_restored_without_updates() {
+        struct Quiet;
+
+        impl Component for Quiet {
+            fn render(&self, _ctx: &RenderContext) -> ViewNode {
+                ViewNode::text("quiet")
+            }
+
+            fn update(&mut self, _ctx: &mut UpdateContext) -> bool {
+                false
+            }
+        }
+
+        let backend = TestBackend::new(10, 2);
+        let mut app = App::with_backend(Box::new(backend.clone()))
+            .root(Quiet)
+            .visual_bell(true);
+
+        app.step(FRAME_TIME, None).unwrap();
+        app.bell().unwrap();
+        for _ in 0..5 {
+            app.step(FRAME_TIME, None).unwrap();
+        }
+        let cell = backend.cell_at(0, 0);
+        assert!(!cell.style.modifiers.contains(Modifier::REVERSE));
+    }
+
+    #[test]
+    fn test_theme_change_forces_frame() {
+        use crate::theme::{Color, Style};
+
+        struct Quiet;
+
+        impl Component for Quiet {
+            fn render(&self, ctx: &RenderContext) -> ViewNode {
+                ViewNode::text_styled("quiet", ctx.theme.primary())
+            }
+
+            fn update(&mut self, _ctx: &mut UpdateContext) -> bool {
+                false
+            }
+        }
+
+        let backend = TestBackend::new(10, 2);
+        let mut app = App::with_backend(Box::new(backend.clone())).root(Quiet);
+        app.step(FRAME_TIME, None).unwrap();
+
+        *app.theme_mut() = Theme::new().with_primary(Style::new().fg(Color::RED));
+        app.step(FRAME_TIME, None).unwrap();
+        assert_eq!(backend.cell_at(0, 0).style.fg, Some(Color::RED));
+
+        // Same for a theme that comes from a config
+        let mut config = Config::default();
+        config.theme.primary = Some("#00FF00".to_string());
+        app.apply_config(&config);
+        app.step(FRAME_TIME, None).unwrap();
+        assert_eq!(backend.cell_at(0, 0).style.fg, Some(Color::rgb(0, 255, 0)));
+    }
+
+    #[test]
+    fn test_bell_debounce() {
+        let backend = TestBackend::new(10, 2);
+        let mut app = counter_app(&backend);
+
+        app.bell().unwrap();
+        app.bell().unwrap();
+        assert_eq!(backend.output_str().matches('\x07').count(), 1);
+
+        // Enough frames for the cooldown to pass
+        for _ in 0..7 {
+            app.step(FRAME_TIME, None).unwrap();
+        }
+        app.bell().unwrap();
+        assert_eq!(backend.output_str().matches('\x07').count(), 2);
+    }
+
+    #[test]
+    fn test_widget_bell_request() {
+        struct Beeper;
+
+        impl Component for Beeper {
+            fn render(&self, _ctx: &RenderContext) -> ViewNode {
+                ViewNode::empty()
+            }
+
+            fn handle_event(&mut self, _event: &Event, ctx: &mut EventContext) -> EventResult {
+                ctx.bell();
+                EventResult::Handled
+            }
+        }
+
+        let backend = TestBackend::new(10, 2);
+        let mut app = App::with_backend(Box::new(backend.clone())).root(Beeper);
+        app.step(FRAME_TIME, Some(key('x'))).unwrap();
+        assert!(backend.output_str().contains('\x07'));
+    }
+
+    #[test]
+    fn test_request_redraw_forces_frame() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        /// Never dirty on its own; 'r' asks for a redraw
+        struct Quiet {
+            renders: Arc<AtomicUsize>,
+        }
+
+        impl Component for Quiet {
+            fn render(&self, _ctx: &RenderContext) -> ViewNode {
+                self.renders.fetch_add(1, Ordering::SeqCst);
+                ViewNode::empty()
+            }
+
+            fn update(&mut self, _ctx: &mut UpdateContext) -> bool {
+                false
+            }
+
+            fn handle_event(&mut self, event: &Event, ctx: &mut EventContext) -> EventResult {
+                if *event == key('r') {
+                    ctx.request_redraw();
+                }
+                EventResult::Handled
+            }
+        }
+
+        let renders = Arc::new(AtomicUsize::new(0));
+        let backend = TestBackend::new(10, 2);
+        let mut app = App::with_backend(Box::new(backend)).root(Quiet {
+            renders: renders.clone(),
+        });
+
+        app.step(FRAME_TIME, None).unwrap(); // First frame always renders
+        app.step(FRAME_TIME, None).unwrap();
+        app.step(FRAME_TIME, Some(key('x'))).unwrap();
+        assert_eq!(renders.load(Ordering::SeqCst), 1);
+
+        app.step(FRAME_TIME, Some(key('r'))).unwrap();
+        assert_eq!(renders.load(Ordering::SeqCst), 2);
+        app.step(FRAME_TIME, None).unwrap();
+        assert_eq!(renders.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_resize_callback() {
+        let backend = TestBackend::new(40, 10);
+        let mut app = counter_app(&backend);
+
+        let sizes = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let s = sizes.clone();
+        app.on_resize(move |w, h| s.lock().unwrap().push((w, h)));
+
+        app.step(FRAME_TIME, None).unwrap();
+        app.step(FRAME_TIME, None).unwrap();
+        assert_eq!(app.dimensions(), (40, 10));
+
+        backend.resize(100, 30);
+        app.step(FRAME_TIME, None).unwrap();
+        app.step(FRAME_TIME, None).unwrap();
+
+        assert_eq!(*sizes.lock().unwrap(), vec![(40, 10), (100, 30)]);
+        assert_eq!(app.dimensions(), (100, 30));
+    }
+
+    #[test]
+    fn test_idle_callback() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let backend = TestBackend::new(20, 3);
+        let mut app = counter_app(&backend);
+
+        let fired = Arc::new(AtomicUsize::new(0));
+        let f = fired.clone();
+        app.on_idle(Duration::from_secs(1), move || {
+            f.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let frame = Duration::from_millis(400);
+        app.step(frame, None).unwrap();
+        app.step(frame, None).unwrap();
+        assert_eq!(fired.load(Ordering::SeqCst), 0);
+
+        app.step(frame, None).unwrap();
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+
+        // Only once per idle stretch
+        app.step(frame, None).unwrap();
+        app.step(frame, None).unwrap();
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+
+        // Input resets the timer, a resize doesn't
+        app.step(frame, Some(key('+'))).unwrap();
+        app.step(frame, Some(Event::Resize(20, 3))).unwrap();
+        assert_eq!(fired.load(Ordering::SeqCst), 1);
+        app.step(frame, None).unwrap();
+        app.step(frame, None).unwrap();
+        assert_eq!(fired.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_focus_moves_between_inputs() {
+        use crate::view::{Input, VStack};
+
+        let backend = TestBackend::new(20, 3);
+        let first = Signal::new(String::new());
+        let second = Signal::new(String::new());
+
+        let mut app = App::with_backend(Box::new(backend.clone()));
+        let (a, b) = (app.focus_mut().new_id(), app.focus_mut().new_id());
+        app.focus_mut().register(a, 0, true);
+        app.focus_mut().register(b, 1, true);
+        let mut app = app.root(
+            VStack::new()
+                .push(Input::new(first.clone()).focus_id(a))
+                .push(Input::new(second.clone()).focus_id(b)),
+        );
+
+        let tab = Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::empty()));
+        let frame = Duration::from_millis(16);
+
+        // Registering focused the first input; it hears about it next frame
+        app.step(frame, None).unwrap();
+        app.step(frame, Some(key('a'))).unwrap();
+        assert_eq!(first.get(), "a");
+        assert_eq!(second.get(), "");
+
+        // Same widget type on both sides: only the second one is focused now
+        app.step(frame, Some(tab.clone())).unwrap();
+        app.step(frame, Some(key('b'))).unwrap();
+        assert_eq!(first.get(), "a");
+        assert_eq!(second.get(), "b");
+
+        // And back
+        app.step(frame, Some(tab)).unwrap();
+        app.step(frame, Some(key('c'))).unwrap();
+        assert_eq!(first.get(), "ac");
+        assert_eq!(second.get(), "b");
+    }
+
+    #[test]
+    fn test_shift_backtab_moves_focus_back() {
+        let backend = TestBackend::new(20, 3);
+        let mut app = counter_app(&backend);
+        let ids: Vec<_> = (0..3).map(|_| app.focus_mut().new_id()).collect();
+        for (order, id) in ids.iter().enumerate() {
+            app.focus_mut().register(*id, order, true);
+        }
+
+        let tab = Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::empty()));
+        // What crossterm reports for Shift+Tab
+        let back = Event::Key(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT));
+
+        app.step(FRAME_TIME, Some(tab)).unwrap();
+        assert_eq!(app.focus().current(), Some(ids[1]));
+        app.step(FRAME_TIME, Some(back)).unwrap();
+        assert_eq!(app.focus().current(), Some(ids[0]));
+    }
+
+    #[test]
+    fn test_low_priority_subtree_waits_for_next_frame() {
+        use crate::view::view_fn;
+
+        let backend = TestBackend::new(10, 2);
+        let mut app = App::with_backend(Box::new(backend.clone()))
+            .with_render_budget(Duration::ZERO)
+            .root(view_fn(|_| {
+                ViewNode::container(vec![
+                    ViewNode::text("main"),
+                    ViewNode::text("chart").low_priority(),
+                ])
+            }));
+        let row = |app: &App, y| -> String {
+            (0..5)
+                .map(|x| match app.last_frame().get(x, y).unwrap().ch {
+                    '\0' => ' ',
+                    ch => ch,
+                })
+                .collect()
+        };
+
+        // Over budget straight away: the chart is skipped...
+        app.step(FRAME_TIME, None).unwrap();
+        assert_eq!(row(&app, 0), "main ");
+        assert_eq!(row(&app, 1), "     ");
+
+        // ...and drawn on the next frame even though nothing changed
+        app.step(FRAME_TIME, None).unwrap();
+        assert_eq!(row(&app, 1), "chart");
+
+        // Skipped again, but the old content stays on screen
+        app.step(FRAME_TIME, None).unwrap();
+        assert_eq!(row(&app, 1), "chart");
+    }
+
+    #[test]
+    fn test_escape_pops_topmost_modal() {
+        let backend = TestBackend::new(20, 3);
+        let root_count = Signal::new(0);
+        let mut app = App::with_backend(Box::new(backend.clone())).root(Counter {
+            count: root_count.clone(),
+        });
+        let id = app.focus_mut().new_id();
+        app.focus_mut().register(id, 0, true);
+
+        let (lower, upper) = (Signal::new(0), Signal::new(0));
+        app.push_modal(Counter {
+            count: lower.clone(),
+        });
+        app.push_modal(Counter {
+            count: upper.clone(),
+        });
+        assert_eq!(app.focus().current(), None);
+
+        // Tab stays within the top modal's focus scope
+        let ok = app.focus_mut().new_id();
+        let cancel = app.focus_mut().new_id();
+        app.focus_mut().register(ok, 0, true);
+        app.focus_mut().register(cancel, 1, true);
+        assert_eq!(app.focus().current(), Some(ok));
+        let tab = Event::Key(KeyEvent::new(KeyCode::Tab, KeyModifiers::empty()));
+        app.step(FRAME_TIME, Some(tab.clone())).unwrap();
+        assert_eq!(app.focus().current(), Some(cancel));
+        app.step(FRAME_TIME, Some(tab)).unwrap();
+        assert_eq!(app.focus().current(), Some(ok));
+
+        // Only the topmost modal hears keys
+        app.step(FRAME_TIME, Some(key('+'))).unwrap();
+        assert_eq!((root_count.get(), lower.get(), upper.get()), (0, 0, 1));
+
+        let esc = Event::Key(KeyEvent::new(KeyCode::Esc, KeyModifiers::empty()));
+        app.step(FRAME_TIME, Some(esc.clone())).unwrap();
+        assert_eq!(app.modals().len(), 1);
+        assert_eq!(app.focus().count(), 1);
+        app.step(FRAME_TIME, Some(key('+'))).unwrap();
+        assert_eq!((root_count.get(), lower.get(), upper.get()), (0, 1, 1));
+
+        // Last one closed: focus comes back and the root gets events again
+        app.step(FRAME_TIME, Some(esc)).unwrap();
+        assert!(app.modals().is_empty());
+        assert_eq!(app.focus().current(), Some(id));
+        app.step(FRAME_TIME, Some(key('+'))).unwrap();
+        assert_eq!((root_count.get(), lower.get(), upper.get()), (1, 1, 1));
+        assert!(!app.pop_modal());
+    }
+
+    #[test]
+    fn test_quit_with_modal_open() {
+        let backend = TestBackend::new(20, 3);
+        let mut app = counter_app(&backend);
+        let count = Signal::new(0);
+        app.push_modal(Counter {
+            count: count.clone(),
+        });
+
+        // Keys the modal handles don't reach the keymap
+        app.step(FRAME_TIME, Some(key('+'))).unwrap();
+        assert_eq!(count.get(), 1);
+        assert!(!app.should_quit);
+
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        app.step(FRAME_TIME, Some(Event::Key(ctrl_c))).unwrap();
+        assert!(app.should_quit);
+        assert_eq!(app.modals().len(), 1);
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn test_prompt_resolves_with_the_answer() {
+        use crate::async_support::AsyncRuntime;
+        use crate::view::ConfirmDialog;
+
+        let runtime = AsyncRuntime::new().unwrap();
+        let mut app = App::with_backend(Box::new(TestBackend::new(30, 7)));
+        let enter = Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+
+        let confirmed = runtime.spawn(app.prompt(ConfirmDialog::new("Save?")));
+        app.step(FRAME_TIME, Some(enter)).unwrap();
+        assert!(app.modals().is_empty());
+        assert!(runtime.block_on(confirmed).unwrap());
+
+        // Closed from outside: counts as a cancel
+        let dismissed = app.prompt(ConfirmDialog::new("Save?"));
+        assert!(app.pop_modal());
+        assert!(!runtime.block_on(dismissed));
+    }
+
+    #[test]
+    fn test_debug_tree_shows_laid_out_nodes() {
+        use crate::view::{Text, VStack};
+
+        let backend = TestBackend::new(10, 4);
+        let mut app = App::with_backend(Box::new(backend.clone())).root(
+            VStack::new()
+                .push(Text::new("top"))
+                .push(Text::new("bottom")),
+        );
+        assert_eq!(app.debug_tree(), "");
+
+        app.step(FRAME_TIME, None).unwrap();
+        let dump = app.debug_tree();
+        let lines: Vec<&str> = dump.lines().collect();
+        assert_eq!(lines[0], "Container(Vertical) @ 0,0 10x4");
+        assert!(lines[1].starts_with("  Text \"top\" @ 0,0 10x2"));
+        assert!(lines[2].starts_with("  Text \"bottom\" @ 0,2 10x2"));
+        assert_eq!(lines.len(), 3);
+    }
+
+    #[test]
+    fn test_focus_follows_mouse() {
+        use crate::event::MouseEvent;
+        use crate::view::{Input, Text, VStack};
+
+        let backend = TestBackend::new(20, 6);
+        let mut app = App::with_backend(Box::new(backend.clone())).focus_follows_mouse(true);
+        let (a, b) = (app.focus_mut().new_id(), app.focus_mut().new_id());
+        app.focus_mut().register(a, 0, true);
+        app.focus_mut().register(b, 1, true);
+        let mut app = app.root(
+            VStack::new()
+                .push(Input::new(Signal::new(String::new())).focus_id(a))
+                .push(Text::new("label"))
+                .push(Input::new(Signal::new(String::new())).focus_id(b)),
+        );
+
+        let moved = |x, y| {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Moved,
+                x,
+                y,
+                modifiers: KeyModifiers::empty(),
+            })
+        };
+
+        // Each child gets two rows
+        app.step(FRAME_TIME, None).unwrap();
+        assert_eq!(app.focus().rect(b), Some(Rect::new(0, 4, 20, 2)));
+
+        app.step(FRAME_TIME, Some(moved(5, 4))).unwrap();
+        assert_eq!(app.focus().current(), Some(b));
+
+        // The label isn't focusable: focus stays put
+        app.step(FRAME_TIME, Some(moved(5, 2))).unwrap();
+        assert_eq!(app.focus().current(), Some(b));
+
+        app.step(FRAME_TIME, Some(moved(0, 1))).unwrap();
+        assert_eq!(app.focus().current(), Some(a));
+    }
+
+    #[test]
+    fn test_keymap_remaps_quit_and_runs_custom_actions() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let backend = TestBackend::new(20, 3);
+        let mut app = counter_app(&backend);
+        let refreshed = std::sync::Arc::new(AtomicUsize::new(0));
+
+        let keymap = app.keymap_mut();
+        keymap.unbind_action(&Action::Quit);
+        keymap.bind(KeyCode::Char('q'), KeyModifiers::CONTROL, Action::Quit);
+        keymap.bind(
+            KeyCode::Char('r'),
+            KeyModifiers::empty(),
+            Action::Custom("refresh".into()),
+        );
+        let r = refreshed.clone();
+        keymap.on_action("refresh", move || {
+            r.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let frame = Duration::from_millis(16);
+        app.step(frame, Some(key('r'))).unwrap();
+        app.step(frame, Some(key('q'))).unwrap();
+        assert_eq!(refreshed.load(Ordering::SeqCst), 1);
+        assert!(!app.should_quit);
+
+        let ctrl_q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL);
+        app.step(frame, Some(Event::Key(ctrl_q))).unwrap();
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_keymap_sequences() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let backend = TestBackend::new(20, 3);
+        let mut app = counter_app(&backend);
+        let tops = std::sync::Arc::new(AtomicUsize::new(0));
+
+        let g = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::empty());
+        let keymap = app.keymap_mut();
+        keymap.bind_sequence(vec![g, g], Action::Custom("top".into()));
+        let t = tops.clone();
+        keymap.on_action("top", move || {
+            t.fetch_add(1, Ordering::SeqCst);
+        });
+
+        let frame = Duration::from_millis(16);
+        app.step(frame, Some(key('g'))).unwrap();
+        assert_eq!(tops.load(Ordering::SeqCst), 0);
+        app.step(frame, Some(key('g'))).unwrap();
+        assert_eq!(tops.load(Ordering::SeqCst), 1);
+
+        // Too slow to count as a sequence
+        app.step(frame, Some(key('g'))).unwrap();
+        app.step(Duration::from_secs(2), Some(key('g'))).unwrap();
+        assert_eq!(tops.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_quick_clicks_make_a_double_click() {
+        use crate::event::{MouseButton, MouseEvent};
+        use std::sync::{Arc, Mutex};
+
+        struct Recorder(Arc<Mutex<Vec<MouseEventKind>>>);
+
+        impl Component for Recorder {
+            fn render(&self, _ctx: &RenderContext) -> ViewNode {
+                ViewNode::text("")
+            }
+
+            fn handle_event(&mut self, event: &Event, _ctx: &mut EventContext) -> EventResult {
+                if let Event::Mouse(mouse) = event {
+                    self.0.lock().unwrap().push(mouse.kind);
+                }
+                EventResult::Handled
+            }
+        }
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let backend = TestBackend::new(20, 3);
+        let mut app = App::with_backend(Box::new(backend))
+            .root(Recorder(seen.clone()))
+            .double_click_threshold(Duration::from_millis(300));
+        let click = Event::Mouse(MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            x: 4,
+            y: 1,
+            modifiers: KeyModifiers::empty(),
+        });
+
+        let frame = Duration::from_millis(16);
+        app.step(frame, Some(click.clone())).unwrap();
+        app.step(Duration::from_millis(100), Some(click.clone()))
+            .unwrap();
+        app.step(Duration::from_secs(1), Some(click)).unwrap();
+
+        let down = MouseEventKind::Down(MouseButton::Left);
+        assert_eq!(
+            *seen.lock().unwrap(),
+            [
+                down,
+                down,
+                MouseEventKind::DoubleClick(MouseButton::Left),
+                down
+            ]
+        );
+    }
+
+    #[test]
+    fn test_config_remaps_quit_and_accent() {
+        use crate::theme::Color;
+
+        let backend = TestBackend::new(20, 3);
+        let mut app = counter_app(&backend);
+
+        let mut config = Config::default();
+        config
+            .keys
+            .insert("quit".to_string(), vec!["ctrl+q".to_string()]);
+        config.theme.accent = Some("#FF8800".to_string());
+        app.apply_config(&config);
+
+        assert_eq!(app.theme().accent().fg, Some(Color::rgb(255, 136, 0)));
+
+        let frame = Duration::from_millis(16);
+        app.step(frame, Some(key('q'))).unwrap();
+        assert!(!app.should_quit);
+
+        let ctrl_q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL);
+        app.step(frame, Some(Event::Key(ctrl_q))).unwrap();
+        assert!(app.should_quit);
+    }
+
+    #[test]
+    fn test_bad_config_reported_not_applied() {
+        let backend = TestBackend::new(20, 3);
+        let mut app = counter_app(&backend);
+
+        let errors = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let e = errors.clone();
+        app.on_error(move |err| e.lock().unwrap().push(err.to_string()));
+
+        let mut config = Config::default();
+        config.theme.accent = Some("orange".to_string());
+        app.apply_config(&config);
+
+        assert_eq!(*app.theme(), Theme::default());
+        assert_eq!(errors.lock().unwrap().len(), 1);
+        assert!(errors.lock().unwrap()[0].contains("orange"));
+
+        #[cfg(feature = "serde")]
+        {
+            app.load_config("{ \"keys\": ");
+            assert_eq!(errors.lock().unwrap().len(), 2);
+            assert_eq!(app.bindings(), Bindings::default());
+        }
+    }
+
+    #[test]
+    fn test_set_title() {
+        let backend = TestBackend::new(80, 24);
+        let mut app = App::with_backend(Box::new(backend.clone()));
+
+        app.set_title("rsdrav demo").unwrap();
+        assert_eq!(backend.output_str(), "\x1b]0;rsdrav demo\x07");
+    }
+
+    #[test]
+    fn test_run_without_terminal_fails_fast() {
+        let backend = TestBackend::new(20, 3);
+        backend.set_interactive(false);
+        // Nothing here quits, so getting into the loop would hang the test
+        backend.push_event(key('+'));
+
+        let result = counter_app(&backend).run();
+        assert!(matches!(result, Err(Error::NotATerminal)));
+        // Nothing drawn, no alternate screen escape codes
+        assert!(backend.output().is_empty());
+    }
+}
@@ -0,0 +1,52 @@
+//! Modals stacked over the root component
+
+use crate::view::Component;
+
+/// Modals the `App` shows over its root, newest on top
+///
+/// Only the topmost modal gets events; Escape it doesn't handle pops it.
+/// Use `App::push_modal` and `App::pop_modal` rather than driving this
+/// directly, so each modal gets its own focus scope.
+#[derive(Default)]
+pub struct ModalStack {
+    layers: Vec<Box<dyn Component>>,
+}
+
+impl ModalStack {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Put a modal on top
+    pub fn push(&mut self, component: Box<dyn Component>) {
+        self.layers.push(component);
+    }
+
+    /// Remove the topmost modal, returning it
+    pub fn pop(&mut self) -> Option<Box<dyn Component>> {
+        self.layers.pop()
+    }
+
+    /// The modal receiving input, if any
+    pub fn top_mut(&mut self) -> Option<&mut (dyn Component + 'static)> {
+        self.layers.last_mut().map(|layer| layer.as_mut())
+    }
+
+    /// Modals bottom to top (render order)
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Component> {
+        self.layers.iter().map(|layer| layer.as_ref())
+    }
+
+    /// Modals bottom to top, mutably
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut (dyn Component + 'static)> {
+        self.layers.iter_mut().map(|layer| layer.as_mut())
+    }
+
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
+    }
+}
@@ -0,0 +1,232 @@
+//! Interval polling of external data into signals
+
+use crate::state::Signal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// Runs a fetch closure on an interval and stores the result in a signal
+///
+/// Time is advanced by `tick` (the `App` does this every frame), so polling
+/// follows the frame clock rather than a separate timer. The first fetch
+/// happens on the first tick.
+///
+/// Blocking fetches (reading files, talking to a socket) should use
+/// `background`, which runs each fetch on its own thread. A fetch that's
+/// still running when the next one is due is not started again - slow
+/// sources just get polled less often instead of piling up threads.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+/// use std::time::Duration;
+///
+/// let load = Signal::new(0.0f32);
+/// let mut poller = Poller::new(Duration::from_secs(1), load.clone(), || 0.42);
+///
+/// poller.tick(Duration::from_millis(16)); // fetches right away
+/// assert_eq!(load.get(), 0.42);
+/// ```
+pub struct Poller<T> {
+    interval: Duration,
+    /// Time since the last fetch, starts "due"
+    elapsed: Duration,
+    target: Signal<T>,
+    fetch: Arc<Mutex<dyn FnMut() -> T + Send>>,
+    background: bool,
+    running: Arc<AtomicBool>,
+}
+
+impl<T: Clone + Send + Sync + 'static> Poller<T> {
+    /// Poll `fetch` every `interval`, writing results into `target`
+    pub fn new(
+        interval: Duration,
+        target: Signal<T>,
+        fetch: impl FnMut() -> T + Send + 'static,
+    ) -> Self {
+        Self {
+            interval,
+            elapsed: interval,
+            target,
+            fetch: Arc::new(Mutex::new(fetch)),
+            background: false,
+            running: Arc::new(AtomicBool::new(false)),
+        }
+    }
+
+    /// Run fetches on a worker thread instead of blocking the frame
+    pub fn background(mut self, background: bool) -> Self {
+        self.background = background;
+        self
+    }
+
+    /// Check if a background fetch is in progress
+    pub fn is_running(&self) -> bool {
+        self.running.load(Ordering::SeqCst)
+    }
+
+    /// Advance the clock, fetching if an interval has passed
+    pub fn tick(&mut self, delta: Duration) {
+        self.elapsed += delta;
+        if self.elapsed < self.interval {
+            return;
+        }
+
+        // Keep the cadence, but don't try to catch up after a long stall
+        self.elapsed -= self.interval;
+        if self.elapsed >= self.interval {
+            self.elapsed = Duration::ZERO;
+        }
+
+        if self.background {
+            self.spawn_fetch();
+        } else {
+            let value = call_fetch(&self.fetch);
+            self.target.set(value);
+        }
+    }
+
+    fn spawn_fetch(&self) {
+        // Previous fetch still going - skip this round
+        if self.running.swap(true, Ordering::SeqCst) {
+            return;
+        }
+
+        let fetch = self.fetch.clone();
+        let target = self.target.clone();
+        let running = self.running.clone();
+        std::thread::spawn(move || {
+            let _running = RunningGuard(running);
+            let value = call_fetch(&fetch);
+            target.set(value);
+        });
+    }
+}
+
+/// Run the fetch closure, even if an earlier call panicked while holding it
+fn call_fetch<T>(fetch: &Mutex<dyn FnMut() -> T + Send>) -> T {
+    let mut fetch = fetch
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner());
+    fetch()
+}
+
+/// Clears the running flag when a background fetch ends, panic or not, so
+/// one failed fetch doesn't stop the poller for good
+struct RunningGuard(Arc<AtomicBool>);
+
+impl Drop for RunningGuard {
+    fn drop(&mut self) {
+        self.0.store(false, Ordering::SeqCst);
+    }
+}
+
+/// Type-erased poller so the app can hold pollers of any value type
+pub(crate) trait PollTask: Send {
+    fn tick(&mut self, delta: Duration);
+}
+
+impl<T: Clone + Send + Sync + 'static> PollTask for Poller<T> {
+    fn tick(&mut self, delta: Duration) {
+        Poller::tick(self, delta);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    #[test]
+    fn test_polls_at_interval() {
+        let value = Signal::new(0);
+        let mut counter = 0;
+        let mut poller = Poller::new(Duration::from_millis(100), value.clone(), move || {
+            counter += 1;
+            counter
+        });
+
+        poller.tick(Duration::from_millis(16));
+        assert_eq!(value.get(), 1);
+
+        // 16ms left over from the first tick + 5 * 16ms = 96ms - not due yet
+        for _ in 0..5 {
+            poller.tick(Duration::from_millis(16));
+        }
+        assert_eq!(value.get(), 1);
+
+        poller.tick(Duration::from_millis(16));
+        assert_eq!(value.get(), 2);
+    }
+
+    #[test]
+    fn test_no_catch_up_burst() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+        let mut poller = Poller::new(Duration::from_millis(100), Signal::new(()), move || {
+            c.fetch_add(1, Ordering::SeqCst);
+        });
+
+        poller.tick(Duration::ZERO);
+        // A 1s stall is one fetch, not ten
+        poller.tick(Duration::from_secs(1));
+        poller.tick(Duration::ZERO);
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_background_skips_while_running() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let release = Arc::new(AtomicBool::new(false));
+
+        let (c, r) = (calls.clone(), release.clone());
+        let value = Signal::new(0);
+        let mut poller = Poller::new(Duration::from_millis(10), value.clone(), move || {
+            while !r.load(Ordering::SeqCst) {
+                std::thread::sleep(Duration::from_millis(1));
+            }
+            c.fetch_add(1, Ordering::SeqCst) + 1
+        })
+        .background(true);
+
+        poller.tick(Duration::ZERO);
+        for _ in 0..5 {
+            poller.tick(Duration::from_millis(10));
+        }
+        assert!(poller.is_running());
+
+        release.store(true, Ordering::SeqCst);
+        while poller.is_running() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(value.get(), 1);
+    }
+
+    #[test]
+    fn test_background_recovers_from_panicking_fetch() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let c = calls.clone();
+        let value = Signal::new(0);
+        let mut poller = Poller::new(Duration::from_millis(10), value.clone(), move || {
+            let n = c.fetch_add(1, Ordering::SeqCst) + 1;
+            if n == 1 {
+                panic!("source unavailable");
+            }
+            n
+        })
+        .background(true);
+
+        poller.tick(Duration::ZERO);
+        while calls.load(Ordering::SeqCst) == 0 || poller.is_running() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+
+        // The next round still runs, through the poisoned lock
+        poller.tick(Duration::from_millis(10));
+        while poller.is_running() {
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(value.get(), 2);
+    }
+}
@@ -0,0 +1,172 @@
+//! Progress reporting from background work into signals
+
+use super::poller::PollTask;
+use crate::state::Signal;
+use crossbeam_channel::{unbounded, Receiver, Sender};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+enum ProgressUpdate {
+    Progress(f32),
+    Message(String),
+    Finished,
+}
+
+/// Handle for reporting progress from another thread or an async task
+///
+/// Get one from `App::progress_reporter` and move clones of it into the
+/// work. Updates go through a channel and land in the bound signals on the
+/// next frame, so reporting never touches UI state directly. After
+/// `finish` every clone goes quiet - late reports are dropped.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let mut app = App::new()?;
+/// let progress = Signal::new(0.0f32);
+/// let reporter = app.progress_reporter(progress.clone(), None);
+///
+/// std::thread::spawn(move || {
+///     for chunk in 1..=10 {
+///         reporter.report(chunk as f32 / 10.0);
+///     }
+///     reporter.finish();
+/// });
+/// # Ok::<(), rsdrav::Error>(())
+/// ```
+#[derive(Clone)]
+pub struct ProgressReporter {
+    sender: Sender<ProgressUpdate>,
+    finished: Arc<AtomicBool>,
+}
+
+impl ProgressReporter {
+    /// Report completion as a fraction, clamped to 0.0-1.0
+    pub fn report(&self, fraction: f32) {
+        self.send(ProgressUpdate::Progress(fraction.clamp(0.0, 1.0)));
+    }
+
+    /// Report a status message (shown if a message signal was bound)
+    pub fn message(&self, message: impl Into<String>) {
+        self.send(ProgressUpdate::Message(message.into()));
+    }
+
+    /// Mark the work done: progress goes to 1.0 and further reports from
+    /// any clone are ignored
+    pub fn finish(&self) {
+        if !self.finished.swap(true, Ordering::SeqCst) {
+            // Receiver gone means the app quit - nothing to update
+            let _ = self.sender.send(ProgressUpdate::Finished);
+        }
+    }
+
+    /// Check if `finish` has been called on this reporter or a clone
+    pub fn is_finished(&self) -> bool {
+        self.finished.load(Ordering::SeqCst)
+    }
+
+    fn send(&self, update: ProgressUpdate) {
+        if !self.is_finished() {
+            let _ = self.sender.send(update);
+        }
+    }
+}
+
+/// Receiving end, drained into the signals every frame
+pub(crate) struct ProgressTask {
+    receiver: Receiver<ProgressUpdate>,
+    progress: Signal<f32>,
+    message: Option<Signal<String>>,
+    finished: bool,
+}
+
+/// A connected reporter and the task that applies its updates
+pub(crate) fn progress_channel(
+    progress: Signal<f32>,
+    message: Option<Signal<String>>,
+) -> (ProgressReporter, ProgressTask) {
+    let (sender, receiver) = unbounded();
+    let reporter = ProgressReporter {
+        sender,
+        finished: Arc::new(AtomicBool::new(false)),
+    };
+    let task = ProgressTask {
+        receiver,
+        progress,
+        message,
+        finished: false,
+    };
+    (reporter, task)
+}
+
+impl PollTask for ProgressTask {
+    fn tick(&mut self, _delta: Duration) {
+        // Only the latest value matters, so set each signal once
+        let mut progress = None;
+        let mut message = None;
+        while let Ok(update) = self.receiver.try_recv() {
+            if self.finished {
+                continue;
+            }
+            match update {
+                ProgressUpdate::Progress(value) => progress = Some(value),
+                ProgressUpdate::Message(text) => message = Some(text),
+                ProgressUpdate::Finished => {
+                    progress = Some(1.0);
+                    self.finished = true;
+                }
+            }
+        }
+
+        if let Some(value) = progress {
+            self.progress.set(value);
+        }
+        if let (Some(text), Some(signal)) = (message, &self.message) {
+            signal.set(text);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reports_flow_into_signals_across_ticks() {
+        let progress = Signal::new(0.0f32);
+        let message = Signal::new(String::new());
+        let (reporter, mut task) = progress_channel(progress.clone(), Some(message.clone()));
+        let tick = Duration::from_millis(16);
+
+        let worker = reporter.clone();
+        std::thread::spawn(move || {
+            worker.message("downloading");
+            worker.report(0.25);
+            worker.report(0.5);
+        })
+        .join()
+        .unwrap();
+
+        // Nothing moves until the frame drains the channel
+        assert_eq!(progress.get(), 0.0);
+        task.tick(tick);
+        assert_eq!(progress.get(), 0.5);
+        assert_eq!(message.get(), "downloading");
+
+        reporter.report(2.0);
+        task.tick(tick);
+        assert_eq!(progress.get(), 1.0);
+
+        // Done: progress is full and late reports are dropped
+        reporter.report(0.1);
+        reporter.finish();
+        reporter.report(0.2);
+        reporter.message("late");
+        task.tick(tick);
+        assert_eq!(progress.get(), 1.0);
+        assert_eq!(message.get(), "downloading");
+        assert!(reporter.is_finished());
+    }
+}
@@ -0,0 +1,71 @@
+//! Awaiting a dialog's result from async code
+
+use crate::event::{Event, EventResult};
+use crate::focus::ComponentId;
+use crate::view::{
+    Component, Dialog, EventContext, MountContext, RenderContext, UpdateContext, ViewNode,
+};
+use tokio::sync::oneshot;
+
+/// Modal layer that hands its dialog's result to a waiting future
+///
+/// If the layer goes away without a result (Escape, `App::pop_modal`,
+/// the app quitting) the sender is dropped, which the future reads as a
+/// cancel.
+pub(crate) struct PromptLayer<D: Dialog> {
+    dialog: D,
+    sender: Option<oneshot::Sender<D::Output>>,
+}
+
+impl<D: Dialog> PromptLayer<D> {
+    pub(crate) fn new(dialog: D) -> (Self, oneshot::Receiver<D::Output>) {
+        let (sender, receiver) = oneshot::channel();
+        let layer = Self {
+            dialog,
+            sender: Some(sender),
+        };
+        (layer, receiver)
+    }
+}
+
+impl<D: Dialog> Component for PromptLayer<D> {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        ctx.render_child(&self.dialog)
+    }
+
+    fn mount(&mut self, ctx: &mut MountContext) {
+        self.dialog.mount(ctx);
+    }
+
+    fn unmount(&mut self, ctx: &mut MountContext) {
+        self.dialog.unmount(ctx);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateContext) -> bool {
+        self.dialog.update(ctx)
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut EventContext) -> EventResult {
+        let result = self.dialog.handle_event(event, ctx);
+        if let Some(output) = self.dialog.take_result() {
+            if let Some(sender) = self.sender.take() {
+                // Nobody awaiting any more is fine
+                let _ = sender.send(output);
+            }
+        }
+        result
+    }
+
+    fn focus_changed(
+        &mut self,
+        previous: Option<ComponentId>,
+        current: Option<ComponentId>,
+        ctx: &mut EventContext,
+    ) {
+        self.dialog.focus_changed(previous, current, ctx);
+    }
+
+    fn children(&self) -> Vec<&dyn Component> {
+        vec![&self.dialog]
+    }
+}
@@ -0,0 +1,61 @@
+use super::Command;
+use std::future::Future;
+use std::pin::Pin;
+
+/// Future returned by an `AsyncCommandHandler`
+pub type CommandFuture = Pin<Box<dyn Future<Output = ()> + Send + 'static>>;
+
+/// Handler for long-running commands, run on the async runtime
+///
+/// `CommandRegistry` spawns the returned future and returns straight away,
+/// so the main loop keeps drawing while it runs. The future can't borrow
+/// the `CommandContext`; it reports back by setting signals it closed
+/// over, which the UI picks up like any other signal change.
+///
+/// Closures taking a `Command` and returning a future are handlers too.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::async_support::AsyncRuntime;
+/// use rsdrav::command::{Command, CommandContext, CommandRegistry};
+/// use rsdrav::state::{Signal, Store};
+/// use std::time::Duration;
+///
+/// let runtime = AsyncRuntime::new()?;
+/// let status = Signal::new(String::new());
+/// let mut registry = CommandRegistry::new();
+/// registry.set_runtime(&runtime);
+/// registry.register_async("fetch", {
+///     let status = status.clone();
+///     move |_cmd: Command| {
+///         let status = status.clone();
+///         async move {
+///             tokio::time::sleep(Duration::from_secs(2)).await;
+///             status.set("Fetched".to_string());
+///         }
+///     }
+/// });
+///
+/// let mut ctx = CommandContext::new(Store::new());
+/// registry.execute_line("fetch", &mut ctx)?;
+/// # Ok::<(), rsdrav::Error>(())
+/// ```
+pub trait AsyncCommandHandler: Send + Sync {
+    /// Start the command; the future runs off the main thread
+    fn execute(&self, cmd: Command) -> CommandFuture;
+
+    /// Get command description for help
+    fn description(&self) -> &str {
+        "No description available"
+    }
+}
+
+impl<F, Fut> AsyncCommandHandler for F
+where
+    F: Fn(Command) -> Fut + Send + Sync,
+    Fut: Future<Output = ()> + Send + 'static,
+{
+    fn execute(&self, cmd: Command) -> CommandFuture {
+        Box::pin(self(cmd))
+    }
+}
@@ -30,6 +30,85 @@ pub trait Completer: Send + Sync {
     fn complete(&self, args: &[String], current: &str) -> Vec<CompletionItem>;
 }
 
+/// Any closure taking the earlier args and the partial one is a completer
+impl<F> Completer for F
+where
+    F: Fn(&[String], &str) -> Vec<CompletionItem> + Send + Sync,
+{
+    fn complete(&self, args: &[String], current: &str) -> Vec<CompletionItem> {
+        self(args, current)
+    }
+}
+
+/// Completions for a command line, from `CommandRegistry::complete`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LineCompletions {
+    /// Byte offset where the token being completed starts; a chosen item
+    /// replaces the line from here to the cursor
+    pub start: usize,
+    pub items: Vec<CompletionItem>,
+}
+
+/// The command line up to the cursor, split for completion
+#[derive(Debug, PartialEq)]
+pub(crate) struct CursorTokens {
+    /// Words of the command the cursor is in, before the partial one
+    pub words: Vec<String>,
+    /// The partial word under the cursor, unquoted
+    pub current: String,
+    /// Byte offset where the partial word starts
+    pub start: usize,
+}
+
+/// Split `line[..cursor]` the way `parse` would, keeping only the command
+/// the cursor is in
+pub(crate) fn tokens_at(line: &str, cursor: usize) -> CursorTokens {
+    let mut tokens = CursorTokens {
+        words: Vec::new(),
+        current: String::new(),
+        start: 0,
+    };
+    let mut in_quote: Option<char> = None;
+    let mut escape_next = false;
+    let mut in_word = false;
+
+    for (i, ch) in line[..cursor.min(line.len())].char_indices() {
+        if !in_word && !ch.is_whitespace() {
+            tokens.start = i;
+        }
+        if escape_next {
+            tokens.current.push(ch);
+            escape_next = false;
+            in_word = true;
+            continue;
+        }
+
+        match ch {
+            '\\' => escape_next = true,
+            '"' | '\'' if in_quote.is_none() => in_quote = Some(ch),
+            _ if in_quote == Some(ch) => in_quote = None,
+            ' ' | '\t' | '|' | '>' if in_quote.is_none() => {
+                if in_word {
+                    tokens.words.push(std::mem::take(&mut tokens.current));
+                }
+                if ch == '|' {
+                    // A new command starts after a pipe
+                    tokens.words.clear();
+                }
+                in_word = false;
+                continue;
+            }
+            _ => tokens.current.push(ch),
+        }
+        in_word = true;
+    }
+
+    if !in_word {
+        tokens.start = cursor.min(line.len());
+    }
+    tokens
+}
+
 /// Simple completion from a fixed list
 pub struct ListCompleter {
     items: Vec<String>,
@@ -51,25 +130,21 @@ impl Completer for ListCompleter {
     }
 }
 
-/// File path completer (placeholder - would need actual FS access)
+/// File path completer
+///
+/// Completes the last part of the path typed so far from the directory
+/// it names, keeping what was typed before it. Directories end in `/`, so
+/// completing again goes inside them.
 pub struct FileCompleter;
 
 impl Completer for FileCompleter {
     fn complete(&self, _args: &[String], current: &str) -> Vec<CompletionItem> {
         use std::fs;
-        use std::path::Path;
-
-        // Parse the current path
-        let path = Path::new(current);
-        let (dir, prefix) = if current.ends_with('/') || current.ends_with('\\') {
-            // User is completing within a directory
-            (path, "")
-        } else {
-            // User is typing a file/dir name
-            let dir = path.parent().unwrap_or(Path::new("."));
-            let prefix = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
-            (dir, prefix)
-        };
+
+        // Split into the directory typed so far and the name being typed
+        let split = current.rfind(['/', '\\']).map_or(0, |i| i + 1);
+        let (typed_dir, prefix) = current.split_at(split);
+        let dir = if typed_dir.is_empty() { "." } else { typed_dir };
 
         // List directory contents
         let Ok(entries) = fs::read_dir(dir) else {
@@ -91,28 +166,13 @@ impl Completer for FileCompleter {
             // Check if it's a directory
             let is_dir = entry.file_type().map(|t| t.is_dir()).unwrap_or(false);
 
-            // Build full path
-            let full_path = if current.is_empty() {
-                name.clone()
+            let (text, description) = if is_dir {
+                (format!("{}{}/", typed_dir, name), "directory")
             } else {
-                let parent = dir.to_string_lossy();
-                if parent == "." {
-                    name.clone()
-                } else {
-                    format!("{}/{}", parent, name)
-                }
+                (format!("{}{}", typed_dir, name), "file")
             };
 
-            let description = if is_dir {
-                "directory".to_string()
-            } else {
-                "file".to_string()
-            };
-
-            completions.push(CompletionItem {
-                text: full_path,
-                description: Some(description),
-            });
+            completions.push(CompletionItem::new(text).with_description(description));
         }
 
         // Sort completions
@@ -148,6 +208,34 @@ mod tests {
         assert!(results.iter().any(|c| c.text == "help"));
     }
 
+    #[test]
+    fn test_file_completer_keeps_typed_dir() {
+        let results = FileCompleter.complete(&[], "./sr");
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].text, "./src/");
+
+        let results = FileCompleter.complete(&[], "src/command/hist");
+        assert_eq!(results[0].text, "src/command/history.rs");
+    }
+
+    #[test]
+    fn test_tokens_at_cursor() {
+        let tokens = tokens_at(r#"open "my fi"#, 11);
+        assert_eq!(tokens.words, ["open"]);
+        assert_eq!(tokens.current, "my fi");
+        assert_eq!(tokens.start, 5);
+
+        let tokens = tokens_at("search foo | filter ", 20);
+        assert_eq!(tokens.words, ["filter"]);
+        assert_eq!(tokens.current, "");
+        assert_eq!(tokens.start, 20);
+
+        // Only the line before the cursor counts
+        let tokens = tokens_at("open notes.txt", 2);
+        assert!(tokens.words.is_empty());
+        assert_eq!(tokens.current, "op");
+    }
+
     #[test]
     fn test_list_completer_no_match() {
         let completer = ListCompleter::new(vec!["hello".to_string(), "world".to_string()]);
@@ -63,6 +63,25 @@ impl CommandContext {
     }
 }
 
+/// Callback turning the user's answer to a prompt into the next command
+pub type InputCallback = Box<dyn FnOnce(String) -> Command + Send + Sync>;
+
+/// Something a command wants to happen after it finishes
+pub enum FollowUp {
+    /// Run another command right away
+    Chain(Command),
+
+    /// Ask the user for input, then run the command built from the answer
+    ///
+    /// Neither the registry nor `App` shows UI for this: it's handed back
+    /// to the caller (e.g. a command palette), which shows the prompt and
+    /// executes `on_submit(answer)`. `examples/command_palette.rs` does it.
+    OpenInput {
+        prompt: String,
+        on_submit: InputCallback,
+    },
+}
+
 /// Result of command execution
 ///
 /// Commands can return data, request UI updates, provide undo info, etc.
@@ -76,6 +95,9 @@ pub struct CommandResult {
     /// Optional undo data for this command
     /// Stored as type-erased Any so different commands can use different types
     pub undo_data: Option<Box<dyn Any + Send + Sync>>,
+
+    /// What to do next, if anything
+    pub follow_up: Option<FollowUp>,
 }
 
 impl CommandResult {
@@ -84,6 +106,7 @@ impl CommandResult {
             message: None,
             needs_redraw: false,
             undo_data: None,
+            follow_up: None,
         }
     }
 
@@ -92,6 +115,7 @@ impl CommandResult {
             message: Some(msg.into()),
             needs_redraw: false,
             undo_data: None,
+            follow_up: None,
         }
     }
 
@@ -104,6 +128,25 @@ impl CommandResult {
         self.undo_data = Some(Box::new(data));
         self
     }
+
+    /// Run another command after this one
+    pub fn then(mut self, cmd: Command) -> Self {
+        self.follow_up = Some(FollowUp::Chain(cmd));
+        self
+    }
+
+    /// Ask the user for input and run the command `on_submit` builds from it
+    pub fn open_input(
+        mut self,
+        prompt: impl Into<String>,
+        on_submit: impl FnOnce(String) -> Command + Send + Sync + 'static,
+    ) -> Self {
+        self.follow_up = Some(FollowUp::OpenInput {
+            prompt: prompt.into(),
+            on_submit: Box::new(on_submit),
+        });
+        self
+    }
 }
 
 /// Trait for command handlers
@@ -0,0 +1,207 @@
+use crate::error::Result;
+use std::collections::VecDeque;
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// Previously entered command lines, for Up/Down recall
+///
+/// Keeps the newest `capacity` lines, dropping the oldest. Entering the
+/// same line twice in a row stores it once.
+///
+/// `prev` and `next` walk the history like a shell: the first `prev`
+/// remembers the line being typed, and `next` past the newest entry gives
+/// it back.
+///
+/// ## Example
+/// ```
+/// use rsdrav::command::CommandHistory;
+///
+/// let mut history = CommandHistory::new(100);
+/// history.push("open notes.txt");
+/// history.push("save");
+///
+/// assert_eq!(history.prev("sa"), Some("save"));
+/// assert_eq!(history.prev("sa"), Some("open notes.txt"));
+/// assert_eq!(history.next(), Some("save"));
+/// assert_eq!(history.next(), Some("sa"));
+/// ```
+#[derive(Clone, Debug)]
+pub struct CommandHistory {
+    entries: VecDeque<String>,
+    capacity: usize,
+    /// Entry being shown, or None when back on the line being typed
+    cursor: Option<usize>,
+    /// The line being typed when navigation started
+    draft: String,
+}
+
+impl CommandHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::new(),
+            capacity: capacity.max(1),
+            cursor: None,
+            draft: String::new(),
+        }
+    }
+
+    /// Add a line as the newest entry and stop navigating
+    ///
+    /// Blank lines and repeats of the newest entry are skipped.
+    pub fn push(&mut self, line: impl Into<String>) {
+        let line = line.into();
+        self.reset();
+        if line.trim().is_empty() || self.entries.back() == Some(&line) {
+            return;
+        }
+        self.entries.push_back(line);
+        if self.entries.len() > self.capacity {
+            self.entries.pop_front();
+        }
+    }
+
+    /// Step back to an older entry
+    ///
+    /// `current` is the line being typed, kept for `next` to return to.
+    /// Stays on the oldest entry once there; None if the history is empty.
+    pub fn prev(&mut self, current: &str) -> Option<&str> {
+        let index = match self.cursor {
+            None if self.entries.is_empty() => return None,
+            None => {
+                self.draft = current.to_string();
+                self.entries.len() - 1
+            }
+            Some(index) => index.saturating_sub(1),
+        };
+        self.cursor = Some(index);
+        self.entries.get(index).map(String::as_str)
+    }
+
+    /// Step forward to a newer entry, or past the newest back to the line
+    /// that was being typed
+    ///
+    /// None when not navigating.
+    #[allow(clippy::should_implement_trait)]
+    pub fn next(&mut self) -> Option<&str> {
+        let index = self.cursor?;
+        if index + 1 < self.entries.len() {
+            self.cursor = Some(index + 1);
+            self.entries.get(index + 1).map(String::as_str)
+        } else {
+            self.cursor = None;
+            Some(&self.draft)
+        }
+    }
+
+    /// Check if `prev` has moved off the line being typed
+    pub fn is_navigating(&self) -> bool {
+        self.cursor.is_some()
+    }
+
+    /// Stop navigating; the next `prev` starts from the newest entry
+    pub fn reset(&mut self) {
+        self.cursor = None;
+        self.draft.clear();
+    }
+
+    /// Entries from oldest to newest
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Write the entries to a file, one per line, oldest first
+    pub fn save_to(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut contents = String::new();
+        for entry in &self.entries {
+            contents.push_str(entry);
+            contents.push('\n');
+        }
+        std::fs::write(path, contents)?;
+        Ok(())
+    }
+
+    /// Add the entries from a file written by `save_to`
+    ///
+    /// A file that doesn't exist yet counts as empty, so the first run of
+    /// an app can load from where it will save.
+    pub fn load_from(&mut self, path: impl AsRef<Path>) -> Result<()> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(e) => return Err(e.into()),
+        };
+        for line in contents.lines() {
+            self.push(line);
+        }
+        Ok(())
+    }
+}
+
+impl Default for CommandHistory {
+    fn default() -> Self {
+        Self::new(100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_navigation_returns_to_the_draft() {
+        let mut history = CommandHistory::new(10);
+        assert_eq!(history.prev("x"), None);
+
+        history.push("a");
+        history.push("b");
+        history.push("b");
+        history.push("  ");
+        assert_eq!(history.len(), 2);
+
+        assert_eq!(history.prev("typed"), Some("b"));
+        assert_eq!(history.prev("ignored"), Some("a"));
+        assert_eq!(history.prev("ignored"), Some("a"));
+        assert_eq!(history.next(), Some("b"));
+        assert_eq!(history.next(), Some("typed"));
+        assert!(!history.is_navigating());
+        assert_eq!(history.next(), None);
+    }
+
+    #[test]
+    fn test_capacity_drops_the_oldest() {
+        let mut history = CommandHistory::new(2);
+        for line in ["one", "two", "three"] {
+            history.push(line);
+        }
+        assert_eq!(history.iter().collect::<Vec<_>>(), ["two", "three"]);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let path = std::env::temp_dir().join(format!("rsdrav-history-{}", std::process::id()));
+        let mut history = CommandHistory::default();
+        history.push("open \"my file\"");
+        history.push("quit");
+        history.save_to(&path).unwrap();
+
+        let mut loaded = CommandHistory::default();
+        loaded.load_from(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            loaded.iter().collect::<Vec<_>>(),
+            ["open \"my file\"", "quit"]
+        );
+
+        // Nothing saved yet is fine
+        loaded.load_from(&path).unwrap();
+        assert_eq!(loaded.len(), 2);
+    }
+}
@@ -1,21 +1,31 @@
 //! Command engine with parser, registry, completion, and undo/redo
 //!
 //! The command system is first-class in rsdrav - provides:
-//! - Shell-like command parsing with quotes and escapes
+//! - Shell-like command parsing with quotes, escapes, pipes and redirection
 //! - Type-safe command handlers with context
 //! - Tab completion for commands and arguments
 //! - Built-in undo/redo with state snapshots
+//! - History of entered command lines with Up/Down recall
+//! - Async handlers for long-running commands (`tokio` feature)
 
+#[cfg(feature = "tokio")]
+mod async_handler;
 mod complete;
 mod handler;
+mod history;
 mod undo;
 
-pub use complete::{Completer, CompletionItem};
-pub use handler::{CommandContext, CommandHandler, CommandResult};
+#[cfg(feature = "tokio")]
+pub use async_handler::{AsyncCommandHandler, CommandFuture};
+pub use complete::{Completer, CompletionItem, FileCompleter, LineCompletions, ListCompleter};
+pub use handler::{CommandContext, CommandHandler, CommandResult, FollowUp, InputCallback};
+pub use history::CommandHistory;
 pub use undo::{UndoAction, UndoStack};
 
 use crate::error::{CommandError, Result};
 use std::collections::HashMap;
+#[cfg(feature = "tokio")]
+use std::sync::Arc;
 
 /// Parsed command with name and arguments
 #[derive(Clone, Debug, PartialEq)]
@@ -43,28 +53,92 @@ impl Command {
     }
 }
 
-/// Parse a command line into a Command
+/// Parsed command line: one or more commands joined by `|`, with an
+/// optional `> file` redirection of the last one's output
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pipeline {
+    /// Commands in the order they run; never empty when from `parse`
+    pub commands: Vec<Command>,
+    /// File the last command's output goes to
+    pub redirect: Option<String>,
+}
+
+impl Pipeline {
+    /// Check if the line was a single command with no pipe or redirection
+    pub fn is_simple(&self) -> bool {
+        self.commands.len() == 1 && self.redirect.is_none()
+    }
+}
+
+impl From<Command> for Pipeline {
+    fn from(cmd: Command) -> Self {
+        Self {
+            commands: vec![cmd],
+            redirect: None,
+        }
+    }
+}
+
+/// Piece of a command line: a word, or a `|`/`>` outside quotes
+#[derive(Debug, PartialEq)]
+enum Token {
+    Word(String),
+    Pipe,
+    Redirect,
+}
+
+/// Parse a command line into a Pipeline
 ///
 /// Supports shell-like syntax:
 /// - `command arg1 arg2` - simple args
 /// - `command "quoted arg"` - quoted args with spaces
 /// - `command 'single quotes'` - single quotes
 /// - `command arg\ with\ escape` - escaped spaces
+/// - `search foo | filter bar` - pipes between commands
+/// - `dump > out.txt` - redirecting output to a file, at the end only
 ///
-/// TODO: could add piping, redirection, etc. later if needed
-pub fn parse(input: &str) -> Result<Command> {
-    let input = input.trim();
+/// Quoted or escaped `|` and `>` are literal.
+pub fn parse(input: &str) -> Result<Pipeline> {
+    let mut commands = Vec::new();
+    let mut redirect = None;
+    let mut words = Vec::new();
+    let mut tokens = tokenize(input)?.into_iter();
+
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Word(word) => words.push(word),
+            Token::Pipe => commands.push(stage(std::mem::take(&mut words))?),
+            Token::Redirect => match (tokens.next(), tokens.next()) {
+                (Some(Token::Word(target)), None) => redirect = Some(target),
+                _ => return Err(CommandError::BadRedirect.into()),
+            },
+        }
+    }
 
-    if input.is_empty() {
+    if words.is_empty() && commands.is_empty() {
         return Err(CommandError::Empty.into());
     }
+    commands.push(stage(words)?);
 
+    Ok(Pipeline { commands, redirect })
+}
+
+/// Turn the words between pipes into a command
+fn stage(mut words: Vec<String>) -> Result<Command> {
+    if words.is_empty() {
+        return Err(CommandError::UnbalancedPipe.into());
+    }
+    let name = words.remove(0);
+    Ok(Command { name, args: words })
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>> {
     let mut tokens = Vec::new();
     let mut current = String::new();
     let mut in_quote: Option<char> = None;
     let mut escape_next = false;
 
-    for ch in input.chars() {
+    for ch in input.trim().chars() {
         if escape_next {
             current.push(ch);
             escape_next = false;
@@ -89,17 +163,19 @@ pub fn parse(input: &str) -> Result<Command> {
                     in_quote = Some(ch);
                 }
             }
-            ' ' | '\t' => {
-                if in_quote.is_some() {
-                    // Space inside quotes
-                    current.push(ch);
-                } else if !current.is_empty() {
-                    // End of token
-                    tokens.push(current.clone());
-                    current.clear();
+            ' ' | '\t' | '|' | '>' if in_quote.is_none() => {
+                // End of token
+                if !current.is_empty() {
+                    tokens.push(Token::Word(std::mem::take(&mut current)));
+                }
+                match ch {
+                    '|' => tokens.push(Token::Pipe),
+                    '>' => tokens.push(Token::Redirect),
+                    _ => {}
                 }
             }
             _ => {
+                // Including spaces, pipes and redirects inside quotes
                 current.push(ch);
             }
         }
@@ -112,38 +188,128 @@ pub fn parse(input: &str) -> Result<Command> {
 
     // Push last token
     if !current.is_empty() {
-        tokens.push(current);
-    }
-
-    if tokens.is_empty() {
-        return Err(CommandError::Empty.into());
+        tokens.push(Token::Word(current));
     }
 
-    let name = tokens[0].clone();
-    let args = tokens[1..].to_vec();
-
-    Ok(Command { name, args })
+    Ok(tokens)
 }
 
+/// How many chained commands one `execute` may run - stops a command
+/// that (directly or not) chains to itself from looping forever
+pub const MAX_CHAIN_DEPTH: usize = 16;
+
 /// Command registry - maps command names to handlers
 pub struct CommandRegistry {
     handlers: HashMap<String, Box<dyn CommandHandler>>,
+    /// Argument completers by command name and argument index
+    completers: HashMap<(String, usize), Box<dyn Completer>>,
+    #[cfg(feature = "tokio")]
+    async_handlers: HashMap<String, Arc<dyn AsyncCommandHandler>>,
+    /// Where async commands are spawned; falls back to the current runtime
+    #[cfg(feature = "tokio")]
+    runtime: Option<tokio::runtime::Handle>,
+    /// Whether `> file` in a pipeline may write to disk
+    redirect_to_files: bool,
 }
 
 impl CommandRegistry {
     pub fn new() -> Self {
         Self {
             handlers: HashMap::new(),
+            completers: HashMap::new(),
+            #[cfg(feature = "tokio")]
+            async_handlers: HashMap::new(),
+            #[cfg(feature = "tokio")]
+            runtime: None,
+            redirect_to_files: false,
         }
     }
 
+    /// Let `> file` redirections write command output to disk
+    ///
+    /// Off by default, since lines usually come from whoever is typing
+    /// into a command palette; with it off, a redirected pipeline fails
+    /// with `CommandError::RedirectDisabled` before anything runs.
+    pub fn with_redirect_to_files(mut self, enabled: bool) -> Self {
+        self.redirect_to_files = enabled;
+        self
+    }
+
     /// Register a command handler
     pub fn register<H: CommandHandler + 'static>(&mut self, name: &str, handler: H) {
+        #[cfg(feature = "tokio")]
+        self.async_handlers.remove(name);
         self.handlers.insert(name.to_string(), Box::new(handler));
     }
 
+    /// Register a handler for a long-running command
+    ///
+    /// Executing it spawns the handler's future and succeeds straight
+    /// away. Replaces a sync handler of the same name.
+    #[cfg(feature = "tokio")]
+    pub fn register_async<H: AsyncCommandHandler + 'static>(&mut self, name: &str, handler: H) {
+        self.handlers.remove(name);
+        self.async_handlers
+            .insert(name.to_string(), Arc::new(handler));
+    }
+
+    /// Spawn async commands on `runtime`
+    ///
+    /// Without one, they're spawned on the runtime the registry is
+    /// called from, and fail outside of one.
+    #[cfg(feature = "tokio")]
+    pub fn set_runtime(&mut self, runtime: &crate::async_support::AsyncRuntime) {
+        self.runtime = Some(runtime.handle());
+    }
+
     /// Execute a command by name
+    ///
+    /// `FollowUp::Chain` results are run here, and the last command's result
+    /// is returned. `FollowUp::OpenInput` is left in the result for the
+    /// caller to prompt for.
     pub fn execute(&mut self, cmd: Command, ctx: &mut CommandContext) -> Result<CommandResult> {
+        let mut result = self.execute_one(cmd, ctx)?;
+        let mut depth = 0;
+
+        while let Some(follow_up) = result.follow_up.take() {
+            let next = match follow_up {
+                FollowUp::Chain(next) => next,
+                other => {
+                    result.follow_up = Some(other);
+                    break;
+                }
+            };
+
+            depth += 1;
+            if depth > MAX_CHAIN_DEPTH {
+                return Err(CommandError::ChainTooDeep(MAX_CHAIN_DEPTH).into());
+            }
+
+            let needs_redraw = result.needs_redraw;
+            result = self.execute_one(next, ctx)?;
+            // Any step asking for a redraw still counts
+            result.needs_redraw |= needs_redraw;
+        }
+
+        Ok(result)
+    }
+
+    fn execute_one(&mut self, cmd: Command, ctx: &mut CommandContext) -> Result<CommandResult> {
+        #[cfg(feature = "tokio")]
+        if let Some(handler) = self.async_handlers.get(&cmd.name) {
+            let runtime = match &self.runtime {
+                Some(runtime) => runtime.clone(),
+                None => tokio::runtime::Handle::try_current().map_err(|_| {
+                    CommandError::ExecutionFailed(format!(
+                        "{} needs an async runtime to run on",
+                        cmd.name
+                    ))
+                })?,
+            };
+            runtime.spawn(handler.execute(cmd));
+            return Ok(CommandResult::success());
+        }
+
         let handler = self
             .handlers
             .get_mut(&cmd.name)
@@ -152,20 +318,121 @@ impl CommandRegistry {
         handler.execute(cmd, ctx)
     }
 
-    /// Execute a command from a string
+    /// Execute the commands of a pipeline in order
+    ///
+    /// Each command's message is passed to the next as its last argument.
+    /// With a redirection, the last message is written to the file instead
+    /// of being returned - only if enabled with `with_redirect_to_files`.
+    /// Only the last command's follow-up is kept.
+    pub fn execute_pipeline(
+        &mut self,
+        pipeline: Pipeline,
+        ctx: &mut CommandContext,
+    ) -> Result<CommandResult> {
+        if pipeline.redirect.is_some() && !self.redirect_to_files {
+            return Err(CommandError::RedirectDisabled.into());
+        }
+        let mut result: Option<CommandResult> = None;
+
+        for mut cmd in pipeline.commands {
+            let mut needs_redraw = false;
+            if let Some(prev) = result.take() {
+                cmd.args.extend(prev.message);
+                needs_redraw = prev.needs_redraw;
+            }
+            let mut next = self.execute(cmd, ctx)?;
+            next.needs_redraw |= needs_redraw;
+            result = Some(next);
+        }
+
+        let mut result = result.ok_or(CommandError::Empty)?;
+        if let Some(target) = pipeline.redirect {
+            let mut output = result.message.take().unwrap_or_default();
+            output.push('\n');
+            std::fs::write(target, output)?;
+        }
+        Ok(result)
+    }
+
+    /// Execute a command line from a string
     pub fn execute_line(&mut self, line: &str, ctx: &mut CommandContext) -> Result<CommandResult> {
-        let cmd = parse(line)?;
-        self.execute(cmd, ctx)
+        let pipeline = parse(line)?;
+        self.execute_pipeline(pipeline, ctx)
+    }
+
+    /// Complete argument `index` (0 for the first) of a command with
+    /// `completer`
+    ///
+    /// Closures taking the earlier args and the partial one work too.
+    pub fn register_completer<C: Completer + 'static>(
+        &mut self,
+        name: &str,
+        index: usize,
+        completer: C,
+    ) {
+        self.completers
+            .insert((name.to_string(), index), Box::new(completer));
+    }
+
+    /// Complete the token under the cursor (a byte offset into `line`)
+    ///
+    /// The first word of a command completes to registered command names;
+    /// later words go to the completer registered for that argument, if
+    /// any. After a pipe, the next command is completed.
+    pub fn complete(&self, line: &str, cursor: usize) -> LineCompletions {
+        let tokens = complete::tokens_at(line, cursor);
+
+        let items = match tokens.words.split_first() {
+            None => {
+                let mut names: Vec<_> = self
+                    .descriptions()
+                    .filter(|(name, _)| name.starts_with(&tokens.current))
+                    .map(|(name, description)| {
+                        CompletionItem::new(name).with_description(description)
+                    })
+                    .collect();
+                names.sort_by(|a, b| a.text.cmp(&b.text));
+                names
+            }
+            Some((name, args)) => self
+                .completers
+                .get(&(name.clone(), args.len()))
+                .map(|completer| completer.complete(args, &tokens.current))
+                .unwrap_or_default(),
+        };
+
+        LineCompletions {
+            start: tokens.start,
+            items,
+        }
     }
 
     /// Get list of registered command names
     pub fn command_names(&self) -> Vec<String> {
-        self.handlers.keys().cloned().collect()
+        self.descriptions()
+            .map(|(name, _)| name.to_string())
+            .collect()
     }
 
     /// Check if a command exists
     pub fn has_command(&self, name: &str) -> bool {
-        self.handlers.contains_key(name)
+        self.descriptions()
+            .any(|(registered, _)| registered == name)
+    }
+
+    /// Every command's name and description, sync and async
+    fn descriptions(&self) -> impl Iterator<Item = (&str, &str)> {
+        let sync = self
+            .handlers
+            .iter()
+            .map(|(name, handler)| (name.as_str(), handler.description()));
+        #[cfg(feature = "tokio")]
+        let sync = sync.chain(
+            self.async_handlers
+                .iter()
+                .map(|(name, handler)| (name.as_str(), handler.description())),
+        );
+        sync
     }
 }
 
@@ -179,37 +446,43 @@ impl Default for CommandRegistry {
 mod tests {
     use super::*;
 
+    fn parse_one(input: &str) -> Command {
+        let pipeline = parse(input).unwrap();
+        assert!(pipeline.is_simple());
+        pipeline.commands.into_iter().next().unwrap()
+    }
+
     #[test]
     fn test_parse_simple() {
-        let cmd = parse("echo hello world").unwrap();
+        let cmd = parse_one("echo hello world");
         assert_eq!(cmd.name, "echo");
         assert_eq!(cmd.args, vec!["hello", "world"]);
     }
 
     #[test]
     fn test_parse_quoted() {
-        let cmd = parse(r#"echo "hello world""#).unwrap();
+        let cmd = parse_one(r#"echo "hello world""#);
         assert_eq!(cmd.name, "echo");
         assert_eq!(cmd.args, vec!["hello world"]);
     }
 
     #[test]
     fn test_parse_single_quotes() {
-        let cmd = parse("echo 'hello world'").unwrap();
+        let cmd = parse_one("echo 'hello world'");
         assert_eq!(cmd.name, "echo");
         assert_eq!(cmd.args, vec!["hello world"]);
     }
 
     #[test]
     fn test_parse_mixed_quotes() {
-        let cmd = parse(r#"cmd "arg 1" arg2 'arg 3'"#).unwrap();
+        let cmd = parse_one(r#"cmd "arg 1" arg2 'arg 3'"#);
         assert_eq!(cmd.name, "cmd");
         assert_eq!(cmd.args, vec!["arg 1", "arg2", "arg 3"]);
     }
 
     #[test]
     fn test_parse_escaped() {
-        let cmd = parse(r"echo hello\ world").unwrap();
+        let cmd = parse_one(r"echo hello\ world");
         assert_eq!(cmd.args, vec!["hello world"]);
     }
 
@@ -224,6 +497,229 @@ mod tests {
         assert!(parse(r#"echo "unclosed"#).is_err());
     }
 
+    #[test]
+    fn test_parse_pipe() {
+        let pipeline = parse("search foo | filter 'bar baz'").unwrap();
+        assert_eq!(
+            pipeline.commands,
+            vec![
+                Command::new("search").arg("foo"),
+                Command::new("filter").arg("bar baz"),
+            ]
+        );
+        assert_eq!(pipeline.redirect, None);
+    }
+
+    #[test]
+    fn test_parse_redirect() {
+        let pipeline = parse("dump>out.txt").unwrap();
+        assert_eq!(pipeline.commands, vec![Command::new("dump")]);
+        assert_eq!(pipeline.redirect.as_deref(), Some("out.txt"));
+
+        assert!(matches!(
+            parse("dump >"),
+            Err(crate::error::Error::Command(CommandError::BadRedirect))
+        ));
+        assert!(parse("dump > a b").is_err());
+        assert!(parse("dump > a | more").is_err());
+    }
+
+    #[test]
+    fn test_parse_quoted_pipe_is_literal() {
+        let cmd = parse_one(r#"echo "a|b" 'c > d' e\|f"#);
+        assert_eq!(cmd.args, vec!["a|b", "c > d", "e|f"]);
+    }
+
+    #[test]
+    fn test_parse_unbalanced_pipe() {
+        for input in ["foo |", "| foo", "foo || bar", "foo | > out"] {
+            assert!(
+                matches!(
+                    parse(input),
+                    Err(crate::error::Error::Command(CommandError::UnbalancedPipe))
+                ),
+                "{}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_pipeline_passes_output_along() {
+        use crate::state::Store;
+
+        let mut registry = CommandRegistry::new();
+        registry.register("echo", handler::EchoHandler);
+
+        let mut ctx = CommandContext::new(Store::new());
+        let result = registry
+            .execute_line("echo hello | echo big | echo", &mut ctx)
+            .unwrap();
+        assert_eq!(result.message.as_deref(), Some("big hello"));
+    }
+
+    #[test]
+    fn test_redirect_needs_opt_in() {
+        use crate::state::Store;
+
+        let path = std::env::temp_dir().join(format!("rsdrav-redirect-{}", std::process::id()));
+        let line = format!("echo hello > {}", path.display());
+        let mut ctx = CommandContext::new(Store::new());
+
+        let mut registry = CommandRegistry::new();
+        registry.register("echo", handler::EchoHandler);
+        assert!(matches!(
+            registry.execute_line(&line, &mut ctx),
+            Err(crate::error::Error::Command(CommandError::RedirectDisabled))
+        ));
+        assert!(!path.exists());
+
+        let mut registry = CommandRegistry::new().with_redirect_to_files(true);
+        registry.register("echo", handler::EchoHandler);
+        let result = registry.execute_line(&line, &mut ctx).unwrap();
+        assert_eq!(result.message, None);
+        let written = std::fs::read_to_string(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(written, "hello\n");
+    }
+
+    struct ChainTo(&'static str);
+
+    impl CommandHandler for ChainTo {
+        fn execute(&mut self, _cmd: Command, _ctx: &mut CommandContext) -> Result<CommandResult> {
+            Ok(CommandResult::success().then(Command::new(self.0)))
+        }
+    }
+
+    #[test]
+    fn test_chain_runs_next_command() {
+        use crate::state::Store;
+
+        let mut registry = CommandRegistry::new();
+        registry.register("save-and-quit", ChainTo("quit"));
+        registry.register("quit", handler::QuitHandler);
+
+        let mut ctx = CommandContext::new(Store::new());
+        let result = registry
+            .execute(Command::new("save-and-quit"), &mut ctx)
+            .unwrap();
+
+        let should_quit: bool = ctx.store.get("app:should_quit").unwrap().get();
+        assert!(should_quit);
+        assert!(result.needs_redraw);
+        assert!(result.follow_up.is_none());
+    }
+
+    #[test]
+    fn test_chain_depth_limit() {
+        use crate::state::Store;
+
+        let mut registry = CommandRegistry::new();
+        registry.register("ping", ChainTo("pong"));
+        registry.register("pong", ChainTo("ping"));
+
+        let mut ctx = CommandContext::new(Store::new());
+        let err = registry
+            .execute(Command::new("ping"), &mut ctx)
+            .err()
+            .unwrap();
+        assert!(matches!(
+            err,
+            crate::error::Error::Command(CommandError::ChainTooDeep(_))
+        ));
+    }
+
+    #[test]
+    fn test_open_input_left_for_caller() {
+        use crate::state::Store;
+
+        struct Rename;
+        impl CommandHandler for Rename {
+            fn execute(&mut self, _: Command, _: &mut CommandContext) -> Result<CommandResult> {
+                Ok(CommandResult::success()
+                    .open_input("New name:", |name| Command::new("echo").arg(name)))
+            }
+        }
+
+        let mut registry = CommandRegistry::new();
+        registry.register("rename", Rename);
+        registry.register("echo", handler::EchoHandler);
+
+        let mut ctx = CommandContext::new(Store::new());
+        let result = registry.execute(Command::new("rename"), &mut ctx).unwrap();
+
+        match result.follow_up {
+            Some(FollowUp::OpenInput { prompt, on_submit }) => {
+                assert_eq!(prompt, "New name:");
+                let next = registry.execute(on_submit("notes.txt".into()), &mut ctx);
+                assert_eq!(next.unwrap().message.as_deref(), Some("notes.txt"));
+            }
+            _ => panic!("Expected an input request"),
+        }
+    }
+
+    #[test]
+    fn test_complete_dispatches_by_argument() {
+        let mut registry = CommandRegistry::new();
+        registry.register("open", handler::EchoHandler);
+        registry.register("echo", handler::EchoHandler);
+        registry.register_completer("open", 0, FileCompleter);
+        registry.register_completer("open", 1, |args: &[String], current: &str| {
+            vec![CompletionItem::new(format!("{}:{}", args[0], current))]
+        });
+
+        let names = registry.complete("o", 1);
+        assert_eq!(names.start, 0);
+        assert_eq!(names.items.len(), 1);
+        assert_eq!(names.items[0].text, "open");
+
+        let paths = registry.complete("open ./sr", 9);
+        assert_eq!(paths.start, 5);
+        assert_eq!(paths.items[0].text, "./src/");
+
+        let second = registry.complete("echo x | open a b", 17);
+        assert_eq!(second.start, 16);
+        assert_eq!(second.items[0].text, "a:b");
+
+        assert!(registry.complete("echo ./sr", 9).items.is_empty());
+    }
+
+    #[cfg(feature = "tokio")]
+    #[test]
+    fn test_async_command_sets_signal_when_done() {
+        use crate::async_support::AsyncRuntime;
+        use crate::state::{Signal, Store};
+        use std::time::Duration;
+
+        let runtime = AsyncRuntime::new().unwrap();
+        let status = Signal::new("idle".to_string());
+        let (done_tx, done_rx) = std::sync::mpsc::channel();
+
+        let mut registry = CommandRegistry::new();
+        registry.set_runtime(&runtime);
+        registry.register_async("fetch", {
+            let status = status.clone();
+            move |cmd: Command| {
+                let status = status.clone();
+                let done_tx = done_tx.clone();
+                async move {
+                    tokio::time::sleep(Duration::from_millis(20)).await;
+                    status.set(format!("fetched {}", cmd.args.join(" ")));
+                    done_tx.send(()).unwrap();
+                }
+            }
+        });
+        assert!(registry.has_command("fetch"));
+
+        // Returns before the command has finished
+        let mut ctx = CommandContext::new(Store::new());
+        registry.execute_line("fetch news", &mut ctx).unwrap();
+        assert_eq!(status.get(), "idle");
+
+        done_rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert_eq!(status.get(), "fetched news");
+    }
+
     #[test]
     fn test_command_builder() {
         let cmd = Command::new("test").arg("arg1").arg("arg2");
@@ -55,14 +55,66 @@ impl Clone for UndoAction {
     }
 }
 
+/// Actions recorded in one group, undone and redone together
+#[derive(Clone)]
+struct GroupAction {
+    actions: Vec<UndoAction>,
+}
+
+impl UndoableAction for GroupAction {
+    fn command_name(&self) -> &str {
+        self.actions
+            .first()
+            .map(UndoAction::command_name)
+            .unwrap_or("group")
+    }
+
+    fn undo(&mut self) -> bool {
+        // Last recorded is undone first
+        for i in (0..self.actions.len()).rev() {
+            if !self.actions[i].undo() {
+                // Put back the ones already undone
+                for action in &mut self.actions[i + 1..] {
+                    action.redo();
+                }
+                return false;
+            }
+        }
+        true
+    }
+
+    fn redo(&mut self) -> bool {
+        for i in 0..self.actions.len() {
+            if !self.actions[i].redo() {
+                for action in self.actions[..i].iter_mut().rev() {
+                    action.undo();
+                }
+                return false;
+            }
+        }
+        true
+    }
+
+    fn clone_box(&self) -> Box<dyn UndoableAction> {
+        Box::new(self.clone())
+    }
+}
+
 /// Stack for undo/redo operations
 ///
 /// Maintains history of undoable actions with a max size.
 /// When undo is called, pops from undo stack and pushes to redo stack.
+///
+/// Actions pushed between `begin_group` and `end_group` (or inside
+/// `transaction`) become one entry, undone and redone as a unit. Groups
+/// opened inside a group just join the outer one.
 pub struct UndoStack {
     undo: Vec<UndoAction>,
     redo: Vec<UndoAction>,
     max_size: usize,
+    /// Actions of the open group, and how many groups are open
+    group: Vec<UndoAction>,
+    group_depth: usize,
 }
 
 impl UndoStack {
@@ -71,9 +123,50 @@ impl UndoStack {
             undo: Vec::new(),
             redo: Vec::new(),
             max_size,
+            group: Vec::new(),
+            group_depth: 0,
         }
     }
 
+    /// Start collecting pushed actions into one undo entry
+    pub fn begin_group(&mut self) {
+        self.group_depth += 1;
+    }
+
+    /// Close the group opened by the matching `begin_group`
+    ///
+    /// Closing the outermost group pushes what it collected as one entry;
+    /// an empty group pushes nothing.
+    pub fn end_group(&mut self) {
+        if self.group_depth == 0 {
+            return;
+        }
+        self.group_depth -= 1;
+        if self.group_depth > 0 {
+            return;
+        }
+
+        let mut actions = std::mem::take(&mut self.group);
+        match actions.len() {
+            0 => {}
+            1 => self.push(actions.remove(0)),
+            _ => self.push(UndoAction::new(GroupAction { actions })),
+        }
+    }
+
+    /// Check if a group is collecting actions
+    pub fn in_group(&self) -> bool {
+        self.group_depth > 0
+    }
+
+    /// Run `f` with every action it pushes grouped into one entry
+    pub fn transaction<R>(&mut self, f: impl FnOnce(&mut Self) -> R) -> R {
+        self.begin_group();
+        let result = f(self);
+        self.end_group();
+        result
+    }
+
     /// Push an action onto the undo stack
     ///
     /// Clears the redo stack since we're on a new timeline.
@@ -81,6 +174,11 @@ impl UndoStack {
         // Clear redo stack - we're on a new branch now
         self.redo.clear();
 
+        if self.group_depth > 0 {
+            self.group.push(action);
+            return;
+        }
+
         // Add to undo stack
         self.undo.push(action);
 
@@ -136,10 +234,11 @@ impl UndoStack {
         !self.redo.is_empty()
     }
 
-    /// Clear all history
+    /// Clear all history, including an open group's actions
     pub fn clear(&mut self) {
         self.undo.clear();
         self.redo.clear();
+        self.group.clear();
     }
 
     /// Get size of undo stack
@@ -253,6 +352,63 @@ mod tests {
         assert_eq!(stack.redo_len(), 0);
     }
 
+    /// Adds to a shared total
+    #[derive(Clone)]
+    struct Add(std::sync::Arc<std::sync::atomic::AtomicI32>, i32);
+
+    impl UndoableAction for Add {
+        fn command_name(&self) -> &str {
+            "add"
+        }
+
+        fn undo(&mut self) -> bool {
+            self.0
+                .fetch_sub(self.1, std::sync::atomic::Ordering::SeqCst);
+            true
+        }
+
+        fn redo(&mut self) -> bool {
+            self.0
+                .fetch_add(self.1, std::sync::atomic::Ordering::SeqCst);
+            true
+        }
+
+        fn clone_box(&self) -> Box<dyn UndoableAction> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[test]
+    fn test_group_undoes_as_one() {
+        use std::sync::atomic::{AtomicI32, Ordering};
+        use std::sync::Arc;
+
+        let total = Arc::new(AtomicI32::new(0));
+        let add = |stack: &mut UndoStack, n| {
+            total.fetch_add(n, Ordering::SeqCst);
+            stack.push(UndoAction::new(Add(total.clone(), n)));
+        };
+
+        let mut stack = UndoStack::new(10);
+        add(&mut stack, 100);
+        stack.begin_group();
+        add(&mut stack, 1);
+        stack.transaction(|stack| {
+            // Nested: joins the outer group
+            add(stack, 2);
+            add(stack, 3);
+        });
+        assert!(stack.in_group());
+        assert_eq!(stack.undo_len(), 1);
+        stack.end_group();
+        assert_eq!(stack.undo_len(), 2);
+
+        assert!(stack.undo().is_some());
+        assert_eq!(total.load(Ordering::SeqCst), 100);
+        assert!(stack.redo().is_some());
+        assert_eq!(total.load(Ordering::SeqCst), 106);
+    }
+
     #[test]
     fn test_clear() {
         let mut stack = UndoStack::new(10);
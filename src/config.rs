@@ -0,0 +1,305 @@
+//! User configuration: keybindings and theme overrides
+//!
+//! A `Config` only lists what the user changed. `bindings()` and `theme()`
+//! merge it over the defaults, so a config file with a single entry is
+//! fine.
+//!
+//! With the `serde` feature, configs can be loaded from JSON:
+//!
+//! ```json
+//! {
+//!     "keys": { "quit": ["ctrl+q"] },
+//!     "theme": { "accent": "#FF8800" }
+//! }
+//! ```
+//!
+//! and with the `toml` feature, from TOML (see also
+//! `theme::load_from_toml` for a file with just the theme).
+
+use crate::error::{Error, Result};
+use crate::event::KeyEvent;
+use crate::theme::{Color, Theme};
+use std::collections::HashMap;
+
+/// Keys bound to named actions
+///
+/// An action can have several keys. The app uses `quit`, `focus_next` and
+/// `focus_prev`; anything else is free for the application to look up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bindings {
+    actions: HashMap<String, Vec<KeyEvent>>,
+}
+
+impl Bindings {
+    /// No bindings at all
+    pub fn empty() -> Self {
+        Self {
+            actions: HashMap::new(),
+        }
+    }
+
+    /// Replace the keys for an action
+    pub fn bind(&mut self, action: impl Into<String>, keys: Vec<KeyEvent>) {
+        self.actions.insert(action.into(), keys);
+    }
+
+    /// Keys bound to an action
+    pub fn keys(&self, action: &str) -> &[KeyEvent] {
+        self.actions.get(action).map_or(&[], |keys| keys.as_slice())
+    }
+
+    /// Check if `key` triggers `action`
+    pub fn matches(&self, action: &str, key: &KeyEvent) -> bool {
+        self.keys(action).contains(key)
+    }
+
+    /// The action a key is bound to, if any
+    pub fn action_for(&self, key: &KeyEvent) -> Option<&str> {
+        self.actions
+            .iter()
+            .find(|(_, keys)| keys.contains(key))
+            .map(|(action, _)| action.as_str())
+    }
+
+    /// Every action with its keys, in no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[KeyEvent])> {
+        self.actions
+            .iter()
+            .map(|(action, keys)| (action.as_str(), keys.as_slice()))
+    }
+}
+
+impl Default for Bindings {
+    fn default() -> Self {
+        let mut bindings = Self::empty();
+        let parse = |keys: &[&str]| keys.iter().filter_map(|k| KeyEvent::parse(k)).collect();
+        bindings.bind("quit", parse(&["q", "ctrl+c"]));
+        bindings.bind("focus_next", parse(&["tab"]));
+        bindings.bind("focus_prev", parse(&["shift+tab", "backtab"]));
+        bindings
+    }
+}
+
+/// Theme colors to override; unset ones keep the default
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct ThemeOverrides {
+    pub primary: Option<String>,
+    pub accent: Option<String>,
+    pub background: Option<String>,
+    pub error: Option<String>,
+    pub focus: Option<String>,
+    /// Background of striped table rows
+    pub stripe: Option<String>,
+}
+
+/// User configuration as loaded from a file
+///
+/// Keys map action names to key descriptions (see `KeyEvent::parse`).
+/// Colors are `#RRGGBB` or a name (see `Color::parse`).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default, deny_unknown_fields))]
+pub struct Config {
+    pub keys: HashMap<String, Vec<String>>,
+    pub theme: ThemeOverrides,
+}
+
+impl Config {
+    /// Parse a JSON config
+    #[cfg(feature = "serde")]
+    pub fn from_json(source: &str) -> Result<Self> {
+        serde_json::from_str(source).map_err(|e| Error::Config(e.to_string()))
+    }
+
+    /// Parse a TOML config
+    #[cfg(feature = "toml")]
+    pub fn from_toml(source: &str) -> Result<Self> {
+        toml::from_str(source).map_err(|e| Error::Config(e.to_string()))
+    }
+
+    /// Default theme with the colors from a TOML theme table applied
+    #[cfg(feature = "toml")]
+    pub(crate) fn theme_from_toml(source: &str) -> Result<Theme> {
+        let theme: ThemeOverrides =
+            toml::from_str(source).map_err(|e| Error::Config(e.to_string()))?;
+        Config {
+            theme,
+            ..Config::default()
+        }
+        .theme()
+    }
+
+    /// Default bindings with this config's keys applied on top
+    ///
+    /// An action listed in the config replaces that action's default keys;
+    /// other actions keep theirs.
+    pub fn bindings(&self) -> Result<Bindings> {
+        let mut bindings = Bindings::default();
+        for (action, keys) in &self.keys {
+            let keys = keys
+                .iter()
+                .map(|k| {
+                    KeyEvent::parse(k)
+                        .ok_or_else(|| Error::Config(format!("invalid key '{}' for {}", k, action)))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            bindings.bind(action.clone(), keys);
+        }
+        Ok(bindings)
+    }
+
+    /// Default theme with this config's colors applied on top
+    pub fn theme(&self) -> Result<Theme> {
+        let color = |name: &str, value: &Option<String>| -> Result<Option<Color>> {
+            value
+                .as_deref()
+                .map(|color| {
+                    Color::parse(color).ok_or_else(|| {
+                        Error::Config(format!("invalid color '{}' for {}", color, name))
+                    })
+                })
+                .transpose()
+        };
+
+        let overrides = &self.theme;
+        let mut theme = Theme::default();
+        if let Some(c) = color("primary", &overrides.primary)? {
+            theme = theme.with_primary(theme.primary().fg(c));
+        }
+        if let Some(c) = color("accent", &overrides.accent)? {
+            theme = theme.with_accent(theme.accent().fg(c));
+        }
+        if let Some(c) = color("background", &overrides.background)? {
+            theme = theme.with_background(theme.background().bg(c));
+        }
+        if let Some(c) = color("error", &overrides.error)? {
+            theme = theme.with_error(theme.error().fg(c));
+        }
+        if let Some(c) = color("focus", &overrides.focus)? {
+            theme = theme.with_focus_indicator(theme.focus_indicator().fg(c));
+        }
+        if let Some(c) = color("stripe", &overrides.stripe)? {
+            theme = theme.with_stripe(theme.stripe().bg(c));
+        }
+        Ok(theme)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{KeyCode, KeyModifiers};
+
+    #[test]
+    fn test_parse_keys() {
+        assert_eq!(
+            KeyEvent::parse("ctrl+s"),
+            Some(KeyEvent::new(KeyCode::Char('s'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(
+            KeyEvent::parse("Shift+Tab"),
+            Some(KeyEvent::new(KeyCode::Tab, KeyModifiers::SHIFT))
+        );
+        assert_eq!(
+            KeyEvent::parse("f5"),
+            Some(KeyEvent::new(KeyCode::F(5), KeyModifiers::empty()))
+        );
+        assert_eq!(
+            KeyEvent::parse("ctrl++"),
+            Some(KeyEvent::new(KeyCode::Char('+'), KeyModifiers::CONTROL))
+        );
+        assert_eq!(KeyEvent::parse("hyper+x"), None);
+        assert_eq!(KeyEvent::parse("nope"), None);
+    }
+
+    #[test]
+    fn test_partial_config_merges_over_defaults() {
+        let mut config = Config::default();
+        config
+            .keys
+            .insert("quit".to_string(), vec!["ctrl+q".to_string()]);
+
+        let bindings = config.bindings().unwrap();
+        let ctrl_q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL);
+        let q = KeyEvent::new(KeyCode::Char('q'), KeyModifiers::empty());
+        assert!(bindings.matches("quit", &ctrl_q));
+        assert!(!bindings.matches("quit", &q));
+        assert_eq!(
+            bindings.keys("focus_next"),
+            Bindings::default().keys("focus_next")
+        );
+
+        assert_eq!(config.theme().unwrap(), Theme::default());
+    }
+
+    #[test]
+    fn test_invalid_entries_are_errors() {
+        let mut config = Config::default();
+        config.theme.accent = Some("orange".to_string());
+        assert!(matches!(config.theme(), Err(Error::Config(_))));
+
+        config
+            .keys
+            .insert("quit".to_string(), vec!["bogus".to_string()]);
+        assert!(matches!(config.bindings(), Err(Error::Config(_))));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_load_json() {
+        let config = Config::from_json(
+            r##"{ "keys": { "quit": ["ctrl+q"] }, "theme": { "accent": "#FF8800" } }"##,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.theme().unwrap().accent().fg,
+            Some(Color::rgb(255, 136, 0))
+        );
+        assert!(config.bindings().unwrap().matches(
+            "quit",
+            &KeyEvent::new(KeyCode::Char('q'), KeyModifiers::CONTROL)
+        ));
+
+        assert!(matches!(
+            Config::from_json("{ not json"),
+            Err(Error::Config(_))
+        ));
+    }
+
+    #[cfg(feature = "toml")]
+    #[test]
+    fn test_load_theme_toml() {
+        use crate::theme::load_from_toml;
+
+        let path = std::env::temp_dir().join(format!("rsdrav-dark-{}.toml", std::process::id()));
+        std::fs::write(
+            &path,
+            "primary = \"#E0E0E0\"\naccent = \"Magenta\"\nbackground = \"#1e1e2e\"\n",
+        )
+        .unwrap();
+        let theme = load_from_toml(&path);
+        std::fs::remove_file(&path).unwrap();
+        let theme = theme.unwrap();
+
+        assert_eq!(theme.primary().fg, Some(Color::gray(224)));
+        assert_eq!(theme.accent().fg, Some(Color::MAGENTA));
+        assert_eq!(theme.background().bg, Some(Color::rgb(30, 30, 46)));
+        assert_eq!(theme.error(), Theme::default().error());
+
+        assert!(matches!(
+            Config::theme_from_toml("accent = \"orange\""),
+            Err(Error::Config(_))
+        ));
+        assert!(matches!(
+            Config::theme_from_toml("acent = \"red\""),
+            Err(Error::Config(_))
+        ));
+        assert!(matches!(
+            load_from_toml("/nonexistent/theme.toml"),
+            Err(Error::Io(_))
+        ));
+    }
+}
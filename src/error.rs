@@ -11,6 +11,9 @@ pub enum Error {
     #[error("Backend error: {0}")]
     Backend(String),
 
+    #[error("Not a terminal: stdin and stdout must both be a TTY")]
+    NotATerminal,
+
     #[error("Render error: {0}")]
     Render(String),
 
@@ -28,6 +31,16 @@ pub enum Error {
 
     #[error("Layout error: {0}")]
     Layout(String),
+
+    #[error("Config error: {0}")]
+    Config(String),
+
+    #[error("Frame {frame} took {elapsed:?} (budget {budget:?})")]
+    SlowFrame {
+        frame: u64,
+        elapsed: std::time::Duration,
+        budget: std::time::Duration,
+    },
 }
 
 #[derive(Debug, Error)]
@@ -44,8 +57,20 @@ pub enum CommandError {
     #[error("Unclosed quote in command")]
     UnclosedQuote,
 
+    #[error("Pipe with no command on one side")]
+    UnbalancedPipe,
+
+    #[error("Redirection needs one target file at the end of the command")]
+    BadRedirect,
+
+    #[error("Redirecting output to files is disabled")]
+    RedirectDisabled,
+
     #[error("Empty command")]
     Empty,
+
+    #[error("Command chain too deep (limit {0})")]
+    ChainTooDeep(usize),
 }
 
 #[derive(Debug, Error)]
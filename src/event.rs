@@ -1,6 +1,9 @@
 // Event types for keyboard, mouse, resize, etc.
 // Full event routing system comes later
 
+use crate::error::{Error, Result};
+use std::time::Duration;
+
 /// Result of event handling
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum EventResult {
@@ -56,6 +59,61 @@ impl KeyEvent {
     pub fn new(code: KeyCode, modifiers: KeyModifiers) -> Self {
         Self { code, modifiers }
     }
+
+    /// Parse a key description like `"q"`, `"ctrl+s"` or `"shift+tab"`
+    ///
+    /// Modifiers (`ctrl`, `alt`, `shift`, `super`) come first, joined with
+    /// `+`. Named keys are case-insensitive; single characters are taken
+    /// as-is, so `"Q"` and `"q"` are different keys.
+    pub fn parse(s: &str) -> Option<Self> {
+        let mut parts: Vec<&str> = s.split('+').collect();
+        // "ctrl++" binds the plus key
+        if s.ends_with("++") {
+            parts.truncate(parts.len() - 2);
+            parts.push("+");
+        }
+        let key = parts.pop()?;
+
+        let mut modifiers = KeyModifiers::empty();
+        for part in parts {
+            modifiers |= match part.to_ascii_lowercase().as_str() {
+                "ctrl" | "control" => KeyModifiers::CONTROL,
+                "alt" => KeyModifiers::ALT,
+                "shift" => KeyModifiers::SHIFT,
+                "super" | "cmd" => KeyModifiers::SUPER,
+                _ => return None,
+            };
+        }
+
+        let mut chars = key.chars();
+        let code = match (chars.next(), chars.next()) {
+            (Some(c), None) => KeyCode::Char(c),
+            _ => match key.to_ascii_lowercase().as_str() {
+                "enter" | "return" => KeyCode::Enter,
+                "esc" | "escape" => KeyCode::Esc,
+                "tab" => KeyCode::Tab,
+                "backtab" => KeyCode::BackTab,
+                "backspace" => KeyCode::Backspace,
+                "delete" | "del" => KeyCode::Delete,
+                "insert" => KeyCode::Insert,
+                "space" => KeyCode::Char(' '),
+                "left" => KeyCode::Left,
+                "right" => KeyCode::Right,
+                "up" => KeyCode::Up,
+                "down" => KeyCode::Down,
+                "home" => KeyCode::Home,
+                "end" => KeyCode::End,
+                "pageup" => KeyCode::PageUp,
+                "pagedown" => KeyCode::PageDown,
+                name => {
+                    let n = name.strip_prefix('f')?.parse().ok()?;
+                    KeyCode::F(n)
+                }
+            },
+        };
+
+        Some(Self::new(code, modifiers))
+    }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -68,6 +126,9 @@ pub enum MouseButton {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MouseEventKind {
     Down(MouseButton),
+    /// Second press of a button on the same cell in quick succession,
+    /// sent by the `App` right after that press's `Down`
+    DoubleClick(MouseButton),
     Up(MouseButton),
     Drag(MouseButton),
     Moved,
@@ -93,6 +154,145 @@ pub enum Event {
     Paste(String),
 }
 
+/// What a key did to a `ChordMatcher`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChordResult {
+    /// The key finished a sequence bound to this action
+    Matched(String),
+    /// The key is part of a sequence that isn't finished yet
+    Pending,
+    /// The key isn't part of any sequence
+    Unmatched,
+}
+
+/// Recognizes multi-key sequences like `g g`, vim style
+///
+/// Feed it every key with `feed`; it fires an action when the keys pressed
+/// so far spell out a registered sequence. A key that doesn't continue any
+/// sequence drops the keys before it (and may start a new sequence
+/// itself), as does waiting longer than the timeout between keys. Time
+/// only moves with `advance` - from a component, call it from `update`
+/// with the frame delta.
+///
+/// ## Example
+/// ```
+/// use rsdrav::event::{ChordMatcher, ChordResult, KeyCode, KeyEvent, KeyModifiers};
+///
+/// let mut chords = ChordMatcher::new();
+/// chords.bind(&["g", "g"], "top").unwrap();
+///
+/// let g = KeyEvent::new(KeyCode::Char('g'), KeyModifiers::empty());
+/// assert_eq!(chords.feed(g), ChordResult::Pending);
+/// assert_eq!(chords.feed(g), ChordResult::Matched("top".into()));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ChordMatcher {
+    sequences: Vec<(Vec<KeyEvent>, String)>,
+    pending: Vec<KeyEvent>,
+    since_last: Duration,
+    timeout: Duration,
+}
+
+impl ChordMatcher {
+    /// A matcher with no sequences and a one second timeout
+    pub fn new() -> Self {
+        Self {
+            sequences: Vec::new(),
+            pending: Vec::new(),
+            since_last: Duration::ZERO,
+            timeout: Duration::from_secs(1),
+        }
+    }
+
+    /// Set the longest wait between two keys of a sequence
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Fire `action` when `keys` are pressed in order, replacing any
+    /// action already on that sequence
+    pub fn register(&mut self, keys: Vec<KeyEvent>, action: impl Into<String>) {
+        if keys.is_empty() {
+            return;
+        }
+        let action = action.into();
+        match self.sequences.iter_mut().find(|(seq, _)| *seq == keys) {
+            Some((_, bound)) => *bound = action,
+            None => self.sequences.push((keys, action)),
+        }
+    }
+
+    /// Like `register`, with keys described as for `KeyEvent::parse`
+    pub fn bind(&mut self, keys: &[&str], action: impl Into<String>) -> Result<()> {
+        let keys = keys
+            .iter()
+            .map(|k| {
+                KeyEvent::parse(k).ok_or_else(|| Error::Config(format!("invalid key '{}'", k)))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        self.register(keys, action);
+        Ok(())
+    }
+
+    /// Check if some keys of an unfinished sequence are waiting
+    pub fn is_pending(&self) -> bool {
+        !self.pending.is_empty()
+    }
+
+    /// Let time pass
+    pub fn advance(&mut self, delta: Duration) {
+        self.since_last += delta;
+        if self.since_last > self.timeout {
+            self.pending.clear();
+        }
+    }
+
+    /// Forget any keys pressed so far
+    pub fn reset(&mut self) {
+        self.pending.clear();
+    }
+
+    /// Take a key press
+    pub fn feed(&mut self, key: KeyEvent) -> ChordResult {
+        self.since_last = Duration::ZERO;
+        self.pending.push(key);
+        let continuing = self.pending.len() > 1;
+        match self.check() {
+            ChordResult::Unmatched if continuing => {
+                // Broke off a sequence; maybe this key starts another
+                self.pending = vec![key];
+                self.check()
+            }
+            result => result,
+        }
+    }
+
+    fn check(&mut self) -> ChordResult {
+        let pending = &self.pending;
+        if let Some((_, action)) = self.sequences.iter().find(|(seq, _)| seq == pending) {
+            let action = action.clone();
+            self.pending.clear();
+            return ChordResult::Matched(action);
+        }
+        if self
+            .sequences
+            .iter()
+            .any(|(seq, _)| seq.starts_with(pending))
+        {
+            return ChordResult::Pending;
+        }
+        self.pending.clear();
+        ChordResult::Unmatched
+    }
+}
+
+impl Default for ChordMatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // Conversion from crossterm events
 #[cfg(feature = "crossterm")]
 impl Event {
@@ -191,3 +391,48 @@ impl Event {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "crossterm")]
+    #[test]
+    fn test_crossterm_focus_events() {
+        use crossterm::event as ct;
+
+        assert_eq!(
+            Event::from_crossterm(ct::Event::FocusGained),
+            Event::FocusGained
+        );
+        assert_eq!(
+            Event::from_crossterm(ct::Event::FocusLost),
+            Event::FocusLost
+        );
+    }
+
+    #[test]
+    fn test_chord_fires_on_the_full_sequence() {
+        let key = |c| KeyEvent::new(KeyCode::Char(c), KeyModifiers::empty());
+        let mut chords = ChordMatcher::new().timeout(Duration::from_millis(500));
+        chords.bind(&["g", "g"], "top").unwrap();
+        chords.bind(&["d", "d"], "delete").unwrap();
+
+        assert_eq!(chords.feed(key('g')), ChordResult::Pending);
+        assert_eq!(chords.feed(key('g')), ChordResult::Matched("top".into()));
+
+        // A mismatch drops the first key but can start a new sequence
+        chords.feed(key('g'));
+        assert_eq!(chords.feed(key('d')), ChordResult::Pending);
+        assert_eq!(chords.feed(key('d')), ChordResult::Matched("delete".into()));
+        assert_eq!(chords.feed(key('x')), ChordResult::Unmatched);
+
+        // Too slow
+        chords.feed(key('g'));
+        chords.advance(Duration::from_millis(600));
+        assert!(!chords.is_pending());
+        assert_eq!(chords.feed(key('g')), ChordResult::Pending);
+
+        assert!(chords.bind(&["g", "bogus"], "nope").is_err());
+    }
+}
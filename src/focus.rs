@@ -1,9 +1,20 @@
 //! Focus management system
 //!
-//! Tracks which component has keyboard focus and enables Tab navigation.
+//! Tracks which component has keyboard focus and enables Tab navigation,
+//! plus arrow-key navigation between components laid out on screen.
 
+use crate::layout::Rect;
 use std::collections::HashMap;
 
+/// Screen direction for `FocusManager::focus_direction`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
 /// Unique identifier for a focusable component
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ComponentId(pub usize);
@@ -20,6 +31,10 @@ impl ComponentId {
 /// Components register themselves with an ID and order.
 /// Tab/Shift+Tab cycles through focusable components.
 ///
+/// `push_scope` starts a new focus scope, e.g. for a modal: components
+/// registered after it are the only ones focus can move to until
+/// `pop_scope` removes them and gives focus back to what had it before.
+///
 /// ## Example
 /// ```no_run
 /// use rsdrav::focus::{FocusManager, ComponentId};
@@ -42,6 +57,8 @@ pub struct FocusManager {
     current: Option<ComponentId>,
     /// Next ID to assign
     next_id: usize,
+    /// Focus to restore for each pushed scope, innermost last
+    scopes: Vec<Option<ComponentId>>,
 }
 
 #[derive(Debug, Clone)]
@@ -49,6 +66,10 @@ struct FocusableComponent {
     id: ComponentId,
     order: usize,
     focusable: bool,
+    /// Screen area from the last frame, if reported
+    rect: Option<Rect>,
+    /// How many scopes were pushed when it was registered
+    scope: usize,
 }
 
 impl FocusManager {
@@ -58,6 +79,7 @@ impl FocusManager {
             components: Vec::new(),
             current: None,
             next_id: 1,
+            scopes: Vec::new(),
         }
     }
 
@@ -68,7 +90,7 @@ impl FocusManager {
         id
     }
 
-    /// Register a focusable component
+    /// Register a focusable component in the current scope
     ///
     /// - `id`: Unique component identifier
     /// - `order`: Tab order (lower numbers focused first)
@@ -82,6 +104,8 @@ impl FocusManager {
             id,
             order,
             focusable,
+            rect: None,
+            scope: self.scopes.len(),
         });
 
         // Keep sorted by order
@@ -93,6 +117,59 @@ impl FocusManager {
         }
     }
 
+    /// Register a component along with where it is on screen
+    ///
+    /// Components registered this way come after the others in tab order,
+    /// in the order they're registered; registering one again keeps its
+    /// place and updates the rect.
+    pub fn register_at(&mut self, id: ComponentId, rect: Rect, focusable: bool) {
+        let order = match self.components.iter().find(|c| c.id == id) {
+            Some(existing) => existing.order,
+            None => self
+                .components
+                .iter()
+                .map(|c| c.order + 1)
+                .max()
+                .unwrap_or(0),
+        };
+        self.register(id, order, focusable);
+        self.set_rect(id, rect);
+    }
+
+    /// Start a scope that focus can't leave, e.g. when a modal opens
+    ///
+    /// Nothing is focused until a component registers in the new scope.
+    pub fn push_scope(&mut self) {
+        self.scopes.push(self.current.take());
+    }
+
+    /// End the innermost scope, unregistering its components and focusing
+    /// what had focus when it was pushed
+    ///
+    /// Returns false if no scope was pushed.
+    pub fn pop_scope(&mut self) -> bool {
+        let Some(restore) = self.scopes.pop() else {
+            return false;
+        };
+        let depth = self.scopes.len();
+        self.components.retain(|c| c.scope <= depth);
+        self.current = None;
+        if let Some(id) = restore {
+            self.focus(id);
+        }
+        true
+    }
+
+    /// Number of scopes pushed and not yet popped
+    pub fn scope_depth(&self) -> usize {
+        self.scopes.len()
+    }
+
+    /// Check if a component can take focus in the current scope
+    fn can_focus(&self, comp: &FocusableComponent) -> bool {
+        comp.focusable && comp.scope == self.scopes.len()
+    }
+
     /// Unregister a component (when it's removed from UI)
     pub fn unregister(&mut self, id: ComponentId) {
         self.components.retain(|c| c.id != id);
@@ -118,10 +195,12 @@ impl FocusManager {
     }
 
     /// Set focus to a specific component
+    ///
+    /// Fails for components outside the current scope.
     pub fn focus(&mut self, id: ComponentId) -> bool {
         // Check if component exists and is focusable
         if let Some(comp) = self.components.iter().find(|c| c.id == id) {
-            if comp.focusable {
+            if self.can_focus(comp) {
                 self.current = Some(id);
                 return true;
             }
@@ -150,7 +229,7 @@ impl FocusManager {
             let idx = (start_idx + offset) % self.components.len();
             let comp = &self.components[idx];
 
-            if comp.focusable {
+            if self.can_focus(comp) {
                 self.current = Some(comp.id);
                 return true;
             }
@@ -193,7 +272,7 @@ impl FocusManager {
 
             let comp = &self.components[idx];
 
-            if comp.focusable {
+            if self.can_focus(comp) {
                 self.current = Some(comp.id);
                 return true;
             }
@@ -202,15 +281,117 @@ impl FocusManager {
         false
     }
 
+    /// Focus the nearest component to the left (Left arrow)
+    pub fn focus_left(&mut self) -> bool {
+        self.focus_direction(Direction::Left)
+    }
+
+    /// Focus the nearest component to the right (Right arrow)
+    pub fn focus_right(&mut self) -> bool {
+        self.focus_direction(Direction::Right)
+    }
+
+    /// Focus the nearest component above (Up arrow)
+    pub fn focus_up(&mut self) -> bool {
+        self.focus_direction(Direction::Up)
+    }
+
+    /// Focus the nearest component below (Down arrow)
+    pub fn focus_down(&mut self) -> bool {
+        self.focus_direction(Direction::Down)
+    }
+
+    /// Focus the nearest focusable component in a direction on screen
+    ///
+    /// Compares rect centers: candidates must lie past the focused one in
+    /// that direction, and the closest wins, with sideways distance
+    /// counting double so a component straight across beats a nearer one
+    /// off at an angle. Components without a rect are skipped.
+    ///
+    /// Returns false, leaving focus alone, when nothing lies that way.
+    /// With nothing focused, or a focused component with no rect, it
+    /// falls back to `focus_next`.
+    pub fn focus_direction(&mut self, direction: Direction) -> bool {
+        let Some(from) = self.current.and_then(|id| self.rect(id)) else {
+            return self.focus_next();
+        };
+        let (fx, fy) = center(from);
+
+        let target = self
+            .components
+            .iter()
+            .filter(|c| self.can_focus(c) && Some(c.id) != self.current)
+            .filter_map(|c| {
+                let (x, y) = center(c.rect?);
+                let (along, across) = match direction {
+                    Direction::Left => (fx - x, y - fy),
+                    Direction::Right => (x - fx, y - fy),
+                    Direction::Up => (fy - y, x - fx),
+                    Direction::Down => (y - fy, x - fx),
+                };
+                (along > 0).then_some((c.id, along + 2 * across.abs()))
+            })
+            .min_by_key(|(_, distance)| *distance)
+            .map(|(id, _)| id);
+
+        match target {
+            Some(id) => {
+                self.current = Some(id);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Record where a component was drawn, for mouse hit-testing
+    ///
+    /// The App does this every frame for components rendered through
+    /// `RenderContext::render_child`. Ignored for unregistered ids.
+    pub fn set_rect(&mut self, id: ComponentId, rect: Rect) {
+        if let Some(comp) = self.components.iter_mut().find(|c| c.id == id) {
+            comp.rect = Some(rect);
+        }
+    }
+
+    /// Forget all reported rects (e.g. before a new frame reports them)
+    pub fn clear_rects(&mut self) {
+        for comp in &mut self.components {
+            comp.rect = None;
+        }
+    }
+
+    /// Last reported rect of a component
+    pub fn rect(&self, id: ComponentId) -> Option<Rect> {
+        self.components
+            .iter()
+            .find(|c| c.id == id)
+            .and_then(|c| c.rect)
+    }
+
+    /// The focusable component under a screen position, in the current
+    /// scope
+    ///
+    /// When rects overlap (a focusable panel around a button) the smallest
+    /// one wins, since that's the innermost.
+    pub fn focusable_at(&self, x: u16, y: u16) -> Option<ComponentId> {
+        self.components
+            .iter()
+            .filter(|c| self.can_focus(c))
+            .filter_map(|c| c.rect.filter(|r| r.contains(x, y)).map(|r| (c.id, r)))
+            .min_by_key(|(_, r)| r.width as u32 * r.height as u32)
+            .map(|(id, _)| id)
+    }
+
     /// Clear all focus (no component focused)
     pub fn clear(&mut self) {
         self.current = None;
     }
 
-    /// Clear all registered components
+    /// Clear all registered components and scopes
     pub fn clear_all(&mut self) {
         self.components.clear();
         self.current = None;
+        self.scopes.clear();
     }
 
     /// Get number of registered components
@@ -224,6 +405,14 @@ impl FocusManager {
     }
 }
 
+/// Center of a rect, doubled to stay in whole cells
+fn center(rect: Rect) -> (i32, i32) {
+    (
+        2 * rect.x as i32 + rect.width as i32,
+        2 * rect.y as i32 + rect.height as i32,
+    )
+}
+
 impl Default for FocusManager {
     fn default() -> Self {
         Self::new()
@@ -389,6 +578,64 @@ mod tests {
         assert_eq!(mgr.current(), Some(id3));
     }
 
+    #[test]
+    fn test_arrow_navigation_in_a_grid() {
+        // 1 2
+        // 3 4
+        let mut mgr = FocusManager::new();
+        let ids: Vec<_> = (1..=4).map(ComponentId::new).collect();
+        mgr.register_at(ids[0], Rect::new(0, 0, 10, 3), true);
+        mgr.register_at(ids[1], Rect::new(12, 0, 10, 3), true);
+        mgr.register_at(ids[2], Rect::new(0, 4, 10, 3), true);
+        mgr.register_at(ids[3], Rect::new(12, 4, 10, 3), true);
+        assert_eq!(mgr.current(), Some(ids[0]));
+
+        assert!(mgr.focus_right());
+        assert_eq!(mgr.current(), Some(ids[1]));
+        assert!(mgr.focus_down());
+        assert_eq!(mgr.current(), Some(ids[3]));
+        assert!(mgr.focus_left());
+        assert_eq!(mgr.current(), Some(ids[2]));
+
+        // Nothing further down: focus stays put
+        assert!(!mgr.focus_down());
+        assert_eq!(mgr.current(), Some(ids[2]));
+
+        // Tab order follows registration
+        mgr.focus_next();
+        assert_eq!(mgr.current(), Some(ids[3]));
+    }
+
+    #[test]
+    fn test_scope_traps_tab() {
+        let mut mgr = FocusManager::new();
+        let (form_a, form_b) = (ComponentId::new(1), ComponentId::new(2));
+        let (ok, cancel) = (ComponentId::new(3), ComponentId::new(4));
+
+        mgr.register(form_a, 0, true);
+        mgr.register(form_b, 1, true);
+        mgr.focus(form_b);
+
+        mgr.push_scope();
+        assert_eq!(mgr.current(), None);
+        mgr.register(ok, 0, true);
+        mgr.register(cancel, 1, true);
+        assert_eq!(mgr.current(), Some(ok));
+
+        for expected in [cancel, ok, cancel] {
+            mgr.focus_next();
+            assert_eq!(mgr.current(), Some(expected));
+        }
+        mgr.focus_prev();
+        assert_eq!(mgr.current(), Some(ok));
+        assert!(!mgr.focus(form_a));
+
+        assert!(mgr.pop_scope());
+        assert_eq!(mgr.current(), Some(form_b));
+        assert_eq!(mgr.count(), 2);
+        assert!(!mgr.pop_scope());
+    }
+
     #[test]
     fn test_clear() {
         let mut mgr = FocusManager::new();
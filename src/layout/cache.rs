@@ -0,0 +1,243 @@
+//! Layout cache
+//!
+//! Remembers computed child rects per (key, area) so static subtrees
+//! don't get re-measured every frame.
+//!
+//! Container and layout nodes cache their child rects under a key built
+//! from everything besides the area that those rects depend on, so they
+//! never go stale. Components that do their own expensive measuring wrap
+//! it in `RenderContext::cached_layout` with their id; those entries are
+//! dropped whenever the app sees the tree change (the root's `update`
+//! returned true or a redraw was requested). Entries that weren't used in
+//! the last rendered frame are evicted, so areas that moved on don't pile
+//! up.
+
+use super::Rect;
+use crate::focus::ComponentId;
+use std::collections::HashMap;
+
+/// What a cached layout belongs to
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LayoutKey {
+    /// A component's own measuring, invalidated when the tree is dirty
+    Component(ComponentId),
+    /// A container node's child rects, keyed by a hash of its direction,
+    /// sizes and child count - the rects are a pure function of those and
+    /// the area
+    Node(u64),
+}
+
+impl From<ComponentId> for LayoutKey {
+    fn from(id: ComponentId) -> Self {
+        LayoutKey::Component(id)
+    }
+}
+
+#[derive(Debug)]
+struct Entry {
+    rects: Vec<Rect>,
+    /// Frame the entry was last looked up or stored in
+    used: u64,
+}
+
+/// Cache of computed layouts, keyed by component identity and area
+///
+/// Component entries stay valid until the component is invalidated (its
+/// layout inputs changed), the tree is dirty, or the screen size changes,
+/// which throws everything away.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::focus::ComponentId;
+/// use rsdrav::layout::{LayoutCache, Length, Rect, Row};
+///
+/// let mut cache = LayoutCache::new();
+/// let id = ComponentId::new(1);
+/// let area = Rect::new(0, 0, 80, 24);
+///
+/// let rects = cache.get_or_compute(id, area, || {
+///     Row::new().layout(area, &[Length::Fill(1), Length::Fill(1)])
+/// });
+/// ```
+#[derive(Debug, Default)]
+pub struct LayoutCache {
+    entries: HashMap<(LayoutKey, Rect), Entry>,
+    screen: Option<(u16, u16)>,
+    frame: u64,
+    misses: usize,
+}
+
+impl LayoutCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Get the cached layout, computing and storing it on a miss
+    pub fn get_or_compute(
+        &mut self,
+        key: impl Into<LayoutKey>,
+        area: Rect,
+        compute: impl FnOnce() -> Vec<Rect>,
+    ) -> Vec<Rect> {
+        let key = key.into();
+        if let Some(rects) = self.get(key, area) {
+            return rects;
+        }
+
+        let rects = compute();
+        self.insert(key, area, rects.clone());
+        rects
+    }
+
+    /// Get the cached layout, if there is one, marking it as used
+    pub fn get(&mut self, key: impl Into<LayoutKey>, area: Rect) -> Option<Vec<Rect>> {
+        let entry = self.entries.get_mut(&(key.into(), area))?;
+        entry.used = self.frame;
+        Some(entry.rects.clone())
+    }
+
+    /// Store a freshly computed layout
+    pub fn insert(&mut self, key: impl Into<LayoutKey>, area: Rect, rects: Vec<Rect>) {
+        self.misses += 1;
+        let entry = Entry {
+            rects,
+            used: self.frame,
+        };
+        self.entries.insert((key.into(), area), entry);
+    }
+
+    /// Drop every entry for a component (call when it's dirty)
+    pub fn invalidate(&mut self, id: ComponentId) {
+        self.entries
+            .retain(|(key, _), _| *key != LayoutKey::Component(id));
+    }
+
+    /// Drop every component entry, keeping container node layouts
+    ///
+    /// The app calls this on frames where the tree reports a change, since
+    /// it can't tell which component's inputs moved.
+    pub fn invalidate_components(&mut self) {
+        self.entries
+            .retain(|(key, _), _| matches!(key, LayoutKey::Node(_)));
+    }
+
+    /// Finish a rendered frame, evicting entries it didn't use
+    pub fn end_frame(&mut self) {
+        let frame = self.frame;
+        self.entries.retain(|_, entry| entry.used == frame);
+        self.frame += 1;
+    }
+
+    /// Drop everything
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Record the screen size, clearing the whole cache if it changed
+    ///
+    /// Child areas depend on the parent's, so a resize can't be handled
+    /// per entry - every layout is stale.
+    pub fn set_screen_size(&mut self, width: u16, height: u16) {
+        if self.screen != Some((width, height)) {
+            self.screen = Some((width, height));
+            self.clear();
+        }
+    }
+
+    /// Number of cached layouts
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if nothing is cached
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// How many times a layout actually had to be computed
+    pub fn misses(&self) -> usize {
+        self.misses
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_computes_once_per_key() {
+        let mut cache = LayoutCache::new();
+        let id = ComponentId::new(1);
+        let area = Rect::new(0, 0, 10, 10);
+
+        for _ in 0..3 {
+            let rects = cache.get_or_compute(id, area, || vec![area.inner(1)]);
+            assert_eq!(rects, vec![Rect::new(1, 1, 8, 8)]);
+        }
+        assert_eq!(cache.misses(), 1);
+
+        // A different area is a different entry
+        cache.get_or_compute(id, Rect::new(0, 0, 5, 5), Vec::new);
+        assert_eq!(cache.misses(), 2);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn test_invalidate_only_that_component() {
+        let mut cache = LayoutCache::new();
+        let area = Rect::new(0, 0, 10, 10);
+        cache.get_or_compute(ComponentId::new(1), area, Vec::new);
+        cache.get_or_compute(ComponentId::new(2), area, Vec::new);
+
+        cache.invalidate(ComponentId::new(1));
+        assert_eq!(cache.len(), 1);
+
+        cache.get_or_compute(ComponentId::new(2), area, Vec::new);
+        assert_eq!(cache.misses(), 2);
+    }
+
+    #[test]
+    fn test_resize_clears_everything() {
+        let mut cache = LayoutCache::new();
+        cache.set_screen_size(80, 24);
+        cache.get_or_compute(ComponentId::new(1), Rect::new(0, 0, 5, 5), Vec::new);
+        cache.get_or_compute(ComponentId::new(2), Rect::new(0, 0, 80, 24), Vec::new);
+
+        // Same size is a no-op
+        cache.set_screen_size(80, 24);
+        assert_eq!(cache.len(), 2);
+
+        cache.set_screen_size(100, 30);
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_dirty_tree_keeps_node_layouts() {
+        let mut cache = LayoutCache::new();
+        let area = Rect::new(0, 0, 10, 10);
+        cache.get_or_compute(ComponentId::new(1), area, Vec::new);
+        cache.get_or_compute(LayoutKey::Node(7), area, Vec::new);
+
+        cache.invalidate_components();
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(LayoutKey::Node(7), area).is_some());
+    }
+
+    #[test]
+    fn test_end_frame_evicts_unused_areas() {
+        let mut cache = LayoutCache::new();
+        let id = ComponentId::new(1);
+        cache.get_or_compute(id, Rect::new(0, 0, 10, 10), Vec::new);
+        cache.end_frame();
+
+        // The component moved to a new area; the old entry goes
+        cache.get_or_compute(id, Rect::new(0, 0, 12, 10), Vec::new);
+        cache.end_frame();
+        assert_eq!(cache.len(), 1);
+        assert!(cache.get(id, Rect::new(0, 0, 12, 10)).is_some());
+
+        // Still in use, so it stays
+        cache.end_frame();
+        assert_eq!(cache.len(), 1);
+    }
+}
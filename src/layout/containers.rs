@@ -39,8 +39,23 @@ impl Row {
     ///
     /// Returns a vec of Rects, one for each child based on their widths.
     /// Respects Length specifications (Fixed, Percent, Fill) and distributes
-    /// space according to fill weights.
+    /// space according to fill weights. Every child gets the full height;
+    /// see `layout_aligned` for children shorter than the row.
     pub fn layout(&self, area: Rect, child_widths: &[Length]) -> Vec<Rect> {
+        self.layout_aligned(area, child_widths, &[])
+    }
+
+    /// Layout children horizontally, placing each within the row's height
+    /// by `align`
+    ///
+    /// `child_heights[i]` is how tall child `i` wants to be; children
+    /// without an entry (or with `Align::Stretch`) get the full height.
+    pub fn layout_aligned(
+        &self,
+        area: Rect,
+        child_widths: &[Length],
+        child_heights: &[u16],
+    ) -> Vec<Rect> {
         if child_widths.is_empty() {
             return Vec::new();
         }
@@ -80,40 +95,16 @@ impl Row {
 
         // Phase 3: create rects based on justification
         let mut rects = Vec::with_capacity(n);
-        let total_size: u16 = sizes.iter().sum();
-        let total_with_gaps = total_size.saturating_add(total_gap);
-
-        let mut x = match self.justify {
-            Justify::Start => area.x,
-            Justify::End => area
-                .x
-                .saturating_add(area.width.saturating_sub(total_with_gaps)),
-            Justify::Center => area
-                .x
-                .saturating_add((area.width.saturating_sub(total_with_gaps)) / 2),
-            Justify::SpaceBetween | Justify::SpaceAround | Justify::SpaceEvenly => area.x,
-        };
-
-        for &width in sizes.iter() {
-            let y = match self.align {
-                Align::Start => area.y,
-                Align::End => area
-                    .y
-                    .saturating_add(area.height.saturating_sub(area.height)),
-                Align::Center => area
-                    .y
-                    .saturating_add(area.height / 2)
-                    .saturating_sub(area.height / 2),
-                Align::Stretch => area.y,
-            };
-
-            // Height is determined by alignment:
-            // - Stretch: fill full cross-axis
-            // - Other alignments: use full height (child can choose to render smaller)
-            let height = area.height;
-
+        let positions = justify_positions(area.x, area.width, &sizes, self.gap, self.justify);
+
+        for (i, (&width, x)) in sizes.iter().zip(positions).enumerate() {
+            let (y, height) = align_cross(
+                area.y,
+                area.height,
+                child_heights.get(i).copied(),
+                self.align,
+            );
             rects.push(Rect::new(x, y, width, height));
-            x = x.saturating_add(width).saturating_add(self.gap);
         }
 
         rects
@@ -161,7 +152,24 @@ impl Column {
     }
 
     /// Layout children vertically within the given area
+    ///
+    /// Every child gets the full width; see `layout_aligned` for children
+    /// narrower than the column.
     pub fn layout(&self, area: Rect, child_heights: &[Length]) -> Vec<Rect> {
+        self.layout_aligned(area, child_heights, &[])
+    }
+
+    /// Layout children vertically, placing each within the column's width
+    /// by `align`
+    ///
+    /// `child_widths[i]` is how wide child `i` wants to be; children
+    /// without an entry (or with `Align::Stretch`) get the full width.
+    pub fn layout_aligned(
+        &self,
+        area: Rect,
+        child_heights: &[Length],
+        child_widths: &[u16],
+    ) -> Vec<Rect> {
         if child_heights.is_empty() {
             return Vec::new();
         }
@@ -199,40 +207,14 @@ impl Column {
             }
         }
 
-        // Phase 3: create rects
+        // Phase 3: create rects based on justification
         let mut rects = Vec::with_capacity(n);
-        let total_size: u16 = sizes.iter().sum();
-        let total_with_gaps = total_size.saturating_add(total_gap);
-
-        let mut y = match self.justify {
-            Justify::Start => area.y,
-            Justify::End => area
-                .y
-                .saturating_add(area.height.saturating_sub(total_with_gaps)),
-            Justify::Center => area
-                .y
-                .saturating_add((area.height.saturating_sub(total_with_gaps)) / 2),
-            Justify::SpaceBetween | Justify::SpaceAround | Justify::SpaceEvenly => area.y,
-        };
-
-        for &height in &sizes {
-            let x = match self.align {
-                Align::Start => area.x,
-                Align::End => area.x.saturating_add(area.width.saturating_sub(area.width)),
-                Align::Center => area
-                    .x
-                    .saturating_add(area.width / 2)
-                    .saturating_sub(area.width / 2),
-                Align::Stretch => area.x,
-            };
-
-            // Width is determined by alignment:
-            // - Stretch: fill full cross-axis
-            // - Other alignments: use full width (child can choose to render smaller)
-            let width = area.width;
+        let positions = justify_positions(area.y, area.height, &sizes, self.gap, self.justify);
 
+        for (i, (&height, y)) in sizes.iter().zip(positions).enumerate() {
+            let (x, width) =
+                align_cross(area.x, area.width, child_widths.get(i).copied(), self.align);
             rects.push(Rect::new(x, y, width, height));
-            y = y.saturating_add(height).saturating_add(self.gap);
         }
 
         rects
@@ -245,6 +227,62 @@ impl Default for Column {
     }
 }
 
+/// Start and size of a child on the cross axis
+///
+/// Without a size of its own the child fills the whole line, wherever
+/// `align` would put it.
+fn align_cross(start: u16, len: u16, size: Option<u16>, align: Align) -> (u16, u16) {
+    let size = match (size, align) {
+        (Some(size), Align::Start | Align::Center | Align::End) => size.min(len),
+        _ => return (start, len),
+    };
+    let offset = match align {
+        Align::Center => (len - size) / 2,
+        Align::End => len - size,
+        Align::Start | Align::Stretch => 0,
+    };
+    (start + offset, size)
+}
+
+/// Where each item starts along the main axis
+///
+/// `sizes` are already resolved (Fill included), so justification only
+/// places whatever space they and the gaps leave over.
+fn justify_positions(start: u16, len: u16, sizes: &[u16], gap: u16, justify: Justify) -> Vec<u16> {
+    let n = sizes.len() as u32;
+    let used = sizes
+        .iter()
+        .fold(0u32, |sum, &size| sum + size as u32)
+        .saturating_add(gap as u32 * n.saturating_sub(1));
+    let slack = (len as u32).saturating_sub(used);
+
+    // Share of the slack that goes before item i, as (numerator, denominator)
+    let before = |i: u32| -> (u32, u32) {
+        match justify {
+            Justify::Start => (0, 1),
+            Justify::End => (1, 1),
+            Justify::Center => (1, 2),
+            // A lone item stays at the start
+            Justify::SpaceBetween => (i, n.saturating_sub(1).max(1)),
+            // Half a space at each end, a whole one between items
+            Justify::SpaceAround => (2 * i + 1, 2 * n),
+            Justify::SpaceEvenly => (i + 1, n + 1),
+        }
+    };
+
+    let mut offset = start as u32;
+    sizes
+        .iter()
+        .enumerate()
+        .map(|(i, &size)| {
+            let (num, den) = before(i as u32);
+            let pos = offset + slack * num / den;
+            offset += size as u32 + gap as u32;
+            pos.min(u16::MAX as u32) as u16
+        })
+        .collect()
+}
+
 /// Stack container - overlays children on top of each other
 ///
 /// All children get the same area. Useful for modals, overlays, etc.
@@ -341,4 +379,77 @@ mod tests {
             assert_eq!(rect, area);
         }
     }
+
+    #[test]
+    fn test_row_justify_space_modes() {
+        let area = Rect::new(0, 0, 100, 1);
+        let widths = vec![Length::Fixed(10); 3];
+        let xs = |justify| -> Vec<u16> {
+            Row::new()
+                .justify(justify)
+                .layout(area, &widths)
+                .iter()
+                .map(|r| r.x)
+                .collect()
+        };
+
+        // 70 cells of slack
+        assert_eq!(xs(Justify::SpaceBetween), vec![0, 45, 90]);
+        assert_eq!(xs(Justify::SpaceAround), vec![11, 45, 78]);
+        assert_eq!(xs(Justify::SpaceEvenly), vec![17, 45, 72]);
+        assert_eq!(xs(Justify::Start), vec![0, 10, 20]);
+        assert_eq!(xs(Justify::End), vec![70, 80, 90]);
+        assert_eq!(xs(Justify::Center), vec![35, 45, 55]);
+    }
+
+    #[test]
+    fn test_column_justify_space_modes() {
+        let area = Rect::new(0, 5, 10, 20);
+        let heights = vec![Length::Fixed(2); 3];
+        let ys = |justify| -> Vec<u16> {
+            Column::new()
+                .gap(1)
+                .justify(justify)
+                .layout(area, &heights)
+                .iter()
+                .map(|r| r.y)
+                .collect()
+        };
+
+        // 12 cells of slack on top of the gaps
+        assert_eq!(ys(Justify::SpaceBetween), vec![5, 14, 23]);
+        assert_eq!(ys(Justify::SpaceAround), vec![7, 14, 21]);
+        assert_eq!(ys(Justify::SpaceEvenly), vec![8, 14, 20]);
+
+        // Fill takes the slack first, leaving nothing to spread
+        let rects = Column::new()
+            .justify(Justify::SpaceBetween)
+            .layout(area, &[Length::Fixed(2), Length::Fill(1)]);
+        assert_eq!(rects[1], Rect::new(0, 7, 10, 18));
+    }
+
+    #[test]
+    fn test_cross_axis_alignment() {
+        let area = Rect::new(0, 3, 30, 10);
+        let widths = vec![Length::Fixed(10); 2];
+        let rect = |align| Row::new().align(align).layout_aligned(area, &widths, &[2])[0];
+
+        assert_eq!(rect(Align::Start), Rect::new(0, 3, 10, 2));
+        assert_eq!(rect(Align::Center), Rect::new(0, 7, 10, 2));
+        assert_eq!(rect(Align::End), Rect::new(0, 11, 10, 2));
+        assert_eq!(rect(Align::Stretch), Rect::new(0, 3, 10, 10));
+
+        // No size given: the full height, as with `layout`
+        let rects = Row::new()
+            .align(Align::Center)
+            .layout_aligned(area, &widths, &[2]);
+        assert_eq!(rects[1], Rect::new(10, 3, 10, 10));
+
+        let rects = Column::new().align(Align::End).layout_aligned(
+            Rect::new(0, 0, 20, 4),
+            &[Length::Fill(1)],
+            &[6],
+        );
+        assert_eq!(rects[0], Rect::new(14, 0, 6, 4));
+    }
 }
@@ -0,0 +1,214 @@
+use super::{Column, Length, Rect, Row};
+
+/// Grid container - lays out cells in rows and columns
+///
+/// Column widths and row heights are `Length`s, resolved the same way a
+/// `Row` or `Column` would resolve them, with gaps between tracks. Unlike
+/// `GridArea`, which splits an area into equal tracks, each track here can
+/// be sized on its own.
+///
+/// ## Example
+/// ```
+/// use rsdrav::layout::{Grid, GridItem, Length, Rect};
+///
+/// let grid = Grid::new(vec![Length::Fill(1); 3], vec![Length::Fixed(3), Length::Fill(1)]).gap(1);
+///
+/// // Banner across the top, then one panel per column
+/// let rects = grid.layout_items(
+///     Rect::new(0, 0, 32, 20),
+///     &[GridItem::new(0, 0).col_span(3), GridItem::new(1, 1)],
+/// );
+/// assert_eq!(rects[0], Rect::new(0, 0, 32, 3));
+/// assert_eq!(rects[1], Rect::new(11, 4, 10, 16));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Grid {
+    pub columns: Vec<Length>,
+    pub rows: Vec<Length>,
+    pub column_gap: u16,
+    pub row_gap: u16,
+}
+
+impl Grid {
+    pub fn new(columns: Vec<Length>, rows: Vec<Length>) -> Self {
+        Self {
+            columns,
+            rows,
+            column_gap: 0,
+            row_gap: 0,
+        }
+    }
+
+    /// Set the gap between both columns and rows
+    pub fn gap(mut self, gap: u16) -> Self {
+        self.column_gap = gap;
+        self.row_gap = gap;
+        self
+    }
+
+    pub fn column_gap(mut self, gap: u16) -> Self {
+        self.column_gap = gap;
+        self
+    }
+
+    pub fn row_gap(mut self, gap: u16) -> Self {
+        self.row_gap = gap;
+        self
+    }
+
+    /// Layout every cell within the given area
+    ///
+    /// Returns one rect per cell in row-major order (the whole first row,
+    /// then the second, ...).
+    pub fn layout(&self, area: Rect) -> Vec<Rect> {
+        let (columns, rows) = self.tracks(area);
+        rows.iter()
+            .flat_map(|row| {
+                columns
+                    .iter()
+                    .map(move |col| Rect::new(col.x, row.y, col.width, row.height))
+            })
+            .collect()
+    }
+
+    /// Layout items that may span several cells, one rect per item
+    ///
+    /// A spanned item covers its tracks and the gaps between them. Spans
+    /// running past the grid are cut off at its edge; an item starting
+    /// outside the grid gets an empty rect.
+    pub fn layout_items(&self, area: Rect, items: &[GridItem]) -> Vec<Rect> {
+        let (columns, rows) = self.tracks(area);
+        items
+            .iter()
+            .map(|item| {
+                let (x, width) = span(
+                    columns.iter().map(|r| (r.x, r.width)),
+                    item.col,
+                    item.col_span,
+                );
+                let (y, height) = span(
+                    rows.iter().map(|r| (r.y, r.height)),
+                    item.row,
+                    item.row_span,
+                );
+                match (x, y) {
+                    (Some(x), Some(y)) => Rect::new(x, y, width, height),
+                    _ => Rect::new(area.x, area.y, 0, 0),
+                }
+            })
+            .collect()
+    }
+
+    /// Column rects across the top and row rects down the side
+    fn tracks(&self, area: Rect) -> (Vec<Rect>, Vec<Rect>) {
+        let columns = Row::new().gap(self.column_gap).layout(area, &self.columns);
+        let rows = Column::new().gap(self.row_gap).layout(area, &self.rows);
+        (columns, rows)
+    }
+}
+
+/// Start and length from the start of track `first` to the end of the
+/// last spanned track, or no start if `first` is past the last track
+fn span(tracks: impl Iterator<Item = (u16, u16)>, first: u16, count: u16) -> (Option<u16>, u16) {
+    let spanned: Vec<(u16, u16)> = tracks
+        .skip(first as usize)
+        .take(count.max(1) as usize)
+        .collect();
+    match (spanned.first(), spanned.last()) {
+        (Some(&(start, _)), Some(&(last, len))) => (Some(start), last + len - start),
+        _ => (None, 0),
+    }
+}
+
+/// Where an item sits in a `Grid`
+///
+/// Spans default to one cell.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GridItem {
+    pub col: u16,
+    pub row: u16,
+    pub col_span: u16,
+    pub row_span: u16,
+}
+
+impl GridItem {
+    pub fn new(col: u16, row: u16) -> Self {
+        Self {
+            col,
+            row,
+            col_span: 1,
+            row_span: 1,
+        }
+    }
+
+    /// Cover `span` columns starting at `col`
+    pub fn col_span(mut self, span: u16) -> Self {
+        self.col_span = span.max(1);
+        self
+    }
+
+    /// Cover `span` rows starting at `row`
+    pub fn row_span(mut self, span: u16) -> Self {
+        self.row_span = span.max(1);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cells_in_row_major_order() {
+        let grid = Grid::new(
+            vec![Length::Fixed(10), Length::Fill(1)],
+            vec![Length::Fixed(2), Length::Fill(1)],
+        )
+        .column_gap(2)
+        .row_gap(1);
+        let rects = grid.layout(Rect::new(0, 0, 30, 10));
+
+        assert_eq!(
+            rects,
+            vec![
+                Rect::new(0, 0, 10, 2),
+                Rect::new(12, 0, 18, 2),
+                Rect::new(0, 3, 10, 7),
+                Rect::new(12, 3, 18, 7),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_dashboard_with_spanning_banner() {
+        let grid = Grid::new(vec![Length::Fill(1); 3], vec![Length::Fill(1); 3]).gap(1);
+        let area = Rect::new(0, 0, 32, 11);
+        let rects = grid.layout_items(
+            area,
+            &[
+                GridItem::new(0, 0).col_span(3),
+                GridItem::new(0, 1),
+                GridItem::new(1, 1).col_span(2).row_span(2),
+                GridItem::new(0, 2),
+            ],
+        );
+
+        assert_eq!(rects[0], Rect::new(0, 0, 32, 3));
+        assert_eq!(rects[1], Rect::new(0, 4, 10, 3));
+        assert_eq!(rects[2], Rect::new(11, 4, 21, 7));
+        assert_eq!(rects[3], Rect::new(0, 8, 10, 3));
+    }
+
+    #[test]
+    fn test_spans_cut_off_at_the_edge() {
+        let grid = Grid::new(vec![Length::Fixed(5); 2], vec![Length::Fixed(1)]);
+        let area = Rect::new(0, 0, 10, 1);
+        let rects = grid.layout_items(
+            area,
+            &[GridItem::new(1, 0).col_span(4), GridItem::new(2, 0)],
+        );
+
+        assert_eq!(rects[0], Rect::new(5, 0, 5, 1));
+        assert_eq!(rects[1], Rect::new(0, 0, 0, 0));
+    }
+}
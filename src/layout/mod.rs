@@ -9,11 +9,17 @@
 //! - `Length`: size specification (Fixed, Percent, Fill, Min, Max)
 //! - `Align`/`Justify`: alignment modes
 
+mod cache;
 mod containers;
 mod flex;
+mod grid;
+mod overlay;
 
+pub use cache::{LayoutCache, LayoutKey};
 pub use containers::{Column, Row, Stack};
 pub use flex::{Flex, FlexItem};
+pub use grid::{Grid, GridItem};
+pub use overlay::{place_overlay, Placement};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct Rect {
@@ -126,6 +132,81 @@ impl Rect {
 
         Rect::new(x1, y1, x2 - x1, y2 - y1)
     }
+
+    /// Grow to the smallest rect on a `cell_w` x `cell_h` grid that
+    /// contains this one
+    ///
+    /// The grid starts at (0, 0). A zero cell size leaves that axis alone.
+    pub fn snap_to_grid(&self, cell_w: u16, cell_h: u16) -> Rect {
+        let snap = |start: u16, len: u16, cell: u16| -> (u16, u16) {
+            if cell == 0 {
+                return (start, len);
+            }
+            let cell = cell as u32;
+            let lo = start as u32 / cell * cell;
+            let hi = (start as u32 + len as u32 + cell - 1) / cell * cell;
+            let lo = lo.min(u16::MAX as u32);
+            (lo as u16, (hi.min(u16::MAX as u32) - lo) as u16)
+        };
+        let (x, width) = snap(self.x, self.width, cell_w);
+        let (y, height) = snap(self.y, self.height, cell_h);
+        Rect::new(x, y, width, height)
+    }
+}
+
+/// Cells of a grid, for placing dashboard panels without manual math
+///
+/// ## Example
+/// ```
+/// use rsdrav::layout::{GridArea, Rect};
+///
+/// // Right half of the top row in a 4x3 grid over an 80x24 screen
+/// let area = GridArea::new(2, 0, 2, 1).resolve(Rect::new(0, 0, 80, 24), 4, 3);
+/// assert_eq!(area, Rect::new(40, 0, 40, 8));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct GridArea {
+    pub col: u16,
+    pub row: u16,
+    /// Columns spanned
+    pub cols: u16,
+    /// Rows spanned
+    pub rows: u16,
+}
+
+impl GridArea {
+    pub const fn new(col: u16, row: u16, cols: u16, rows: u16) -> Self {
+        Self {
+            col,
+            row,
+            cols,
+            rows,
+        }
+    }
+
+    /// Where this area lands in `container` split into `columns` x `rows`
+    ///
+    /// When the container doesn't divide evenly, the extra cells are spread
+    /// across the tracks so they differ by at most one. Spans running past
+    /// the grid are cut off at its edge.
+    pub fn resolve(&self, container: Rect, columns: u16, rows: u16) -> Rect {
+        let (x, width) = track_span(container.x, container.width, columns, self.col, self.cols);
+        let (y, height) = track_span(container.y, container.height, rows, self.row, self.rows);
+        Rect::new(x, y, width, height)
+    }
+}
+
+/// Start and length of tracks `first..first + span` out of `count` equal
+/// tracks over `len` cells
+fn track_span(start: u16, len: u16, count: u16, first: u16, span: u16) -> (u16, u16) {
+    if count == 0 {
+        return (start, 0);
+    }
+    // Track i begins at i * len / count, which spreads the remainder
+    let edge = |i: u16| (i.min(count) as u32 * len as u32 / count as u32) as u16;
+    let from = edge(first);
+    let to = edge(first.saturating_add(span));
+    (start + from, to - from)
 }
 
 /// Size specification for layout
@@ -162,20 +243,15 @@ impl Length {
 }
 
 /// Alignment along cross axis
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
 pub enum Align {
     Start, // top or left
     Center,
-    End,     // bottom or right
+    End, // bottom or right
+    #[default]
     Stretch, // fill entire cross axis
 }
 
-impl Default for Align {
-    fn default() -> Self {
-        Self::Stretch
-    }
-}
-
 /// Justification along main axis
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub enum Justify {
@@ -263,4 +339,41 @@ mod tests {
         assert_eq!(Length::Max(50).resolve(200), 50);
         assert_eq!(Length::Max(300).resolve(200), 200);
     }
+
+    #[test]
+    fn test_snap_to_grid() {
+        let rect = Rect::new(3, 5, 10, 2);
+        assert_eq!(rect.snap_to_grid(4, 4), Rect::new(0, 4, 16, 4));
+        assert_eq!(rect.snap_to_grid(0, 1), rect);
+        assert_eq!(
+            Rect::new(8, 8, 8, 8).snap_to_grid(4, 4),
+            Rect::new(8, 8, 8, 8)
+        );
+    }
+
+    #[test]
+    fn test_grid_area_resolve() {
+        let container = Rect::new(10, 2, 80, 24);
+        assert_eq!(
+            GridArea::new(0, 0, 1, 1).resolve(container, 4, 3),
+            Rect::new(10, 2, 20, 8)
+        );
+        assert_eq!(
+            GridArea::new(1, 1, 3, 2).resolve(container, 4, 3),
+            Rect::new(30, 10, 60, 16)
+        );
+
+        // 10 columns over 3 tracks: 3, 3, 4 - the tracks tile with no gaps
+        let widths: Vec<u16> = (0..3)
+            .map(|c| GridArea::new(c, 0, 1, 1).resolve(Rect::new(0, 0, 10, 1), 3, 1))
+            .map(|r| r.width)
+            .collect();
+        assert_eq!(widths, vec![3, 3, 4]);
+
+        // Past the edge of the grid
+        assert_eq!(
+            GridArea::new(3, 0, 5, 1).resolve(container, 4, 3),
+            Rect::new(70, 2, 20, 8)
+        );
+    }
 }
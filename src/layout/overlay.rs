@@ -0,0 +1,125 @@
+//! Positioning floating boxes (menus, tooltips, dropdowns) near an anchor
+
+use super::Rect;
+
+/// Which side of the anchor an overlay should go on
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Default)]
+pub enum Placement {
+    #[default]
+    Below,
+    Above,
+    Left,
+    Right,
+}
+
+impl Placement {
+    /// The side across the anchor from this one
+    pub fn opposite(self) -> Self {
+        match self {
+            Placement::Below => Placement::Above,
+            Placement::Above => Placement::Below,
+            Placement::Left => Placement::Right,
+            Placement::Right => Placement::Left,
+        }
+    }
+}
+
+/// Pick where to draw a `size` (width, height) overlay next to `anchor`
+///
+/// Tries `prefer` first and flips to the opposite side if the overlay
+/// would run off `screen` there. If neither side has room, it stays on the
+/// preferred side and gets pushed back on-screen, covering the anchor.
+/// On the other axis the overlay lines up with the anchor's start and
+/// slides to stay visible. An overlay bigger than the screen is shrunk to
+/// fit.
+pub fn place_overlay(anchor: Rect, size: (u16, u16), screen: Rect, prefer: Placement) -> Rect {
+    let width = size.0.min(screen.width);
+    let height = size.1.min(screen.height);
+
+    let placement = [prefer, prefer.opposite()]
+        .into_iter()
+        .find(|&p| fits(anchor, width, height, screen, p))
+        .unwrap_or(prefer);
+
+    let (x, y) = position(anchor, width, height, placement);
+    let max_x = screen.x as i32 + screen.width as i32 - width as i32;
+    let max_y = screen.y as i32 + screen.height as i32 - height as i32;
+
+    Rect::new(
+        x.clamp(screen.x as i32, max_x) as u16,
+        y.clamp(screen.y as i32, max_y) as u16,
+        width,
+        height,
+    )
+}
+
+/// Top-left corner for a placement, before clamping (may be off-screen)
+fn position(anchor: Rect, width: u16, height: u16, placement: Placement) -> (i32, i32) {
+    let (ax, ay) = (anchor.x as i32, anchor.y as i32);
+    match placement {
+        Placement::Below => (ax, ay + anchor.height as i32),
+        Placement::Above => (ax, ay - height as i32),
+        Placement::Right => (ax + anchor.width as i32, ay),
+        Placement::Left => (ax - width as i32, ay),
+    }
+}
+
+/// Whether the overlay stays on-screen along the placement's axis
+fn fits(anchor: Rect, width: u16, height: u16, screen: Rect, placement: Placement) -> bool {
+    let (x, y) = position(anchor, width, height, placement);
+    match placement {
+        Placement::Below | Placement::Above => {
+            y >= screen.y as i32 && y + height as i32 <= screen.y as i32 + screen.height as i32
+        }
+        Placement::Left | Placement::Right => {
+            x >= screen.x as i32 && x + width as i32 <= screen.x as i32 + screen.width as i32
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCREEN: Rect = Rect::new(0, 0, 80, 24);
+
+    #[test]
+    fn test_below_when_there_is_room() {
+        let anchor = Rect::new(10, 5, 8, 1);
+        let rect = place_overlay(anchor, (20, 6), SCREEN, Placement::Below);
+        assert_eq!(rect, Rect::new(10, 6, 20, 6));
+    }
+
+    #[test]
+    fn test_flips_above_near_bottom_edge() {
+        let anchor = Rect::new(10, 21, 8, 1);
+        let rect = place_overlay(anchor, (20, 6), SCREEN, Placement::Below);
+        assert_eq!(rect, Rect::new(10, 15, 20, 6));
+    }
+
+    #[test]
+    fn test_slides_along_cross_axis_and_flips_left() {
+        // Right edge: Right flips to Left
+        let anchor = Rect::new(70, 3, 5, 1);
+        let rect = place_overlay(anchor, (12, 3), SCREEN, Placement::Right);
+        assert_eq!(rect, Rect::new(58, 3, 12, 3));
+
+        // Below, but too wide to start at the anchor: slides left
+        let rect = place_overlay(anchor, (20, 3), SCREEN, Placement::Below);
+        assert_eq!(rect, Rect::new(60, 4, 20, 3));
+    }
+
+    #[test]
+    fn test_no_room_either_side_clamps() {
+        let anchor = Rect::new(0, 10, 10, 1);
+        let rect = place_overlay(anchor, (30, 14), SCREEN, Placement::Below);
+        assert_eq!(rect, Rect::new(0, 10, 30, 14));
+    }
+
+    #[test]
+    fn test_larger_than_screen_shrinks() {
+        let anchor = Rect::new(5, 5, 1, 1);
+        let rect = place_overlay(anchor, (200, 50), SCREEN, Placement::Above);
+        assert_eq!(rect, SCREEN);
+    }
+}
@@ -41,10 +41,12 @@ pub mod theme;
 
 pub mod animation;
 pub mod command;
+pub mod config;
 pub mod event_router;
 pub mod focus;
 pub mod layout;
 pub mod plugin;
+pub mod testing;
 pub mod view;
 
 #[cfg(feature = "tokio")]
@@ -56,11 +58,13 @@ pub use error::{Error, Result};
 
 /// Prelude module for convenient imports
 pub mod prelude {
-    pub use crate::animation::{Animatable, EasingFunction, Timeline, Tween};
-    pub use crate::app::App;
+    pub use crate::animation::{Animatable, EasingFunction, Spring, Timeline, Tween};
+    pub use crate::app::{App, Poller, ProgressReporter};
     pub use crate::command::{
-        Command, CommandContext, CommandHandler, CommandRegistry, CommandResult,
+        Command, CommandContext, CommandHandler, CommandHistory, CommandRegistry, CommandResult,
+        FollowUp, Pipeline,
     };
+    pub use crate::config::{Bindings, Config};
     pub use crate::error::{Error, Result};
     pub use crate::event::{
         Event, EventResult, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEventKind,
@@ -68,18 +72,22 @@ pub mod prelude {
     pub use crate::event_router::{EventHandler, EventPhase, EventRouter, EventRoutingContext};
     pub use crate::focus::{ComponentId, FocusManager};
     pub use crate::layout::{
-        Align, Column, Flex, FlexDirection, FlexItem, Justify, Length, Rect, Row, Stack,
+        place_overlay, Align, Column, Flex, FlexDirection, FlexItem, GridArea, Justify, Length,
+        Placement, Rect, Row, Stack,
     };
     pub use crate::plugin::{Capability, Plugin, PluginManager};
     pub use crate::render::{Buffer, Cell};
-    pub use crate::state::{Derived, Signal, Store};
-    pub use crate::theme::{Color, Modifier, Style};
+    pub use crate::state::{batch, ComputedList, Derived, Effect, Signal, Store};
+    pub use crate::theme::{Color, Modifier, Style, Theme};
     pub use crate::view::{
-        Button, HStack, Input, List, Modal, Panel, ProgressBar, Scrollable, SortOrder, Table,
-        TableColumn, Tabs, Text, VStack,
+        stateful_view, view_fn, Component, Dialog, EventContext, MountContext, RenderContext,
+        UpdateContext, ViewNode,
     };
     pub use crate::view::{
-        Component, EventContext, MountContext, RenderContext, UpdateContext, ViewNode,
+        AnimatedNumber, BorderSet, Button, ColorPicker, ConfirmDialog, DiffView, HStack, Input,
+        List, LogView, Modal, NumberInput, Padding, Panel, ProgressBar, RawView, RenderMode,
+        Scrollable, Select, SortOrder, SplitDirection, SplitPane, StatsOverlay, Table, TableColumn,
+        Tabs, Text, TextAlign, TextArea, VStack, VirtualList, ZStack,
     };
 
     #[cfg(feature = "tokio")]
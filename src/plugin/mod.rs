@@ -66,6 +66,8 @@ pub enum Capability {
 pub struct PluginManager {
     plugins: HashMap<String, Box<dyn Plugin>>,
     capabilities: HashMap<String, Vec<Capability>>,
+    /// Registration order - the map alone would iterate randomly
+    order: Vec<String>,
 }
 
 impl PluginManager {
@@ -74,6 +76,7 @@ impl PluginManager {
         Self {
             plugins: HashMap::new(),
             capabilities: HashMap::new(),
+            order: Vec::new(),
         }
     }
 
@@ -95,23 +98,30 @@ impl PluginManager {
         }
 
         self.capabilities.insert(name.clone(), caps);
-        self.plugins.insert(name, plugin);
+        // Re-registering a name replaces it but keeps its original slot
+        if self.plugins.insert(name.clone(), plugin).is_none() {
+            self.order.push(name);
+        }
 
         Ok(())
     }
 
-    /// Initialize all plugins
+    /// Initialize all plugins, in registration order
     pub fn init_all(&mut self) -> Result<()> {
-        for plugin in self.plugins.values_mut() {
-            plugin.init()?;
+        for name in &self.order {
+            if let Some(plugin) = self.plugins.get_mut(name) {
+                plugin.init()?;
+            }
         }
         Ok(())
     }
 
-    /// Cleanup all plugins
+    /// Cleanup all plugins, in registration order
     pub fn cleanup_all(&mut self) -> Result<()> {
-        for plugin in self.plugins.values_mut() {
-            plugin.cleanup()?;
+        for name in &self.order {
+            if let Some(plugin) = self.plugins.get_mut(name) {
+                plugin.cleanup()?;
+            }
         }
         Ok(())
     }
@@ -131,9 +141,9 @@ impl PluginManager {
         }
     }
 
-    /// List all registered plugins
+    /// List all registered plugins, in registration order
     pub fn list_plugins(&self) -> Vec<&str> {
-        self.plugins.keys().map(|s| s.as_str()).collect()
+        self.order.iter().map(|s| s.as_str()).collect()
     }
 }
 
@@ -211,4 +221,62 @@ mod tests {
         let result = manager.init_all();
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn test_registration_order() {
+        let mut manager = PluginManager::new();
+        for name in ["a", "b", "c"] {
+            manager
+                .register(Box::new(ExamplePlugin::new(name)))
+                .unwrap();
+        }
+        assert_eq!(manager.list_plugins(), vec!["a", "b", "c"]);
+
+        // Replacing keeps the original position
+        manager.register(Box::new(ExamplePlugin::new("a"))).unwrap();
+        assert_eq!(manager.list_plugins(), vec!["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_init_in_registration_order() {
+        use std::sync::{Arc, Mutex};
+
+        struct Recorder {
+            name: &'static str,
+            log: Arc<Mutex<Vec<&'static str>>>,
+        }
+
+        impl Plugin for Recorder {
+            fn name(&self) -> &str {
+                self.name
+            }
+            fn version(&self) -> &str {
+                "0.1.0"
+            }
+            fn required_capabilities(&self) -> Vec<Capability> {
+                Vec::new()
+            }
+            fn init(&mut self) -> Result<()> {
+                self.log.lock().unwrap().push(self.name);
+                Ok(())
+            }
+            fn cleanup(&mut self) -> Result<()> {
+                Ok(())
+            }
+        }
+
+        let log = Arc::new(Mutex::new(Vec::new()));
+        let mut manager = PluginManager::new();
+        for name in ["zeta", "alpha", "mid", "beta"] {
+            manager
+                .register(Box::new(Recorder {
+                    name,
+                    log: log.clone(),
+                }))
+                .unwrap();
+        }
+
+        manager.init_all().unwrap();
+        assert_eq!(*log.lock().unwrap(), vec!["zeta", "alpha", "mid", "beta"]);
+    }
 }
@@ -0,0 +1,497 @@
+//! Minimal ANSI/VT parser
+//!
+//! Turns terminal output (text plus escape sequences) into styled cells.
+//! Covers what most programs actually emit - SGR colors and attributes,
+//! cursor movement and erasing. Anything else is skipped.
+
+use super::{Buffer, Cell};
+use crate::theme::{Color, Modifier, Style};
+
+/// Standard xterm colors for codes 0-15
+const PALETTE: [Color; 16] = [
+    Color::rgb(0, 0, 0),
+    Color::rgb(205, 0, 0),
+    Color::rgb(0, 205, 0),
+    Color::rgb(205, 205, 0),
+    Color::rgb(0, 0, 238),
+    Color::rgb(205, 0, 205),
+    Color::rgb(0, 205, 205),
+    Color::rgb(229, 229, 229),
+    Color::rgb(127, 127, 127),
+    Color::rgb(255, 0, 0),
+    Color::rgb(0, 255, 0),
+    Color::rgb(255, 255, 0),
+    Color::rgb(92, 92, 255),
+    Color::rgb(255, 0, 255),
+    Color::rgb(0, 255, 255),
+    Color::rgb(255, 255, 255),
+];
+
+/// Color for an index in the 256-color palette
+pub fn color_256(index: u8) -> Color {
+    match index {
+        0..=15 => PALETTE[index as usize],
+        16..=231 => {
+            // 6x6x6 color cube
+            let i = index - 16;
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            Color::rgb(level(i / 36), level((i / 6) % 6), level(i % 6))
+        }
+        232..=255 => Color::gray(8 + (index - 232) * 10),
+    }
+}
+
+/// Parses terminal output into a grid of styled cells
+///
+/// Bytes can be fed in chunks of any size - an escape sequence or UTF-8
+/// character split across two `feed` calls is held back until the rest
+/// arrives. Output past the bottom scrolls the grid up.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::render::AnsiParser;
+///
+/// let mut parser = AnsiParser::new(80, 24);
+/// parser.feed(b"\x1b[31mred\x1b[0m plain");
+/// assert_eq!(parser.buffer().get(0, 0).unwrap().ch, 'r');
+/// ```
+pub struct AnsiParser {
+    buffer: Buffer,
+    x: u16,
+    y: u16,
+    style: Style,
+    /// Unfinished escape sequence / UTF-8 char from the last feed
+    pending: Vec<u8>,
+}
+
+/// Longest unfinished sequence kept between feeds
+const MAX_PENDING: usize = 4096;
+
+/// What a chunk of input starts with
+enum Token {
+    Char(char, usize),
+    Csi {
+        params: Vec<u16>,
+        private: bool,
+        action: u8,
+        len: usize,
+    },
+    /// Recognized but ignored sequence
+    Skip(usize),
+    /// Need more bytes
+    Incomplete,
+}
+
+impl AnsiParser {
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            buffer: Buffer::new(width, height),
+            x: 0,
+            y: 0,
+            style: Style::default(),
+            pending: Vec::new(),
+        }
+    }
+
+    /// The parsed screen
+    pub fn buffer(&self) -> &Buffer {
+        &self.buffer
+    }
+
+    /// Cursor position (x, y)
+    pub fn cursor(&self) -> (u16, u16) {
+        (self.x, self.y)
+    }
+
+    /// Clear the screen and forget all state
+    pub fn reset(&mut self) {
+        self.buffer.clear();
+        self.x = 0;
+        self.y = 0;
+        self.style = Style::default();
+        self.pending.clear();
+    }
+
+    /// Parse more output
+    pub fn feed(&mut self, bytes: &[u8]) {
+        let mut data = std::mem::take(&mut self.pending);
+        data.extend_from_slice(bytes);
+
+        let mut pos = 0;
+        while pos < data.len() {
+            match next_token(&data[pos..]) {
+                Token::Char(ch, len) => {
+                    self.put_char(ch);
+                    pos += len;
+                }
+                Token::Csi {
+                    params,
+                    private,
+                    action,
+                    len,
+                } => {
+                    // Private modes (`?25l` etc.) don't affect the grid
+                    if !private {
+                        self.csi(&params, action);
+                    }
+                    pos += len;
+                }
+                Token::Skip(len) => pos += len,
+                Token::Incomplete => {
+                    // A sequence that never ends (an OSC missing its
+                    // terminator) is dropped rather than kept growing
+                    if data.len() - pos <= MAX_PENDING {
+                        self.pending = data[pos..].to_vec();
+                    }
+                    return;
+                }
+            }
+        }
+    }
+
+    fn put_char(&mut self, ch: char) {
+        let (width, height) = (self.buffer.width, self.buffer.height);
+        if width == 0 || height == 0 {
+            return;
+        }
+
+        match ch {
+            // Output is usually meant for a cooked terminal, where \n is a newline
+            '\n' => self.newline(),
+            '\r' => self.x = 0,
+            '\t' => self.x = ((self.x / 8 + 1).saturating_mul(8)).min(width - 1),
+            '\x08' => self.x = self.x.saturating_sub(1),
+            c if c.is_control() => {}
+            c => {
+                if self.x >= width {
+                    self.newline();
+                }
+                let cell = Cell::with_style(c, self.style);
+                // A wide glyph takes two columns, like on a real terminal
+                let advance = cell.width().max(1);
+                self.buffer.set(self.x, self.y, cell);
+                self.x = self.x.saturating_add(advance);
+            }
+        }
+    }
+
+    fn newline(&mut self) {
+        self.x = 0;
+        if self.y + 1 >= self.buffer.height {
+            self.scroll_up();
+        } else {
+            self.y += 1;
+        }
+    }
+
+    fn scroll_up(&mut self) {
+        let (width, height) = (self.buffer.width, self.buffer.height);
+        for y in 1..height {
+            for x in 0..width {
+                let cell = self.buffer.get(x, y).cloned().unwrap_or_default();
+                self.buffer.set(x, y - 1, cell);
+            }
+        }
+        self.erase(0, height - 1, width, height - 1);
+    }
+
+    /// Blank cells from (x0, y0) up to (x1, y1) exclusive, in reading order
+    fn erase(&mut self, x0: u16, y0: u16, x1: u16, y1: u16) {
+        let width = self.buffer.width;
+        for y in y0..=y1.min(self.buffer.height.saturating_sub(1)) {
+            let start = if y == y0 { x0 } else { 0 };
+            let end = if y == y1 { x1 } else { width };
+            for x in start..end.min(width) {
+                self.buffer.set(x, y, Cell::default());
+            }
+        }
+    }
+
+    fn csi(&mut self, params: &[u16], action: u8) {
+        let (width, height) = (self.buffer.width, self.buffer.height);
+        // Missing or zero count means 1 for movement
+        let n = params.first().copied().unwrap_or(0).max(1);
+
+        match action {
+            b'm' => self.sgr(params),
+            b'A' => self.y = self.y.saturating_sub(n),
+            b'B' => self.y = self.y.saturating_add(n).min(height.saturating_sub(1)),
+            b'C' => self.x = self.x.saturating_add(n).min(width.saturating_sub(1)),
+            b'D' => self.x = self.x.saturating_sub(n),
+            b'G' => self.x = (n - 1).min(width.saturating_sub(1)),
+            b'H' | b'f' => {
+                let row = params.first().copied().unwrap_or(1).max(1);
+                let col = params.get(1).copied().unwrap_or(1).max(1);
+                self.y = (row - 1).min(height.saturating_sub(1));
+                self.x = (col - 1).min(width.saturating_sub(1));
+            }
+            b'J' => match params.first().copied().unwrap_or(0) {
+                0 => self.erase(self.x, self.y, width, height.saturating_sub(1)),
+                1 => self.erase(0, 0, self.x.saturating_add(1), self.y),
+                _ => self.buffer.clear(),
+            },
+            b'K' => match params.first().copied().unwrap_or(0) {
+                0 => self.erase(self.x, self.y, width, self.y),
+                1 => self.erase(0, self.y, self.x.saturating_add(1), self.y),
+                _ => self.erase(0, self.y, width, self.y),
+            },
+            _ => {}
+        }
+    }
+
+    fn sgr(&mut self, params: &[u16]) {
+        if params.is_empty() {
+            self.style = Style::default();
+            return;
+        }
+
+        let mut i = 0;
+        while i < params.len() {
+            let p = params[i];
+            match p {
+                0 => self.style = Style::default(),
+                1 => self.style = self.style.add_modifier(Modifier::BOLD),
+                2 => self.style = self.style.add_modifier(Modifier::DIM),
+                3 => self.style = self.style.add_modifier(Modifier::ITALIC),
+                4 => self.style = self.style.add_modifier(Modifier::UNDERLINE),
+                5 => self.style = self.style.add_modifier(Modifier::BLINK),
+                7 => self.style = self.style.add_modifier(Modifier::REVERSE),
+                8 => self.style = self.style.add_modifier(Modifier::HIDDEN),
+                9 => self.style = self.style.add_modifier(Modifier::STRIKETHROUGH),
+                22 => self.style = self.style.remove_modifier(Modifier::BOLD | Modifier::DIM),
+                23 => self.style = self.style.remove_modifier(Modifier::ITALIC),
+                24 => self.style = self.style.remove_modifier(Modifier::UNDERLINE),
+                25 => self.style = self.style.remove_modifier(Modifier::BLINK),
+                27 => self.style = self.style.remove_modifier(Modifier::REVERSE),
+                28 => self.style = self.style.remove_modifier(Modifier::HIDDEN),
+                29 => self.style = self.style.remove_modifier(Modifier::STRIKETHROUGH),
+                30..=37 => self.style.fg = Some(PALETTE[(p - 30) as usize]),
+                39 => self.style.fg = None,
+                40..=47 => self.style.bg = Some(PALETTE[(p - 40) as usize]),
+                49 => self.style.bg = None,
+                90..=97 => self.style.fg = Some(PALETTE[(p - 90 + 8) as usize]),
+                100..=107 => self.style.bg = Some(PALETTE[(p - 100 + 8) as usize]),
+                38 | 48 => {
+                    let (color, used) = extended_color(&params[i + 1..]);
+                    if let Some(color) = color {
+                        if p == 38 {
+                            self.style.fg = Some(color);
+                        } else {
+                            self.style.bg = Some(color);
+                        }
+                    }
+                    i += used;
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+    }
+}
+
+/// Parse the tail of `38;5;n` / `38;2;r;g;b`, returning how many params it used
+fn extended_color(params: &[u16]) -> (Option<Color>, usize) {
+    let byte = |i: usize| params.get(i).map(|&v| v.min(255) as u8);
+    match params.first() {
+        Some(5) => (byte(1).map(color_256), 2),
+        Some(2) => match (byte(1), byte(2), byte(3)) {
+            (Some(r), Some(g), Some(b)) => (Some(Color::rgb(r, g, b)), 4),
+            _ => (None, params.len()),
+        },
+        _ => (None, 0),
+    }
+}
+
+fn next_token(data: &[u8]) -> Token {
+    match data[0] {
+        0x1b => {
+            let Some(&kind) = data.get(1) else {
+                return Token::Incomplete;
+            };
+            match kind {
+                b'[' => parse_csi(data),
+                b']' => {
+                    // OSC (titles etc.) - ends with BEL or ESC \
+                    for i in 2..data.len() {
+                        if data[i] == 0x07 {
+                            return Token::Skip(i + 1);
+                        }
+                        if data[i] == 0x1b && data.get(i + 1) == Some(&b'\\') {
+                            return Token::Skip(i + 2);
+                        }
+                    }
+                    Token::Incomplete
+                }
+                // Charset selection and friends take one more byte
+                b'(' | b')' | b'#' => {
+                    if data.len() < 3 {
+                        Token::Incomplete
+                    } else {
+                        Token::Skip(3)
+                    }
+                }
+                _ => Token::Skip(2),
+            }
+        }
+        b if b < 0x80 => Token::Char(b as char, 1),
+        b => {
+            let len = match b {
+                0xc0..=0xdf => 2,
+                0xe0..=0xef => 3,
+                0xf0..=0xf7 => 4,
+                // Stray continuation byte
+                _ => return Token::Skip(1),
+            };
+            if data.len() < len {
+                return Token::Incomplete;
+            }
+            match std::str::from_utf8(&data[..len]) {
+                Ok(s) => Token::Char(s.chars().next().unwrap_or('\u{fffd}'), len),
+                Err(_) => Token::Char('\u{fffd}', 1),
+            }
+        }
+    }
+}
+
+fn parse_csi(data: &[u8]) -> Token {
+    let mut params = Vec::new();
+    let mut current: Option<u16> = None;
+    let mut private = false;
+
+    for (i, &b) in data.iter().enumerate().skip(2) {
+        match b {
+            b'0'..=b'9' => {
+                let digit = (b - b'0') as u16;
+                current = Some(
+                    current
+                        .unwrap_or(0)
+                        .saturating_mul(10)
+                        .saturating_add(digit),
+                );
+            }
+            b';' | b':' => params.push(current.take().unwrap_or(0)),
+            b'?' | b'>' | b'<' | b'=' => private = true,
+            // Intermediate bytes
+            0x20..=0x2f => {}
+            0x40..=0x7e => {
+                if let Some(p) = current {
+                    params.push(p);
+                }
+                return Token::Csi {
+                    params,
+                    private,
+                    action: b,
+                    len: i + 1,
+                };
+            }
+            // Garbage inside the sequence - drop what we've seen
+            _ => return Token::Skip(i),
+        }
+    }
+    Token::Incomplete
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn text(parser: &AnsiParser, y: u16) -> String {
+        parser
+            .buffer()
+            .line(y)
+            .iter()
+            .map(|c| if c.ch == '\0' { ' ' } else { c.ch })
+            .collect::<String>()
+            .trim_end()
+            .to_string()
+    }
+
+    #[test]
+    fn test_colored_text() {
+        let mut parser = AnsiParser::new(20, 2);
+        parser.feed(b"\x1b[1;31mab\x1b[0mc\x1b[38;2;1;2;3md");
+
+        let buf = parser.buffer();
+        let a = buf.get(0, 0).unwrap();
+        assert_eq!(a.ch, 'a');
+        assert_eq!(a.style.fg, Some(PALETTE[1]));
+        assert!(a.style.modifiers.contains(Modifier::BOLD));
+
+        let c = buf.get(2, 0).unwrap();
+        assert_eq!(c.ch, 'c');
+        assert_eq!(c.style, Style::default());
+
+        assert_eq!(buf.get(3, 0).unwrap().style.fg, Some(Color::rgb(1, 2, 3)));
+    }
+
+    #[test]
+    fn test_256_colors_and_background() {
+        let mut parser = AnsiParser::new(10, 1);
+        parser.feed(b"\x1b[48;5;196;38;5;232mx");
+
+        let cell = parser.buffer().get(0, 0).unwrap();
+        assert_eq!(cell.style.bg, Some(Color::rgb(255, 0, 0)));
+        assert_eq!(cell.style.fg, Some(Color::gray(8)));
+    }
+
+    #[test]
+    fn test_split_sequences_are_buffered() {
+        let mut parser = AnsiParser::new(10, 1);
+        parser.feed(b"\x1b[3");
+        parser.feed(b"2mo");
+        // "é" split between feeds
+        parser.feed(&[0xc3]);
+        parser.feed(&[0xa9]);
+
+        let buf = parser.buffer();
+        assert_eq!(buf.get(0, 0).unwrap().ch, 'o');
+        assert_eq!(buf.get(0, 0).unwrap().style.fg, Some(PALETTE[2]));
+        assert_eq!(buf.get(1, 0).unwrap().ch, 'é');
+    }
+
+    #[test]
+    fn test_cursor_moves_and_erase() {
+        let mut parser = AnsiParser::new(10, 3);
+        parser.feed(b"hello\r\nworld");
+        parser.feed(b"\x1b[1;3HX");
+        assert_eq!(text(&parser, 0), "heXlo");
+
+        parser.feed(b"\x1b[2;1H\x1b[K");
+        assert_eq!(text(&parser, 1), "");
+
+        parser.feed(b"\x1b[2J");
+        assert_eq!(text(&parser, 0), "");
+    }
+
+    #[test]
+    fn test_unsupported_sequences_skipped() {
+        let mut parser = AnsiParser::new(20, 1);
+        parser.feed(b"\x1b[?25la\x1b]0;title\x07b\x1b(Bc\x1b[5Zd");
+        assert_eq!(text(&parser, 0), "abcd");
+    }
+
+    #[test]
+    fn test_huge_parameters_clamp() {
+        let mut parser = AnsiParser::new(10, 5);
+        parser.feed(b"a\n\x1b[65535B\x1b[65535C\x1b[1J\x1b[1KZ");
+        assert_eq!(text(&parser, 4), "         Z");
+        assert_eq!(text(&parser, 0), "");
+    }
+
+    #[test]
+    fn test_unterminated_osc_is_dropped() {
+        let mut parser = AnsiParser::new(10, 1);
+        let mut data = b"\x1b]0;".to_vec();
+        data.resize(MAX_PENDING + 1, b'x');
+        parser.feed(&data);
+        assert!(parser.pending.is_empty());
+    }
+
+    #[test]
+    fn test_scrolls_at_bottom() {
+        let mut parser = AnsiParser::new(5, 2);
+        parser.feed(b"one\ntwo\nthree");
+        assert_eq!(text(&parser, 0), "two");
+        assert_eq!(text(&parser, 1), "three");
+    }
+}
@@ -36,6 +36,16 @@ pub trait Backend: Send + Sync {
     /// Write bytes to terminal
     fn write(&mut self, content: &[u8]) -> Result<()>;
 
+    /// Check that stdin and stdout are both attached to a terminal
+    ///
+    /// `App::run` refuses to start otherwise: raw mode fails on a pipe,
+    /// reading events from one can block forever, and redrawing into a file
+    /// only fills it with escape codes.
+    fn is_terminal(&self) -> bool {
+        use std::io::IsTerminal;
+        std::io::stdin().is_terminal() && std::io::stdout().is_terminal()
+    }
+
     /// Read event with timeout (returns None if timeout)
     fn read_event(&mut self, timeout: Duration) -> Result<Option<Event>>;
 
@@ -47,6 +57,37 @@ pub trait Backend: Send + Sync {
 
     /// Hide cursor
     fn cursor_hide(&mut self) -> Result<()>;
+
+    /// Ask the terminal to report window focus changes
+    /// (`Event::FocusGained` / `Event::FocusLost`)
+    ///
+    /// Default does nothing, for backends that can't report focus.
+    fn enable_focus_reporting(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Stop focus change reports
+    fn disable_focus_reporting(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    /// Set the terminal window title
+    ///
+    /// Default writes the standard OSC sequence (`ESC]0;title BEL`).
+    /// The title isn't restored on exit - most terminals reset it themselves.
+    fn set_title(&mut self, title: &str) -> Result<()> {
+        self.write(title_sequence(title).as_bytes())?;
+        self.flush()
+    }
+}
+
+/// Build the OSC sequence for setting the window title
+///
+/// Control characters are stripped since a stray BEL or ESC would end
+/// the sequence early and dump the rest onto the screen.
+pub(crate) fn title_sequence(title: &str) -> String {
+    let clean: String = title.chars().filter(|c| !c.is_control()).collect();
+    format!("\x1b]0;{}\x07", clean)
 }
 
 #[cfg(feature = "crossterm")]
@@ -139,6 +180,16 @@ mod crossterm_impl {
             execute!(self.stdout, cursor::Hide)
                 .map_err(|e| crate::error::Error::Backend(e.to_string()))
         }
+
+        fn enable_focus_reporting(&mut self) -> Result<()> {
+            execute!(self.stdout, ct_event::EnableFocusChange)
+                .map_err(|e| crate::error::Error::Backend(e.to_string()))
+        }
+
+        fn disable_focus_reporting(&mut self) -> Result<()> {
+            execute!(self.stdout, ct_event::DisableFocusChange)
+                .map_err(|e| crate::error::Error::Backend(e.to_string()))
+        }
     }
 }
 
@@ -325,6 +376,18 @@ mod termion_impl {
             }
             Ok(())
         }
+
+        fn set_title(&mut self, title: &str) -> Result<()> {
+            // Termion has no title helper, so write the OSC sequence ourselves
+            if let Some(ref mut stdout) = self.stdout {
+                write!(stdout, "{}", title_sequence(title))
+                    .map_err(|e| crate::error::Error::Backend(e.to_string()))?;
+                stdout
+                    .flush()
+                    .map_err(|e| crate::error::Error::Backend(e.to_string()))?;
+            }
+            Ok(())
+        }
     }
 
     // Convert termion events to our Event type
@@ -417,7 +480,7 @@ mod termion_impl {
 
         match mouse {
             TermionMouse::Press(btn, x, y) => MouseEvent {
-                kind: MouseEventKind::Down(convert_mouse_button(btn)),
+                kind: convert_mouse_press(btn),
                 x: x.saturating_sub(1), // Termion uses 1-indexed
                 y: y.saturating_sub(1),
                 modifiers: KeyModifiers::empty(),
@@ -437,16 +500,17 @@ mod termion_impl {
         }
     }
 
-    fn convert_mouse_button(btn: termion::event::MouseButton) -> MouseButton {
+    /// Termion reports the wheel as button presses
+    fn convert_mouse_press(btn: termion::event::MouseButton) -> MouseEventKind {
         use termion::event::MouseButton as TButton;
         match btn {
-            TButton::Left => MouseButton::Left,
-            TButton::Right => MouseButton::Right,
-            TButton::Middle => MouseButton::Middle,
-            TButton::WheelUp => MouseButton::Left, // Approximation
-            TButton::WheelDown => MouseButton::Left, // Approximation
-            TButton::WheelLeft => MouseButton::Left, // Approximation
-            TButton::WheelRight => MouseButton::Left, // Approximation
+            TButton::Left => MouseEventKind::Down(MouseButton::Left),
+            TButton::Right => MouseEventKind::Down(MouseButton::Right),
+            TButton::Middle => MouseEventKind::Down(MouseButton::Middle),
+            TButton::WheelUp => MouseEventKind::ScrollUp,
+            TButton::WheelDown => MouseEventKind::ScrollDown,
+            // No horizontal scrolling, same as the crossterm backend
+            TButton::WheelLeft | TButton::WheelRight => MouseEventKind::Moved,
         }
     }
 }
@@ -1,4 +1,11 @@
+use super::diff::line_hash;
 use crate::theme::Style;
+use std::sync::atomic::{AtomicU64, Ordering};
+use unicode_width::UnicodeWidthChar;
+
+/// Marks the column after a wide glyph - a noncharacter, so it can't
+/// clash with real text
+const CONTINUATION: char = '\u{FFFF}';
 
 /// Single terminal cell with character and styling
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
@@ -18,13 +25,55 @@ impl Cell {
     pub fn with_style(ch: char, style: Style) -> Self {
         Self { ch, style }
     }
+
+    /// Placeholder for the right half of the wide glyph to its left
+    ///
+    /// `Buffer::set` writes these itself; the diff and the renderer skip
+    /// them, since the terminal fills that column when drawing the glyph.
+    pub fn continuation(style: Style) -> Self {
+        Self::with_style(CONTINUATION, style)
+    }
+
+    /// Check if this is the right half of a wide glyph
+    pub fn is_continuation(&self) -> bool {
+        self.ch == CONTINUATION
+    }
+
+    /// Columns the glyph takes on screen: 2 for wide characters (CJK,
+    /// most emoji), 0 for a continuation, 1 for everything else
+    pub fn width(&self) -> u16 {
+        if self.is_continuation() {
+            0
+        } else if self.ch.width() == Some(2) {
+            2
+        } else {
+            1
+        }
+    }
 }
 
 /// Virtual terminal buffer - represents a 2D grid of cells
+///
+/// Each line's hash is cached for the diff and dropped whenever a cell in
+/// that line changes, so a line nobody touched since the last frame isn't
+/// hashed again.
+#[derive(Debug)]
 pub struct Buffer {
     pub width: u16,
     pub height: u16,
     cells: Vec<Cell>, // flat array: cells[y * width + x]
+    /// Cached `line_hash` per line (`STALE_HASH` = not cached). Filled in
+    /// lazily by `line_hash`, which only has `&self`; atomics rather than a
+    /// Cell keep `Buffer` Sync.
+    line_hashes: Vec<AtomicU64>,
+}
+
+/// Marks a line whose hash has to be computed again. A line that really
+/// hashes to this just never gets cached.
+const STALE_HASH: u64 = 0;
+
+fn stale_hashes(height: u16) -> Vec<AtomicU64> {
+    (0..height).map(|_| AtomicU64::new(STALE_HASH)).collect()
 }
 
 impl Buffer {
@@ -34,6 +83,7 @@ impl Buffer {
             width,
             height,
             cells: vec![Cell::default(); size],
+            line_hashes: stale_hashes(height),
         }
     }
 
@@ -47,19 +97,68 @@ impl Buffer {
     }
 
     /// Get mutable cell reference
+    ///
+    /// Drops the line's cached hash, since we can't tell what gets written.
     pub fn get_mut(&mut self, x: u16, y: u16) -> Option<&mut Cell> {
         if x >= self.width || y >= self.height {
             return None;
         }
+        *self.line_hashes[y as usize].get_mut() = STALE_HASH;
         let idx = self.index(x, y);
         self.cells.get_mut(idx)
     }
 
     /// Set cell (silently ignores out-of-bounds)
+    ///
+    /// A wide glyph also takes the next column, filled with a continuation
+    /// cell; one that would end past the right edge is written as a blank
+    /// instead. Overwriting either half of a wide glyph blanks the other.
     pub fn set(&mut self, x: u16, y: u16, cell: Cell) {
-        if x < self.width && y < self.height {
-            let idx = self.index(x, y);
+        if x >= self.width || y >= self.height {
+            return;
+        }
+        // Copied verbatim, e.g. from another buffer along with its glyph
+        if cell.is_continuation() {
+            self.put(x, y, cell);
+            return;
+        }
+
+        let wide = cell.width() == 2;
+        if wide && x + 1 >= self.width {
+            self.set(x, y, Cell::with_style(' ', cell.style));
+            return;
+        }
+
+        self.break_wide_glyph(x, y);
+        if wide {
+            self.break_wide_glyph(x + 1, y);
+        }
+        let style = cell.style;
+        self.put(x, y, cell);
+        if wide {
+            self.put(x + 1, y, Cell::continuation(style));
+        }
+    }
+
+    /// Blank the other half of a wide glyph that (x, y) is part of, as
+    /// that column is about to be overwritten
+    fn break_wide_glyph(&mut self, x: u16, y: u16) {
+        let current = &self.cells[self.index(x, y)];
+        let style = current.style;
+        if current.is_continuation() && x > 0 {
+            self.put(x - 1, y, Cell::with_style(' ', style));
+        } else if current.width() == 2 && x + 1 < self.width {
+            self.put(x + 1, y, Cell::with_style(' ', style));
+        }
+    }
+
+    /// Store a cell as is
+    fn put(&mut self, x: u16, y: u16, cell: Cell) {
+        let idx = self.index(x, y);
+        // Rewriting the same cell keeps the line's cached hash
+        if self.cells[idx] != cell {
             self.cells[idx] = cell;
+            *self.line_hashes[y as usize].get_mut() = STALE_HASH;
         }
     }
 
@@ -73,10 +172,31 @@ impl Buffer {
         &self.cells[start..end]
     }
 
+    /// Hash of line `y`, from the cache when the line hasn't changed
+    ///
+    /// Out-of-bounds lines hash like an empty line.
+    pub fn line_hash(&self, y: u16) -> u64 {
+        let Some(cached) = self.line_hashes.get(y as usize) else {
+            return line_hash(&[]);
+        };
+        let hash = cached.load(Ordering::Relaxed);
+        if hash != STALE_HASH {
+            return hash;
+        }
+        let hash = line_hash(self.line(y));
+        cached.store(hash, Ordering::Relaxed);
+        hash
+    }
+
     /// Clear buffer to blank cells
     pub fn clear(&mut self) {
-        for cell in &mut self.cells {
-            *cell = Cell::default();
+        let width = (self.width as usize).max(1);
+        for (line, hash) in self.cells.chunks_mut(width).zip(&mut self.line_hashes) {
+            // Already-blank lines keep their cached hash
+            if line.iter().any(|cell| *cell != Cell::default()) {
+                line.fill(Cell::default());
+                *hash.get_mut() = STALE_HASH;
+            }
         }
     }
 
@@ -86,6 +206,7 @@ impl Buffer {
         self.height = height;
         let size = (width as usize) * (height as usize);
         self.cells = vec![Cell::default(); size];
+        self.line_hashes = stale_hashes(height);
     }
 
     #[inline]
@@ -101,14 +222,34 @@ impl Clone for Buffer {
             width: self.width,
             height: self.height,
             cells: self.cells.clone(),
+            line_hashes: self
+                .line_hashes
+                .iter()
+                .map(|hash| AtomicU64::new(hash.load(Ordering::Relaxed)))
+                .collect(),
         }
     }
 }
 
+// The hash cache is derived from the cells, so it stays out of equality
+impl PartialEq for Buffer {
+    fn eq(&self, other: &Self) -> bool {
+        self.width == other.width && self.height == other.height && self.cells == other.cells
+    }
+}
+
+impl Eq for Buffer {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_buffer_is_send_and_sync() {
+        fn assert_sync<T: Send + Sync>() {}
+        assert_sync::<Buffer>();
+    }
+
     #[test]
     fn test_buffer_creation() {
         let buf = Buffer::new(80, 24);
@@ -163,4 +304,68 @@ mod tests {
         // Content cleared after resize
         assert_eq!(buf.get(5, 5).unwrap().ch, '\0');
     }
+
+    #[test]
+    fn test_cell_width() {
+        assert_eq!(Cell::new('a').width(), 1);
+        assert_eq!(Cell::new('日').width(), 2);
+        assert_eq!(Cell::new('🦀').width(), 2);
+        assert_eq!(Cell::default().width(), 1);
+        assert_eq!(Cell::continuation(Style::default()).width(), 0);
+    }
+
+    #[test]
+    fn test_wide_glyphs() {
+        let row = |buf: &Buffer| -> String {
+            buf.line(0)
+                .iter()
+                .map(|c| match c.ch {
+                    '\0' => '.',
+                    _ if c.is_continuation() => '>',
+                    ch => ch,
+                })
+                .collect()
+        };
+        let mut buf = Buffer::new(5, 1);
+
+        buf.set(0, 0, Cell::new('日'));
+        buf.set(2, 0, Cell::new('本'));
+        assert_eq!(row(&buf), "日>本>.");
+
+        // No room for the right half on the last column
+        buf.set(4, 0, Cell::new('語'));
+        assert_eq!(row(&buf), "日>本> ");
+
+        // Overwriting either half leaves a blank in the other
+        buf.set(0, 0, Cell::new('a'));
+        buf.set(3, 0, Cell::new('b'));
+        assert_eq!(row(&buf), "a  b ");
+
+        // A wide glyph straddling two others breaks both
+        buf.set(0, 0, Cell::new('日'));
+        buf.set(2, 0, Cell::new('本'));
+        buf.set(1, 0, Cell::new('語'));
+        assert_eq!(row(&buf), " 語>  ");
+    }
+
+    #[test]
+    fn test_line_hash_cache_invalidation() {
+        let mut buf = Buffer::new(5, 3);
+        let blank = buf.line_hash(1);
+
+        buf.set(0, 1, Cell::new('A'));
+        let written = buf.line_hash(1);
+        assert_ne!(written, blank);
+        assert_eq!(buf.line_hash(0), blank);
+
+        buf.get_mut(0, 1).unwrap().ch = 'B';
+        assert_ne!(buf.line_hash(1), written);
+
+        buf.clear();
+        assert_eq!(buf.line_hash(1), blank);
+
+        buf.set(0, 2, Cell::new('A'));
+        buf.resize(5, 3);
+        assert_eq!(buf.line_hash(2), blank);
+    }
 }
@@ -37,8 +37,8 @@ pub fn compute_diff(old: &Buffer, new: &Buffer) -> Vec<DirtyRegion> {
         let old_line = old.line(y);
         let new_line = new.line(y);
 
-        // Quick hash comparison first
-        if line_hash(old_line) == line_hash(new_line) {
+        // Quick hash comparison first (cached for lines that didn't change)
+        if old.line_hash(y) == new.line_hash(y) {
             continue; // lines are identical, skip
         }
 
@@ -61,7 +61,9 @@ fn find_changed_spans(old_line: &[Cell], new_line: &[Cell], y: u16, dirty: &mut
         let old_cell = &old_line[x];
         let new_cell = &new_line[x];
 
-        if old_cell != new_cell {
+        // The right half of a wide glyph is drawn along with its left half,
+        // which is part of the span whenever the glyph changed
+        if old_cell != new_cell && !new_cell.is_continuation() {
             // Cell changed
             if start.is_none() {
                 start = Some(x as u16);
@@ -124,7 +126,7 @@ fn merge_adjacent_regions(dirty: &mut Vec<DirtyRegion>) {
 ///
 /// Uses FNV-1a hash - simple and fast for this use case.
 /// Not cryptographic, just needs to detect changes reliably.
-fn line_hash(line: &[Cell]) -> u64 {
+pub(super) fn line_hash(line: &[Cell]) -> u64 {
     let mut hash = 0xcbf29ce484222325u64; // FNV offset basis
 
     for cell in line {
@@ -289,4 +291,47 @@ mod tests {
         // Should remain separate (gap is too large)
         assert!(!diff.is_empty()); // At least one region
     }
+
+    /// Same diff, comparing every line cell by cell with no hashing
+    fn naive_diff(old: &Buffer, new: &Buffer) -> Vec<DirtyRegion> {
+        let mut dirty = Vec::new();
+        for y in 0..new.height {
+            find_changed_spans(old.line(y), new.line(y), y, &mut dirty);
+        }
+        merge_adjacent_regions(&mut dirty);
+        dirty
+    }
+
+    #[test]
+    fn test_cached_hashes_match_naive_diff() {
+        let mut prev = Buffer::new(30, 8);
+        let mut next = Buffer::new(30, 8);
+
+        // Small LCG so the "frames" are varied but reproducible
+        let mut seed = 12345u32;
+        let mut rand = move |n: u32| {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345);
+            (seed >> 16) % n
+        };
+
+        for frame in 0..50 {
+            if frame % 7 == 0 {
+                next.clear();
+            }
+            for _ in 0..rand(10) {
+                let (x, y) = (rand(30) as u16, rand(8) as u16);
+                let ch = (b'a' + rand(3) as u8) as char;
+                if rand(2) == 0 {
+                    next.set(x, y, Cell::new(ch));
+                } else {
+                    next.get_mut(x, y).unwrap().ch = ch;
+                }
+            }
+
+            assert_eq!(compute_diff(&prev, &next), naive_diff(&prev, &next));
+            std::mem::swap(&mut prev, &mut next);
+            // Next frame starts from the current one, like a redraw would
+            next.clone_from(&prev);
+        }
+    }
 }
@@ -1,14 +1,21 @@
 // Rendering core - buffer, backend abstraction, diff, and rendering
 
+mod ansi;
 mod backend;
 mod buffer;
 mod diff;
 mod renderer;
+mod test_backend;
 
+pub use ansi::{color_256, AnsiParser};
 pub use backend::Backend;
 pub use buffer::{Buffer, Cell};
 pub use diff::{compute_diff, compute_diff_precise, DirtyRegion};
-pub use renderer::Renderer;
+pub use renderer::{FrameStats, Renderer};
+pub use test_backend::TestBackend;
 
 #[cfg(feature = "crossterm")]
 pub use backend::CrosstermBackend;
+
+#[cfg(feature = "termion")]
+pub use backend::TermionBackend;
@@ -1,33 +1,104 @@
 use super::diff::{compute_diff, DirtyRegion};
-use super::{Backend, Buffer};
+use super::{Backend, Buffer, Cell};
 use crate::error::Result;
-use crate::theme::Modifier;
+use crate::theme::{Modifier, Style};
 use std::io::Write;
+use std::time::{Duration, Instant};
+
+/// What the last frame cost
+///
+/// `Renderer` fills in the region and cell counts and its own write time
+/// as `frame_time`; the App replaces `frame_time` with the whole frame
+/// (events, render, diff) and sets `interval` to the time since the
+/// frame before.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FrameStats {
+    /// Regions the diff found changed (0 for an unchanged frame)
+    pub dirty_regions: usize,
+    /// Cells written to the terminal
+    pub cells_redrawn: usize,
+    pub frame_time: Duration,
+    pub interval: Duration,
+}
+
+impl FrameStats {
+    /// Frames per second implied by `interval` (0 if unknown)
+    pub fn fps(&self) -> f64 {
+        if self.interval.is_zero() {
+            0.0
+        } else {
+            1.0 / self.interval.as_secs_f64()
+        }
+    }
+}
 
 /// Renderer that efficiently writes buffer changes to a backend
 ///
 /// Uses diff algorithm to minimize terminal writes. Only updates
 /// regions that actually changed between frames.
+///
+/// By default every `render` flushes. With `defer_flush(true)`, renders
+/// only write, and nothing reaches the screen until `present` - handy for
+/// drawing a base layer and an overlay as one terminal update. Forgetting
+/// `present` in that mode means the screen never updates, so pair every
+/// batch of renders with one.
 pub struct Renderer {
     // Track if we've done first render (forces full redraw)
     first_render: bool,
+    deferred: bool,
+    /// Written but not yet flushed
+    pending_flush: bool,
+    last_stats: Option<FrameStats>,
 }
 
 impl Renderer {
     pub fn new() -> Self {
-        Self { first_render: true }
+        Self {
+            first_render: true,
+            deferred: false,
+            pending_flush: false,
+            last_stats: None,
+        }
+    }
+
+    /// Stats for the most recent `render` (None before the first)
+    pub fn last_frame_stats(&self) -> Option<FrameStats> {
+        self.last_stats
+    }
+
+    /// Hold flushes until `present` is called
+    pub fn defer_flush(mut self, deferred: bool) -> Self {
+        self.deferred = deferred;
+        self
+    }
+
+    /// Check if there's written output waiting for `present`
+    pub fn has_pending_flush(&self) -> bool {
+        self.pending_flush
+    }
+
+    /// Flush everything rendered since the last present
+    pub fn present(&mut self, backend: &mut dyn Backend) -> Result<()> {
+        if std::mem::take(&mut self.pending_flush) {
+            backend.flush()?;
+        }
+        Ok(())
     }
 
     /// Render buffer to backend using diff from previous buffer
     ///
-    /// If prev_buffer is None, does a full redraw.
+    /// Only cells that differ from `prev_buffer` are written, in runs that
+    /// each start with a cursor move. If prev_buffer is None, or its size
+    /// differs from `buffer`, does a full redraw.
     pub fn render(
         &mut self,
         backend: &mut dyn Backend,
         prev_buffer: Option<&Buffer>,
         buffer: &Buffer,
     ) -> Result<()> {
-        let dirty_regions = if self.first_render || prev_buffer.is_none() {
+        let start = Instant::now();
+        let full_redraw = self.first_render || prev_buffer.is_none();
+        let dirty_regions = if full_redraw {
             // First render or no previous buffer - redraw everything
             self.first_render = false;
             vec![DirtyRegion::full_screen(buffer.width, buffer.height)]
@@ -35,59 +106,110 @@ impl Renderer {
             compute_diff(prev_buffer.unwrap(), buffer)
         };
 
+        let mut stats = FrameStats {
+            dirty_regions: dirty_regions.len(),
+            ..FrameStats::default()
+        };
+
         // Nothing to update?
         if dirty_regions.is_empty() {
+            stats.frame_time = start.elapsed();
+            self.last_stats = Some(stats);
             return Ok(());
         }
 
-        // Render each dirty region
+        // Render each dirty region; a full redraw writes every cell. The
+        // terminal keeps its style across cursor moves, so it's only reset
+        // once at the end
+        let prev = if full_redraw { None } else { prev_buffer };
+        let mut style = None;
         for region in dirty_regions {
-            self.render_region(backend, buffer, &region)?;
+            stats.cells_redrawn +=
+                self.render_region(backend, prev, buffer, &region, &mut style)?;
         }
+        if style.is_some() {
+            let mut output = Vec::new();
+            write_reset_codes(&mut output)?;
+            backend.write(&output)?;
+        }
+        stats.frame_time = start.elapsed();
+        self.last_stats = Some(stats);
 
-        backend.flush()?;
+        self.pending_flush = true;
+        if !self.deferred {
+            self.present(backend)?;
+        }
         Ok(())
     }
 
-    /// Render a specific region of the buffer
+    /// Render the changed cells of a region, returning the cells written
+    ///
+    /// Merged regions can cover a few unchanged cells; with a previous
+    /// buffer of the same size those are skipped. Each run of adjacent
+    /// changed cells is one cursor move and one write, with a style escape
+    /// only where the style differs from what the terminal has (`style`,
+    /// carried over from the previous region).
     fn render_region(
         &self,
         backend: &mut dyn Backend,
+        prev: Option<&Buffer>,
         buffer: &Buffer,
         region: &DirtyRegion,
-    ) -> Result<()> {
+        style: &mut Option<Style>,
+    ) -> Result<usize> {
         let rect = region.rect;
+        let prev = prev.filter(|p| p.width == buffer.width && p.height == buffer.height);
+        let mut written = 0;
+        // Output for the current run
+        let mut output = Vec::new();
 
         // Render each line in the region
         for y in rect.y..(rect.y + rect.height).min(buffer.height) {
-            backend.cursor_goto(rect.x, y)?;
-
-            // Build output for this line
-            let mut output = Vec::new();
-            let mut current_style = None;
+            let mut in_run = false;
 
             for x in rect.x..(rect.x + rect.width).min(buffer.width) {
-                if let Some(cell) = buffer.get(x, y) {
-                    // Apply style if it changed
-                    if current_style.as_ref() != Some(&cell.style) {
-                        write_style_codes(&mut output, &cell.style)?;
-                        current_style = Some(cell.style);
+                let Some(cell) = buffer.get(x, y) else {
+                    continue;
+                };
+                // Already covered by the wide glyph before it. Never start
+                // a run on one either: the cursor would be one column
+                // ahead of the cells from there on
+                if cell.is_continuation() {
+                    continue;
+                }
+
+                if prev.is_some_and(|p| p.get(x, y) == Some(cell)) {
+                    // Unchanged: end the run, the next one moves the cursor
+                    if std::mem::take(&mut in_run) {
+                        backend.write(&output)?;
+                        output.clear();
                     }
+                    continue;
+                }
 
-                    // Write the character
-                    write!(output, "{}", cell.ch)?;
+                if !in_run {
+                    in_run = true;
+                    backend.cursor_goto(x, y)?;
+                }
+
+                // Apply style if it changed
+                if style.as_ref() != Some(&cell.style) {
+                    write_style_codes(&mut output, &cell.style)?;
+                    *style = Some(cell.style);
                 }
-            }
 
-            // Reset style at end of line
-            if current_style.is_some() {
-                write_reset_codes(&mut output)?;
+                // Write the character
+                write!(output, "{}", cell.ch)?;
+                written += 1;
             }
 
-            backend.write(&output)?;
+            if in_run {
+                backend.write(&output)?;
+                output.clear();
+            }
         }
 
-        Ok(())
+        Ok(written)
     }
 }
 
@@ -98,7 +220,7 @@ impl Default for Renderer {
 }
 
 /// Write ANSI escape codes for style
-fn write_style_codes(output: &mut Vec<u8>, style: &crate::theme::Style) -> Result<()> {
+fn write_style_codes(output: &mut Vec<u8>, style: &Style) -> Result<()> {
     // Reset first to clear previous style
     write!(output, "\x1b[0m")?;
 
@@ -150,7 +272,7 @@ fn write_reset_codes(output: &mut Vec<u8>) -> Result<()> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::theme::{Color, Style};
+    use crate::theme::Color;
 
     #[test]
     fn test_renderer_creation() {
@@ -169,4 +291,174 @@ mod tests {
         let s = String::from_utf8_lossy(&output);
         assert!(s.contains("\x1b["));
     }
+
+    #[test]
+    fn test_reversed_cell_is_diffed_and_emits_sgr_7() {
+        use crate::render::{Cell, TestBackend};
+
+        let backend = TestBackend::new(4, 1);
+        let mut handle = backend.clone();
+        let mut renderer = Renderer::new();
+
+        let mut before = Buffer::new(4, 1);
+        before.set(1, 0, Cell::new('x'));
+        let mut after = before.clone();
+        after.set(1, 0, Cell::with_style('x', Style::new().reversed()));
+        assert!(!compute_diff(&before, &after).is_empty());
+
+        renderer.render(&mut handle, None, &before).unwrap();
+        backend.clear_output();
+        renderer.render(&mut handle, Some(&before), &after).unwrap();
+        assert!(backend.output_str().contains("\x1b[7m"));
+    }
+
+    #[test]
+    fn test_wide_glyphs_skip_continuation_cells() {
+        use crate::render::TestBackend;
+
+        let backend = TestBackend::new(5, 1);
+        let mut handle = backend.clone();
+        let mut renderer = Renderer::new();
+
+        let mut before = Buffer::new(5, 1);
+        before.set(0, 0, Cell::new('a'));
+        before.set(1, 0, Cell::new('b'));
+        before.set(2, 0, Cell::new('c'));
+        let mut after = before.clone();
+        after.set(0, 0, Cell::new('日'));
+
+        renderer.render(&mut handle, None, &before).unwrap();
+        backend.clear_output();
+        renderer.render(&mut handle, Some(&before), &after).unwrap();
+
+        // Only the glyph goes out; the terminal fills its second column
+        let output = backend.output_str();
+        assert!(output.contains('日'));
+        assert!(!output.contains('b'));
+        assert!(!output.contains('\u{FFFF}'));
+        let stats = renderer.last_frame_stats().unwrap();
+        assert_eq!((stats.dirty_regions, stats.cells_redrawn), (1, 1));
+    }
+
+    #[test]
+    fn test_only_changed_cells_are_written() {
+        use crate::render::TestBackend;
+
+        let backend = TestBackend::new(8, 3);
+        let mut handle = backend.clone();
+        let mut renderer = Renderer::new();
+
+        let mut before = Buffer::new(8, 3);
+        for y in 0..3 {
+            for (x, ch) in "abcdefgh".chars().enumerate() {
+                before.set(x as u16, y, Cell::new(ch));
+            }
+        }
+        let mut after = before.clone();
+        after.set(2, 1, Cell::new('X'));
+        after.set(4, 1, Cell::new('Y'));
+
+        renderer.render(&mut handle, None, &before).unwrap();
+        backend.clear_output();
+        renderer.render(&mut handle, Some(&before), &after).unwrap();
+
+        // One merged region, but 'e' between the changes isn't rewritten,
+        // and the style carries over the cursor move
+        assert_eq!(backend.output_str(), "\x1b[2;3H\x1b[0mX\x1b[2;5HY\x1b[0m");
+        let stats = renderer.last_frame_stats().unwrap();
+        assert_eq!((stats.dirty_regions, stats.cells_redrawn), (1, 2));
+        assert_eq!(backend.snapshot().lines().nth(1), Some("abXdYfgh"));
+
+        // A different size redraws everything
+        let resized = Buffer::new(4, 2);
+        renderer
+            .render(&mut handle, Some(&after), &resized)
+            .unwrap();
+        assert_eq!(renderer.last_frame_stats().unwrap().cells_redrawn, 8);
+    }
+
+    #[test]
+    fn test_style_carried_across_rows_matches_buffer() {
+        use crate::render::TestBackend;
+
+        let backend = TestBackend::new(3, 2);
+        let mut handle = backend.clone();
+        let mut renderer = Renderer::new();
+
+        let red = Style::new().fg(Color::RED);
+        let mut buffer = Buffer::new(3, 2);
+        buffer.set(0, 0, Cell::new(' '));
+        buffer.set(1, 0, Cell::with_style('r', red));
+        buffer.set(2, 0, Cell::with_style('r', red));
+        buffer.set(0, 1, Cell::with_style('r', red));
+        buffer.set(1, 1, Cell::new('p'));
+        buffer.set(2, 1, Cell::new(' '));
+        renderer.render(&mut handle, None, &buffer).unwrap();
+
+        // One escape for the whole red stretch, wrapping onto the next row
+        assert_eq!(backend.output_str().matches("38;2;").count(), 1);
+        for y in 0..2 {
+            for x in 0..3 {
+                let (shown, drawn) = (backend.cell_at(x, y), buffer.get(x, y).unwrap());
+                assert_eq!((shown.ch, shown.style), (drawn.ch, drawn.style));
+            }
+        }
+    }
+
+    #[test]
+    fn test_deferred_renders_flush_once() {
+        use crate::render::TestBackend;
+
+        let backend = TestBackend::new(4, 2);
+        let mut handle = backend.clone();
+        let mut renderer = Renderer::new().defer_flush(true);
+
+        let base = Buffer::new(4, 2);
+        let mut overlay = base.clone();
+        overlay.set(1, 1, crate::render::Cell::new('x'));
+
+        renderer.render(&mut handle, None, &base).unwrap();
+        renderer.render(&mut handle, Some(&base), &overlay).unwrap();
+        assert_eq!(backend.flush_count(), 0);
+        assert!(renderer.has_pending_flush());
+
+        renderer.present(&mut handle).unwrap();
+        assert_eq!(backend.flush_count(), 1);
+
+        // Nothing new - nothing to flush
+        renderer.present(&mut handle).unwrap();
+        assert_eq!(backend.flush_count(), 1);
+    }
+
+    #[test]
+    fn test_frame_stats() {
+        use crate::render::TestBackend;
+
+        let mut backend = TestBackend::new(4, 2);
+        let mut renderer = Renderer::new();
+        assert_eq!(renderer.last_frame_stats(), None);
+
+        let base = Buffer::new(4, 2);
+        renderer.render(&mut backend, None, &base).unwrap();
+        let stats = renderer.last_frame_stats().unwrap();
+        assert_eq!((stats.dirty_regions, stats.cells_redrawn), (1, 8));
+
+        renderer.render(&mut backend, Some(&base), &base).unwrap();
+        let stats = renderer.last_frame_stats().unwrap();
+        assert_eq!((stats.dirty_regions, stats.cells_redrawn), (0, 0));
+    }
+
+    #[test]
+    fn test_immediate_mode_flushes_each_render() {
+        use crate::render::TestBackend;
+
+        let backend = TestBackend::new(4, 2);
+        let mut handle = backend.clone();
+        let mut renderer = Renderer::new();
+
+        let buffer = Buffer::new(4, 2);
+        renderer.render(&mut handle, None, &buffer).unwrap();
+        assert_eq!(backend.flush_count(), 1);
+        assert!(!renderer.has_pending_flush());
+    }
 }
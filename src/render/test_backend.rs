@@ -0,0 +1,254 @@
+use super::{AnsiParser, Backend, Cell};
+use crate::error::Result;
+use crate::event::Event;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// In-memory backend for tests
+///
+/// Records everything written to it and never touches the real terminal.
+/// The output is also played onto an in-memory screen, so tests can check
+/// what a terminal would show with `snapshot` and `cell_at`. Clones share
+/// the same state, so you can hand one to an `App` and keep another around
+/// to inspect the output.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::render::{Backend, TestBackend};
+///
+/// let backend = TestBackend::new(80, 24);
+/// let mut handle = backend.clone();
+/// handle.write(b"hello").unwrap();
+/// assert_eq!(backend.output_str(), "hello");
+/// assert_eq!(backend.cell_at(1, 0).ch, 'e');
+/// ```
+#[derive(Clone)]
+pub struct TestBackend {
+    state: Arc<Mutex<TestState>>,
+}
+
+struct TestState {
+    width: u16,
+    height: u16,
+    output: Vec<u8>,
+    /// The output as it would appear on screen
+    screen: AnsiParser,
+    events: VecDeque<Event>,
+    flushes: usize,
+    interactive: bool,
+}
+
+impl TestBackend {
+    /// Create a test backend with the given terminal size
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            state: Arc::new(Mutex::new(TestState {
+                width,
+                height,
+                output: Vec::new(),
+                screen: AnsiParser::new(width, height),
+                events: VecDeque::new(),
+                flushes: 0,
+                interactive: true,
+            })),
+        }
+    }
+
+    /// Everything written so far (raw bytes)
+    pub fn output(&self) -> Vec<u8> {
+        self.state.lock().unwrap().output.clone()
+    }
+
+    /// Everything written so far, lossily decoded as UTF-8
+    pub fn output_str(&self) -> String {
+        String::from_utf8_lossy(&self.state.lock().unwrap().output).into_owned()
+    }
+
+    /// Forget recorded output
+    ///
+    /// The screen keeps showing what's on it.
+    pub fn clear_output(&self) {
+        self.state.lock().unwrap().output.clear();
+    }
+
+    /// The screen as text, one line per row with trailing blanks trimmed
+    pub fn snapshot(&self) -> String {
+        let state = self.state.lock().unwrap();
+        crate::testing::buffer_lines(state.screen.buffer()).join("\n")
+    }
+
+    /// The cell on screen at (x, y) - a default cell outside the screen
+    pub fn cell_at(&self, x: u16, y: u16) -> Cell {
+        let state = self.state.lock().unwrap();
+        state.screen.buffer().get(x, y).cloned().unwrap_or_default()
+    }
+
+    /// Number of times `flush` was called
+    pub fn flush_count(&self) -> usize {
+        self.state.lock().unwrap().flushes
+    }
+
+    /// Queue an event to be returned by `read_event`
+    pub fn push_event(&self, event: Event) {
+        self.state.lock().unwrap().events.push_back(event);
+    }
+
+    /// Pretend stdin/stdout aren't a terminal (default: they are)
+    pub fn set_interactive(&self, interactive: bool) {
+        self.state.lock().unwrap().interactive = interactive;
+    }
+
+    /// Change the reported terminal size
+    ///
+    /// The screen starts over blank, to be redrawn at the new size.
+    pub fn resize(&self, width: u16, height: u16) {
+        let mut state = self.state.lock().unwrap();
+        state.width = width;
+        state.height = height;
+        state.screen = AnsiParser::new(width, height);
+    }
+}
+
+impl Backend for TestBackend {
+    fn enter_raw_mode(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn leave_raw_mode(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn enter_alt_screen(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn leave_alt_screen(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn enable_mouse(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn disable_mouse(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.state.lock().unwrap().interactive
+    }
+
+    fn size(&self) -> Result<(u16, u16)> {
+        let state = self.state.lock().unwrap();
+        Ok((state.width, state.height))
+    }
+
+    fn clear(&mut self) -> Result<()> {
+        self.state.lock().unwrap().screen.reset();
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.state.lock().unwrap().flushes += 1;
+        Ok(())
+    }
+
+    fn write(&mut self, content: &[u8]) -> Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.output.extend_from_slice(content);
+        state.screen.feed(content);
+        Ok(())
+    }
+
+    fn read_event(&mut self, _timeout: Duration) -> Result<Option<Event>> {
+        // Never block in tests
+        Ok(self.state.lock().unwrap().events.pop_front())
+    }
+
+    fn cursor_goto(&mut self, x: u16, y: u16) -> Result<()> {
+        // Same sequence a real terminal would get (1-indexed)
+        self.write(format!("\x1b[{};{}H", y + 1, x + 1).as_bytes())
+    }
+
+    fn cursor_show(&mut self) -> Result<()> {
+        Ok(())
+    }
+
+    fn cursor_hide(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_title_emits_osc() {
+        let mut backend = TestBackend::new(80, 24);
+        backend.set_title("my app").unwrap();
+        assert_eq!(backend.output_str(), "\x1b]0;my app\x07");
+    }
+
+    #[test]
+    fn test_set_title_sanitizes_control_chars() {
+        let mut backend = TestBackend::new(80, 24);
+        // A stray BEL or ESC would terminate the sequence early
+        backend.set_title("evil\x07\x1b]0;title\n").unwrap();
+        assert_eq!(backend.output_str(), "\x1b]0;evil]0;title\x07");
+    }
+
+    #[test]
+    fn test_clones_share_state() {
+        let backend = TestBackend::new(10, 5);
+        let mut handle = backend.clone();
+        handle.write(b"abc").unwrap();
+        handle.flush().unwrap();
+
+        assert_eq!(backend.output(), b"abc");
+        assert_eq!(backend.flush_count(), 1);
+    }
+
+    #[test]
+    fn test_screen_follows_output() {
+        let mut backend = TestBackend::new(10, 3);
+        backend.write(b"hi").unwrap();
+        backend.cursor_goto(3, 2).unwrap();
+        backend.write("\x1b[31m日x".as_bytes()).unwrap();
+
+        assert_eq!(backend.snapshot(), "hi\n\n   日x");
+        assert_eq!(backend.cell_at(5, 2).ch, 'x');
+        assert_eq!(
+            backend.cell_at(5, 2).style.fg,
+            Some(crate::theme::Color::rgb(205, 0, 0))
+        );
+        assert_eq!(backend.cell_at(20, 20), Cell::default());
+
+        backend.clear().unwrap();
+        assert_eq!(backend.snapshot(), "\n\n");
+    }
+
+    #[test]
+    fn test_drives_an_app() {
+        use crate::app::App;
+        use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+        use crate::state::Signal;
+        use crate::view::Input;
+
+        let backend = TestBackend::new(20, 2);
+        let mut app = App::with_backend(Box::new(backend.clone()))
+            .root(Input::new(Signal::new(String::new())).focused(true));
+        for c in "abc".chars() {
+            backend.push_event(Event::Key(KeyEvent::new(
+                KeyCode::Char(c),
+                KeyModifiers::empty(),
+            )));
+        }
+        for _ in 0..3 {
+            app.tick().unwrap();
+        }
+
+        assert_eq!(backend.snapshot(), "abc|\n");
+    }
+}
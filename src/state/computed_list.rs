@@ -0,0 +1,244 @@
+use super::{Signal, Subscription};
+use std::any::Any;
+use std::cmp::Ordering;
+use std::sync::{Arc, RwLock};
+
+type Matcher<T> = Box<dyn Fn(&[T]) -> Vec<usize> + Send + Sync>;
+type Comparator<T> = Arc<dyn Fn(&T, &T) -> Ordering + Send + Sync>;
+
+/// Filtered and sorted view over a `Signal<Vec<T>>`
+///
+/// Keeps an output signal in sync with the source list, a filter query and
+/// an optional sort comparator. The view is only recomputed when one of
+/// those inputs changes, so reading it in `render` is cheap.
+///
+/// Indices into the view can be mapped back to the source list, which is
+/// what you need when a `List` is bound to the filtered output but actions
+/// should apply to the original item.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let fruits = Signal::new(vec!["banana", "apple", "cherry"]);
+/// let query = Signal::new(String::from("an"));
+///
+/// let view = ComputedList::new(fruits)
+///     .filter(query, |item, q: &String| item.contains(q.as_str()))
+///     .sort_by(|a, b| a.cmp(b));
+///
+/// assert_eq!(view.get(), vec!["banana"]);
+/// ```
+pub struct ComputedList<T> {
+    inner: Arc<ListInner<T>>,
+    // Keep subscriptions alive for as long as the view exists
+    subscriptions: Vec<Box<dyn Any + Send + Sync>>,
+}
+
+struct ListInner<T> {
+    source: Signal<Vec<T>>,
+    output: Signal<Vec<T>>,
+    matcher: RwLock<Option<Matcher<T>>>,
+    comparator: RwLock<Option<Comparator<T>>>,
+    // indices[view_idx] = source_idx
+    indices: RwLock<Vec<usize>>,
+}
+
+impl<T: Clone + Send + Sync + 'static> ListInner<T> {
+    fn recompute(&self) {
+        let items = self.source.get();
+
+        let mut indices = match self.matcher.read().unwrap().as_ref() {
+            Some(matcher) => matcher(&items),
+            None => (0..items.len()).collect(),
+        };
+
+        if let Some(cmp) = self.comparator.read().unwrap().as_ref() {
+            // Stable sort so equal items keep their source order
+            indices.sort_by(|&a, &b| cmp(&items[a], &items[b]));
+        }
+
+        let view: Vec<T> = indices.iter().map(|&i| items[i].clone()).collect();
+        *self.indices.write().unwrap() = indices;
+        self.output.set(view);
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> ComputedList<T> {
+    /// Create a view over `source` (unfiltered and unsorted until configured)
+    pub fn new(source: Signal<Vec<T>>) -> Self {
+        let inner = Arc::new(ListInner {
+            output: Signal::new(source.get()),
+            indices: RwLock::new((0..source.get().len()).collect()),
+            source: source.clone(),
+            matcher: RwLock::new(None),
+            comparator: RwLock::new(None),
+        });
+
+        let weak = Arc::downgrade(&inner);
+        let sub: Subscription<Vec<T>> = source.subscribe(move |_| {
+            if let Some(inner) = weak.upgrade() {
+                inner.recompute();
+            }
+        });
+
+        Self {
+            inner,
+            subscriptions: vec![Box::new(sub)],
+        }
+    }
+
+    /// Filter items using a query signal
+    ///
+    /// The predicate receives each item and the current query value. The view
+    /// is recomputed whenever the query changes.
+    pub fn filter<Q, F>(mut self, query: Signal<Q>, predicate: F) -> Self
+    where
+        Q: Clone + Send + Sync + 'static,
+        F: Fn(&T, &Q) -> bool + Send + Sync + 'static,
+    {
+        let q = query.clone();
+        let matcher: Matcher<T> = Box::new(move |items| {
+            // Read the query once per recompute, not once per item
+            let current = q.get();
+            items
+                .iter()
+                .enumerate()
+                .filter(|(_, item)| predicate(item, &current))
+                .map(|(i, _)| i)
+                .collect()
+        });
+        *self.inner.matcher.write().unwrap() = Some(matcher);
+
+        let weak = Arc::downgrade(&self.inner);
+        let sub: Subscription<Q> = query.subscribe(move |_| {
+            if let Some(inner) = weak.upgrade() {
+                inner.recompute();
+            }
+        });
+        self.subscriptions.push(Box::new(sub));
+
+        self.inner.recompute();
+        self
+    }
+
+    /// Sort the view with a comparator (stable, source order breaks ties)
+    pub fn sort_by<F>(self, cmp: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+    {
+        *self.inner.comparator.write().unwrap() = Some(Arc::new(cmp));
+        self.inner.recompute();
+        self
+    }
+
+    /// Get the current filtered/sorted items
+    pub fn get(&self) -> Vec<T> {
+        self.inner.output.get()
+    }
+
+    /// Get the output signal (bind this to a `List` or `Table`)
+    pub fn signal(&self) -> Signal<Vec<T>> {
+        self.inner.output.clone()
+    }
+
+    /// Number of items currently in the view
+    pub fn len(&self) -> usize {
+        self.inner.indices.read().unwrap().len()
+    }
+
+    /// Check if the view is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Map an index in the view back to an index in the source list
+    pub fn source_index(&self, view_index: usize) -> Option<usize> {
+        self.inner.indices.read().unwrap().get(view_index).copied()
+    }
+
+    /// Get the source item behind a view index
+    pub fn source_item(&self, view_index: usize) -> Option<T> {
+        let idx = self.source_index(view_index)?;
+        self.inner.source.get().get(idx).cloned()
+    }
+
+    /// Force a recompute (e.g. when the predicate captures non-signal state)
+    pub fn refresh(&self) {
+        self.inner.recompute();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unfiltered_view_mirrors_source() {
+        let source = Signal::new(vec![3, 1, 2]);
+        let view = ComputedList::new(source.clone());
+
+        assert_eq!(view.get(), vec![3, 1, 2]);
+
+        source.set(vec![5]);
+        assert_eq!(view.get(), vec![5]);
+    }
+
+    #[test]
+    fn test_filter_change_updates_view() {
+        let source = Signal::new(vec!["apple", "banana", "avocado", "cherry"]);
+        let query = Signal::new(String::from("a"));
+
+        let view = ComputedList::new(source).filter(query.clone(), |item, q: &String| {
+            item.starts_with(q.as_str())
+        });
+
+        assert_eq!(view.get(), vec!["apple", "avocado"]);
+
+        query.set(String::from("c"));
+        assert_eq!(view.get(), vec!["cherry"]);
+
+        query.set(String::from("z"));
+        assert!(view.is_empty());
+    }
+
+    #[test]
+    fn test_source_change_updates_view() {
+        let source = Signal::new(vec![1, 2, 3, 4]);
+        let min = Signal::new(3);
+
+        let view = ComputedList::new(source.clone()).filter(min, |n, min| n >= min);
+        assert_eq!(view.get(), vec![3, 4]);
+
+        source.update(|v| v.push(10));
+        assert_eq!(view.get(), vec![3, 4, 10]);
+    }
+
+    #[test]
+    fn test_sorted_view_maps_back_to_source() {
+        let source = Signal::new(vec!["pear", "fig", "kiwi", "date"]);
+        let view = ComputedList::new(source).sort_by(|a, b| a.cmp(b));
+
+        assert_eq!(view.get(), vec!["date", "fig", "kiwi", "pear"]);
+        assert_eq!(view.source_index(0), Some(3));
+        assert_eq!(view.source_item(1), Some("fig"));
+        assert_eq!(view.source_index(10), None);
+    }
+
+    #[test]
+    fn test_output_signal_notifies() {
+        use std::sync::atomic::{AtomicUsize, Ordering as AtomicOrdering};
+
+        let source = Signal::new(vec![1, 2, 3]);
+        let view = ComputedList::new(source.clone());
+
+        let count = Arc::new(AtomicUsize::new(0));
+        let c = count.clone();
+        let _sub = view.signal().subscribe(move |_| {
+            c.fetch_add(1, AtomicOrdering::SeqCst);
+        });
+
+        source.set(vec![4, 5]);
+        assert_eq!(count.load(AtomicOrdering::SeqCst), 1);
+    }
+}
@@ -1,21 +1,75 @@
+use super::Signal;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, RwLock};
 
+/// Something a `Derived` can depend on
+///
+/// The version must change whenever the value does. `Signal` bumps it on
+/// every write; `Derived` bumps it whenever it recomputes.
+pub trait Dependency {
+    fn version(&self) -> u64;
+}
+
+impl<T: Clone + Send + Sync + 'static> Dependency for Signal<T> {
+    fn version(&self) -> u64 {
+        Signal::version(self)
+    }
+}
+
+impl<T: Clone + Send + Sync + 'static> Dependency for Derived<T> {
+    fn version(&self) -> u64 {
+        Derived::version(self)
+    }
+}
+
 /// Computed signal derived from other signals
 ///
 /// Lazily recomputes when accessed after dependencies change.
 /// Caches the result so repeated gets are cheap.
 ///
-/// Note: Currently requires manual invalidation when deps change.
-/// Auto-tracking would be nice but adds complexity... maybe later?
+/// Dependencies declared with `depends_on` are checked on every `get` by
+/// comparing versions, so there's nothing to invalidate by hand. A
+/// `Derived` can depend on another `Derived`: checking it brings it up to
+/// date first, so chains and diamonds recompute each stage at most once
+/// per change. Without declared dependencies, call `invalidate` when the
+/// inputs change.
+///
+/// ## Example
+/// ```
+/// use rsdrav::prelude::*;
+///
+/// let items = Signal::new(vec![1, 2, 3, 4]);
+/// let evens = {
+///     let source = items.clone();
+///     Derived::new(move || source.get().into_iter().filter(|n| n % 2 == 0).collect::<Vec<_>>())
+///         .depends_on(&items)
+/// };
+/// let count = {
+///     let source = evens.clone();
+///     Derived::new(move || source.get().len()).depends_on(&evens)
+/// };
+///
+/// items.update(|v| v.push(6));
+/// assert_eq!(count.get(), 3);
+/// ```
 pub struct Derived<T> {
     inner: Arc<DerivedInner<T>>,
 }
 
 struct DerivedInner<T> {
     compute: Box<dyn Fn() -> T + Send + Sync>,
-    cached: RwLock<Option<(T, u64)>>, // (value, dep_version)
+    cached: RwLock<Option<Cached<T>>>,
+    /// Bumped by `invalidate`
     deps_version: AtomicU64,
+    deps: RwLock<Vec<Box<dyn Dependency + Send + Sync>>>,
+    /// Bumped on every recompute, for whoever depends on us
+    version: AtomicU64,
+}
+
+struct Cached<T> {
+    value: T,
+    manual_version: u64,
+    dep_versions: Vec<u64>,
 }
 
 impl<T: Clone + Send + Sync + 'static> Derived<T> {
@@ -25,37 +79,73 @@ impl<T: Clone + Send + Sync + 'static> Derived<T> {
                 compute: Box::new(compute),
                 cached: RwLock::new(None),
                 deps_version: AtomicU64::new(0),
+                deps: RwLock::new(Vec::new()),
+                version: AtomicU64::new(0),
             }),
         }
     }
 
+    /// Recompute whenever `dep` changes
+    ///
+    /// `dep` should be (a clone of) something the compute closure reads.
+    pub fn depends_on(self, dep: &(impl Dependency + Clone + Send + Sync + 'static)) -> Self {
+        self.inner.deps.write().unwrap().push(Box::new(dep.clone()));
+        self
+    }
+
     /// Get computed value (uses cache if dependencies unchanged)
     pub fn get(&self) -> T {
-        let current_ver = self.inner.deps_version.load(Ordering::SeqCst);
+        self.refresh();
+        let cached = self.inner.cached.read().unwrap();
+        cached.as_ref().expect("refreshed above").value.clone()
+    }
+
+    /// Changes every time the value is recomputed
+    ///
+    /// Brings the value up to date first, which is what lets a `Derived`
+    /// depend on another one.
+    pub fn version(&self) -> u64 {
+        self.refresh();
+        self.inner.version.load(Ordering::SeqCst)
+    }
+
+    /// Mark dependencies as changed (call this when dependent signals change)
+    ///
+    /// Only needed for inputs that weren't declared with `depends_on`.
+    pub fn invalidate(&self) {
+        self.inner.deps_version.fetch_add(1, Ordering::SeqCst);
+    }
+
+    /// Recompute if anything changed since the cached value
+    fn refresh(&self) {
+        let manual_version = self.inner.deps_version.load(Ordering::SeqCst);
+        // Checking a Derived dependency refreshes it, depth first
+        let dep_versions: Vec<u64> = self
+            .inner
+            .deps
+            .read()
+            .unwrap()
+            .iter()
+            .map(|dep| dep.version())
+            .collect();
 
-        // Check cache first
         {
             let cached = self.inner.cached.read().unwrap();
-            if let Some((ref val, ver)) = *cached {
-                if ver == current_ver {
-                    return val.clone();
+            if let Some(cached) = cached.as_ref() {
+                if cached.manual_version == manual_version && cached.dep_versions == dep_versions {
+                    return;
                 }
             }
         }
 
         // Cache miss or stale - recompute
-        let new_val = (self.inner.compute)();
-        *self.inner.cached.write().unwrap() = Some((new_val.clone(), current_ver));
-        new_val
-    }
-
-    /// Mark dependencies as changed (call this when dependent signals change)
-    ///
-    /// TODO: would be great to auto-track this somehow...
-    /// Maybe subscribe to all accessed signals during compute?
-    /// Could work but seems tricky. Good enough for now.
-    pub fn invalidate(&self) {
-        self.inner.deps_version.fetch_add(1, Ordering::SeqCst);
+        let value = (self.inner.compute)();
+        *self.inner.cached.write().unwrap() = Some(Cached {
+            value,
+            manual_version,
+            dep_versions,
+        });
+        self.inner.version.fetch_add(1, Ordering::SeqCst);
     }
 }
 
@@ -144,4 +234,40 @@ mod tests {
         squared.invalidate();
         assert_eq!(squared.get(), 36); // (3 * 2)^2 = 36
     }
+
+    #[test]
+    fn test_derived_of_derived_recomputes_transitively() {
+        let a = Signal::new(1);
+        let b_runs = Arc::new(AtomicU64::new(0));
+
+        let b = {
+            let (a2, runs) = (a.clone(), b_runs.clone());
+            Derived::new(move || {
+                runs.fetch_add(1, Ordering::SeqCst);
+                a2.get() * 10
+            })
+            .depends_on(&a)
+        };
+        let c = {
+            let b2 = b.clone();
+            Derived::new(move || b2.get() + 1).depends_on(&b)
+        };
+        // Diamond: d reads b directly and through c
+        let d = {
+            let (b2, c2) = (b.clone(), c.clone());
+            Derived::new(move || b2.get() + c2.get())
+                .depends_on(&b)
+                .depends_on(&c)
+        };
+
+        assert_eq!(c.get(), 11);
+        assert_eq!(d.get(), 21);
+        assert_eq!(b_runs.load(Ordering::SeqCst), 1);
+
+        a.set(2);
+        assert_eq!(c.get(), 21);
+        assert_eq!(d.get(), 41);
+        assert_eq!(b.get(), 20);
+        assert_eq!(b_runs.load(Ordering::SeqCst), 2);
+    }
 }
@@ -0,0 +1,185 @@
+use super::Signal;
+use std::any::Any;
+use std::cell::RefCell;
+use std::sync::{Arc, Mutex, Weak};
+
+/// Subscribes a running effect to a signal it read, returning the
+/// subscription to keep
+type Subscribe = Box<dyn FnOnce(Weak<EffectInner>) -> Box<dyn Any + Send + Sync>>;
+
+thread_local! {
+    /// Signals read by each effect running on this thread, innermost last
+    static TRACKING: RefCell<Vec<Vec<(usize, Subscribe)>>> = RefCell::new(Vec::new());
+}
+
+/// Side effect that re-runs whenever a signal it read changes
+///
+/// The closure runs once right away. Every `Signal` it reads with `get` or
+/// `with` is recorded, and a change to any of them runs it again, picking
+/// up a fresh set of signals each time - so a branch that stops reading a
+/// signal stops depending on it. Like `Derived`, but for things like
+/// logging or saving a file rather than computing a value.
+///
+/// The effect lives as long as the returned handle; drop it to stop.
+/// An effect that sets a signal it reads doesn't re-trigger itself.
+///
+/// ## Example
+/// ```
+/// use rsdrav::state::{Effect, Signal};
+///
+/// let path = Signal::new(String::from("notes.txt"));
+/// let source = path.clone();
+/// let _effect = Effect::new(move || println!("editing {}", source.get()));
+///
+/// path.set("todo.txt".to_string()); // prints "editing todo.txt"
+/// ```
+#[must_use = "the effect stops when it's dropped"]
+pub struct Effect {
+    _inner: Arc<EffectInner>,
+}
+
+struct EffectInner {
+    run: Mutex<Box<dyn FnMut() + Send>>,
+    /// Subscriptions to the signals read on the last run
+    subscriptions: Mutex<Vec<Box<dyn Any + Send + Sync>>>,
+}
+
+impl Effect {
+    pub fn new(f: impl FnMut() + Send + 'static) -> Self {
+        let inner = Arc::new(EffectInner {
+            run: Mutex::new(Box::new(f)),
+            subscriptions: Mutex::new(Vec::new()),
+        });
+        inner.run();
+        Self { _inner: inner }
+    }
+}
+
+impl EffectInner {
+    fn run(self: &Arc<Self>) {
+        // Already running: this is the effect writing its own dependency
+        let Ok(mut f) = self.run.try_lock() else {
+            return;
+        };
+
+        /// Stops tracking even if the closure panics
+        struct Tracking;
+
+        impl Drop for Tracking {
+            fn drop(&mut self) {
+                TRACKING.with(|stack| stack.borrow_mut().pop());
+            }
+        }
+
+        TRACKING.with(|stack| stack.borrow_mut().push(Vec::new()));
+        let reads = {
+            let _tracking = Tracking;
+            f();
+            TRACKING.with(|stack| std::mem::take(stack.borrow_mut().last_mut().unwrap()))
+        };
+        drop(f);
+
+        let weak = Arc::downgrade(self);
+        let subscriptions = reads
+            .into_iter()
+            .map(|(_, subscribe)| subscribe(weak.clone()))
+            .collect();
+        // Replacing the old subscriptions unsubscribes from them
+        *self.subscriptions.lock().unwrap() = subscriptions;
+    }
+}
+
+/// Note that the running effect (if any) read `signal`
+pub(super) fn track<T: Clone + Send + Sync + 'static>(signal: &Signal<T>, key: usize) {
+    TRACKING.with(|stack| {
+        let mut stack = stack.borrow_mut();
+        let Some(reads) = stack.last_mut() else {
+            return;
+        };
+        if reads.iter().any(|(k, _)| *k == key) {
+            return;
+        }
+        let signal = signal.clone();
+        reads.push((
+            key,
+            Box::new(move |effect: Weak<EffectInner>| {
+                let subscription = signal.subscribe(move |_| {
+                    if let Some(effect) = effect.upgrade() {
+                        effect.run();
+                    }
+                });
+                Box::new(subscription) as Box<dyn Any + Send + Sync>
+            }),
+        ));
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_reruns_on_dependency_changes() {
+        let (a, b, unrelated) = (Signal::new(1), Signal::new(10), Signal::new(0));
+        let runs = Arc::new(AtomicUsize::new(0));
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let effect = {
+            let (a, b, runs, seen) = (a.clone(), b.clone(), runs.clone(), seen.clone());
+            Effect::new(move || {
+                runs.fetch_add(1, Ordering::SeqCst);
+                seen.lock().unwrap().push(a.get() + b.get());
+            })
+        };
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+
+        a.set(2);
+        b.set(20);
+        unrelated.set(5);
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+        assert_eq!(*seen.lock().unwrap(), vec![11, 12, 22]);
+
+        drop(effect);
+        a.set(3);
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_dependencies_follow_the_last_run() {
+        let (flag, a, b) = (Signal::new(true), Signal::new(0), Signal::new(0));
+        let runs = Arc::new(AtomicUsize::new(0));
+        let _effect = {
+            let (flag, a, b, runs) = (flag.clone(), a.clone(), b.clone(), runs.clone());
+            Effect::new(move || {
+                runs.fetch_add(1, Ordering::SeqCst);
+                let _ = if flag.get() { a.get() } else { b.get() };
+            })
+        };
+
+        b.set(1);
+        assert_eq!(runs.load(Ordering::SeqCst), 1);
+        flag.set(false);
+        a.set(1);
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+        b.set(2);
+        assert_eq!(runs.load(Ordering::SeqCst), 3);
+    }
+
+    #[test]
+    fn test_writing_own_dependency_does_not_loop() {
+        let count = Signal::new(0);
+        let _effect = {
+            let count = count.clone();
+            Effect::new(move || {
+                let n = count.get();
+                if n < 100 {
+                    count.set(n + 1);
+                }
+            })
+        };
+        assert_eq!(count.get(), 1);
+        count.set(5);
+        assert_eq!(count.get(), 6);
+    }
+}
@@ -2,13 +2,20 @@
 //!
 //! Core primitives for building reactive UIs:
 //! - `Signal<T>`: Mutable reactive value with auto-notification
-//! - `Derived<T>`: Computed value from signals (cached)
+//! - `Derived<T>`: Computed value from signals or other `Derived`s (cached)
+//! - `Effect`: Side effect re-run when the signals it read change
+//! - `ComputedList<T>`: Filtered/sorted view over a list signal
 //! - `Store`: Global state container for sharing signals
+//! - `batch`: Coalesce notifications from several updates
 
+mod computed_list;
 mod derived;
+mod effect;
 mod signal;
 mod store;
 
-pub use derived::Derived;
-pub use signal::{Signal, Subscription};
+pub use computed_list::ComputedList;
+pub use derived::{Dependency, Derived};
+pub use effect::Effect;
+pub use signal::{batch, Signal, Subscription};
 pub use store::Store;
@@ -1,8 +1,72 @@
+use super::{effect, Derived};
+use std::cell::RefCell;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::{Arc, Mutex, RwLock, Weak};
 
 type SubscriberId = u64;
 
+thread_local! {
+    /// Open `batch` calls on this thread and the notifications they're
+    /// holding back
+    static BATCH: RefCell<Batch> = RefCell::new(Batch::default());
+}
+
+#[derive(Default)]
+struct Batch {
+    depth: usize,
+    /// One flush per changed signal (keyed by its address), in the order
+    /// they first changed
+    pending: Vec<(usize, Box<dyn FnOnce()>)>,
+}
+
+/// Run `f`, holding back signal notifications until it returns
+///
+/// Each signal set inside `f` (on this thread) notifies its subscribers
+/// once afterwards, with its final value, however many times it was set.
+/// Values and versions still change right away, so `get` inside the batch
+/// sees the new value. Nested batches flush when the outermost one ends.
+///
+/// ## Example
+/// ```
+/// use rsdrav::state::{batch, Signal};
+///
+/// let (x, y) = (Signal::new(0), Signal::new(0));
+/// batch(|| {
+///     x.set(1);
+///     y.set(2);
+///     x.set(3); // x's subscribers hear about 3, once
+/// });
+/// ```
+pub fn batch<R>(f: impl FnOnce() -> R) -> R {
+    /// Closes the batch even if `f` panics
+    struct Guard;
+
+    impl Drop for Guard {
+        fn drop(&mut self) {
+            let pending = BATCH.with(|batch| {
+                let mut batch = batch.borrow_mut();
+                batch.depth -= 1;
+                if batch.depth == 0 {
+                    std::mem::take(&mut batch.pending)
+                } else {
+                    Vec::new()
+                }
+            });
+            if std::thread::panicking() {
+                return;
+            }
+            // Outside the batch now, so anything these set fires directly
+            for (_, flush) in pending {
+                flush();
+            }
+        }
+    }
+
+    BATCH.with(|batch| batch.borrow_mut().depth += 1);
+    let _guard = Guard;
+    f()
+}
+
 /// Reactive value that notifies subscribers when it changes
 ///
 /// This is the core building block of reactivity. When the value changes,
@@ -33,10 +97,27 @@ impl<T: Clone + Send + Sync + 'static> Signal<T> {
 
     /// Get current value (clones it out)
     pub fn get(&self) -> T {
+        effect::track(self, self.key());
         // Lock might be held briefly, shouldn't be a problem
         self.inner.value.read().unwrap().clone()
     }
 
+    /// Read the value through a borrow instead of cloning it
+    ///
+    /// Use this for big values (like a `Vec` of rows) when you only need to
+    /// look at them.
+    ///
+    /// The signal's read lock is held while `f` runs, so:
+    /// - don't touch the same signal from inside `f`. Writing (`set`,
+    ///   `update`, ...) deadlocks, and even a nested `get`/`with` can block
+    ///   if another thread is waiting to write
+    /// - other signals are fine
+    /// - keep `f` short; writers on other threads wait for it
+    pub fn with<R>(&self, f: impl FnOnce(&T) -> R) -> R {
+        effect::track(self, self.key());
+        f(&self.inner.value.read().unwrap())
+    }
+
     /// Set new value and notify subscribers
     pub fn set(&self, new_val: T) {
         {
@@ -64,24 +145,106 @@ impl<T: Clone + Send + Sync + 'static> Signal<T> {
         self.notify(&new_val);
     }
 
+    /// Replace the value, returning the old one
+    pub fn replace(&self, new_val: T) -> T {
+        let old = {
+            let mut guard = self.inner.value.write().unwrap();
+            std::mem::replace(&mut *guard, new_val.clone())
+        };
+
+        self.inner.version.fetch_add(1, Ordering::SeqCst);
+        self.notify(&new_val);
+        old
+    }
+
+    /// Compute the new value from the old one under the write lock
+    ///
+    /// Unlike `let v = sig.get(); sig.set(f(v))`, no other thread can sneak a
+    /// write in between the read and the write.
+    pub fn set_with(&self, f: impl FnOnce(&T) -> T) {
+        let new_val = {
+            let mut guard = self.inner.value.write().unwrap();
+            *guard = f(&guard);
+            guard.clone()
+        };
+
+        self.inner.version.fetch_add(1, Ordering::SeqCst);
+        self.notify(&new_val);
+    }
+
+    /// A `Derived` computed from this signal's value
+    ///
+    /// Shorthand for a `Derived` that reads this signal and depends on it:
+    /// it recomputes on the first `get` after the signal changes, and
+    /// reads the value by reference so it isn't cloned for `f`.
+    ///
+    /// ## Example
+    /// ```
+    /// use rsdrav::state::Signal;
+    ///
+    /// let count = Signal::new(2);
+    /// let doubled = count.map(|n| n * 2);
+    /// count.set(5);
+    /// assert_eq!(doubled.get(), 10);
+    /// ```
+    pub fn map<U: Clone + Send + Sync + 'static>(
+        &self,
+        f: impl Fn(&T) -> U + Send + Sync + 'static,
+    ) -> Derived<U> {
+        let source = self.clone();
+        // `f` runs on a snapshot, not under the read lock, so it can read
+        // or even write the source
+        Derived::new(move || f(&source.get())).depends_on(self)
+    }
+
     /// Get current version (for Derived dependency tracking)
     pub fn version(&self) -> u64 {
         self.inner.version.load(Ordering::SeqCst)
     }
 
+    /// Identifies the signal (shared by its clones)
+    fn key(&self) -> usize {
+        Arc::as_ptr(&self.inner) as *const () as usize
+    }
+
     fn notify(&self, val: &T) {
-        let mut subs = self.inner.subscribers.lock().unwrap();
+        let key = self.key();
+        let deferred = BATCH.with(|batch| {
+            let mut batch = batch.borrow_mut();
+            if batch.depth == 0 {
+                return false;
+            }
+            if !batch.pending.iter().any(|(k, _)| *k == key) {
+                let signal = self.clone();
+                batch
+                    .pending
+                    .push((key, Box::new(move || signal.notify_current())));
+            }
+            true
+        });
+        if deferred {
+            return;
+        }
 
         // Clean up dead subscribers while notifying
         // This keeps the subscriber list from growing forever
-        subs.retain(|(_, weak)| {
-            if let Some(callback) = weak.upgrade() {
-                callback(val);
-                true // keep
-            } else {
-                false // drop dead ref
-            }
-        });
+        let callbacks: Vec<_> = {
+            let mut subs = self.inner.subscribers.lock().unwrap();
+            subs.retain(|(_, weak)| weak.strong_count() > 0);
+            subs.iter().filter_map(|(_, weak)| weak.upgrade()).collect()
+        };
+
+        // Called without the lock held, so a callback can subscribe or
+        // unsubscribe (an `Effect` re-running does both)
+        for callback in callbacks {
+            callback(val);
+        }
+    }
+
+    /// Notify with whatever the value is now, without counting as a read
+    fn notify_current(&self) {
+        let value = self.inner.value.read().unwrap().clone();
+        self.notify(&value);
     }
 
     /// Subscribe to changes
@@ -99,6 +262,27 @@ impl<T: Clone + Send + Sync + 'static> Signal<T> {
     }
 }
 
+impl<T: Clone + PartialEq + Send + Sync + 'static> Signal<T> {
+    /// Set a new value, unless it equals the current one
+    ///
+    /// An equal value leaves the version alone and notifies nobody, so
+    /// polling outside state into a signal only re-renders on real
+    /// changes. Returns whether the value changed.
+    pub fn set_if_changed(&self, new_val: T) -> bool {
+        {
+            let mut guard = self.inner.value.write().unwrap();
+            if *guard == new_val {
+                return false;
+            }
+            *guard = new_val.clone();
+        }
+
+        self.inner.version.fetch_add(1, Ordering::SeqCst);
+        self.notify(&new_val);
+        true
+    }
+}
+
 impl<T: Clone + Send + Sync> Clone for Signal<T> {
     fn clone(&self) -> Self {
         Self {
@@ -120,6 +304,95 @@ mod tests {
     use super::*;
     use std::sync::atomic::AtomicBool;
 
+    #[test]
+    fn test_batch_notifies_once() {
+        let sig = Signal::new(0);
+        let other = Signal::new(0);
+        let calls = Arc::new(Mutex::new(Vec::new()));
+
+        let c = calls.clone();
+        let _sub = sig.subscribe(move |v| c.lock().unwrap().push(*v));
+        let c = calls.clone();
+        let _other_sub = other.subscribe(move |v| c.lock().unwrap().push(*v * 100));
+
+        batch(|| {
+            sig.set(1);
+            sig.update(|v| *v += 1);
+            batch(|| {
+                sig.set(3);
+                other.set(1);
+            });
+            // Inner batch ended, but the outer one still holds everything
+            assert!(calls.lock().unwrap().is_empty());
+            assert_eq!(sig.get(), 3);
+        });
+
+        assert_eq!(*calls.lock().unwrap(), vec![3, 100]);
+        assert_eq!(sig.version(), 3);
+
+        sig.set(4);
+        assert_eq!(*calls.lock().unwrap(), vec![3, 100, 4]);
+    }
+
+    #[test]
+    fn test_map_follows_the_signal() {
+        let count = Signal::new(1);
+        let runs = Arc::new(AtomicU64::new(0));
+        let r = runs.clone();
+        let doubled = count.map(move |n| {
+            r.fetch_add(1, Ordering::SeqCst);
+            n * 2
+        });
+
+        assert_eq!(doubled.get(), 2);
+        assert_eq!(doubled.get(), 2);
+        count.set(21);
+        assert_eq!(doubled.get(), 42);
+        assert_eq!(runs.load(Ordering::SeqCst), 2);
+
+        let label = doubled.clone();
+        let text = count.map(move |_| format!("{}!", label.get()));
+        count.set(3);
+        assert_eq!(text.get(), "6!");
+    }
+
+    #[test]
+    fn test_map_can_write_its_source() {
+        let count = Signal::new(50);
+        let source = count.clone();
+        let clamped = count.map(move |n| {
+            if *n > 10 {
+                source.set(10);
+            }
+            (*n).min(10)
+        });
+
+        assert_eq!(clamped.get(), 10);
+        assert_eq!(count.get(), 10);
+    }
+
+    #[test]
+    fn test_set_if_changed_skips_equal_values() {
+        let sig = Signal::new(String::from("idle"));
+        let calls = Arc::new(AtomicU64::new(0));
+        let c = calls.clone();
+        let _sub = sig.subscribe(move |_| {
+            c.fetch_add(1, Ordering::SeqCst);
+        });
+
+        assert!(!sig.set_if_changed("idle".to_string()));
+        assert!(!sig.set_if_changed("idle".to_string()));
+        assert_eq!(calls.load(Ordering::SeqCst), 0);
+        assert_eq!(sig.version(), 0);
+
+        assert!(sig.set_if_changed("busy".to_string()));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        // Plain set still always notifies
+        sig.set("busy".to_string());
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+    }
+
     #[test]
     fn test_signal_basics() {
         let sig = Signal::new(42);
@@ -129,6 +402,59 @@ mod tests {
         assert_eq!(sig.get(), 100);
     }
 
+    #[test]
+    fn test_with_borrows_current_value() {
+        let sig = Signal::new(vec![1, 2, 3]);
+        assert_eq!(sig.with(|v| v.iter().sum::<i32>()), 6);
+
+        sig.update(|v| v.push(4));
+        assert_eq!(sig.with(|v| v.len()), 4);
+        assert_eq!(sig.with(|v| v.clone()), sig.get());
+    }
+
+    #[test]
+    fn test_signal_replace() {
+        let sig = Signal::new(String::from("old"));
+        let prev = sig.replace(String::from("new"));
+
+        assert_eq!(prev, "old");
+        assert_eq!(sig.get(), "new");
+        assert_eq!(sig.version(), 1);
+    }
+
+    #[test]
+    fn test_signal_set_with() {
+        let sig = Signal::new(vec![1, 2]);
+        let seen = Arc::new(Mutex::new(Vec::new()));
+
+        let s = seen.clone();
+        let _sub = sig.subscribe(move |v: &Vec<i32>| s.lock().unwrap().push(v.len()));
+
+        sig.set_with(|old| old.iter().map(|x| x * 10).collect());
+        assert_eq!(sig.get(), vec![10, 20]);
+        assert_eq!(*seen.lock().unwrap(), vec![2]);
+    }
+
+    #[test]
+    fn test_set_with_is_atomic() {
+        let sig = Signal::new(0u64);
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let sig = sig.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..1000 {
+                        sig.set_with(|v| v + 1);
+                    }
+                })
+            })
+            .collect();
+
+        for h in handles {
+            h.join().unwrap();
+        }
+        assert_eq!(sig.get(), 4000);
+    }
+
     #[test]
     fn test_signal_update() {
         let sig = Signal::new(0);
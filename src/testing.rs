@@ -0,0 +1,208 @@
+//! Headless rendering helpers for tests
+//!
+//! These draw a component straight into a `Buffer` the same way the `App`
+//! does each frame - component to view tree, view tree to cells - without
+//! a terminal or backend, so layout can be checked with plain asserts.
+
+use crate::layout::Rect;
+use crate::render::Buffer;
+use crate::state::Store;
+use crate::view::{Component, RenderContext};
+
+/// Render `component` into a fresh `width` x `height` buffer
+///
+/// A zero width or height gives an empty buffer of that size; the
+/// component isn't rendered at all.
+///
+/// ## Example
+/// ```
+/// use rsdrav::prelude::*;
+/// use rsdrav::testing::{buffer_lines, render_to_buffer};
+///
+/// let buffer = render_to_buffer(&Text::new("hello"), 10, 1);
+/// assert_eq!(buffer_lines(&buffer), vec!["hello"]);
+/// ```
+pub fn render_to_buffer(component: &dyn Component, width: u16, height: u16) -> Buffer {
+    let mut buffer = Buffer::new(width, height);
+    if width == 0 || height == 0 {
+        return buffer;
+    }
+
+    let store = Store::new();
+    let area = Rect::new(0, 0, width, height);
+    let view_tree = RenderContext::new(&mut buffer, area, &store).render_child(component);
+    view_tree.render(&mut RenderContext::new(&mut buffer, area, &store));
+    buffer
+}
+
+/// Render `component` once per `(width, height)` in `sizes`
+///
+/// Each size gets its own buffer, in the same order, so one test can
+/// check a layout across a range of terminal sizes.
+///
+/// ## Example
+/// ```
+/// use rsdrav::prelude::*;
+/// use rsdrav::testing::layout_at;
+///
+/// let buffers = layout_at(&Text::new("hi"), &[(20, 5), (80, 24)]);
+/// assert_eq!(buffers[1].width, 80);
+/// ```
+pub fn layout_at(component: &dyn Component, sizes: &[(u16, u16)]) -> Vec<Buffer> {
+    sizes
+        .iter()
+        .map(|&(width, height)| render_to_buffer(component, width, height))
+        .collect()
+}
+
+/// The buffer's rows as strings, with trailing blanks trimmed
+///
+/// Cells nothing was drawn into read as spaces, and wide glyphs appear
+/// once (without their continuation cell).
+pub fn buffer_lines(buffer: &Buffer) -> Vec<String> {
+    (0..buffer.height)
+        .map(|y| {
+            buffer
+                .line(y)
+                .iter()
+                .filter(|cell| !cell.is_continuation())
+                .map(|cell| match cell.ch {
+                    '\0' => ' ',
+                    ch => ch,
+                })
+                .collect::<String>()
+                .trim_end()
+                .to_string()
+        })
+        .collect()
+}
+
+/// Compare a buffer's text against expected rows, describing every
+/// mismatched row if they differ
+///
+/// Trailing spaces don't matter on either side, and rows missing from
+/// `expected` must be blank. Used by `assert_renders!`.
+pub fn frame_diff(buffer: &Buffer, expected: &[&str]) -> Option<String> {
+    let actual = buffer_lines(buffer);
+    let rows = actual.len().max(expected.len());
+    let mut report = String::new();
+
+    for y in 0..rows {
+        let want = expected.get(y).map_or("", |line| line.trim_end());
+        let got = actual.get(y).map_or("<no row>", String::as_str);
+        if want == got {
+            continue;
+        }
+
+        // Point at each differing cell under the actual row
+        let (want_chars, got_chars): (Vec<char>, Vec<char>) =
+            (want.chars().collect(), got.chars().collect());
+        let markers: String = (0..want_chars.len().max(got_chars.len()))
+            .map(|x| {
+                if want_chars.get(x) == got_chars.get(x) {
+                    ' '
+                } else {
+                    '^'
+                }
+            })
+            .collect();
+        report.push_str(&format!(
+            "  row {}:\n    expected: \"{}\"\n    actual:   \"{}\"\n               {}\n",
+            y,
+            want,
+            got,
+            markers.trim_end()
+        ));
+    }
+
+    if report.is_empty() {
+        None
+    } else {
+        Some(format!(
+            "rendered {}x{} frame doesn't match:\n{}",
+            buffer.width, buffer.height, report
+        ))
+    }
+}
+
+/// Render a component headlessly and check its text against expected rows
+///
+/// Trailing whitespace in the expected rows is ignored, and rows left out
+/// at the bottom must render blank. On mismatch it panics with each
+/// differing row and a `^` under every differing cell.
+///
+/// ## Example
+/// ```
+/// use rsdrav::assert_renders;
+/// use rsdrav::prelude::*;
+///
+/// let stack = VStack::new().push(Text::new("one")).push(Text::new("two"));
+/// assert_renders!(stack, 6, 2, &["one", "two"]);
+/// ```
+#[macro_export]
+macro_rules! assert_renders {
+    ($component:expr, $width:expr, $height:expr, $expected:expr $(,)?) => {{
+        let buffer = $crate::testing::render_to_buffer(&$component, $width, $height);
+        if let Some(diff) = $crate::testing::frame_diff(&buffer, $expected) {
+            panic!("{}", diff);
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::view::{Text, VStack};
+
+    #[test]
+    fn test_vstack_at_several_sizes() {
+        let stack = VStack::new()
+            .push(Text::new("top"))
+            .push(Text::new("bottom"));
+
+        let buffers = layout_at(&stack, &[(40, 10), (80, 24), (0, 0), (10, 0)]);
+        assert_eq!(buffers.len(), 4);
+
+        // Children split the height evenly
+        let small = buffer_lines(&buffers[0]);
+        assert_eq!((buffers[0].width, buffers[0].height), (40, 10));
+        assert_eq!(small[0], "top");
+        assert_eq!(small[5], "bottom");
+
+        let large = buffer_lines(&buffers[1]);
+        assert_eq!((buffers[1].width, buffers[1].height), (80, 24));
+        assert_eq!(large[0], "top");
+        assert_eq!(large[12], "bottom");
+
+        // Zero sizes come back empty instead of panicking
+        assert!(buffer_lines(&buffers[2]).is_empty());
+        assert_eq!(buffers[3].width, 10);
+        assert!(buffer_lines(&buffers[3]).is_empty());
+    }
+
+    #[test]
+    fn test_assert_renders_matching() {
+        let stack = VStack::new().push(Text::new("ab")).push(Text::new("cd"));
+        // Trailing spaces in the art are fine, missing rows must be blank
+        assert_renders!(stack, 5, 4, &["ab   ", "", "cd"]);
+    }
+
+    #[test]
+    #[should_panic(expected = "rendered 5x1 frame doesn't match")]
+    fn test_assert_renders_mismatch_panics() {
+        assert_renders!(Text::new("hello"), 5, 1, &["help"]);
+    }
+
+    #[test]
+    fn test_frame_diff_marks_differing_cells() {
+        let buffer = render_to_buffer(&Text::new("hello"), 6, 2);
+        let diff = frame_diff(&buffer, &["help", "x"]).unwrap();
+        assert_eq!(
+            diff,
+            "rendered 6x2 frame doesn't match:\n\
+             \x20 row 0:\n    expected: \"help\"\n    actual:   \"hello\"\n                  ^^\n\
+             \x20 row 1:\n    expected: \"x\"\n    actual:   \"\"\n               ^\n"
+        );
+        assert_eq!(frame_diff(&buffer, &["hello"]), None);
+    }
+}
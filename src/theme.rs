@@ -13,6 +13,44 @@ impl Color {
         Self { r, g, b }
     }
 
+    /// Shade of gray - `gray(30)` is `rgb(30, 30, 30)`
+    pub const fn gray(level: u8) -> Self {
+        Self::rgb(level, level, level)
+    }
+
+    /// `#RRGGBB` form
+    pub fn to_hex(&self) -> String {
+        format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+
+    /// Parse `#RRGGBB` (the `#` is optional)
+    pub fn from_hex(hex: &str) -> Option<Self> {
+        let hex = hex.strip_prefix('#').unwrap_or(hex);
+        if hex.len() != 6 || !hex.is_ascii() {
+            return None;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        Some(Self::rgb(channel(0)?, channel(2)?, channel(4)?))
+    }
+
+    /// Parse `#RRGGBB` or the name of one of the constants below
+    /// (`"cyan"`, `"Gray"`, ...; case doesn't matter)
+    pub fn parse(s: &str) -> Option<Self> {
+        let named = match s.to_ascii_lowercase().as_str() {
+            "black" => Self::BLACK,
+            "white" => Self::WHITE,
+            "red" => Self::RED,
+            "green" => Self::GREEN,
+            "blue" => Self::BLUE,
+            "yellow" => Self::YELLOW,
+            "cyan" => Self::CYAN,
+            "magenta" => Self::MAGENTA,
+            "gray" | "grey" => Self::GRAY,
+            _ => return Self::from_hex(s),
+        };
+        Some(named)
+    }
+
     // Some common colors - makes life easier
     pub const BLACK: Self = Self::rgb(0, 0, 0);
     pub const WHITE: Self = Self::rgb(255, 255, 255);
@@ -33,7 +71,10 @@ bitflags::bitflags! {
         const ITALIC = 0b0000_0100;
         const UNDERLINE = 0b0000_1000;
         const BLINK = 0b0001_0000;
+        /// Inverse video: swap fg and bg (SGR 7)
         const REVERSE = 0b0010_0000;
+        /// Same as `REVERSE`
+        const REVERSED = Self::REVERSE.bits();
         const HIDDEN = 0b0100_0000;
         const STRIKETHROUGH = 0b1000_0000;
     }
@@ -52,6 +93,11 @@ impl Style {
         Self::default()
     }
 
+    /// Style with both colors set
+    pub fn fg_bg(fg: Color, bg: Color) -> Self {
+        Self::new().fg(fg).bg(bg)
+    }
+
     pub fn fg(mut self, color: Color) -> Self {
         self.fg = Some(color);
         self
@@ -71,15 +117,192 @@ impl Style {
         self.modifiers &= !modifier;
         self
     }
+
+    // Shortcuts for the common modifiers
+    pub fn bold(self) -> Self {
+        self.add_modifier(Modifier::BOLD)
+    }
+
+    pub fn dim(self) -> Self {
+        self.add_modifier(Modifier::DIM)
+    }
+
+    pub fn italic(self) -> Self {
+        self.add_modifier(Modifier::ITALIC)
+    }
+
+    pub fn underline(self) -> Self {
+        self.add_modifier(Modifier::UNDERLINE)
+    }
+
+    /// Inverse video - a highlight that works whatever the theme's colors
+    pub fn reversed(self) -> Self {
+        self.add_modifier(Modifier::REVERSED)
+    }
+
+    /// Layer another style on top - its colors win when set, modifiers combine
+    pub fn patch(mut self, other: Style) -> Self {
+        if other.fg.is_some() {
+            self.fg = other.fg;
+        }
+        if other.bg.is_some() {
+            self.bg = other.bg;
+        }
+        self.modifiers |= other.modifiers;
+        self
+    }
+}
+
+/// A bare color means a foreground color
+impl From<Color> for Style {
+    fn from(color: Color) -> Self {
+        Self::new().fg(color)
+    }
+}
+
+/// Named styles shared across widgets
+///
+/// Widgets look these up from the `RenderContext` instead of hardcoding
+/// colors, so an app can restyle everything in one place.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Theme {
+    primary: Style,
+    accent: Style,
+    background: Style,
+    error: Style,
+    focus_indicator: Style,
+    stripe: Style,
+}
+
+impl Theme {
+    pub fn new() -> Self {
+        Self {
+            primary: Style::new().fg(Color::WHITE),
+            accent: Style::new().fg(Color::CYAN),
+            background: Style::new().bg(Color::gray(20)),
+            error: Style::new().fg(Color::RED).add_modifier(Modifier::BOLD),
+            focus_indicator: Style::new().fg(Color::YELLOW).add_modifier(Modifier::BOLD),
+            stripe: Style::new().bg(Color::gray(30)),
+        }
+    }
+
+    pub fn primary(&self) -> Style {
+        self.primary
+    }
+
+    pub fn accent(&self) -> Style {
+        self.accent
+    }
+
+    pub fn background(&self) -> Style {
+        self.background
+    }
+
+    pub fn error(&self) -> Style {
+        self.error
+    }
+
+    /// Style used to highlight whichever component has focus
+    pub fn focus_indicator(&self) -> Style {
+        self.focus_indicator
+    }
+
+    /// Background for every other row in tables
+    pub fn stripe(&self) -> Style {
+        self.stripe
+    }
+
+    pub fn with_primary(mut self, style: Style) -> Self {
+        self.primary = style;
+        self
+    }
+
+    pub fn with_accent(mut self, style: Style) -> Self {
+        self.accent = style;
+        self
+    }
+
+    pub fn with_background(mut self, style: Style) -> Self {
+        self.background = style;
+        self
+    }
+
+    pub fn with_error(mut self, style: Style) -> Self {
+        self.error = style;
+        self
+    }
+
+    pub fn with_focus_indicator(mut self, style: Style) -> Self {
+        self.focus_indicator = style;
+        self
+    }
+
+    pub fn with_stripe(mut self, style: Style) -> Self {
+        self.stripe = style;
+        self
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Load a theme from a TOML file mapping style names to colors
+///
+/// Keys are the same as a config's `theme` section (`primary`, `accent`,
+/// `background`, `error`, `focus`, `stripe`); colors are `#RRGGBB` or a
+/// name like `"cyan"`. Anything left out keeps the default.
+///
+/// ```toml
+/// primary = "#E0E0E0"
+/// accent = "magenta"
+/// background = "#1E1E2E"
+/// ```
+#[cfg(feature = "toml")]
+pub fn load_from_toml(path: impl AsRef<std::path::Path>) -> crate::error::Result<Theme> {
+    let source = std::fs::read_to_string(path)?;
+    crate::config::Config::theme_from_toml(&source)
 }
 
 // Make Color animatable for smooth color transitions
+//
+// Colors are always truecolor, so this is a straight blend per channel.
+// Easings that overshoot (t outside 0..=1) clamp at the channel limits.
 impl crate::animation::Animatable for Color {
     fn lerp(&self, other: &Self, t: f32) -> Self {
-        let r = (self.r as f32 + (other.r as f32 - self.r as f32) * t) as u8;
-        let g = (self.g as f32 + (other.g as f32 - self.g as f32) * t) as u8;
-        let b = (self.b as f32 + (other.b as f32 - self.b as f32) * t) as u8;
-        Color { r, g, b }
+        let channel = |from: u8, to: u8| -> u8 {
+            (from as f32 + (to as f32 - from as f32) * t)
+                .round()
+                .clamp(0.0, 255.0) as u8
+        };
+        Color {
+            r: channel(self.r, other.r),
+            g: channel(self.g, other.g),
+            b: channel(self.b, other.b),
+        }
+    }
+}
+
+// Colors set on both ends blend; anything else (a color appearing or going
+// away, modifiers) switches over halfway through
+impl crate::animation::Animatable for Style {
+    fn lerp(&self, other: &Self, t: f32) -> Self {
+        let color = |from: Option<Color>, to: Option<Color>| match (from, to) {
+            (Some(from), Some(to)) => Some(from.lerp(&to, t)),
+            _ if t < 0.5 => from,
+            _ => to,
+        };
+        Style {
+            fg: color(self.fg, other.fg),
+            bg: color(self.bg, other.bg),
+            modifiers: if t < 0.5 {
+                self.modifiers
+            } else {
+                other.modifiers
+            },
+        }
     }
 }
 
@@ -88,6 +311,33 @@ mod tests {
     use super::*;
     use crate::animation::Animatable;
 
+    #[test]
+    fn test_style_from_color() {
+        let style = Style::from(Color::RED);
+        assert_eq!(style.fg, Some(Color::RED));
+        assert_eq!(style.bg, None);
+        assert!(style.modifiers.is_empty());
+
+        let style: Style = Color::gray(30).into();
+        assert_eq!(style.fg, Some(Color::rgb(30, 30, 30)));
+    }
+
+    #[test]
+    fn test_style_shortcuts() {
+        let style = Style::fg_bg(Color::WHITE, Color::BLUE).bold().underline();
+        assert_eq!(style.fg, Some(Color::WHITE));
+        assert_eq!(style.bg, Some(Color::BLUE));
+        assert_eq!(style.modifiers, Modifier::BOLD | Modifier::UNDERLINE);
+    }
+
+    #[test]
+    fn test_color_parse() {
+        assert_eq!(Color::parse("#FF8800"), Some(Color::rgb(255, 136, 0)));
+        assert_eq!(Color::parse("Cyan"), Some(Color::CYAN));
+        assert_eq!(Color::parse("grey"), Some(Color::GRAY));
+        assert_eq!(Color::parse("orange"), None);
+    }
+
     #[test]
     fn test_color_interpolation() {
         let black = Color::BLACK;
@@ -98,4 +348,45 @@ mod tests {
         assert!(mid.g > 120 && mid.g < 135);
         assert!(mid.b > 120 && mid.b < 135);
     }
+
+    #[test]
+    fn test_color_tween_midpoint() {
+        use crate::animation::Tween;
+        use std::time::Duration;
+
+        let mut tween = Tween::new(Color::RED, Color::BLUE, Duration::from_millis(100));
+        tween.update(Duration::from_millis(50));
+        let purple = tween.value();
+        assert!(purple.r.abs_diff(128) <= 2 && purple.b.abs_diff(128) <= 2);
+        assert_eq!(purple.g, 0);
+
+        // Overshooting easings clamp instead of wrapping
+        assert_eq!(Color::gray(200).lerp(&Color::WHITE, 2.0), Color::WHITE);
+    }
+
+    #[test]
+    fn test_style_tween() {
+        let from = Style::new().fg(Color::BLACK);
+        let to = Style::new().fg(Color::WHITE).bg(Color::BLUE).bold();
+
+        let early = from.lerp(&to, 0.25);
+        assert_eq!(early.fg, Some(Color::gray(64)));
+        assert_eq!(early.bg, None);
+        assert!(early.modifiers.is_empty());
+
+        let late = from.lerp(&to, 0.75);
+        assert_eq!(late.fg, Some(Color::gray(191)));
+        assert_eq!(late.bg, Some(Color::BLUE));
+        assert_eq!(late.modifiers, Modifier::BOLD);
+    }
+
+    #[test]
+    fn test_style_patch() {
+        let base = Style::new().fg(Color::WHITE).bg(Color::BLACK);
+        let patched = base.patch(Style::new().fg(Color::YELLOW).add_modifier(Modifier::BOLD));
+
+        assert_eq!(patched.fg, Some(Color::YELLOW));
+        assert_eq!(patched.bg, Some(Color::BLACK)); // Untouched
+        assert!(patched.modifiers.contains(Modifier::BOLD));
+    }
 }
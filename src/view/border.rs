@@ -0,0 +1,70 @@
+//! Border glyph sets for boxed views
+
+/// Characters used to draw a box border
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BorderSet {
+    pub top_left: char,
+    pub top_right: char,
+    pub bottom_left: char,
+    pub bottom_right: char,
+    pub horizontal: char,
+    pub vertical: char,
+}
+
+impl BorderSet {
+    /// Thin single lines: ┌─┐
+    pub const PLAIN: Self = Self {
+        top_left: '┌',
+        top_right: '┐',
+        bottom_left: '└',
+        bottom_right: '┘',
+        horizontal: '─',
+        vertical: '│',
+    };
+
+    /// Rounded corners: ╭─╮
+    pub const ROUNDED: Self = Self {
+        top_left: '╭',
+        top_right: '╮',
+        bottom_left: '╰',
+        bottom_right: '╯',
+        horizontal: '─',
+        vertical: '│',
+    };
+
+    /// Double lines: ╔═╗
+    pub const DOUBLE: Self = Self {
+        top_left: '╔',
+        top_right: '╗',
+        bottom_left: '╚',
+        bottom_right: '╝',
+        horizontal: '═',
+        vertical: '║',
+    };
+
+    /// Plain ASCII: +-+
+    pub const ASCII: Self = Self {
+        top_left: '+',
+        top_right: '+',
+        bottom_left: '+',
+        bottom_right: '+',
+        horizontal: '-',
+        vertical: '|',
+    };
+
+    /// Heavy lines: ┏━┓
+    pub const THICK: Self = Self {
+        top_left: '┏',
+        top_right: '┓',
+        bottom_left: '┗',
+        bottom_right: '┛',
+        horizontal: '━',
+        vertical: '┃',
+    };
+}
+
+impl Default for BorderSet {
+    fn default() -> Self {
+        Self::PLAIN
+    }
+}
@@ -5,6 +5,7 @@
 use super::{EventContext, MountContext, RenderContext, UpdateContext, ViewNode};
 use crate::error::Result;
 use crate::event::{Event, EventResult};
+use crate::focus::ComponentId;
 
 /// Core component trait - the heart of the reactive UI system
 ///
@@ -58,6 +59,12 @@ pub trait Component: Send {
     ///
     /// Return `true` if this component needs to re-render,
     /// `false` to skip rendering this frame.
+    ///
+    /// The app asks the root once per frame. Resizes, focus moves and
+    /// `EventContext::request_redraw` render the frame regardless.
+    /// Containers pass it on to all their children (so animated widgets
+    /// anywhere in the tree see every frame's `delta`) and still return
+    /// `true`.
     fn update(&mut self, _ctx: &mut UpdateContext) -> bool {
         // Default: always re-render on updates
         true
@@ -72,6 +79,79 @@ pub trait Component: Send {
     fn handle_event(&mut self, _event: &Event, _ctx: &mut EventContext) -> EventResult {
         EventResult::Ignored
     }
+
+    /// Focus id registered with the `FocusManager`, if this component
+    /// can take focus
+    ///
+    /// The render path uses this to apply the theme's focus indicator.
+    fn focus_id(&self) -> Option<ComponentId> {
+        None
+    }
+
+    /// Called when the `FocusManager` moves focus onto this component
+    ///
+    /// Only components that return a `focus_id` get this.
+    fn on_focus(&mut self, _ctx: &mut EventContext) {}
+
+    /// Called when focus moves away from this component
+    fn on_blur(&mut self, _ctx: &mut EventContext) {}
+
+    /// Deliver a focus change from `previous` to `current`
+    ///
+    /// The app calls this on the root whenever the focused id changes.
+    /// Containers override it to forward the change to their children;
+    /// leaf components can rely on the default, which matches by id so
+    /// two widgets of the same type are told apart.
+    fn focus_changed(
+        &mut self,
+        previous: Option<ComponentId>,
+        current: Option<ComponentId>,
+        ctx: &mut EventContext,
+    ) {
+        notify_focus(self, previous, current, ctx);
+    }
+
+    /// Direct children, for code that walks the component tree
+    ///
+    /// Containers override this; leaves keep the empty default. Children
+    /// built on the fly during `render` aren't components and don't show
+    /// up here.
+    fn children(&self) -> Vec<&dyn Component> {
+        Vec::new()
+    }
+}
+
+/// A component that ends with a value the user picked, e.g. a yes/no
+/// confirmation
+///
+/// Shown as a modal (see `App::prompt_dialog`), the dialog records its
+/// result and calls `EventContext::close_modal` once the user has chosen.
+pub trait Dialog: Component {
+    type Output: Send + 'static;
+
+    /// The user's choice, once made - taken at most once
+    fn take_result(&mut self) -> Option<Self::Output>;
+}
+
+/// Call `on_blur`/`on_focus` on a component if the change concerns it
+///
+/// Containers with their own focus id use this before forwarding to
+/// their children.
+pub(crate) fn notify_focus<C: Component + ?Sized>(
+    component: &mut C,
+    previous: Option<ComponentId>,
+    current: Option<ComponentId>,
+    ctx: &mut EventContext,
+) {
+    let Some(id) = component.focus_id() else {
+        return;
+    };
+    if previous == Some(id) && current != Some(id) {
+        component.on_blur(ctx);
+    }
+    if current == Some(id) && previous != Some(id) {
+        component.on_focus(ctx);
+    }
 }
 
 /// A boxed component for dynamic dispatch
@@ -121,6 +201,107 @@ impl<C: Component> IntoComponent for C {
     }
 }
 
+/// Render-only component made from a closure - see `view_fn`
+pub struct ViewFn<F> {
+    render: F,
+}
+
+/// Turn a render closure into a component
+///
+/// For small, stateless pieces of UI that don't deserve a struct. Events
+/// are ignored; use `stateful_view` if the component needs to react.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let count = Signal::new(0);
+/// let label = view_fn(move |_ctx| ViewNode::text(format!("Count: {}", count.get())));
+/// ```
+pub fn view_fn<F>(render: F) -> ViewFn<F>
+where
+    F: Fn(&RenderContext) -> ViewNode + Send,
+{
+    ViewFn { render }
+}
+
+impl<F> Component for ViewFn<F>
+where
+    F: Fn(&RenderContext) -> ViewNode + Send,
+{
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        (self.render)(ctx)
+    }
+}
+
+type StateRender<S> = Box<dyn Fn(&S, &RenderContext) -> ViewNode + Send>;
+type StateHandler<S> = Box<dyn FnMut(&mut S, &Event, &mut EventContext) -> EventResult + Send>;
+
+/// Component made from a piece of state and closures - see `stateful_view`
+pub struct StatefulView<S> {
+    state: S,
+    render: StateRender<S>,
+    on_event: Option<StateHandler<S>>,
+}
+
+/// Build a component from state plus a render closure
+///
+/// Add event handling with `on_event`.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let counter = stateful_view(0, |count, _ctx| ViewNode::text(format!("{}", count)))
+///     .on_event(|count, event, _ctx| match event {
+///         Event::Key(key) if key.code == KeyCode::Char('+') => {
+///             *count += 1;
+///             EventResult::Handled
+///         }
+///         _ => EventResult::Ignored,
+///     });
+/// ```
+pub fn stateful_view<S, F>(state: S, render: F) -> StatefulView<S>
+where
+    S: Send + 'static,
+    F: Fn(&S, &RenderContext) -> ViewNode + Send + 'static,
+{
+    StatefulView {
+        state,
+        render: Box::new(render),
+        on_event: None,
+    }
+}
+
+impl<S: Send + 'static> StatefulView<S> {
+    /// Handle events with access to the state
+    pub fn on_event<F>(mut self, handler: F) -> Self
+    where
+        F: FnMut(&mut S, &Event, &mut EventContext) -> EventResult + Send + 'static,
+    {
+        self.on_event = Some(Box::new(handler));
+        self
+    }
+
+    /// Current state
+    pub fn state(&self) -> &S {
+        &self.state
+    }
+}
+
+impl<S: Send + 'static> Component for StatefulView<S> {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        (self.render)(&self.state, ctx)
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut EventContext) -> EventResult {
+        match self.on_event {
+            Some(ref mut handler) => handler(&mut self.state, event, ctx),
+            None => EventResult::Ignored,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -193,4 +374,66 @@ mod tests {
             _ => panic!("Expected text node"),
         }
     }
+
+    #[test]
+    fn test_view_fn_renders() {
+        use crate::layout::Rect;
+        use crate::render::Buffer;
+        use crate::state::Store;
+
+        let value = Signal::new(7);
+        let v = value.clone();
+        let mut comp = view_fn(move |ctx| {
+            ViewNode::text(format!(
+                "{}x{} {}",
+                ctx.area.width,
+                ctx.area.height,
+                v.get()
+            ))
+        });
+
+        let mut buffer = Buffer::new(40, 10);
+        let mut store = Store::new();
+        let area = Rect::new(0, 0, 40, 10);
+        match comp.render(&RenderContext::new(&mut buffer, area, &store)) {
+            ViewNode::Text { content, .. } => assert_eq!(content, "40x10 7"),
+            _ => panic!("Expected text node"),
+        }
+
+        // Stateless - events fall through
+        let mut ctx = EventContext {
+            store: &mut store,
+            area,
+        };
+        let event = Event::Resize(1, 1);
+        assert_eq!(comp.handle_event(&event, &mut ctx), EventResult::Ignored);
+    }
+
+    #[test]
+    fn test_stateful_view() {
+        use crate::event::{KeyCode, KeyEvent, KeyModifiers};
+        use crate::layout::Rect;
+        use crate::state::Store;
+
+        let mut comp =
+            stateful_view(0, |n, _ctx| ViewNode::text(n.to_string())).on_event(|n, event, _ctx| {
+                match event {
+                    Event::Key(_) => {
+                        *n += 1;
+                        EventResult::Handled
+                    }
+                    _ => EventResult::Ignored,
+                }
+            });
+
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 10, 1),
+        };
+        let key = Event::Key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::empty()));
+        comp.handle_event(&key, &mut ctx);
+        comp.handle_event(&key, &mut ctx);
+        assert_eq!(*comp.state(), 2);
+    }
 }
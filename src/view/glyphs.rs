@@ -0,0 +1,158 @@
+//! Unicode vs ASCII drawing characters
+
+use super::BorderSet;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
+
+/// Which characters widgets may draw with
+///
+/// `Ascii` is for terminals, fonts and pipes that mangle box drawing and
+/// other non-ASCII symbols. Widgets ask `RenderContext::glyphs()` instead
+/// of hardcoding Unicode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RenderMode {
+    #[default]
+    Unicode,
+    Ascii,
+}
+
+impl RenderMode {
+    /// Guess from the locale environment (`LC_ALL`, `LC_CTYPE`, `LANG`)
+    ///
+    /// Anything that doesn't mention UTF-8 gets `Ascii`.
+    pub fn detect() -> Self {
+        let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+            .iter()
+            .find_map(|var| std::env::var(var).ok().filter(|v| !v.is_empty()))
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        if locale.contains("utf-8") || locale.contains("utf8") {
+            RenderMode::Unicode
+        } else {
+            RenderMode::Ascii
+        }
+    }
+
+    /// Symbols for this mode
+    pub fn glyphs(self) -> &'static Glyphs {
+        match self {
+            RenderMode::Unicode => &Glyphs::UNICODE,
+            RenderMode::Ascii => &Glyphs::ASCII,
+        }
+    }
+
+    /// The border set to actually draw - every set is `+-|` in ASCII
+    pub fn border(self, border: BorderSet) -> BorderSet {
+        match self {
+            RenderMode::Unicode => border,
+            RenderMode::Ascii => BorderSet::ASCII,
+        }
+    }
+}
+
+/// Symbols widgets draw with, beyond borders
+///
+/// Single-char glyphs keep the same width in both modes; the `&str` ones
+/// (`ellipsis`, `scroll`) don't, so callers must measure them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyphs {
+    pub ellipsis: &'static str,
+    /// Scroll position marker, e.g. `[↕ 1-10 of 50]`
+    pub scroll: &'static str,
+    pub horizontal: char,
+    pub vertical: char,
+    /// Where a horizontal and vertical rule meet (table separators)
+    pub cross: char,
+    pub arrow_up: char,
+    pub arrow_down: char,
+    pub arrow_left: char,
+    pub arrow_right: char,
+    /// Marks a collapsed dropdown
+    pub dropdown: char,
+    /// Marks the active row in pickers
+    pub pointer: char,
+    pub bar_filled: char,
+    pub bar_empty: char,
+    pub bullet: char,
+}
+
+impl Glyphs {
+    pub const UNICODE: Self = Self {
+        ellipsis: "…",
+        scroll: "↕",
+        horizontal: '─',
+        vertical: '│',
+        cross: '┼',
+        arrow_up: '▲',
+        arrow_down: '▼',
+        arrow_left: '◀',
+        arrow_right: '▶',
+        dropdown: '▾',
+        pointer: '▸',
+        bar_filled: '█',
+        bar_empty: '░',
+        bullet: '•',
+    };
+
+    pub const ASCII: Self = Self {
+        ellipsis: "...",
+        scroll: "^v",
+        horizontal: '-',
+        vertical: '|',
+        cross: '+',
+        arrow_up: '^',
+        arrow_down: 'v',
+        arrow_left: '<',
+        arrow_right: '>',
+        dropdown: 'v',
+        pointer: '>',
+        bar_filled: '#',
+        bar_empty: '.',
+        bullet: '*',
+    };
+
+    /// Cut `text` to `width` columns, ending in the ellipsis if it was cut
+    ///
+    /// Widths are display widths, so a wide (e.g. CJK) character counts as
+    /// two; one that would straddle the limit is left out, making the result
+    /// a column short. Too narrow for the ellipsis itself (ASCII's is three
+    /// wide) means a plain cut.
+    pub fn truncate(&self, text: &str, width: usize) -> String {
+        if text.width() <= width {
+            return text.to_string();
+        }
+        let marker = self.ellipsis.width();
+        let (budget, ellipsis) = if width <= marker {
+            (width, "")
+        } else {
+            (width - marker, self.ellipsis)
+        };
+
+        let mut out = String::new();
+        let mut used = 0;
+        for ch in text.chars() {
+            let w = ch.width().unwrap_or(0);
+            if used + w > budget {
+                break;
+            }
+            used += w;
+            out.push(ch);
+        }
+        out.push_str(ellipsis);
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_widths() {
+        assert_eq!(Glyphs::UNICODE.truncate("abcdef", 4), "abc…");
+        assert_eq!(Glyphs::ASCII.truncate("abcdef", 5), "ab...");
+        assert_eq!(Glyphs::ASCII.truncate("abcdef", 2), "ab");
+        assert_eq!(Glyphs::ASCII.truncate("abc", 3), "abc");
+        assert_eq!(Glyphs::UNICODE.truncate("太郎です", 6), "太郎…");
+    }
+}
@@ -25,15 +25,22 @@
 
 use crate::error::Result;
 use crate::event::{Event, EventResult};
-use crate::layout::Rect;
+use crate::focus::ComponentId;
+use crate::layout::{LayoutCache, LayoutKey, Length, Rect};
 use crate::render::{Buffer, Cell};
 use crate::state::Store;
-use crate::theme::Style;
+use crate::theme::{Color, Style, Theme};
+use std::cell::RefCell;
+use std::time::Instant;
 
+pub mod border;
 pub mod component;
+pub mod glyphs;
 pub mod widgets;
 
+pub use border::BorderSet;
 pub use component::*;
+pub use glyphs::{Glyphs, RenderMode};
 pub use widgets::*;
 
 /// Context provided during rendering
@@ -42,6 +49,60 @@ pub struct RenderContext<'a> {
     pub area: Rect,
     pub style: Style,
     pub store: &'a Store,
+    /// Component that currently has focus (from the FocusManager)
+    pub focused: Option<ComponentId>,
+    pub theme: Theme,
+    /// Layout cache shared across frames (set by the App)
+    pub layout_cache: Option<&'a RefCell<LayoutCache>>,
+    /// Unicode or ASCII-only drawing
+    pub mode: RenderMode,
+    /// Where focusable components ended up, collected while drawing (set
+    /// by the App so it can hit-test them)
+    pub focus_rects: Option<&'a RefCell<Vec<(ComponentId, Rect)>>>,
+    /// Time limit for this frame; low-priority subtrees past it are skipped
+    pub budget: Option<&'a RenderBudget>,
+    /// What's on screen now, shown in place of skipped subtrees
+    pub previous_frame: Option<&'a Buffer>,
+    /// Writes through `set` and `write_str` outside this are dropped
+    ///
+    /// Starts as the whole area and narrows to each child's area (within
+    /// the parent's clip) in `sub_context`, so the clip is pushed on the
+    /// way down and popped when the child's context goes away.
+    pub clip: Rect,
+}
+
+/// Deadline for drawing a frame, and a count of what missed it
+///
+/// Subtrees marked with `ViewNode::low_priority` check it before drawing;
+/// once the deadline has passed they keep last frame's cells instead and
+/// count themselves as deferred, so the app knows to render again.
+#[derive(Debug)]
+pub struct RenderBudget {
+    deadline: Instant,
+    deferred: std::cell::Cell<usize>,
+}
+
+impl RenderBudget {
+    pub fn new(deadline: Instant) -> Self {
+        Self {
+            deadline,
+            deferred: std::cell::Cell::new(0),
+        }
+    }
+
+    /// Check if the deadline has passed
+    pub fn is_exceeded(&self) -> bool {
+        Instant::now() >= self.deadline
+    }
+
+    /// Number of subtrees skipped so far
+    pub fn deferred(&self) -> usize {
+        self.deferred.get()
+    }
+
+    fn defer(&self) {
+        self.deferred.set(self.deferred.get() + 1);
+    }
 }
 
 impl<'a> RenderContext<'a> {
@@ -51,15 +112,126 @@ impl<'a> RenderContext<'a> {
             area,
             style: Style::default(),
             store,
+            focused: None,
+            theme: Theme::default(),
+            layout_cache: None,
+            mode: RenderMode::Unicode,
+            focus_rects: None,
+            budget: None,
+            previous_frame: None,
+            clip: area,
+        }
+    }
+
+    /// Drawing symbols for the current render mode
+    pub fn glyphs(&self) -> &'static Glyphs {
+        self.mode.glyphs()
+    }
+
+    /// Borrow this context for rendering into a sub-area
+    pub fn sub_context(&mut self, area: Rect) -> RenderContext<'_> {
+        RenderContext {
+            buffer: self.buffer,
+            area,
+            style: self.style,
+            store: self.store,
+            focused: self.focused,
+            theme: self.theme,
+            layout_cache: self.layout_cache,
+            mode: self.mode,
+            focus_rects: self.focus_rects,
+            budget: self.budget,
+            previous_frame: self.previous_frame,
+            clip: self
+                .clip
+                .intersect(&area)
+                .unwrap_or(Rect::new(area.x, area.y, 0, 0)),
+        }
+    }
+
+    /// Check if a component id is the focused one
+    pub fn is_focused(&self, id: Option<ComponentId>) -> bool {
+        id.is_some() && id == self.focused
+    }
+
+    /// Render a child component, applying the theme's focus indicator
+    /// if it's the focused one
+    ///
+    /// Containers should use this instead of calling `render` directly
+    /// so focus shows up consistently across widgets.
+    /// When focus rects are being collected, focusable children are also
+    /// wrapped in `ViewNode::Focusable` so their final area gets recorded.
+    pub fn render_child(&self, child: &dyn Component) -> ViewNode {
+        let node = child.render(self);
+        let node = if self.is_focused(child.focus_id()) {
+            node.with_focus_style(self.theme.focus_indicator())
+        } else {
+            node
+        };
+        match child.focus_id() {
+            Some(id) if self.focus_rects.is_some() => ViewNode::Focusable {
+                id,
+                child: Box::new(node),
+            },
+            _ => node,
+        }
+    }
+
+    /// Compute a component's child rects, reusing the cached result when
+    /// the same component was laid out in the same area before
+    ///
+    /// Without a cache (e.g. in tests) this just calls `compute`. The cache
+    /// isn't borrowed while `compute` runs, so it can lay out children that
+    /// use `cached_layout` themselves. Entries keyed by a component id are
+    /// dropped by the app whenever the tree reports a change.
+    pub fn cached_layout(
+        &self,
+        key: impl Into<LayoutKey>,
+        area: Rect,
+        compute: impl FnOnce() -> Vec<Rect>,
+    ) -> Vec<Rect> {
+        let Some(cache) = self.layout_cache else {
+            return compute();
+        };
+        let key = key.into();
+        let cached = cache.borrow_mut().get(key, area);
+        if let Some(rects) = cached {
+            return rects;
+        }
+
+        let rects = compute();
+        cache.borrow_mut().insert(key, area, rects.clone());
+        rects
+    }
+
+    /// Set a cell, unless it's outside the clip
+    ///
+    /// A wide glyph whose right half would fall outside becomes a blank.
+    pub fn set(&mut self, x: u16, y: u16, cell: Cell) {
+        if !self.clip.contains(x, y) {
+            return;
+        }
+        if cell.width() == 2 && !self.clip.contains(x.saturating_add(1), y) {
+            self.buffer.set(x, y, Cell::with_style(' ', cell.style));
+        } else {
+            self.buffer.set(x, y, cell);
         }
     }
 
     /// Helper to write a string at position with current style
+    ///
+    /// Wide glyphs take two columns. Whatever runs past the clip is cut off.
     pub fn write_str(&mut self, x: u16, y: u16, s: &str) {
         let style = self.style;
-        for (i, ch) in s.chars().enumerate() {
+        let mut cx = x;
+        for ch in s.chars() {
             let cell = Cell::with_style(ch, style);
-            self.buffer.set(x + i as u16, y, cell);
+            let width = cell.width();
+            self.set(cx, y, cell);
+            let Some(next) = cx.checked_add(width) else {
+                break;
+            };
+            cx = next;
         }
     }
 }
@@ -72,6 +244,8 @@ pub struct MountContext<'a> {
 /// Context for component updates
 pub struct UpdateContext<'a> {
     pub store: &'a Store,
+    /// Time since the previous frame, for animating
+    pub delta: std::time::Duration,
 }
 
 /// Context for event handling
@@ -81,6 +255,61 @@ pub struct EventContext<'a> {
     pub area: Rect,
 }
 
+/// Store key widgets set to ask the app for a bell
+pub const BELL_REQUEST_KEY: &str = "app:bell";
+
+/// Store key widgets set to force the next frame to render
+pub const REDRAW_REQUEST_KEY: &str = "app:redraw";
+
+/// Store key widgets set to ask the app to close the topmost modal
+pub const CLOSE_MODAL_REQUEST_KEY: &str = "app:close_modal";
+
+/// Store key the app publishes the last rendered frame's
+/// `render::FrameStats` under
+pub const FRAME_STATS_KEY: &str = "app:frame_stats";
+
+impl EventContext<'_> {
+    /// Ask the app to ring the bell (audible or visual, per app settings)
+    pub fn bell(&mut self) {
+        self.store.set(BELL_REQUEST_KEY, true);
+    }
+
+    /// Make sure the next frame renders
+    ///
+    /// For state that lives outside signals (hover flags, cursors) when the
+    /// root's `update` may say there's nothing to redraw.
+    pub fn request_redraw(&mut self) {
+        self.store.set(REDRAW_REQUEST_KEY, true);
+    }
+
+    /// Ask the app to close the topmost modal once this event is handled
+    pub fn close_modal(&mut self) {
+        self.store.set(CLOSE_MODAL_REQUEST_KEY, true);
+    }
+}
+
+/// A run of text with a single style, used to build up a styled line
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Span {
+    pub content: String,
+    pub style: Style,
+}
+
+impl Span {
+    /// Unstyled span
+    pub fn raw(content: impl Into<String>) -> Self {
+        Self::styled(content, Style::default())
+    }
+
+    /// Span with a style
+    pub fn styled(content: impl Into<String>, style: Style) -> Self {
+        Self {
+            content: content.into(),
+            style,
+        }
+    }
+}
+
 /// View node - the basic building block of the UI tree
 ///
 /// This represents a renderable element. Components produce ViewNodes
@@ -99,16 +328,69 @@ pub enum ViewNode {
         direction: ContainerDirection,
     },
 
+    /// Children with explicit sizes along the layout direction
+    Layout {
+        children: Vec<ViewNode>,
+        sizes: Vec<Length>,
+        direction: ContainerDirection,
+        gap: u16,
+    },
+
+    /// Box border drawn around a child, which renders in the inset area
+    Border {
+        child: Box<ViewNode>,
+        border: BorderSet,
+        title: Option<String>,
+        style: Style,
+    },
+
+    /// One line made of differently styled spans, laid end to end
+    Spans { spans: Vec<Span> },
+
+    /// Pre-styled cells, one row per line (for content that's already
+    /// been laid out, like parsed terminal output)
+    Cells { lines: Vec<Vec<Cell>> },
+
+    /// A focusable component's subtree; records the area it's drawn in
+    /// to `RenderContext::focus_rects`, otherwise renders like `child`
+    Focusable {
+        id: ComponentId,
+        child: Box<ViewNode>,
+    },
+
+    /// A subtree that may wait for a later frame when the frame is over
+    /// its `RenderBudget` (see `ViewNode::low_priority`)
+    LowPriority { child: Box<ViewNode> },
+
     /// Empty/spacer node
     Empty,
 }
 
+/// How deep `ViewNode::debug_tree` goes before eliding children
+pub const DEBUG_TREE_MAX_DEPTH: usize = 32;
+
+/// Index of the first child that can show up on screen
+///
+/// In a stack, everything below the topmost opaque child is hidden, so
+/// there's no point rendering it. Other directions render everything.
+fn first_visible(children: &[ViewNode], direction: ContainerDirection) -> usize {
+    if direction != ContainerDirection::Stacked {
+        return 0;
+    }
+    children.iter().rposition(ViewNode::is_opaque).unwrap_or(0)
+}
+
 /// Direction for container layout
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum ContainerDirection {
     Vertical,
     Horizontal,
-    Stacked, // All children get same area
+    /// All children get the same area and render back to front: later
+    /// children draw over earlier ones. Cells a child doesn't write keep
+    /// whatever is below, so only an opaque child (see
+    /// `ViewNode::with_background`) hides the ones before it - and those
+    /// aren't rendered at all.
+    Stacked,
 }
 
 impl ViewNode {
@@ -151,11 +433,341 @@ impl ViewNode {
         }
     }
 
+    /// Lay out children with explicit sizes (Fixed, Fill, Percent, ...)
+    pub fn sized(direction: ContainerDirection, items: Vec<(Length, ViewNode)>) -> Self {
+        let (sizes, children) = items.into_iter().unzip();
+        Self::Layout {
+            children,
+            sizes,
+            direction,
+            gap: 0,
+        }
+    }
+
+    /// Wrap a node in a border
+    pub fn border(child: ViewNode, border: BorderSet) -> Self {
+        Self::Border {
+            child: Box::new(child),
+            border,
+            title: None,
+            style: Style::default(),
+        }
+    }
+
+    /// Wrap a node in a styled border with a title
+    pub fn border_titled(
+        child: ViewNode,
+        border: BorderSet,
+        title: impl Into<String>,
+        style: Style,
+    ) -> Self {
+        Self::Border {
+            child: Box::new(child),
+            border,
+            title: Some(title.into()),
+            style,
+        }
+    }
+
+    /// Create a single line from styled spans
+    pub fn spans(spans: Vec<Span>) -> Self {
+        Self::Spans { spans }
+    }
+
+    /// Create a node from rows of pre-styled cells
+    pub fn cells(lines: Vec<Vec<Cell>>) -> Self {
+        Self::Cells { lines }
+    }
+
     /// Create an empty node
     pub fn empty() -> Self {
         Self::Empty
     }
 
+    /// Fill the whole area with `bg` before drawing the content
+    ///
+    /// Text inside picks up the background unless it sets its own. Other
+    /// nodes get wrapped in a container.
+    pub fn with_background(self, bg: Color) -> Self {
+        match self {
+            ViewNode::Container {
+                children,
+                area,
+                style,
+                direction,
+            } => ViewNode::Container {
+                children,
+                area,
+                style: style.bg(bg),
+                direction,
+            },
+            other => ViewNode::Container {
+                children: vec![other],
+                area: Rect::new(0, 0, 0, 0),
+                style: Style::default().bg(bg),
+                direction: ContainerDirection::Vertical,
+            },
+        }
+    }
+
+    /// Mark this subtree as safe to postpone when a frame runs long
+    ///
+    /// Under a render budget, once the deadline has passed the subtree
+    /// keeps showing what it drew last time, and the app renders again on
+    /// the next frame without a deadline, so it's never more than one frame
+    /// behind. Good for previews, charts and other decoration.
+    pub fn low_priority(self) -> Self {
+        ViewNode::LowPriority {
+            child: Box::new(self),
+        }
+    }
+
+    /// Whether this node paints every cell of its area
+    pub fn is_opaque(&self) -> bool {
+        match self {
+            ViewNode::Container { style, .. } => style.bg.is_some(),
+            ViewNode::Focusable { child, .. } | ViewNode::LowPriority { child } => {
+                child.is_opaque()
+            }
+            _ => false,
+        }
+    }
+
+    /// Highlight this node as focused
+    ///
+    /// Text gets the focus style layered on top. Containers pass it down to
+    /// their direct text children (borders, labels) without repainting the
+    /// whole subtree.
+    pub fn with_focus_style(self, focus: Style) -> Self {
+        match self {
+            ViewNode::Text { content, style } => ViewNode::Text {
+                content,
+                style: style.patch(focus),
+            },
+            ViewNode::Container {
+                children,
+                area,
+                style,
+                direction,
+            } => ViewNode::Container {
+                children: children
+                    .into_iter()
+                    .map(|child| match child {
+                        ViewNode::Text { .. } => child.with_focus_style(focus),
+                        other => other,
+                    })
+                    .collect(),
+                area,
+                style: style.patch(focus),
+                direction,
+            },
+            ViewNode::Layout {
+                children,
+                sizes,
+                direction,
+                gap,
+            } => ViewNode::Layout {
+                children: children
+                    .into_iter()
+                    .map(|child| match child {
+                        ViewNode::Text { .. } => child.with_focus_style(focus),
+                        other => other,
+                    })
+                    .collect(),
+                sizes,
+                direction,
+                gap,
+            },
+            // Borders are the natural focus ring - only restyle the frame
+            ViewNode::Border {
+                child,
+                border,
+                title,
+                style,
+            } => ViewNode::Border {
+                child,
+                border,
+                title,
+                style: style.patch(focus),
+            },
+            ViewNode::Spans { spans } => ViewNode::Spans {
+                spans: spans
+                    .into_iter()
+                    .map(|span| Span::styled(span.content, span.style.patch(focus)))
+                    .collect(),
+            },
+            // Already styled cell by cell
+            ViewNode::Cells { lines } => ViewNode::Cells { lines },
+            ViewNode::Focusable { id, child } => ViewNode::Focusable {
+                id,
+                child: Box::new(child.with_focus_style(focus)),
+            },
+            ViewNode::LowPriority { child } => ViewNode::LowPriority {
+                child: Box::new(child.with_focus_style(focus)),
+            },
+            ViewNode::Empty => ViewNode::Empty,
+        }
+    }
+
+    /// Where a container's children go within `area`
+    ///
+    /// Empty for nodes that aren't `Container` or `Layout`.
+    fn child_rects(&self, area: Rect) -> Vec<Rect> {
+        use crate::layout::{Column, Row};
+
+        match self {
+            ViewNode::Container {
+                children,
+                direction,
+                ..
+            } => {
+                // Distribute space equally among children
+                let sizes = vec![Length::Fill(1); children.len()];
+                match direction {
+                    ContainerDirection::Vertical => Column::new().layout(area, &sizes),
+                    ContainerDirection::Horizontal => Row::new().layout(area, &sizes),
+                    // All children get the full area
+                    ContainerDirection::Stacked => vec![area; children.len()],
+                }
+            }
+            ViewNode::Layout {
+                children,
+                sizes,
+                direction,
+                gap,
+            } => match direction {
+                ContainerDirection::Vertical => Column::new().gap(*gap).layout(area, sizes),
+                ContainerDirection::Horizontal => Row::new().gap(*gap).layout(area, sizes),
+                ContainerDirection::Stacked => vec![area; children.len()],
+            },
+            _ => Vec::new(),
+        }
+    }
+
+    /// Cache key for a container's child rects
+    ///
+    /// Covers everything `child_rects` reads besides the area, so equal
+    /// keys in the same area always lay out the same way.
+    fn layout_key(&self) -> LayoutKey {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        match self {
+            ViewNode::Container {
+                children,
+                direction,
+                ..
+            } => {
+                0u8.hash(&mut hasher);
+                direction.hash(&mut hasher);
+                children.len().hash(&mut hasher);
+            }
+            ViewNode::Layout {
+                children,
+                sizes,
+                direction,
+                gap,
+            } => {
+                1u8.hash(&mut hasher);
+                direction.hash(&mut hasher);
+                children.len().hash(&mut hasher);
+                gap.hash(&mut hasher);
+                for size in sizes {
+                    match *size {
+                        Length::Fixed(n) => (0u8, n as u32).hash(&mut hasher),
+                        Length::Percent(p) => (1u8, p.to_bits()).hash(&mut hasher),
+                        Length::Fill(n) => (2u8, n as u32).hash(&mut hasher),
+                        Length::Min(n) => (3u8, n as u32).hash(&mut hasher),
+                        Length::Max(n) => (4u8, n as u32).hash(&mut hasher),
+                    }
+                }
+            }
+            _ => 2u8.hash(&mut hasher),
+        }
+        LayoutKey::Node(hasher.finish())
+    }
+
+    /// `child_rects`, through the context's layout cache
+    fn cached_child_rects(&self, ctx: &RenderContext) -> Vec<Rect> {
+        let area = ctx.area;
+        ctx.cached_layout(self.layout_key(), area, || self.child_rects(area))
+    }
+
+    /// Indented dump of this tree as laid out in `area`, one node per line
+    ///
+    /// Each line has the node type, its rect and its content or style.
+    /// Anything nested deeper than `DEBUG_TREE_MAX_DEPTH` is summarized.
+    pub fn debug_tree(&self, area: Rect) -> String {
+        let mut out = String::new();
+        self.write_debug_tree(&mut out, area, 0);
+        out
+    }
+
+    fn write_debug_tree(&self, out: &mut String, area: Rect, depth: usize) {
+        use std::fmt::Write;
+
+        let indent = "  ".repeat(depth);
+        let label = match self {
+            ViewNode::Text { content, .. } => format!("Text {:?}", content),
+            ViewNode::Container { direction, .. } => format!("Container({:?})", direction),
+            ViewNode::Layout { direction, .. } => format!("Layout({:?})", direction),
+            ViewNode::Border { title, .. } => match title {
+                Some(title) => format!("Border {:?}", title),
+                None => "Border".to_string(),
+            },
+            ViewNode::Spans { spans } => {
+                let text: String = spans.iter().map(|s| s.content.as_str()).collect();
+                format!("Spans {:?}", text)
+            }
+            ViewNode::Cells { lines } => format!("Cells ({} lines)", lines.len()),
+            ViewNode::Focusable { id, .. } => format!("Focusable #{}", id.0),
+            ViewNode::LowPriority { .. } => "LowPriority".to_string(),
+            ViewNode::Empty => "Empty".to_string(),
+        };
+        let _ = write!(
+            out,
+            "{}{} @ {},{} {}x{}",
+            indent, label, area.x, area.y, area.width, area.height
+        );
+        match self {
+            ViewNode::Text { style, .. }
+            | ViewNode::Container { style, .. }
+            | ViewNode::Border { style, .. }
+                if *style != Style::default() =>
+            {
+                let _ = write!(out, " {:?}", style);
+            }
+            _ => {}
+        }
+        out.push('\n');
+
+        let children: Vec<(&ViewNode, Rect)> = match self {
+            ViewNode::Container { children, .. } | ViewNode::Layout { children, .. } => {
+                children.iter().zip(self.child_rects(area)).collect()
+            }
+            ViewNode::Border { child, .. } => vec![(child.as_ref(), area.inner(1))],
+            ViewNode::Focusable { child, .. } | ViewNode::LowPriority { child } => {
+                vec![(child.as_ref(), area)]
+            }
+            _ => Vec::new(),
+        };
+        if children.is_empty() {
+            return;
+        }
+        if depth + 1 >= DEBUG_TREE_MAX_DEPTH {
+            let _ = writeln!(
+                out,
+                "{}  ... ({} child nodes not shown)",
+                indent,
+                children.len()
+            );
+            return;
+        }
+        for (child, child_area) in children {
+            child.write_debug_tree(out, child_area, depth + 1);
+        }
+    }
+
     /// Render this view node to the buffer
     ///
     /// This is called during the render phase after layout has been computed.
@@ -166,11 +778,21 @@ impl ViewNode {
                 let x = ctx.area.x;
                 let y = ctx.area.y;
 
-                // Save old style, apply new one
+                // Save old style, apply new one (keeping a background
+                // painted underneath unless we have our own)
                 let old_style = ctx.style;
-                ctx.style = *style;
+                ctx.style = Style {
+                    bg: style.bg.or(old_style.bg),
+                    ..*style
+                };
 
-                ctx.write_str(x, y, content);
+                // Each line gets its own row, stopping at the bottom of the area
+                for (i, line) in content.split('\n').enumerate() {
+                    if i > 0 && i as u16 >= ctx.area.height {
+                        break;
+                    }
+                    ctx.write_str(x, y + i as u16, line);
+                }
 
                 // Restore style
                 ctx.style = old_style;
@@ -179,47 +801,159 @@ impl ViewNode {
             ViewNode::Container {
                 children,
                 direction,
+                style,
                 ..
             } => {
-                use crate::layout::{Column, Length, Row};
+                let old_style = ctx.style;
+                if let Some(bg) = style.bg {
+                    let area = ctx.area;
+                    let fill = Style::default().bg(bg);
+                    for y in area.y..area.y + area.height {
+                        for x in area.x..area.x + area.width {
+                            ctx.set(x, y, Cell::with_style(' ', fill));
+                        }
+                    }
+                    ctx.style.bg = Some(bg);
+                }
 
                 if children.is_empty() {
+                    ctx.style = old_style;
                     return;
                 }
 
-                // Calculate layout based on direction
-                let child_rects = match direction {
-                    ContainerDirection::Vertical => {
-                        let col = Column::new();
-                        // Distribute space equally among children
-                        let heights = vec![Length::Fill(1); children.len()];
-                        col.layout(ctx.area, &heights)
-                    }
-                    ContainerDirection::Horizontal => {
-                        let row = Row::new();
-                        // Distribute space equally among children
-                        let widths = vec![Length::Fill(1); children.len()];
-                        row.layout(ctx.area, &widths)
-                    }
-                    ContainerDirection::Stacked => {
-                        // All children get the full area
-                        vec![ctx.area; children.len()]
+                // Render each child in its calculated rect
+                let child_rects = self.cached_child_rects(ctx);
+                let first = first_visible(children, *direction);
+                for (child, &child_area) in children.iter().zip(child_rects.iter()).skip(first) {
+                    child.render(&mut ctx.sub_context(child_area));
+                }
+                ctx.style = old_style;
+            }
+
+            ViewNode::Layout {
+                children,
+                direction,
+                ..
+            } => {
+                let child_rects = self.cached_child_rects(ctx);
+
+                let first = first_visible(children, *direction);
+                for (child, &child_area) in children.iter().zip(child_rects.iter()).skip(first) {
+                    child.render(&mut ctx.sub_context(child_area));
+                }
+            }
+
+            ViewNode::Border {
+                child,
+                border,
+                title,
+                style,
+            } => {
+                let area = ctx.area;
+                let border = &ctx.mode.border(*border);
+
+                // Need at least the two corners in each direction
+                if area.width < 2 || area.height < 2 {
+                    return;
+                }
+
+                let right = area.x + area.width - 1;
+                let bottom = area.y + area.height - 1;
+
+                for x in area.x + 1..right {
+                    ctx.set(x, area.y, Cell::with_style(border.horizontal, *style));
+                    ctx.set(x, bottom, Cell::with_style(border.horizontal, *style));
+                }
+                for y in area.y + 1..bottom {
+                    ctx.set(area.x, y, Cell::with_style(border.vertical, *style));
+                    ctx.set(right, y, Cell::with_style(border.vertical, *style));
+                }
+                ctx.set(area.x, area.y, Cell::with_style(border.top_left, *style));
+                ctx.set(right, area.y, Cell::with_style(border.top_right, *style));
+                ctx.set(area.x, bottom, Cell::with_style(border.bottom_left, *style));
+                ctx.set(right, bottom, Cell::with_style(border.bottom_right, *style));
+
+                // Title sits on the top edge, cut off before the corner;
+                // wide glyphs take two columns
+                if let Some(title) = title {
+                    let mut x = area.x + 1;
+                    for ch in title.chars() {
+                        let cell = Cell::with_style(ch, *style);
+                        let width = cell.width();
+                        if x.saturating_add(width) > right {
+                            break;
+                        }
+                        ctx.set(x, area.y, cell);
+                        x += width;
                     }
-                };
+                }
 
-                // Render each child in its calculated rect
-                for (child, &child_area) in children.iter().zip(child_rects.iter()) {
-                    let mut child_ctx = RenderContext {
-                        buffer: ctx.buffer,
-                        area: child_area,
-                        style: ctx.style,
-                        store: ctx.store,
+                let inner = area.inner(1);
+                if inner.width > 0 && inner.height > 0 {
+                    child.render(&mut ctx.sub_context(inner));
+                }
+            }
+
+            ViewNode::Spans { spans } => {
+                let area = ctx.area;
+                let right = area.x.saturating_add(area.width);
+                let mut x = area.x;
+                // Like Text, keep a background painted underneath unless
+                // the span has its own
+                let painted_bg = ctx.style.bg;
+                let cells = spans.iter().flat_map(|span| {
+                    let style = Style {
+                        bg: span.style.bg.or(painted_bg),
+                        ..span.style
                     };
+                    span.content
+                        .chars()
+                        .map(move |ch| Cell::with_style(ch, style))
+                });
+                for cell in cells {
+                    // Wide glyphs take two columns
+                    let width = cell.width();
+                    if x.saturating_add(width) > right {
+                        break;
+                    }
+                    ctx.set(x, area.y, cell);
+                    x += width;
+                }
+            }
 
-                    child.render(&mut child_ctx);
+            ViewNode::Cells { lines } => {
+                let area = ctx.area;
+                for (dy, line) in lines.iter().take(area.height as usize).enumerate() {
+                    for (dx, cell) in line.iter().take(area.width as usize).enumerate() {
+                        ctx.set(area.x + dx as u16, area.y + dy as u16, cell.clone());
+                    }
                 }
             }
 
+            ViewNode::Focusable { id, child } => {
+                if let Some(rects) = ctx.focus_rects {
+                    rects.borrow_mut().push((*id, ctx.area));
+                }
+                child.render(ctx);
+            }
+
+            ViewNode::LowPriority { child } => match ctx.budget {
+                Some(budget) if budget.is_exceeded() => {
+                    budget.defer();
+                    if let Some(previous) = ctx.previous_frame {
+                        let area = ctx.area;
+                        for y in area.y..area.y + area.height {
+                            for x in area.x..area.x + area.width {
+                                if let Some(cell) = previous.get(x, y) {
+                                    ctx.set(x, y, cell.clone());
+                                }
+                            }
+                        }
+                    }
+                }
+                _ => child.render(ctx),
+            },
+
             ViewNode::Empty => {
                 // Nothing to render
             }
@@ -230,6 +964,7 @@ impl ViewNode {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::layout::Rect;
     use crate::render::Buffer;
     use crate::state::Store;
 
@@ -244,6 +979,243 @@ mod tests {
         }
     }
 
+    fn row(buffer: &Buffer, y: u16) -> String {
+        // Untouched cells hold '\0', show them as blanks
+        (0..buffer.width)
+            .map(|x| match buffer.get(x, y).unwrap().ch {
+                '\0' => ' ',
+                ch => ch,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_stacked_renders_back_to_front() {
+        let mut buffer = Buffer::new(6, 1);
+        let store = Store::new();
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 6, 1), &store);
+
+        // Text only covers its own cells, so the bottom layer shows through
+        ViewNode::container_with_direction(
+            vec![ViewNode::text("AAAA"), ViewNode::text("BB")],
+            ContainerDirection::Stacked,
+        )
+        .render(&mut ctx);
+        assert_eq!(row(&buffer, 0), "BBAA  ");
+
+        // An opaque top layer hides everything below it
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 6, 1), &store);
+        ViewNode::container_with_direction(
+            vec![
+                ViewNode::text("AAAA"),
+                ViewNode::text("BB").with_background(Color::BLUE),
+            ],
+            ContainerDirection::Stacked,
+        )
+        .render(&mut ctx);
+        assert_eq!(row(&buffer, 0), "BB    ");
+        assert!((0..6).all(|x| buffer.get(x, 0).unwrap().style.bg == Some(Color::BLUE)));
+    }
+
+    #[test]
+    fn test_text_clipped_to_its_area() {
+        let mut buffer = Buffer::new(10, 2);
+        let store = Store::new();
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 10, 2), &store);
+
+        // Long text in the left half stops at the right edge of its area
+        ViewNode::container_with_direction(
+            vec![ViewNode::text("overflowing"), ViewNode::empty()],
+            ContainerDirection::Horizontal,
+        )
+        .render(&mut ctx);
+        assert_eq!(row(&buffer, 0), "overf     ");
+
+        // Nested clips intersect: a child area reaching outside its
+        // parent's still can't draw past the parent
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 10, 2), &store);
+        let mut parent = ctx.sub_context(Rect::new(0, 1, 4, 1));
+        let mut child = parent.sub_context(Rect::new(2, 0, 8, 2));
+        assert_eq!(child.clip, Rect::new(2, 1, 2, 1));
+        child.write_str(2, 0, "xxxxxxxx");
+        child.write_str(2, 1, "yyyyyyyy");
+        assert_eq!(row(&buffer, 1), "  yy      ");
+    }
+
+    #[test]
+    fn test_write_str_advances_by_glyph_width() {
+        let mut buffer = Buffer::new(6, 1);
+        let store = Store::new();
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 6, 1), &store);
+
+        ctx.write_str(0, 0, "日本語");
+        assert_eq!(buffer.get(2, 0).unwrap().ch, '本');
+        assert!(buffer.get(5, 0).unwrap().is_continuation());
+
+        // A wide glyph cut by the clip leaves a blank, not half a glyph
+        let mut buffer = Buffer::new(6, 1);
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 6, 1), &store);
+        ctx.sub_context(Rect::new(0, 0, 3, 1))
+            .write_str(0, 0, "ab日");
+        let row: String = (0..6).map(|x| buffer.get(x, 0).unwrap().ch).collect();
+        assert_eq!(row, "ab \0\0\0");
+    }
+
+    #[test]
+    fn test_debug_tree_depth_cap() {
+        let mut node = ViewNode::text("leaf");
+        for _ in 0..DEBUG_TREE_MAX_DEPTH + 10 {
+            node = ViewNode::container(vec![node]);
+        }
+        let dump = node.debug_tree(Rect::new(0, 0, 4, 4));
+        assert_eq!(dump.lines().count(), DEBUG_TREE_MAX_DEPTH + 1);
+        assert!(dump.ends_with("... (1 child nodes not shown)\n"));
+        assert!(!dump.contains("leaf"));
+    }
+
+    #[test]
+    fn test_spans_advance_by_display_width() {
+        let mut buffer = Buffer::new(5, 1);
+        let store = Store::new();
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 5, 1), &store);
+
+        ViewNode::spans(vec![Span::raw("太"), Span::raw("xy"), Span::raw("郎")]).render(&mut ctx);
+
+        assert_eq!(buffer.get(0, 0).unwrap().ch, '太');
+        assert_eq!(buffer.get(2, 0).unwrap().ch, 'x');
+        assert_eq!(buffer.get(3, 0).unwrap().ch, 'y');
+        // Doesn't fit in the last column
+        assert_eq!(buffer.get(4, 0).unwrap().ch, '\0');
+    }
+
+    #[test]
+    fn test_border_render() {
+        let mut buffer = Buffer::new(8, 4);
+        let store = Store::new();
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 8, 4), &store);
+
+        let node = ViewNode::border_titled(
+            ViewNode::text("hi"),
+            BorderSet::PLAIN,
+            "Title",
+            Style::default(),
+        );
+        node.render(&mut ctx);
+
+        assert_eq!(row(&buffer, 0), "┌Title─┐");
+        assert_eq!(row(&buffer, 1), "│hi    │");
+        assert_eq!(row(&buffer, 2), "│      │");
+        assert_eq!(row(&buffer, 3), "└──────┘");
+    }
+
+    #[test]
+    fn test_border_title_truncated() {
+        let mut buffer = Buffer::new(6, 3);
+        let store = Store::new();
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 6, 3), &store);
+
+        ViewNode::border_titled(
+            ViewNode::empty(),
+            BorderSet::ROUNDED,
+            "Much too long",
+            Style::default(),
+        )
+        .render(&mut ctx);
+
+        assert_eq!(row(&buffer, 0), "╭Much╮");
+    }
+
+    #[test]
+    fn test_border_title_clipped() {
+        let mut buffer = Buffer::new(10, 3);
+        let store = Store::new();
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 10, 3), &store);
+
+        // Border area wider than the clip it's drawn in
+        let mut parent = ctx.sub_context(Rect::new(0, 0, 4, 3));
+        let mut child = parent.sub_context(Rect::new(0, 0, 10, 3));
+        ViewNode::border_titled(
+            ViewNode::empty(),
+            BorderSet::PLAIN,
+            "Title",
+            Style::default(),
+        )
+        .render(&mut child);
+
+        assert_eq!(row(&buffer, 0), "┌Tit      ");
+    }
+
+    #[test]
+    fn test_border_title_wide_glyphs() {
+        let mut buffer = Buffer::new(6, 3);
+        let store = Store::new();
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 6, 3), &store);
+
+        ViewNode::border_titled(
+            ViewNode::empty(),
+            BorderSet::PLAIN,
+            "日本語",
+            Style::default(),
+        )
+        .render(&mut ctx);
+
+        // Two glyphs fill the 4 columns; the third doesn't fit
+        assert_eq!(buffer.get(1, 0).unwrap().ch, '日');
+        assert_eq!(buffer.get(3, 0).unwrap().ch, '本');
+        assert_eq!(buffer.get(5, 0).unwrap().ch, '┐');
+    }
+
+    #[test]
+    fn test_cells_clipped() {
+        let mut buffer = Buffer::new(10, 2);
+        let store = Store::new();
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 10, 2), &store);
+
+        let line: Vec<Cell> = "abcdefgh".chars().map(Cell::new).collect();
+        let mut parent = ctx.sub_context(Rect::new(0, 0, 3, 1));
+        let mut child = parent.sub_context(Rect::new(0, 0, 10, 2));
+        ViewNode::cells(vec![line.clone(), line]).render(&mut child);
+
+        assert_eq!(row(&buffer, 0), "abc       ");
+        assert_eq!(row(&buffer, 1), "          ");
+    }
+
+    #[test]
+    fn test_border_degenerate_sizes() {
+        let mut buffer = Buffer::new(4, 4);
+        let store = Store::new();
+
+        // 1 cell wide - nothing fits, nothing drawn
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 1, 4), &store);
+        ViewNode::border(ViewNode::text("x"), BorderSet::PLAIN).render(&mut ctx);
+        assert_eq!(row(&buffer, 0), "    ");
+
+        // 2x2 - just corners, no room for the child
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 2, 2), &store);
+        ViewNode::border(ViewNode::text("x"), BorderSet::PLAIN).render(&mut ctx);
+        assert_eq!(row(&buffer, 0), "┌┐  ");
+        assert_eq!(row(&buffer, 1), "└┘  ");
+    }
+
+    #[test]
+    fn test_sized_layout() {
+        let mut buffer = Buffer::new(10, 1);
+        let store = Store::new();
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 10, 1), &store);
+
+        ViewNode::sized(
+            ContainerDirection::Horizontal,
+            vec![
+                (Length::Fixed(4), ViewNode::text("abc")),
+                (Length::Fill(1), ViewNode::text("xyz")),
+            ],
+        )
+        .render(&mut ctx);
+
+        // Second child starts right after the fixed 4 columns
+        assert_eq!(row(&buffer, 0), "abc xyz   ");
+    }
+
     #[test]
     fn test_container_with_children() {
         let children = vec![ViewNode::text("Line 1"), ViewNode::text("Line 2")];
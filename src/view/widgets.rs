@@ -2,25 +2,55 @@
 //!
 //! Widgets are pre-built components for common UI patterns.
 
+mod animated_number;
+mod color_picker;
+mod confirm_dialog;
+mod diff_view;
 mod input;
 mod list;
+mod log_view;
 mod modal;
+mod number_input;
 mod progress;
+mod raw_view;
 mod scrollable;
+mod select;
+mod selection;
+mod split_pane;
+mod stats_overlay;
 mod table;
 mod tabs;
+mod text_area;
+mod virtual_list;
 
+pub use animated_number::AnimatedNumber;
+pub use color_picker::ColorPicker;
+pub use confirm_dialog::ConfirmDialog;
+pub use diff_view::{diff_lines, DiffKind, DiffLine, DiffView};
 pub use input::Input;
 pub use list::List;
+pub use log_view::{find_matches, LogView, SearchMatch};
 pub use modal::Modal;
+pub use number_input::NumberInput;
 pub use progress::ProgressBar;
+pub use raw_view::RawView;
 pub use scrollable::Scrollable;
+pub use select::Select;
+pub use split_pane::{SplitDirection, SplitPane};
+pub use stats_overlay::StatsOverlay;
 pub use table::{Column as TableColumn, SortOrder, Table};
 pub use tabs::Tabs;
-
-use super::{Component, EventContext, MountContext, RenderContext, UpdateContext, ViewNode};
+pub use text_area::TextArea;
+pub use virtual_list::VirtualList;
+
+use super::component::notify_focus;
+use super::{
+    BorderSet, Component, ContainerDirection, EventContext, MountContext, RenderContext,
+    UpdateContext, ViewNode,
+};
 use crate::event::{Event, EventResult, KeyCode, MouseButton, MouseEventKind};
-use crate::layout::Rect;
+use crate::focus::ComponentId;
+use crate::layout::{Length, Rect};
 use crate::state::Signal;
 use crate::theme::{Color, Modifier, Style};
 use std::cell::Cell;
@@ -44,6 +74,19 @@ use std::sync::Arc;
 pub struct Text {
     content: TextContent,
     style: Style,
+    wrap: bool,
+    align: TextAlign,
+}
+
+/// Horizontal alignment for text lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TextAlign {
+    #[default]
+    Left,
+    Center,
+    Right,
+    /// Spread words to fill the full width (except on the last line)
+    Justify,
 }
 
 enum TextContent {
@@ -57,6 +100,8 @@ impl Text {
         Self {
             content: TextContent::Static(text.into()),
             style: Style::default(),
+            wrap: false,
+            align: TextAlign::Left,
         }
     }
 
@@ -65,6 +110,8 @@ impl Text {
         Self {
             content: TextContent::Dynamic(Arc::new(f)),
             style: Style::default(),
+            wrap: false,
+            align: TextAlign::Left,
         }
     }
 
@@ -92,17 +139,148 @@ impl Text {
         self
     }
 
+    /// Word-wrap text to the width of the render area
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Set horizontal alignment
+    pub fn align(mut self, align: TextAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// Fully justify wrapped text (implies wrapping)
+    pub fn justify(mut self) -> Self {
+        self.wrap = true;
+        self.align = TextAlign::Justify;
+        self
+    }
+
     fn get_content(&self) -> String {
         match &self.content {
             TextContent::Static(s) => s.clone(),
             TextContent::Dynamic(f) => f(),
         }
     }
+
+    /// Break content into lines and apply alignment for the given width
+    fn layout_lines(&self, content: &str, width: usize) -> Vec<String> {
+        let mut lines = Vec::new();
+
+        for paragraph in content.split('\n') {
+            let wrapped = if self.wrap {
+                wrap_words(paragraph, width)
+            } else {
+                vec![paragraph.to_string()]
+            };
+
+            let count = wrapped.len();
+            for (i, line) in wrapped.into_iter().enumerate() {
+                let is_last = i + 1 == count;
+                lines.push(align_line(&line, width, self.align, is_last));
+            }
+        }
+
+        lines
+    }
 }
 
 impl Component for Text {
-    fn render(&self, _ctx: &RenderContext) -> ViewNode {
-        ViewNode::text_styled(self.get_content(), self.style)
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        let content = self.get_content();
+
+        // Plain left-aligned text doesn't need to know the width
+        if !self.wrap && self.align == TextAlign::Left {
+            return ViewNode::text_styled(content, self.style);
+        }
+
+        let lines = self.layout_lines(&content, ctx.area.width as usize);
+        ViewNode::text_styled(lines.join("\n"), self.style)
+    }
+}
+
+/// Greedy word wrap. Words longer than the width get hard-split.
+fn wrap_words(text: &str, width: usize) -> Vec<String> {
+    if width == 0 {
+        return Vec::new();
+    }
+
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut current_len = 0;
+
+    for word in text.split_whitespace() {
+        let mut chars: Vec<char> = word.chars().collect();
+
+        // Fits on the current line (with a separating space)?
+        if current_len > 0 && current_len + 1 + chars.len() <= width {
+            current.push(' ');
+            current.extend(chars.iter());
+            current_len += 1 + chars.len();
+            continue;
+        }
+
+        if current_len > 0 {
+            lines.push(std::mem::take(&mut current));
+        }
+
+        // Too long for any line - chop it up
+        while chars.len() > width {
+            let rest = chars.split_off(width);
+            lines.push(chars.into_iter().collect());
+            chars = rest;
+        }
+
+        current_len = chars.len();
+        current = chars.into_iter().collect();
+    }
+
+    if current_len > 0 || lines.is_empty() {
+        lines.push(current);
+    }
+
+    lines
+}
+
+/// Pad or stretch a single line to the target width
+fn align_line(line: &str, width: usize, align: TextAlign, is_last: bool) -> String {
+    let len = line.chars().count();
+    if len >= width {
+        return line.to_string();
+    }
+    let slack = width - len;
+
+    match align {
+        TextAlign::Left => line.to_string(),
+        TextAlign::Right => format!("{}{}", " ".repeat(slack), line),
+        TextAlign::Center => format!("{}{}", " ".repeat(slack / 2), line),
+        TextAlign::Justify => {
+            let words: Vec<&str> = line.split(' ').filter(|w| !w.is_empty()).collect();
+
+            // Last line of a paragraph and single words stay left-aligned
+            if is_last || words.len() < 2 {
+                return line.to_string();
+            }
+
+            let word_len: usize = words.iter().map(|w| w.chars().count()).sum();
+            let gaps = words.len() - 1;
+            let spaces = width.saturating_sub(word_len);
+            let base = spaces / gaps;
+            let extra = spaces % gaps;
+
+            let mut out = String::with_capacity(width);
+            for (i, word) in words.iter().enumerate() {
+                out.push_str(word);
+                if i < gaps {
+                    // Leftmost gaps pick up the remainder
+                    let n = base + usize::from(i < extra);
+                    out.push_str(&" ".repeat(n));
+                }
+            }
+            out
+        }
     }
 }
 
@@ -126,6 +304,7 @@ pub struct Button {
     state: ButtonState,
     /// Track the last rendered position for hit-testing (using Cell for interior mutability)
     last_rect: Cell<Option<Rect>>,
+    focus_id: Option<ComponentId>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -167,6 +346,7 @@ impl Button {
             style: ButtonStyle::default(),
             state: ButtonState::Normal,
             last_rect: Cell::new(None),
+            focus_id: None,
         }
     }
 
@@ -188,6 +368,12 @@ impl Button {
         self
     }
 
+    /// Set the focus id used for keyboard focus highlighting
+    pub fn focus_id(mut self, id: ComponentId) -> Self {
+        self.focus_id = Some(id);
+        self
+    }
+
     fn get_style(&self) -> Style {
         match self.state {
             ButtonState::Normal => self.style.normal,
@@ -234,20 +420,16 @@ impl Component for Button {
                 };
 
                 match mouse.kind {
-                    MouseEventKind::Down(MouseButton::Left) => {
-                        if is_over {
-                            self.state = ButtonState::Active;
-                            return EventResult::Handled;
-                        }
+                    MouseEventKind::Down(MouseButton::Left) if is_over => {
+                        self.state = ButtonState::Active;
+                        return EventResult::Handled;
                     }
-                    MouseEventKind::Up(MouseButton::Left) => {
-                        if self.state == ButtonState::Active {
-                            if is_over {
-                                self.activate();
-                            }
-                            self.state = ButtonState::Normal;
-                            return EventResult::Consumed;
+                    MouseEventKind::Up(MouseButton::Left) if self.state == ButtonState::Active => {
+                        if is_over {
+                            self.activate();
                         }
+                        self.state = ButtonState::Normal;
+                        return EventResult::Consumed;
                     }
                     MouseEventKind::Moved => {
                         // Set hover state based on position
@@ -266,6 +448,10 @@ impl Component for Button {
 
         EventResult::Ignored
     }
+
+    fn focus_id(&self) -> Option<ComponentId> {
+        self.focus_id
+    }
 }
 
 /// Container that renders children in a vertical column
@@ -297,7 +483,11 @@ impl VStack {
 
 impl Component for VStack {
     fn render(&self, ctx: &RenderContext) -> ViewNode {
-        let children: Vec<ViewNode> = self.children.iter().map(|c| c.render(ctx)).collect();
+        let children: Vec<ViewNode> = self
+            .children
+            .iter()
+            .map(|c| ctx.render_child(c.as_ref()))
+            .collect();
 
         ViewNode::container(children)
     }
@@ -313,6 +503,28 @@ impl Component for VStack {
         }
         EventResult::Ignored
     }
+
+    fn focus_changed(
+        &mut self,
+        previous: Option<ComponentId>,
+        current: Option<ComponentId>,
+        ctx: &mut EventContext,
+    ) {
+        for child in &mut self.children {
+            child.focus_changed(previous, current, ctx);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateContext) -> bool {
+        for child in &mut self.children {
+            child.update(ctx);
+        }
+        true
+    }
+
+    fn children(&self) -> Vec<&dyn Component> {
+        self.children.iter().map(|child| child.as_ref()).collect()
+    }
 }
 
 impl Default for VStack {
@@ -322,6 +534,9 @@ impl Default for VStack {
 }
 
 /// Container that renders children in a horizontal row
+///
+/// Children share the width equally, with `gap` empty columns between
+/// neighbours.
 pub struct HStack {
     children: Vec<Box<dyn Component>>,
     gap: u16,
@@ -348,11 +563,22 @@ impl HStack {
 
 impl Component for HStack {
     fn render(&self, ctx: &RenderContext) -> ViewNode {
-        // For now, render children side-by-side in a simple way
-        // TODO: proper horizontal layout with the Layout system
-        let children: Vec<ViewNode> = self.children.iter().map(|c| c.render(ctx)).collect();
-
-        ViewNode::container(children)
+        let children: Vec<ViewNode> = self
+            .children
+            .iter()
+            .map(|c| ctx.render_child(c.as_ref()))
+            .collect();
+
+        if self.gap == 0 {
+            return ViewNode::container_with_direction(children, ContainerDirection::Horizontal);
+        }
+        // Equal widths as without a gap, with `gap` columns between them
+        ViewNode::Layout {
+            sizes: vec![Length::Fill(1); children.len()],
+            children,
+            direction: ContainerDirection::Horizontal,
+            gap: self.gap,
+        }
     }
 
     fn handle_event(&mut self, event: &Event, ctx: &mut EventContext) -> EventResult {
@@ -365,6 +591,28 @@ impl Component for HStack {
         }
         EventResult::Ignored
     }
+
+    fn focus_changed(
+        &mut self,
+        previous: Option<ComponentId>,
+        current: Option<ComponentId>,
+        ctx: &mut EventContext,
+    ) {
+        for child in &mut self.children {
+            child.focus_changed(previous, current, ctx);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateContext) -> bool {
+        for child in &mut self.children {
+            child.update(ctx);
+        }
+        true
+    }
+
+    fn children(&self) -> Vec<&dyn Component> {
+        self.children.iter().map(|child| child.as_ref()).collect()
+    }
 }
 
 impl Default for HStack {
@@ -373,13 +621,238 @@ impl Default for HStack {
     }
 }
 
+/// Container that draws its children on top of each other
+///
+/// Each child is a layer with a z-index: higher z draws on top and gets
+/// events first. Layers with the same z keep insertion order - later ones
+/// are on top. Children all get the full area, so layers that shouldn't
+/// hide what's below should leave cells empty (see `ContainerDirection::Stacked`).
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let screen = ZStack::new()
+///     .layer(10, Text::new("tooltip"))
+///     .push(Text::new("main content")); // z = 0, so drawn underneath
+/// ```
+pub struct ZStack {
+    /// Kept sorted by z (stable), i.e. in drawing order
+    layers: Vec<(i32, Box<dyn Component>)>,
+}
+
+impl ZStack {
+    pub fn new() -> Self {
+        Self { layers: Vec::new() }
+    }
+
+    /// Add a layer at z = 0
+    pub fn push(self, child: impl Component + 'static) -> Self {
+        self.layer(0, child)
+    }
+
+    /// Add a layer at the given z-index
+    pub fn layer(mut self, z: i32, child: impl Component + 'static) -> Self {
+        let index = self.layers.partition_point(|&(other, _)| other <= z);
+        self.layers.insert(index, (z, Box::new(child)));
+        self
+    }
+}
+
+impl Component for ZStack {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        let children: Vec<ViewNode> = self
+            .layers
+            .iter()
+            .map(|(_, c)| ctx.render_child(c.as_ref()))
+            .collect();
+
+        ViewNode::container_with_direction(children, ContainerDirection::Stacked)
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut EventContext) -> EventResult {
+        // Topmost first
+        for (_, child) in self.layers.iter_mut().rev() {
+            match child.handle_event(event, ctx) {
+                EventResult::Consumed => return EventResult::Consumed,
+                EventResult::Handled => return EventResult::Handled,
+                EventResult::Ignored => continue,
+            }
+        }
+        EventResult::Ignored
+    }
+
+    fn focus_changed(
+        &mut self,
+        previous: Option<ComponentId>,
+        current: Option<ComponentId>,
+        ctx: &mut EventContext,
+    ) {
+        for (_, child) in &mut self.layers {
+            child.focus_changed(previous, current, ctx);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateContext) -> bool {
+        for (_, child) in &mut self.layers {
+            child.update(ctx);
+        }
+        true
+    }
+
+    fn children(&self) -> Vec<&dyn Component> {
+        self.layers
+            .iter()
+            .map(|(_, child)| child.as_ref())
+            .collect()
+    }
+}
+
+impl Default for ZStack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Wrapper that leaves empty space around its child
+///
+/// The child gets the area inset by the padding on each side, for both
+/// drawing and events. Padding wider or taller than the area leaves the
+/// child nothing, so it isn't drawn at all.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let padded = Padding::new(Text::new("Hello")).all(1).left(3);
+/// ```
+pub struct Padding {
+    child: Box<dyn Component>,
+    top: u16,
+    right: u16,
+    bottom: u16,
+    left: u16,
+}
+
+impl Padding {
+    pub fn new(child: impl Component + 'static) -> Self {
+        Self {
+            child: Box::new(child),
+            top: 0,
+            right: 0,
+            bottom: 0,
+            left: 0,
+        }
+    }
+
+    /// Same padding on all four sides
+    pub fn all(self, padding: u16) -> Self {
+        self.top(padding)
+            .right(padding)
+            .bottom(padding)
+            .left(padding)
+    }
+
+    /// Same padding on the left and right
+    pub fn horizontal(self, padding: u16) -> Self {
+        self.left(padding).right(padding)
+    }
+
+    /// Same padding above and below
+    pub fn vertical(self, padding: u16) -> Self {
+        self.top(padding).bottom(padding)
+    }
+
+    pub fn top(mut self, padding: u16) -> Self {
+        self.top = padding;
+        self
+    }
+
+    pub fn right(mut self, padding: u16) -> Self {
+        self.right = padding;
+        self
+    }
+
+    pub fn bottom(mut self, padding: u16) -> Self {
+        self.bottom = padding;
+        self
+    }
+
+    pub fn left(mut self, padding: u16) -> Self {
+        self.left = padding;
+        self
+    }
+
+    /// The area the child gets inside `area`
+    fn inner(&self, area: Rect) -> Rect {
+        area.inner_margins(self.top, self.right, self.bottom, self.left)
+    }
+}
+
+impl Component for Padding {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        let inner = self.inner(ctx.area);
+        if inner.width == 0 || inner.height == 0 {
+            return ViewNode::Empty;
+        }
+
+        let row = ViewNode::sized(
+            ContainerDirection::Horizontal,
+            vec![
+                (Length::Fixed(self.left), ViewNode::Empty),
+                (
+                    Length::Fixed(inner.width),
+                    ctx.render_child(self.child.as_ref()),
+                ),
+                (Length::Fill(1), ViewNode::Empty),
+            ],
+        );
+        ViewNode::sized(
+            ContainerDirection::Vertical,
+            vec![
+                (Length::Fixed(self.top), ViewNode::Empty),
+                (Length::Fixed(inner.height), row),
+                (Length::Fill(1), ViewNode::Empty),
+            ],
+        )
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut EventContext) -> EventResult {
+        let mut inner_ctx = EventContext {
+            store: ctx.store,
+            area: self.inner(ctx.area),
+        };
+        self.child.handle_event(event, &mut inner_ctx)
+    }
+
+    fn focus_changed(
+        &mut self,
+        previous: Option<ComponentId>,
+        current: Option<ComponentId>,
+        ctx: &mut EventContext,
+    ) {
+        self.child.focus_changed(previous, current, ctx);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateContext) -> bool {
+        self.child.update(ctx);
+        true
+    }
+
+    fn children(&self) -> Vec<&dyn Component> {
+        vec![self.child.as_ref()]
+    }
+}
+
 /// Panel widget - a bordered container with optional title
 ///
-/// Draws a box border around its content with an optional title in the border.
+/// Draws a box border around the edge of its area, with the title set into
+/// the top edge, and renders its content inside the border.
 pub struct Panel {
     title: Option<String>,
     child: Option<Box<dyn Component>>,
     border_style: Style,
+    focus_id: Option<ComponentId>,
 }
 
 impl Panel {
@@ -388,6 +861,7 @@ impl Panel {
             title: None,
             child: None,
             border_style: Style::default().fg(Color::GRAY),
+            focus_id: None,
         }
     }
 
@@ -405,31 +879,39 @@ impl Panel {
         self.border_style = style;
         self
     }
+
+    /// Give the panel its own focus id
+    pub fn focus_id(mut self, id: ComponentId) -> Self {
+        self.focus_id = Some(id);
+        self
+    }
 }
 
 impl Component for Panel {
     fn render(&self, ctx: &RenderContext) -> ViewNode {
-        // Render border and title
-        // For now, simple approach - just add title text and child
-        let mut children = Vec::new();
-
-        // Title line with borders
-        if let Some(ref title) = self.title {
-            let border_line = format!("┌─ {} ─┐", title);
-            children.push(ViewNode::text_styled(border_line, self.border_style));
+        // Highlight the border when the panel or anything inside it has focus
+        let child_focused = self
+            .child
+            .as_ref()
+            .is_some_and(|c| ctx.is_focused(c.focus_id()));
+        let border_style = if child_focused || ctx.is_focused(self.focus_id) {
+            ctx.theme.focus_indicator()
         } else {
-            children.push(ViewNode::text_styled("┌─────┐", self.border_style));
+            self.border_style
+        };
+
+        // The border fills the area and the child gets what's inside it
+        let child = match self.child {
+            Some(ref child) => ctx.render_child(child.as_ref()),
+            None => ViewNode::empty(),
+        };
+
+        ViewNode::Border {
+            child: Box::new(child),
+            border: BorderSet::PLAIN,
+            title: self.title.as_ref().map(|title| format!(" {} ", title)),
+            style: border_style,
         }
-
-        // Child content
-        if let Some(ref child) = self.child {
-            children.push(child.render(ctx));
-        }
-
-        // Bottom border
-        children.push(ViewNode::text_styled("└─────┘", self.border_style));
-
-        ViewNode::container(children)
     }
 
     fn handle_event(&mut self, event: &Event, ctx: &mut EventContext) -> EventResult {
@@ -439,6 +921,33 @@ impl Component for Panel {
             EventResult::Ignored
         }
     }
+
+    fn focus_id(&self) -> Option<ComponentId> {
+        self.focus_id
+    }
+
+    fn focus_changed(
+        &mut self,
+        previous: Option<ComponentId>,
+        current: Option<ComponentId>,
+        ctx: &mut EventContext,
+    ) {
+        notify_focus(self, previous, current, ctx);
+        if let Some(ref mut child) = self.child {
+            child.focus_changed(previous, current, ctx);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateContext) -> bool {
+        if let Some(ref mut child) = self.child {
+            child.update(ctx);
+        }
+        true
+    }
+
+    fn children(&self) -> Vec<&dyn Component> {
+        self.child.iter().map(|child| child.as_ref()).collect()
+    }
 }
 
 impl Default for Panel {
@@ -453,6 +962,58 @@ mod tests {
     use crate::layout::Rect;
     use crate::render::Buffer;
     use crate::state::{Signal, Store};
+    use crate::view::RenderMode;
+
+    fn render_text(text: &Text, width: u16) -> String {
+        let mut buffer = Buffer::new(width, 10);
+        let store = Store::new();
+        let ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, width, 10), &store);
+        match text.render(&ctx) {
+            ViewNode::Text { content, .. } => content,
+            _ => panic!("Expected text node"),
+        }
+    }
+
+    #[test]
+    fn test_text_wrap() {
+        let text = Text::new("the quick brown fox").wrap(true);
+        assert_eq!(render_text(&text, 10), "the quick\nbrown fox");
+    }
+
+    #[test]
+    fn test_text_align_right_and_center() {
+        let right = Text::new("hi").align(TextAlign::Right);
+        assert_eq!(render_text(&right, 6), "    hi");
+
+        let center = Text::new("hi").align(TextAlign::Center);
+        assert_eq!(render_text(&center, 6), "  hi");
+    }
+
+    #[test]
+    fn test_text_justify_fills_width() {
+        let text = Text::new("aa bb cc dd ee ff gg").justify();
+        let content = render_text(&text, 12);
+        let lines: Vec<&str> = content.lines().collect();
+
+        // Every line but the last reaches the exact width
+        for line in &lines[..lines.len() - 1] {
+            assert_eq!(line.chars().count(), 12);
+        }
+        assert_eq!(lines[0], "aa  bb cc dd");
+        assert_eq!(*lines.last().unwrap(), "ee ff gg");
+    }
+
+    #[test]
+    fn test_text_justify_edge_cases() {
+        // Single word lines aren't stretched
+        let single = Text::new("supercalifragilistic word").justify();
+        let content = render_text(&single, 8);
+        assert_eq!(content, "supercal\nifragili\nstic\nword");
+
+        // Zero width doesn't panic
+        let text = Text::new("a b c").justify();
+        assert_eq!(render_text(&text, 0), "");
+    }
 
     #[test]
     fn test_static_text() {
@@ -528,6 +1089,23 @@ mod tests {
         assert_eq!(stack.children.len(), 3);
     }
 
+    #[test]
+    fn test_children_introspection() {
+        fn count(component: &dyn Component) -> usize {
+            1 + component.children().into_iter().map(count).sum::<usize>()
+        }
+
+        let stack = VStack::new()
+            .push(Text::new("Line 1"))
+            .push(Text::new("Line 2"))
+            .push(Text::new("Line 3"));
+        assert_eq!(stack.children().len(), 3);
+        assert!(stack.children()[0].children().is_empty());
+
+        let panel = Panel::new().child(stack);
+        assert_eq!(count(&panel), 5);
+    }
+
     #[test]
     fn test_hstack_creation() {
         let stack = HStack::new()
@@ -539,6 +1117,84 @@ mod tests {
         assert_eq!(stack.gap, 2);
     }
 
+    #[test]
+    fn test_zstack_higher_z_on_top_and_first_for_events() {
+        use crate::event::{KeyEvent, KeyModifiers};
+        use crate::render::Buffer;
+        use crate::state::Store;
+        use std::sync::{Arc, Mutex};
+
+        struct Layer {
+            label: &'static str,
+            seen: Arc<Mutex<Vec<&'static str>>>,
+        }
+
+        impl Component for Layer {
+            fn render(&self, _ctx: &RenderContext) -> ViewNode {
+                ViewNode::text(self.label)
+            }
+
+            fn handle_event(&mut self, _event: &Event, _ctx: &mut EventContext) -> EventResult {
+                self.seen.lock().unwrap().push(self.label);
+                EventResult::Ignored
+            }
+        }
+
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let layer = |label| Layer {
+            label,
+            seen: seen.clone(),
+        };
+        // Inserted out of order; equal z keeps insertion order
+        let mut stack = ZStack::new()
+            .layer(5, layer("TOP"))
+            .push(layer("bottom"))
+            .push(layer("middle"));
+
+        let area = Rect::new(0, 0, 6, 1);
+        let mut buffer = Buffer::new(6, 1);
+        let store = Store::new();
+        let node = stack.render(&RenderContext::new(&mut buffer, area, &store));
+        node.render(&mut RenderContext::new(&mut buffer, area, &store));
+        let row: String = (0..6).map(|x| buffer.get(x, 0).unwrap().ch).collect();
+        assert_eq!(row, "TOPdle");
+
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area,
+        };
+        let key = Event::Key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::empty()));
+        stack.handle_event(&key, &mut ctx);
+        assert_eq!(*seen.lock().unwrap(), vec!["TOP", "middle", "bottom"]);
+    }
+
+    #[test]
+    fn test_hstack_lays_out_side_by_side_with_gap() {
+        let row = HStack::new()
+            .gap(2)
+            .push(Text::new("ab"))
+            .push(Text::new("cd"));
+        crate::assert_renders!(row, 12, 2, &["ab     cd"]);
+
+        let row = HStack::new().push(Text::new("ab")).push(Text::new("cd"));
+        crate::assert_renders!(row, 12, 2, &["ab    cd"]);
+    }
+
+    #[test]
+    fn test_padding_offsets_child() {
+        let padded = Padding::new(Text::new("hello")).top(1).left(3);
+        crate::assert_renders!(padded, 10, 3, &["", "   hello"]);
+
+        // Right padding cuts the child off early
+        let padded = Padding::new(Text::new("hello")).all(1);
+        crate::assert_renders!(padded, 6, 3, &["", " hell"]);
+
+        // More padding than room: nothing drawn
+        let padded = Padding::new(Text::new("hello")).vertical(2);
+        crate::assert_renders!(padded, 6, 3, &[]);
+    }
+
     #[test]
     fn test_panel_with_title() {
         let panel = Panel::new().title("Test Panel").child(Text::new("Content"));
@@ -547,6 +1203,59 @@ mod tests {
         assert_eq!(panel.title.unwrap(), "Test Panel");
     }
 
+    #[test]
+    fn test_focused_panel_border_uses_focus_style() {
+        use crate::theme::Theme;
+
+        let mut focus = crate::focus::FocusManager::new();
+        let input_id = focus.new_id();
+        focus.register(input_id, 0, true);
+        focus.focus(input_id);
+
+        let value = Signal::new(String::new());
+        let panel = Panel::new()
+            .title("Name")
+            .child(Input::new(value).focus_id(input_id));
+
+        let mut buffer = Buffer::new(40, 10);
+        let store = Store::new();
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 40, 10), &store);
+        ctx.focused = focus.current();
+        let focus_style = Theme::default().focus_indicator();
+
+        match panel.render(&ctx) {
+            ViewNode::Border { style, .. } => assert_eq!(style, focus_style),
+            _ => panic!("Expected border node"),
+        }
+
+        // Nothing focused - regular border
+        ctx.focused = None;
+        match panel.render(&ctx) {
+            ViewNode::Border { style, .. } => assert_ne!(style, focus_style),
+            _ => panic!("Expected border node"),
+        }
+    }
+
+    #[test]
+    fn test_render_child_applies_focus_indicator() {
+        let mut focus = crate::focus::FocusManager::new();
+        let id = focus.new_id();
+
+        let button = Button::new("OK", || {}).focus_id(id);
+        let mut buffer = Buffer::new(40, 10);
+        let store = Store::new();
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 40, 10), &store);
+        ctx.focused = Some(id);
+
+        match ctx.render_child(&button) {
+            ViewNode::Text { style, .. } => {
+                assert!(style.modifiers.contains(Modifier::BOLD));
+                assert_eq!(style.fg, ctx.theme.focus_indicator().fg);
+            }
+            _ => panic!("Expected text node"),
+        }
+    }
+
     #[test]
     fn test_panel_render() {
         use crate::layout::Rect;
@@ -562,12 +1271,58 @@ mod tests {
 
         let node = panel.render(&ctx);
 
-        // Should produce a container with border and content
+        // Should produce a border around the content
         match node {
-            ViewNode::Container { children, .. } => {
-                assert!(children.len() >= 2); // At least border and content
+            ViewNode::Border { child, title, .. } => {
+                assert_eq!(title.as_deref(), Some(" Info "));
+                assert!(matches!(*child, ViewNode::Text { .. }));
             }
-            _ => panic!("Expected container node"),
+            _ => panic!("Expected border node"),
         }
     }
+
+    #[test]
+    fn test_panel_border_fills_area() {
+        let panel = Panel::new().title("Info").child(Text::new("hi"));
+
+        let mut buffer = Buffer::new(16, 8);
+        let store = Store::new();
+        let area = Rect::new(2, 1, 12, 5);
+        let mut ctx = RenderContext::new(&mut buffer, area, &store);
+        panel.render(&ctx).render(&mut ctx);
+
+        let at = |x, y| buffer.get(x, y).unwrap().ch;
+        assert_eq!(at(2, 1), '┌');
+        assert_eq!(at(13, 1), '┐');
+        assert_eq!(at(2, 5), '└');
+        assert_eq!(at(13, 5), '┘');
+        assert_eq!((at(2, 3), at(13, 3)), ('│', '│'));
+        assert_eq!(at(4, 1), 'I');
+
+        // Content starts inside the border
+        assert_eq!(at(3, 2), 'h');
+    }
+
+    #[test]
+    fn test_panel_ascii_mode() {
+        let panel = Panel::new().title("Info").child(Text::new("hi"));
+
+        let mut buffer = Buffer::new(12, 3);
+        let store = Store::new();
+        let mut ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 12, 3), &store);
+        ctx.mode = RenderMode::Ascii;
+
+        let node = panel.render(&ctx);
+        node.render(&mut ctx);
+
+        let row = |y: u16| -> String {
+            (0..12)
+                .map(|x| buffer.get(x, y).unwrap().ch)
+                .filter(|&c| c != '\0')
+                .collect()
+        };
+        assert_eq!(row(0), "+ Info ----+");
+        assert_eq!(row(1), "|hi|");
+        assert_eq!(row(2), "+----------+");
+    }
 }
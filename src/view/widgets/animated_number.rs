@@ -0,0 +1,211 @@
+//! Number display that counts smoothly to new values
+//!
+//! Handy for dashboard metrics, where a jump from 1,204 to 1,377 is easier
+//! to follow as a quick count-up.
+
+use crate::animation::{EasingFunction, Tween};
+use crate::state::Signal;
+use crate::theme::Style;
+use crate::view::{Component, RenderContext, UpdateContext, ViewNode};
+use std::time::Duration;
+
+/// Shows a `Signal<f64>`, animating from the shown value to each new one
+///
+/// Time comes from the frame loop through `Component::update`, so the
+/// count runs at whatever rate the app renders. A new value arriving
+/// mid-animation restarts from the number currently on screen, so rapid
+/// updates never jump.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+/// use std::time::Duration;
+///
+/// let requests = Signal::new(0.0);
+/// let counter = AnimatedNumber::new(requests.clone())
+///     .duration(Duration::from_millis(500))
+///     .decimals(1)
+///     .separator(',');
+///
+/// requests.set(12_345.6); // counts up to "12,345.6"
+/// ```
+pub struct AnimatedNumber {
+    value: Signal<f64>,
+    /// Last value read from the signal
+    target: f64,
+    /// Value on screen
+    displayed: f64,
+    tween: Option<Tween<f64>>,
+    duration: Duration,
+    easing: EasingFunction,
+    decimals: usize,
+    separator: Option<char>,
+    style: Style,
+}
+
+impl AnimatedNumber {
+    /// Create a counter showing `value`, starting at its current value
+    pub fn new(value: Signal<f64>) -> Self {
+        let current = value.get();
+        Self {
+            value,
+            target: current,
+            displayed: current,
+            tween: None,
+            duration: Duration::from_millis(300),
+            easing: EasingFunction::EaseOutCubic,
+            decimals: 0,
+            separator: None,
+            style: Style::default(),
+        }
+    }
+
+    /// Set how long a count to a new value takes
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Set the easing curve of the count
+    pub fn easing(mut self, easing: EasingFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Set the number of digits after the decimal point
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = decimals;
+        self
+    }
+
+    /// Group thousands with `separator`, e.g. `','` for `1,234,567`
+    pub fn separator(mut self, separator: char) -> Self {
+        self.separator = Some(separator);
+        self
+    }
+
+    /// Set the text style
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// The value currently on screen
+    pub fn displayed(&self) -> f64 {
+        self.displayed
+    }
+
+    /// Check if a count is in progress
+    pub fn is_animating(&self) -> bool {
+        self.tween.is_some()
+    }
+
+    /// The on-screen value, formatted
+    pub fn text(&self) -> String {
+        format_number(self.displayed, self.decimals, self.separator)
+    }
+}
+
+impl Component for AnimatedNumber {
+    fn render(&self, _ctx: &RenderContext) -> ViewNode {
+        ViewNode::text_styled(self.text(), self.style)
+    }
+
+    fn update(&mut self, ctx: &mut UpdateContext) -> bool {
+        let target = self.value.get();
+        if target != self.target {
+            self.target = target;
+            self.tween =
+                Some(Tween::new(self.displayed, target, self.duration).easing(self.easing));
+        }
+
+        let Some(ref mut tween) = self.tween else {
+            return false;
+        };
+        tween.update(ctx.delta);
+        self.displayed = tween.value();
+        if tween.is_complete() {
+            self.displayed = self.target;
+            self.tween = None;
+        }
+        true
+    }
+}
+
+/// Format with fixed decimals and optional thousands grouping
+///
+/// Values that round to zero never show a minus sign.
+fn format_number(value: f64, decimals: usize, separator: Option<char>) -> String {
+    let digits = format!("{:.*}", decimals, value.abs());
+    let (int_part, frac_part) = match digits.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (digits.as_str(), None),
+    };
+
+    let mut out = String::new();
+    if value < 0.0 && digits.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        out.push('-');
+    }
+    for (i, ch) in int_part.chars().enumerate() {
+        if let Some(sep) = separator {
+            if i > 0 && (int_part.len() - i) % 3 == 0 {
+                out.push(sep);
+            }
+        }
+        out.push(ch);
+    }
+    if let Some(frac_part) = frac_part {
+        out.push('.');
+        out.push_str(frac_part);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::Store;
+
+    fn tick(number: &mut AnimatedNumber, ms: u64) -> bool {
+        let store = Store::new();
+        let mut ctx = UpdateContext {
+            store: &store,
+            delta: Duration::from_millis(ms),
+        };
+        number.update(&mut ctx)
+    }
+
+    #[test]
+    fn test_counts_from_old_to_new_value() {
+        let value = Signal::new(100.0);
+        let mut number = AnimatedNumber::new(value.clone())
+            .duration(Duration::from_millis(400))
+            .easing(EasingFunction::Linear);
+        assert!(!tick(&mut number, 100));
+        assert_eq!(number.text(), "100");
+
+        value.set(500.0);
+        assert!(tick(&mut number, 100));
+        assert_eq!(number.displayed(), 200.0);
+        tick(&mut number, 100);
+        assert_eq!(number.text(), "300");
+
+        // Retarget mid-count: carries on from what's on screen
+        value.set(0.0);
+        tick(&mut number, 200);
+        assert_eq!(number.displayed(), 150.0);
+        tick(&mut number, 200);
+        assert_eq!(number.displayed(), 0.0);
+        assert!(!number.is_animating());
+        assert!(!tick(&mut number, 100));
+    }
+
+    #[test]
+    fn test_formatting() {
+        assert_eq!(format_number(1234567.891, 2, Some(',')), "1,234,567.89");
+        assert_eq!(format_number(-1234567.891, 0, Some(',')), "-1,234,568");
+        assert_eq!(format_number(-999.5, 1, Some('_')), "-999.5");
+        assert_eq!(format_number(123456.0, 0, None), "123456");
+        assert_eq!(format_number(-0.001, 2, Some(',')), "0.00");
+    }
+}
@@ -0,0 +1,243 @@
+//! Color picker widget
+//!
+//! R/G/B channel steppers with a live swatch and hex readout.
+
+use crate::event::{Event, EventResult, KeyCode, KeyModifiers};
+use crate::focus::ComponentId;
+use crate::state::Signal;
+use crate::theme::{Color, Style};
+use crate::view::{Component, EventContext, Glyphs, RenderContext, ViewNode};
+
+const CHANNELS: [&str; 3] = ["R", "G", "B"];
+
+/// Keyboard-driven RGB color picker bound to a `Signal<Color>`
+///
+/// Up/Down pick the channel, Left/Right step it by `step` (Shift for
+/// `big_step`). Channels stop at 0 and 255.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let accent = Signal::new(Color::rgb(0, 128, 255));
+/// let picker = ColorPicker::new(accent).step(5).focused(true);
+/// ```
+pub struct ColorPicker {
+    color: Signal<Color>,
+    /// Channel being edited (0 = red, 1 = green, 2 = blue)
+    channel: usize,
+    step: u8,
+    big_step: u8,
+    focused: bool,
+    focus_id: Option<ComponentId>,
+    style: ColorPickerStyle,
+}
+
+#[derive(Clone)]
+struct ColorPickerStyle {
+    normal: Style,
+    active: Style,
+    label: Style,
+}
+
+impl Default for ColorPickerStyle {
+    fn default() -> Self {
+        Self {
+            normal: Style::default().fg(Color::WHITE),
+            active: Style::fg_bg(Color::WHITE, Color::gray(60)).bold(),
+            label: Style::default().fg(Color::GRAY),
+        }
+    }
+}
+
+impl ColorPicker {
+    /// Create a picker editing the given color signal
+    pub fn new(color: Signal<Color>) -> Self {
+        Self {
+            color,
+            channel: 0,
+            step: 1,
+            big_step: 16,
+            focused: false,
+            focus_id: None,
+            style: ColorPickerStyle::default(),
+        }
+    }
+
+    /// Set the Left/Right step
+    pub fn step(mut self, step: u8) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Set the Shift+Left/Right step
+    pub fn big_step(mut self, step: u8) -> Self {
+        self.big_step = step;
+        self
+    }
+
+    /// Set focused state
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Set the focus id used for focus highlighting
+    pub fn focus_id(mut self, id: ComponentId) -> Self {
+        self.focus_id = Some(id);
+        self
+    }
+
+    /// Index of the channel being edited (0 = red, 1 = green, 2 = blue)
+    pub fn channel(&self) -> usize {
+        self.channel
+    }
+
+    /// Current color as `#RRGGBB`
+    pub fn hex(&self) -> String {
+        self.color.get().to_hex()
+    }
+
+    /// Change the active channel by `delta`, clamped to 0-255
+    fn adjust(&mut self, delta: i16) {
+        let channel = self.channel;
+        self.color.set_with(|c| {
+            let mut c = *c;
+            let value = match channel {
+                0 => &mut c.r,
+                1 => &mut c.g,
+                _ => &mut c.b,
+            };
+            *value = (*value as i16 + delta).clamp(0, 255) as u8;
+            c
+        });
+    }
+
+    fn channel_row(&self, index: usize, value: u8, glyphs: &Glyphs) -> ViewNode {
+        let active = self.focused && index == self.channel;
+        let marker = if active { glyphs.pointer } else { ' ' };
+        let style = if active {
+            self.style.active
+        } else {
+            self.style.normal
+        };
+        ViewNode::text_styled(
+            format!(
+                "{}{} {} {:>3} {}",
+                marker, CHANNELS[index], glyphs.arrow_left, value, glyphs.arrow_right
+            ),
+            style,
+        )
+    }
+}
+
+impl Component for ColorPicker {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        let color = self.color.get();
+        let glyphs = ctx.glyphs();
+
+        ViewNode::container(vec![
+            self.channel_row(0, color.r, glyphs),
+            self.channel_row(1, color.g, glyphs),
+            self.channel_row(2, color.b, glyphs),
+            ViewNode::text_styled("        ", Style::default().bg(color)),
+            ViewNode::text_styled(color.to_hex(), self.style.label),
+        ])
+    }
+
+    fn handle_event(&mut self, event: &Event, _ctx: &mut EventContext) -> EventResult {
+        let Event::Key(key) = event else {
+            return EventResult::Ignored;
+        };
+        if !self.focused {
+            return EventResult::Ignored;
+        }
+
+        let step = if key.modifiers.contains(KeyModifiers::SHIFT) {
+            self.big_step
+        } else {
+            self.step
+        } as i16;
+
+        match key.code {
+            KeyCode::Up => self.channel = self.channel.saturating_sub(1),
+            KeyCode::Down => self.channel = (self.channel + 1).min(CHANNELS.len() - 1),
+            KeyCode::Left => self.adjust(-step),
+            KeyCode::Right => self.adjust(step),
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Handled
+    }
+
+    fn focus_id(&self) -> Option<ComponentId> {
+        self.focus_id
+    }
+
+    fn on_focus(&mut self, _ctx: &mut EventContext) {
+        self.focused = true;
+    }
+
+    fn on_blur(&mut self, _ctx: &mut EventContext) {
+        self.focused = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::KeyEvent;
+    use crate::layout::Rect;
+    use crate::state::Store;
+
+    fn press(picker: &mut ColorPicker, code: KeyCode, modifiers: KeyModifiers) {
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 20, 5),
+        };
+        picker.handle_event(&Event::Key(KeyEvent::new(code, modifiers)), &mut ctx);
+    }
+
+    #[test]
+    fn test_stepping_red_updates_color_and_hex() {
+        let color = Signal::new(Color::rgb(16, 32, 48));
+        let mut picker = ColorPicker::new(color.clone()).focused(true);
+
+        press(&mut picker, KeyCode::Right, KeyModifiers::empty());
+        assert_eq!(color.get(), Color::rgb(17, 32, 48));
+        assert_eq!(picker.hex(), "#112030");
+
+        press(&mut picker, KeyCode::Right, KeyModifiers::SHIFT);
+        assert_eq!(color.get().r, 33);
+        assert_eq!(picker.hex(), "#212030");
+    }
+
+    #[test]
+    fn test_channels_clamp() {
+        let color = Signal::new(Color::rgb(250, 5, 0));
+        let mut picker = ColorPicker::new(color.clone()).big_step(100).focused(true);
+
+        press(&mut picker, KeyCode::Right, KeyModifiers::SHIFT);
+        assert_eq!(color.get().r, 255);
+
+        press(&mut picker, KeyCode::Down, KeyModifiers::empty());
+        press(&mut picker, KeyCode::Left, KeyModifiers::SHIFT);
+        assert_eq!(color.get().g, 0);
+        assert_eq!(picker.hex(), "#FF0000");
+    }
+
+    #[test]
+    fn test_channel_selection_stops_at_ends() {
+        let mut picker = ColorPicker::new(Signal::new(Color::BLACK)).focused(true);
+
+        press(&mut picker, KeyCode::Up, KeyModifiers::empty());
+        assert_eq!(picker.channel(), 0);
+        for _ in 0..5 {
+            press(&mut picker, KeyCode::Down, KeyModifiers::empty());
+        }
+        assert_eq!(picker.channel(), 2);
+
+        press(&mut picker, KeyCode::Right, KeyModifiers::empty());
+        assert_eq!(picker.hex(), "#000001");
+    }
+}
@@ -0,0 +1,245 @@
+//! Yes/no confirmation dialog
+//!
+//! A centered box with a message and two buttons, meant to be shown as a
+//! modal. With the `tokio` feature, `App::prompt` shows one and awaits
+//! the answer.
+
+use crate::event::{Event, EventResult, KeyCode};
+use crate::layout::Length;
+use crate::theme::{Color, Modifier, Style};
+use crate::view::{
+    BorderSet, Component, ContainerDirection, Dialog, EventContext, RenderContext, ViewNode,
+};
+use unicode_width::UnicodeWidthStr;
+
+/// Asks a yes/no question and closes itself once answered
+///
+/// Left/Right or Tab move between the buttons and Enter picks the
+/// highlighted one; `y` and `n` answer directly. Escape is left to the
+/// app, which closes the modal - that counts as "no".
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let dialog = ConfirmDialog::new("Discard unsaved changes?")
+///     .title("Quit")
+///     .labels("Discard", "Keep editing");
+/// ```
+pub struct ConfirmDialog {
+    message: String,
+    title: Option<String>,
+    confirm_label: String,
+    cancel_label: String,
+    /// Confirm button highlighted (otherwise cancel)
+    on_confirm: bool,
+    result: Option<bool>,
+    style: ConfirmDialogStyle,
+}
+
+#[derive(Clone)]
+struct ConfirmDialogStyle {
+    border: Style,
+    button: Style,
+    selected: Style,
+}
+
+impl Default for ConfirmDialogStyle {
+    fn default() -> Self {
+        Self {
+            border: Style::default()
+                .fg(Color::CYAN)
+                .add_modifier(Modifier::BOLD),
+            button: Style::default().fg(Color::WHITE),
+            selected: Style::default()
+                .fg(Color::BLACK)
+                .bg(Color::CYAN)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+}
+
+impl ConfirmDialog {
+    /// Create a dialog asking `message`, with "Yes" highlighted
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+            title: None,
+            confirm_label: "Yes".to_string(),
+            cancel_label: "No".to_string(),
+            on_confirm: true,
+            result: None,
+            style: ConfirmDialogStyle::default(),
+        }
+    }
+
+    /// Set the title shown on the top border
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Set the button labels (default "Yes" and "No")
+    pub fn labels(mut self, confirm: impl Into<String>, cancel: impl Into<String>) -> Self {
+        self.confirm_label = confirm.into();
+        self.cancel_label = cancel.into();
+        self
+    }
+
+    /// Highlight the cancel button at first, so a stray Enter says no
+    pub fn default_cancel(mut self) -> Self {
+        self.on_confirm = false;
+        self
+    }
+
+    fn answer(&mut self, confirmed: bool, ctx: &mut EventContext) -> EventResult {
+        self.result = Some(confirmed);
+        ctx.close_modal();
+        EventResult::Consumed
+    }
+
+    fn button(&self, label: &str, selected: bool) -> ViewNode {
+        let style = if selected {
+            self.style.selected
+        } else {
+            self.style.button
+        };
+        ViewNode::text_styled(format!("[ {} ]", label), style)
+    }
+}
+
+impl Component for ConfirmDialog {
+    fn render(&self, _ctx: &RenderContext) -> ViewNode {
+        let confirm = format!("[ {} ]", self.confirm_label);
+        let cancel = format!("[ {} ]", self.cancel_label);
+        let buttons_width = (confirm.width() + cancel.width() + 2) as u16;
+        let inner_width = (self.message.width() as u16).max(buttons_width) + 2;
+
+        let buttons = ViewNode::sized(
+            ContainerDirection::Horizontal,
+            vec![
+                (Length::Fill(1), ViewNode::Empty),
+                (
+                    Length::Fixed(confirm.width() as u16 + 2),
+                    self.button(&self.confirm_label, self.on_confirm),
+                ),
+                (
+                    Length::Fixed(cancel.width() as u16),
+                    self.button(&self.cancel_label, !self.on_confirm),
+                ),
+                (Length::Fill(1), ViewNode::Empty),
+            ],
+        );
+        let body = ViewNode::sized(
+            ContainerDirection::Vertical,
+            vec![
+                (
+                    Length::Fixed(1),
+                    ViewNode::text(format!(" {}", self.message)),
+                ),
+                (Length::Fixed(1), ViewNode::Empty),
+                (Length::Fixed(1), buttons),
+            ],
+        );
+        let dialog = ViewNode::Border {
+            child: Box::new(body),
+            border: BorderSet::DOUBLE,
+            title: self.title.clone(),
+            style: self.style.border,
+        };
+
+        // Centered in whatever area the modal gets
+        let column = ViewNode::sized(
+            ContainerDirection::Vertical,
+            vec![
+                (Length::Fill(1), ViewNode::Empty),
+                (Length::Fixed(5), dialog),
+                (Length::Fill(1), ViewNode::Empty),
+            ],
+        );
+        ViewNode::sized(
+            ContainerDirection::Horizontal,
+            vec![
+                (Length::Fill(1), ViewNode::Empty),
+                (Length::Fixed(inner_width + 2), column),
+                (Length::Fill(1), ViewNode::Empty),
+            ],
+        )
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut EventContext) -> EventResult {
+        let Event::Key(key) = event else {
+            return EventResult::Ignored;
+        };
+        match key.code {
+            KeyCode::Left | KeyCode::Right | KeyCode::Tab | KeyCode::BackTab => {
+                self.on_confirm = !self.on_confirm;
+                EventResult::Handled
+            }
+            KeyCode::Enter => self.answer(self.on_confirm, ctx),
+            KeyCode::Char('y') | KeyCode::Char('Y') => self.answer(true, ctx),
+            KeyCode::Char('n') | KeyCode::Char('N') => self.answer(false, ctx),
+            _ => EventResult::Ignored,
+        }
+    }
+}
+
+impl Dialog for ConfirmDialog {
+    type Output = bool;
+
+    fn take_result(&mut self) -> Option<bool> {
+        self.result.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{KeyEvent, KeyModifiers};
+    use crate::layout::Rect;
+    use crate::state::Store;
+    use crate::testing::{buffer_lines, render_to_buffer};
+    use crate::view::CLOSE_MODAL_REQUEST_KEY;
+
+    fn press(dialog: &mut ConfirmDialog, code: KeyCode, store: &mut Store) -> EventResult {
+        let mut ctx = EventContext {
+            store,
+            area: Rect::new(0, 0, 30, 7),
+        };
+        dialog.handle_event(
+            &Event::Key(KeyEvent::new(code, KeyModifiers::empty())),
+            &mut ctx,
+        )
+    }
+
+    #[test]
+    fn test_renders_centered() {
+        let lines = buffer_lines(&render_to_buffer(&ConfirmDialog::new("Quit?"), 29, 7));
+        assert_eq!(lines[0], "");
+        assert_eq!(lines[1], "     ╔═════════════════╗");
+        assert_eq!(lines[2], "     ║ Quit?           ║");
+        assert_eq!(lines[4], "     ║ [ Yes ]  [ No ] ║");
+        assert_eq!(lines[5], "     ╚═════════════════╝");
+        assert_eq!(lines[6], "");
+    }
+
+    #[test]
+    fn test_answers_and_asks_to_close() {
+        let mut store = Store::new();
+        let mut dialog = ConfirmDialog::new("Quit?");
+        assert_eq!(
+            press(&mut dialog, KeyCode::Esc, &mut store),
+            EventResult::Ignored
+        );
+        assert_eq!(dialog.take_result(), None);
+
+        press(&mut dialog, KeyCode::Right, &mut store);
+        press(&mut dialog, KeyCode::Enter, &mut store);
+        assert_eq!(dialog.take_result(), Some(false));
+        assert!(store.remove(CLOSE_MODAL_REQUEST_KEY));
+
+        press(&mut dialog, KeyCode::Char('y'), &mut store);
+        assert_eq!(dialog.take_result(), Some(true));
+        assert_eq!(dialog.take_result(), None);
+    }
+}
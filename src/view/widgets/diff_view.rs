@@ -0,0 +1,378 @@
+//! Diff view widget
+//!
+//! Line-based diff between two texts with colored +/- gutters.
+
+use crate::event::{Event, EventResult, KeyCode};
+use crate::theme::{Color, Style};
+use crate::view::{Component, EventContext, RenderContext, ViewNode};
+
+/// What happened to a line between old and new
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    Unchanged,
+    Added,
+    Removed,
+}
+
+impl DiffKind {
+    fn gutter(&self) -> char {
+        match self {
+            DiffKind::Unchanged => ' ',
+            DiffKind::Added => '+',
+            DiffKind::Removed => '-',
+        }
+    }
+}
+
+/// A single line of diff output
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffLine {
+    pub kind: DiffKind,
+    pub text: String,
+}
+
+/// Compute a line diff using longest common subsequence
+///
+/// Common prefix/suffix get trimmed first, and the part that actually
+/// changed is diffed with Hirschberg's algorithm: still O(n*m) time, but
+/// memory only grows with the line counts, not their product.
+pub fn diff_lines(old: &[String], new: &[String]) -> Vec<DiffLine> {
+    let prefix = old
+        .iter()
+        .zip(new.iter())
+        .take_while(|(a, b)| a == b)
+        .count();
+    let suffix = old[prefix..]
+        .iter()
+        .rev()
+        .zip(new[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+
+    let a = &old[prefix..old.len() - suffix];
+    let b = &new[prefix..new.len() - suffix];
+
+    let mut out: Vec<DiffLine> = old[..prefix]
+        .iter()
+        .map(|t| diff_line(DiffKind::Unchanged, t))
+        .collect();
+    diff_middle(a, b, &mut out);
+    out.extend(
+        old[old.len() - suffix..]
+            .iter()
+            .map(|t| diff_line(DiffKind::Unchanged, t)),
+    );
+
+    out
+}
+
+fn diff_line(kind: DiffKind, text: &str) -> DiffLine {
+    DiffLine {
+        kind,
+        text: text.to_string(),
+    }
+}
+
+/// Hirschberg's divide and conquer: split `a` in half, find where `b`
+/// splits along an optimal alignment, and diff each side
+fn diff_middle(a: &[String], b: &[String], out: &mut Vec<DiffLine>) {
+    if a.is_empty() {
+        out.extend(b.iter().map(|t| diff_line(DiffKind::Added, t)));
+        return;
+    }
+    if b.is_empty() {
+        out.extend(a.iter().map(|t| diff_line(DiffKind::Removed, t)));
+        return;
+    }
+    if a.len() == 1 {
+        match b.iter().position(|t| *t == a[0]) {
+            Some(k) => {
+                out.extend(b[..k].iter().map(|t| diff_line(DiffKind::Added, t)));
+                out.push(diff_line(DiffKind::Unchanged, &a[0]));
+                out.extend(b[k + 1..].iter().map(|t| diff_line(DiffKind::Added, t)));
+            }
+            None => {
+                // Removals first, so a changed line reads as "- old" then "+ new"
+                out.push(diff_line(DiffKind::Removed, &a[0]));
+                out.extend(b.iter().map(|t| diff_line(DiffKind::Added, t)));
+            }
+        }
+        return;
+    }
+
+    let mid = a.len() / 2;
+    let front = lcs_lengths(a[..mid].iter(), b.iter());
+    let back = lcs_lengths(a[mid..].iter().rev(), b.iter().rev());
+
+    // front[j] + back[m - j] is the best LCS splitting b at j; the first
+    // best split keeps removals ahead of additions
+    let m = b.len();
+    let mut split = 0;
+    for j in 1..=m {
+        if front[j] + back[m - j] > front[split] + back[m - split] {
+            split = j;
+        }
+    }
+
+    diff_middle(&a[..mid], &b[..split], out);
+    diff_middle(&a[mid..], &b[split..], out);
+}
+
+/// LCS length of all of `a` with each prefix of `b` (index = prefix
+/// length), keeping only one row of the table
+fn lcs_lengths<'a>(
+    a: impl Iterator<Item = &'a String>,
+    b: impl Iterator<Item = &'a String> + Clone,
+) -> Vec<u32> {
+    let mut row = vec![0u32; b.clone().count() + 1];
+    for x in a {
+        // row[j] from the previous line of `a`, before it's overwritten
+        let mut diagonal = 0;
+        for (j, y) in b.clone().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = if x == y {
+                diagonal + 1
+            } else {
+                above.max(row[j])
+            };
+            diagonal = above;
+        }
+    }
+    row
+}
+
+/// Scrollable view of the differences between two texts
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let old = vec!["fn main() {".to_string(), "    old();".to_string(), "}".to_string()];
+/// let new = vec!["fn main() {".to_string(), "    new();".to_string(), "}".to_string()];
+///
+/// let view = DiffView::new(old, new).visible_height(20);
+/// ```
+pub struct DiffView {
+    lines: Vec<DiffLine>,
+    scroll_offset: usize,
+    visible_height: usize,
+    style: DiffStyle,
+}
+
+#[derive(Clone)]
+struct DiffStyle {
+    unchanged: Style,
+    added: Style,
+    removed: Style,
+}
+
+impl Default for DiffStyle {
+    fn default() -> Self {
+        Self {
+            unchanged: Style::default(),
+            added: Style::default().fg(Color::GREEN),
+            removed: Style::default().fg(Color::RED),
+        }
+    }
+}
+
+impl DiffView {
+    /// Diff `old` against `new`
+    pub fn new(old: Vec<String>, new: Vec<String>) -> Self {
+        Self {
+            lines: diff_lines(&old, &new),
+            scroll_offset: 0,
+            visible_height: 20,
+            style: DiffStyle::default(),
+        }
+    }
+
+    /// Set visible height (lines shown at once)
+    pub fn visible_height(mut self, height: usize) -> Self {
+        self.visible_height = height;
+        self
+    }
+
+    /// The computed diff
+    pub fn lines(&self) -> &[DiffLine] {
+        &self.lines
+    }
+
+    /// Scroll down by `n` lines, stopping at the end
+    pub fn scroll_down(&mut self, n: usize) {
+        let max = self.lines.len().saturating_sub(self.visible_height);
+        self.scroll_offset = (self.scroll_offset + n).min(max);
+    }
+
+    /// Scroll up by `n` lines
+    pub fn scroll_up(&mut self, n: usize) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(n);
+    }
+
+    fn style_for(&self, kind: DiffKind) -> Style {
+        match kind {
+            DiffKind::Unchanged => self.style.unchanged,
+            DiffKind::Added => self.style.added,
+            DiffKind::Removed => self.style.removed,
+        }
+    }
+}
+
+impl Component for DiffView {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        if self.lines.is_empty() {
+            return ViewNode::text_styled("(no changes)", Style::default().fg(Color::GRAY));
+        }
+
+        let width = ctx.area.width as usize;
+        let end = (self.scroll_offset + self.visible_height).min(self.lines.len());
+
+        let children = self.lines[self.scroll_offset..end]
+            .iter()
+            .map(|line| {
+                let mut content = format!("{} {}", line.kind.gutter(), line.text);
+
+                // Long lines get cut off rather than spilling past the area
+                if width > 0 {
+                    content = ctx.glyphs().truncate(&content, width);
+                }
+
+                ViewNode::text_styled(content, self.style_for(line.kind))
+            })
+            .collect();
+
+        ViewNode::container(children)
+    }
+
+    fn handle_event(&mut self, event: &Event, _ctx: &mut EventContext) -> EventResult {
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Down => self.scroll_down(1),
+                KeyCode::Up => self.scroll_up(1),
+                KeyCode::PageDown => self.scroll_down(self.visible_height),
+                KeyCode::PageUp => self.scroll_up(self.visible_height),
+                KeyCode::Home => self.scroll_offset = 0,
+                KeyCode::End => self.scroll_down(self.lines.len()),
+                _ => return EventResult::Ignored,
+            },
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Handled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Rect;
+    use crate::render::Buffer;
+    use crate::state::Store;
+
+    fn lines(text: &[&str]) -> Vec<String> {
+        text.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_single_changed_line() {
+        let diff = diff_lines(&lines(&["a", "b", "c"]), &lines(&["a", "x", "c"]));
+
+        let removed: Vec<_> = diff
+            .iter()
+            .filter(|l| l.kind == DiffKind::Removed)
+            .collect();
+        let added: Vec<_> = diff.iter().filter(|l| l.kind == DiffKind::Added).collect();
+
+        assert_eq!(removed.len(), 1);
+        assert_eq!(removed[0].text, "b");
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].text, "x");
+        assert_eq!(diff.len(), 4);
+        assert_eq!(diff[1].kind, DiffKind::Removed);
+        assert_eq!(diff[2].kind, DiffKind::Added);
+    }
+
+    #[test]
+    fn test_insertions_and_deletions() {
+        let diff = diff_lines(&lines(&["a", "b", "c", "d"]), &lines(&["b", "c", "e", "d"]));
+        let kinds: Vec<_> = diff.iter().map(|l| l.kind.gutter()).collect();
+        assert_eq!(kinds, vec!['-', ' ', ' ', '+', ' ']);
+    }
+
+    #[test]
+    fn test_large_inputs_with_scattered_changes() {
+        let old: Vec<String> = (0..2000).map(|i| format!("line {}", i)).collect();
+        let mut new = old.clone();
+        for i in (7..2000).step_by(100) {
+            new[i] = format!("changed {}", i);
+        }
+        new.insert(1000, "inserted".to_string());
+
+        let diff = diff_lines(&old, &new);
+        let count = |kind| diff.iter().filter(|l| l.kind == kind).count();
+        assert_eq!(count(DiffKind::Unchanged), 2000 - 20);
+        assert_eq!(count(DiffKind::Removed), 20);
+        assert_eq!(count(DiffKind::Added), 21);
+
+        // Replaying the diff gives back both sides
+        let side = |skip| {
+            diff.iter()
+                .filter(|l| l.kind != skip)
+                .map(|l| l.text.clone())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(side(DiffKind::Added), old);
+        assert_eq!(side(DiffKind::Removed), new);
+    }
+
+    #[test]
+    fn test_empty_inputs() {
+        assert!(diff_lines(&[], &[]).is_empty());
+
+        let added = diff_lines(&[], &lines(&["new"]));
+        assert_eq!(added.len(), 1);
+        assert_eq!(added[0].kind, DiffKind::Added);
+
+        let removed = diff_lines(&lines(&["old"]), &[]);
+        assert_eq!(removed[0].kind, DiffKind::Removed);
+    }
+
+    #[test]
+    fn test_render_gutters_and_truncation() {
+        let view = DiffView::new(
+            lines(&["same", "gone"]),
+            lines(&["same", "a very long new line"]),
+        );
+
+        let mut buffer = Buffer::new(10, 5);
+        let store = Store::new();
+        let ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 10, 5), &store);
+
+        match view.render(&ctx) {
+            ViewNode::Container { children, .. } => {
+                let texts: Vec<_> = children
+                    .iter()
+                    .map(|c| match c {
+                        ViewNode::Text { content, style } => (content.clone(), style.fg),
+                        _ => panic!("Expected text node"),
+                    })
+                    .collect();
+
+                assert_eq!(texts[0], ("  same".to_string(), None));
+                assert_eq!(texts[1], ("- gone".to_string(), Some(Color::RED)));
+                assert_eq!(texts[2], ("+ a very …".to_string(), Some(Color::GREEN)));
+            }
+            _ => panic!("Expected container node"),
+        }
+    }
+
+    #[test]
+    fn test_scrolling_clamps() {
+        let old: Vec<String> = (0..30).map(|i| i.to_string()).collect();
+        let mut view = DiffView::new(old.clone(), old).visible_height(10);
+
+        view.scroll_down(100);
+        assert_eq!(view.scroll_offset, 20);
+        view.scroll_up(5);
+        assert_eq!(view.scroll_offset, 15);
+    }
+}
@@ -3,9 +3,14 @@
 //! A text input field with cursor positioning, validation, and styling.
 
 use crate::event::{Event, EventResult, KeyCode, KeyModifiers};
+use crate::focus::ComponentId;
 use crate::state::Signal;
 use crate::theme::{Color, Modifier, Style};
 use crate::view::{Component, EventContext, RenderContext, ViewNode};
+use std::sync::Arc;
+#[cfg(feature = "unicode-normalization")]
+use unicode_normalization::UnicodeNormalization;
+use unicode_segmentation::UnicodeSegmentation;
 
 /// Text input widget with cursor and validation
 ///
@@ -16,7 +21,9 @@ use crate::view::{Component, EventContext, RenderContext, ViewNode};
 /// let username = Signal::new(String::new());
 /// let input = Input::new(username)
 ///     .placeholder("Enter username")
-///     .max_length(20);
+///     .max_length(20)
+///     .on_submit(|name| println!("Hello, {}", name))
+///     .clear_on_submit(true);
 /// ```
 pub struct Input {
     value: Signal<String>,
@@ -26,6 +33,12 @@ pub struct Input {
     max_length: Option<usize>,
     focused: bool,
     style: InputStyle,
+    focus_id: Option<ComponentId>,
+    on_submit: Option<Arc<dyn Fn(&str) + Send + Sync>>,
+    clear_on_submit: bool,
+    submit_empty: bool,
+    #[cfg(feature = "unicode-normalization")]
+    normalize: bool,
 }
 
 #[derive(Clone)]
@@ -38,12 +51,12 @@ struct InputStyle {
 impl Default for InputStyle {
     fn default() -> Self {
         Self {
-            normal: Style::default().fg(Color::WHITE).bg(Color::rgb(40, 40, 40)),
+            normal: Style::default().fg(Color::WHITE).bg(Color::gray(40)),
             focused: Style::default()
                 .fg(Color::WHITE)
                 .bg(Color::rgb(60, 60, 80))
                 .add_modifier(Modifier::BOLD),
-            placeholder: Style::default().fg(Color::GRAY).bg(Color::rgb(40, 40, 40)),
+            placeholder: Style::default().fg(Color::GRAY).bg(Color::gray(40)),
         }
     }
 }
@@ -59,6 +72,12 @@ impl Input {
             max_length: None,
             focused: false,
             style: InputStyle::default(),
+            focus_id: None,
+            on_submit: None,
+            clear_on_submit: false,
+            submit_empty: false,
+            #[cfg(feature = "unicode-normalization")]
+            normalize: false,
         }
     }
 
@@ -86,6 +105,59 @@ impl Input {
         self
     }
 
+    /// Set the focus id used for focus highlighting
+    pub fn focus_id(mut self, id: ComponentId) -> Self {
+        self.focus_id = Some(id);
+        self
+    }
+
+    /// Call `f` with the value when Enter is pressed while focused
+    ///
+    /// Without a handler Enter is left for the parent to deal with.
+    pub fn on_submit(mut self, f: impl Fn(&str) + Send + Sync + 'static) -> Self {
+        self.on_submit = Some(Arc::new(f));
+        self
+    }
+
+    /// Empty the input after each submit
+    pub fn clear_on_submit(mut self, clear: bool) -> Self {
+        self.clear_on_submit = clear;
+        self
+    }
+
+    /// Whether Enter on an empty input still submits (off by default)
+    pub fn submit_empty(mut self, submit: bool) -> Self {
+        self.submit_empty = submit;
+        self
+    }
+
+    /// Compose typed text to NFC, so e.g. "e" followed by a combining
+    /// acute accent is stored as a single "é" (off by default)
+    ///
+    /// The cursor counts chars, so without this a decomposed character
+    /// takes two cursor steps. Leave it off if you need input byte-for-byte.
+    #[cfg(feature = "unicode-normalization")]
+    pub fn normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Run the submit handler, if there is one
+    fn submit(&mut self) -> EventResult {
+        let Some(on_submit) = self.on_submit.clone() else {
+            return EventResult::Ignored;
+        };
+        let value = self.value.get();
+        if value.is_empty() && !self.submit_empty {
+            return EventResult::Handled;
+        }
+        on_submit(&value);
+        if self.clear_on_submit {
+            self.clear();
+        }
+        EventResult::Handled
+    }
+
     /// Get the display text (with password masking if needed)
     fn display_text(&self) -> String {
         let text = self.value.get();
@@ -112,12 +184,20 @@ impl Input {
         }
 
         // Insert character at cursor position
-        let mut chars: Vec<char> = current.chars().collect();
-        chars.insert(self.cursor_pos, c);
-        let new_value: String = chars.into_iter().collect();
+        let chars: Vec<char> = current.chars().collect();
+        let cursor = self.cursor_pos.min(chars.len());
+        let mut before: String = chars[..cursor].iter().collect();
+        before.push(c);
+
+        // Composing only what's before the cursor keeps its position exact
+        #[cfg(feature = "unicode-normalization")]
+        if self.normalize {
+            before = before.nfc().collect();
+        }
 
-        self.value.set(new_value);
-        self.cursor_pos += 1;
+        self.cursor_pos = before.chars().count();
+        let after: String = chars[cursor..].iter().collect();
+        self.value.set(before + after.as_str());
     }
 
     /// Delete character before cursor (backspace)
@@ -164,6 +244,59 @@ impl Input {
         }
     }
 
+    /// Words in the value as (start, end) char offsets
+    ///
+    /// Uses Unicode word boundaries; only segments with a letter or digit
+    /// count, so whitespace and punctuation runs are skipped over.
+    fn word_spans(&self) -> Vec<(usize, usize)> {
+        let value = self.value.get();
+        let mut spans = Vec::new();
+        let mut offset = 0;
+        for segment in value.split_word_bounds() {
+            let len = segment.chars().count();
+            if segment.chars().any(char::is_alphanumeric) {
+                spans.push((offset, offset + len));
+            }
+            offset += len;
+        }
+        spans
+    }
+
+    /// Start of the word before the cursor (or the one it's inside)
+    fn prev_word_start(&self) -> usize {
+        self.word_spans()
+            .into_iter()
+            .rev()
+            .find(|&(start, _)| start < self.cursor_pos)
+            .map_or(0, |(start, _)| start)
+    }
+
+    /// End of the word after the cursor (or the one it's inside)
+    fn next_word_end(&self) -> usize {
+        self.word_spans()
+            .into_iter()
+            .find(|&(_, end)| end > self.cursor_pos)
+            .map_or_else(|| self.value.get().chars().count(), |(_, end)| end)
+    }
+
+    /// Delete from the start of the previous word to the cursor (Ctrl+W)
+    fn delete_word_before_cursor(&mut self) {
+        let start = self.prev_word_start();
+        if start == self.cursor_pos {
+            return;
+        }
+        let value: String = self
+            .value
+            .get()
+            .chars()
+            .enumerate()
+            .filter(|&(i, _)| i < start || i >= self.cursor_pos)
+            .map(|(_, c)| c)
+            .collect();
+        self.value.set(value);
+        self.cursor_pos = start;
+    }
+
     /// Move cursor to start
     fn move_cursor_home(&mut self) {
         self.cursor_pos = 0;
@@ -223,12 +356,10 @@ impl Component for Input {
 
         if let Event::Key(key) = event {
             match key.code {
-                KeyCode::Char(c) => {
-                    // Don't handle Ctrl combinations as regular chars
-                    if !key.modifiers.contains(KeyModifiers::CONTROL) {
-                        self.insert_char(c);
-                        return EventResult::Handled;
-                    }
+                // Don't handle Ctrl combinations as regular chars
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.insert_char(c);
+                    return EventResult::Handled;
                 }
                 KeyCode::Backspace => {
                     self.delete_before_cursor();
@@ -238,6 +369,14 @@ impl Component for Input {
                     self.delete_at_cursor();
                     return EventResult::Handled;
                 }
+                KeyCode::Left if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.cursor_pos = self.prev_word_start();
+                    return EventResult::Handled;
+                }
+                KeyCode::Right if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    self.cursor_pos = self.next_word_end();
+                    return EventResult::Handled;
+                }
                 KeyCode::Left => {
                     self.move_cursor_left();
                     return EventResult::Handled;
@@ -254,6 +393,7 @@ impl Component for Input {
                     self.move_cursor_end();
                     return EventResult::Handled;
                 }
+                KeyCode::Enter => return self.submit(),
                 _ => {}
             }
 
@@ -275,6 +415,11 @@ impl Component for Input {
                         self.move_cursor_end();
                         return EventResult::Handled;
                     }
+                    KeyCode::Char('w') => {
+                        // Ctrl+W: Delete previous word
+                        self.delete_word_before_cursor();
+                        return EventResult::Handled;
+                    }
                     _ => {}
                 }
             }
@@ -282,6 +427,18 @@ impl Component for Input {
 
         EventResult::Ignored
     }
+
+    fn focus_id(&self) -> Option<ComponentId> {
+        self.focus_id
+    }
+
+    fn on_focus(&mut self, _ctx: &mut EventContext) {
+        self.focused = true;
+    }
+
+    fn on_blur(&mut self, _ctx: &mut EventContext) {
+        self.focused = false;
+    }
 }
 
 #[cfg(test)]
@@ -313,6 +470,28 @@ mod tests {
         assert_eq!(input.cursor_pos, 2);
     }
 
+    #[cfg(feature = "unicode-normalization")]
+    #[test]
+    fn test_normalize_composes_typed_text() {
+        let value = Signal::new("cafx".to_string());
+        let mut input = Input::new(value.clone()).focused(true).normalize(true);
+        input.cursor_pos = 3;
+
+        input.insert_char('e');
+        input.insert_char('\u{301}');
+        assert_eq!(value.get(), "caf\u{e9}x");
+        assert_eq!(value.get().graphemes(true).count(), 5);
+        assert_eq!(input.cursor_pos, 4);
+
+        // Off: the combining accent stays its own char
+        let raw = Signal::new(String::new());
+        let mut input = Input::new(raw.clone()).focused(true);
+        input.insert_char('e');
+        input.insert_char('\u{301}');
+        assert_eq!(raw.get(), "e\u{301}");
+        assert_eq!(input.cursor_pos, 2);
+    }
+
     #[test]
     fn test_delete_before_cursor() {
         let value = Signal::new("hello".to_string());
@@ -447,4 +626,101 @@ mod tests {
         assert_eq!(result, EventResult::Ignored);
         assert_eq!(value.get(), ""); // No change
     }
+
+    #[test]
+    fn test_enter_submits_current_value() {
+        use crate::event::KeyEvent;
+        use std::sync::Mutex;
+
+        let submitted = Arc::new(Mutex::new(Vec::new()));
+        let s = submitted.clone();
+        let value = Signal::new("query".to_string());
+        let mut input = Input::new(value.clone())
+            .focused(true)
+            .on_submit(move |text| s.lock().unwrap().push(text.to_string()))
+            .clear_on_submit(true);
+
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 20, 1),
+        };
+        let enter = Event::Key(KeyEvent::new(KeyCode::Enter, KeyModifiers::empty()));
+
+        assert_eq!(input.handle_event(&enter, &mut ctx), EventResult::Handled);
+        assert_eq!(*submitted.lock().unwrap(), vec!["query".to_string()]);
+        assert_eq!(value.get(), "");
+        assert_eq!(input.cursor_pos, 0);
+
+        // Empty input doesn't submit unless asked to
+        input.handle_event(&enter, &mut ctx);
+        assert_eq!(submitted.lock().unwrap().len(), 1);
+        let mut input = input.submit_empty(true);
+        input.handle_event(&enter, &mut ctx);
+        assert_eq!(
+            *submitted.lock().unwrap(),
+            vec!["query".to_string(), String::new()]
+        );
+    }
+
+    fn ctrl(input: &mut Input, code: KeyCode) {
+        use crate::event::KeyEvent;
+
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 40, 1),
+        };
+        let event = Event::Key(KeyEvent::new(code, KeyModifiers::CONTROL));
+        assert_eq!(input.handle_event(&event, &mut ctx), EventResult::Handled);
+    }
+
+    #[test]
+    fn test_word_jumps() {
+        let value = Signal::new("  foo-bar  baz ".to_string());
+        let mut input = Input::new(value).focused(true);
+        input.move_cursor_end();
+
+        // Trailing whitespace and punctuation are skipped
+        ctrl(&mut input, KeyCode::Left);
+        assert_eq!(input.cursor_pos, 11);
+        ctrl(&mut input, KeyCode::Left);
+        assert_eq!(input.cursor_pos, 6);
+        ctrl(&mut input, KeyCode::Left);
+        assert_eq!(input.cursor_pos, 2);
+        ctrl(&mut input, KeyCode::Left);
+        assert_eq!(input.cursor_pos, 0);
+
+        ctrl(&mut input, KeyCode::Right);
+        assert_eq!(input.cursor_pos, 5);
+        ctrl(&mut input, KeyCode::Right);
+        assert_eq!(input.cursor_pos, 9);
+        ctrl(&mut input, KeyCode::Right);
+        assert_eq!(input.cursor_pos, 14);
+        ctrl(&mut input, KeyCode::Right);
+        assert_eq!(input.cursor_pos, 15);
+    }
+
+    #[test]
+    fn test_ctrl_w_deletes_previous_word() {
+        let value = Signal::new("git commit -m".to_string());
+        let mut input = Input::new(value.clone()).focused(true);
+        input.move_cursor_end();
+
+        ctrl(&mut input, KeyCode::Char('w'));
+        assert_eq!(value.get(), "git commit -");
+        assert_eq!(input.cursor_pos, 12);
+
+        ctrl(&mut input, KeyCode::Char('w'));
+        assert_eq!(value.get(), "git ");
+
+        // Mid-word deletes only up to the cursor
+        input.cursor_pos = 2;
+        ctrl(&mut input, KeyCode::Char('w'));
+        assert_eq!(value.get(), "t ");
+        assert_eq!(input.cursor_pos, 0);
+
+        ctrl(&mut input, KeyCode::Char('w'));
+        assert_eq!(value.get(), "t ");
+    }
 }
@@ -2,10 +2,14 @@
 //!
 //! A vertical list of items with selection, scrolling, and keyboard navigation.
 
-use crate::event::{Event, EventResult, KeyCode};
+use super::selection::track_selection;
+use crate::event::{Event, EventResult, KeyCode, KeyModifiers, MouseButton, MouseEventKind};
+use crate::layout::Rect;
 use crate::state::Signal;
 use crate::theme::{Color, Modifier, Style};
 use crate::view::{Component, EventContext, RenderContext, ViewNode};
+use std::any::Any;
+use std::cell::Cell;
 use std::sync::Arc;
 
 /// Scrollable list widget with selection
@@ -34,10 +38,21 @@ use std::sync::Arc;
 pub struct List<T> {
     items: Signal<Vec<T>>,
     selected: Signal<Option<usize>>,
-    scroll_offset: usize,
+    /// Clamped during render, hence the Cell
+    scroll_offset: Cell<usize>,
     visible_height: usize,
     render_item: Arc<dyn Fn(&T, bool) -> ViewNode + Send + Sync>,
     style: ListStyle,
+    wrap: bool,
+    /// Keeps the selection on the same item across updates (see `key`)
+    selection_tracker: Option<Box<dyn Any + Send + Sync>>,
+    reorderable: bool,
+    /// Up/Down move the selected item instead of the cursor
+    grabbed: bool,
+    /// Mouse button held on an item
+    dragging: bool,
+    /// Where we last rendered, for mapping mouse rows to items
+    last_rect: Cell<Option<Rect>>,
 }
 
 #[derive(Clone)]
@@ -68,13 +83,19 @@ impl<T: Clone + Send + Sync + 'static> List<T> {
         Self {
             items,
             selected,
-            scroll_offset: 0,
+            scroll_offset: Cell::new(0),
             visible_height: 10, // Default, will be updated based on available space
             render_item: Arc::new(|_item, _selected| {
                 // Default renderer - just use Debug
                 ViewNode::text(format!("{:?}", std::any::type_name::<T>()))
             }),
             style: ListStyle::default(),
+            wrap: false,
+            selection_tracker: None,
+            reorderable: false,
+            grabbed: false,
+            dragging: false,
+            last_rect: Cell::new(None),
         }
     }
 
@@ -95,10 +116,129 @@ impl<T: Clone + Send + Sync + 'static> List<T> {
         self
     }
 
+    /// Wrap selection around at either end (default: stop at the ends)
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Identify items by key so the selection follows the same item when
+    /// the list get replaced or reordered
+    ///
+    /// If the selected item disappears, the nearest remaining one is picked.
+    pub fn key<K, F>(mut self, key: F) -> Self
+    where
+        K: PartialEq + Send + Sync + 'static,
+        F: Fn(&T) -> K + Send + Sync + 'static,
+    {
+        self.selection_tracker = Some(track_selection(&self.items, &self.selected, key));
+        self
+    }
+
+    /// Let the user reorder items (default: off)
+    ///
+    /// Space grabs the selected item, after which Up/Down move it through
+    /// the list until Space, Enter or Esc drops it. Alt+Up/Down move it
+    /// without grabbing, and dragging with the left mouse button works too.
+    /// Moves write to the items signal and keep the moved item selected.
+    pub fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Check if the selected item is grabbed for reordering
+    pub fn is_grabbed(&self) -> bool {
+        self.grabbed
+    }
+
+    /// Move the selected item to `to`, shifting the ones in between
+    ///
+    /// Out-of-range targets are a no-op - reordering never wraps.
+    fn move_selected_to(&mut self, to: usize) {
+        let len = self.items.with(|items| items.len());
+        let Some(from) = self.selected.get().filter(|&i| i < len) else {
+            return;
+        };
+        if to >= len || to == from {
+            return;
+        }
+
+        self.items.update(|items| {
+            let item = items.remove(from);
+            items.insert(to, item);
+        });
+        self.selected.set(Some(to));
+        self.ensure_visible(to);
+    }
+
+    /// Move the selected item one step up (`-1`) or down (`1`)
+    fn move_selected_by(&mut self, delta: isize) {
+        if let Some(from) = self.selected.get() {
+            if let Some(to) = from.checked_add_signed(delta) {
+                self.move_selected_to(to);
+            }
+        }
+    }
+
+    /// Check if a point is in the last rendered area
+    fn is_over(&self, x: u16, y: u16) -> bool {
+        self.last_rect.get().is_some_and(|rect| rect.contains(x, y))
+    }
+
+    /// Item index under a screen row, using the last rendered area
+    pub(super) fn index_at(&self, y: u16) -> Option<usize> {
+        let rect = self.last_rect.get()?;
+        if y < rect.y || y >= rect.y + rect.height {
+            return None;
+        }
+        let row = (y - rect.y) as usize;
+        let index = self.scroll_offset.get() + row;
+        let len = self.items.with(|items| items.len());
+        (row < self.visible_height && index < len).then_some(index)
+    }
+
+    fn handle_reorder(&mut self, event: &Event) -> EventResult {
+        match event {
+            Event::Key(key) => {
+                let alt = key.modifiers.contains(KeyModifiers::ALT);
+                match key.code {
+                    KeyCode::Char(' ') => self.grabbed = !self.grabbed,
+                    KeyCode::Enter | KeyCode::Esc if self.grabbed => self.grabbed = false,
+                    KeyCode::Up if self.grabbed || alt => self.move_selected_by(-1),
+                    KeyCode::Down if self.grabbed || alt => self.move_selected_by(1),
+                    _ => return EventResult::Ignored,
+                }
+                EventResult::Handled
+            }
+            Event::Mouse(mouse) => match mouse.kind {
+                MouseEventKind::Down(MouseButton::Left) => match self.index_at(mouse.y) {
+                    Some(index) => {
+                        self.selected.set(Some(index));
+                        self.dragging = true;
+                        EventResult::Handled
+                    }
+                    None => EventResult::Ignored,
+                },
+                MouseEventKind::Drag(MouseButton::Left) if self.dragging => {
+                    if let Some(index) = self.index_at(mouse.y) {
+                        self.move_selected_to(index);
+                    }
+                    EventResult::Handled
+                }
+                MouseEventKind::Up(MouseButton::Left) if self.dragging => {
+                    self.dragging = false;
+                    EventResult::Handled
+                }
+                _ => EventResult::Ignored,
+            },
+            _ => EventResult::Ignored,
+        }
+    }
+
     /// Select next item (Down arrow)
     fn select_next(&mut self) {
-        let items = self.items.get();
-        if items.is_empty() {
+        let len = self.items.with(|items| items.len());
+        if len == 0 {
             return;
         }
 
@@ -106,8 +246,10 @@ impl<T: Clone + Send + Sync + 'static> List<T> {
         let next = match current {
             None => Some(0),
             Some(idx) => {
-                if idx + 1 < items.len() {
+                if idx + 1 < len {
                     Some(idx + 1)
+                } else if self.wrap {
+                    Some(0)
                 } else {
                     Some(idx) // Stay at last item
                 }
@@ -120,17 +262,19 @@ impl<T: Clone + Send + Sync + 'static> List<T> {
 
     /// Select previous item (Up arrow)
     fn select_prev(&mut self) {
-        let items = self.items.get();
-        if items.is_empty() {
+        let len = self.items.with(|items| items.len());
+        if len == 0 {
             return;
         }
 
         let current = self.selected.get();
         let prev = match current {
-            None => Some(items.len() - 1),
+            None => Some(len - 1),
             Some(idx) => {
                 if idx > 0 {
                     Some(idx - 1)
+                } else if self.wrap {
+                    Some(len - 1)
                 } else {
                     Some(0) // Stay at first item
                 }
@@ -143,18 +287,18 @@ impl<T: Clone + Send + Sync + 'static> List<T> {
 
     /// Jump to first item (Home)
     fn select_first(&mut self) {
-        let items = self.items.get();
-        if !items.is_empty() {
+        let len = self.items.with(|items| items.len());
+        if len > 0 {
             self.selected.set(Some(0));
-            self.scroll_offset = 0;
+            self.scroll_offset.set(0);
         }
     }
 
     /// Jump to last item (End)
     fn select_last(&mut self) {
-        let items = self.items.get();
-        if !items.is_empty() {
-            let last = items.len() - 1;
+        let len = self.items.with(|items| items.len());
+        if len > 0 {
+            let last = len - 1;
             self.selected.set(Some(last));
             self.ensure_visible(last);
         }
@@ -162,112 +306,153 @@ impl<T: Clone + Send + Sync + 'static> List<T> {
 
     /// Page down
     fn page_down(&mut self) {
-        let items = self.items.get();
-        if items.is_empty() {
+        let len = self.items.with(|items| items.len());
+        if len == 0 {
             return;
         }
 
+        let last = len - 1;
         let current = self.selected.get().unwrap_or(0);
-        let next = (current + self.visible_height).min(items.len() - 1);
+        // Paging clamps to the end first, and only wraps once we're there
+        let next = if self.wrap && current == last {
+            0
+        } else {
+            (current + self.visible_height).min(last)
+        };
         self.selected.set(Some(next));
         self.ensure_visible(next);
     }
 
     /// Page up
     fn page_up(&mut self) {
-        let items = self.items.get();
-        if items.is_empty() {
+        let len = self.items.with(|items| items.len());
+        if len == 0 {
             return;
         }
 
         let current = self.selected.get().unwrap_or(0);
-        let prev = current.saturating_sub(self.visible_height);
+        let prev = if self.wrap && current == 0 {
+            len - 1
+        } else {
+            current.saturating_sub(self.visible_height)
+        };
         self.selected.set(Some(prev));
         self.ensure_visible(prev);
     }
 
     /// Ensure selected item is visible (adjust scroll offset)
-    fn ensure_visible(&mut self, index: usize) {
+    fn ensure_visible(&self, index: usize) {
         // Scroll down if selected is below visible area
-        if index >= self.scroll_offset + self.visible_height {
-            self.scroll_offset = index - self.visible_height + 1;
+        if index >= self.scroll_offset.get() + self.visible_height {
+            self.scroll_offset.set(index - self.visible_height + 1);
         }
         // Scroll up if selected is above visible area
-        else if index < self.scroll_offset {
-            self.scroll_offset = index;
+        else if index < self.scroll_offset.get() {
+            self.scroll_offset.set(index);
         }
     }
-}
 
-impl<T: Clone + Send + Sync + 'static> Component for List<T> {
-    fn render(&self, _ctx: &RenderContext) -> ViewNode {
-        let items = self.items.get();
-        let selected_idx = self.selected.get();
-
-        if items.is_empty() {
-            return ViewNode::text_styled("(empty list)", Style::default().fg(Color::GRAY));
+    /// Bring the scroll offset back in range for `len` items
+    ///
+    /// `visible_height` or the item count may have changed since the
+    /// offset was set, which would leave blank rows (or an offset past the
+    /// end). Also keeps the selection on screen.
+    fn clamp_scroll(&self, len: usize) -> usize {
+        let max = len.saturating_sub(self.visible_height);
+        self.scroll_offset.set(self.scroll_offset.get().min(max));
+        if let Some(index) = self.selected.get().filter(|&i| i < len) {
+            self.ensure_visible(index);
         }
+        self.scroll_offset.get()
+    }
+}
 
-        // Render visible items only (scrolling viewport)
-        let end = (self.scroll_offset + self.visible_height).min(items.len());
-        let visible_items = &items[self.scroll_offset..end];
-
-        let mut children = Vec::new();
+impl<T: Clone + Send + Sync + 'static> Component for List<T> {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        self.last_rect.set(Some(ctx.area));
 
-        for (offset, item) in visible_items.iter().enumerate() {
-            let absolute_idx = self.scroll_offset + offset;
-            let is_selected = selected_idx == Some(absolute_idx);
+        // Borrow the items - cloning a big list every frame adds up
+        self.items.with(|items| {
+            let selected_idx = self.selected.get();
 
-            // Render item with custom renderer
-            let mut item_node = (self.render_item)(item, is_selected);
+            if items.is_empty() {
+                return ViewNode::text_styled("(empty list)", Style::default().fg(Color::GRAY));
+            }
 
-            // Apply selection styling if selected
-            if is_selected {
-                // Wrap in styled container
-                match item_node {
-                    ViewNode::Text { content, style } => {
+            // Render visible items only (scrolling viewport)
+            let offset = self.clamp_scroll(items.len());
+            let end = (offset + self.visible_height).min(items.len());
+            let visible_items = &items[offset..end];
+
+            let mut children = Vec::new();
+
+            for (i, item) in visible_items.iter().enumerate() {
+                let absolute_idx = offset + i;
+                let is_selected = selected_idx == Some(absolute_idx);
+
+                // Render item with custom renderer
+                let mut item_node = (self.render_item)(item, is_selected);
+
+                // Apply selection styling if selected
+                if is_selected {
+                    // Wrap in styled container
+                    match item_node {
+                        ViewNode::Text { content, style } => {
+                            item_node = ViewNode::Text {
+                                content: format!("> {}", content),
+                                style: style.bg(self
+                                    .style
+                                    .focused_selected
+                                    .bg
+                                    .unwrap_or(Color::BLUE)),
+                            };
+                        }
+                        _ => {
+                            // For other node types, just add indicator
+                            children.push(ViewNode::text_styled("> ", self.style.focused_selected));
+                        }
+                    }
+                } else {
+                    // Add spacing for non-selected items
+                    if let ViewNode::Text { content, style } = item_node {
                         item_node = ViewNode::Text {
-                            content: format!("> {}", content),
-                            style: style.bg(self.style.focused_selected.bg.unwrap_or(Color::BLUE)),
+                            content: format!("  {}", content),
+                            style,
                         };
                     }
-                    _ => {
-                        // For other node types, just add indicator
-                        children.push(ViewNode::text_styled("> ", self.style.focused_selected));
-                    }
                 }
-            } else {
-                // Add spacing for non-selected items
-                if let ViewNode::Text { content, style } = item_node {
-                    item_node = ViewNode::Text {
-                        content: format!("  {}", content),
-                        style,
-                    };
-                }
-            }
 
-            children.push(item_node);
-        }
+                children.push(item_node);
+            }
 
-        // Add scroll indicator if needed
-        let total_items = items.len();
-        if total_items > self.visible_height {
-            let scroll_info = format!(
-                "  [↕ {}-{} of {}]",
-                self.scroll_offset + 1,
-                end,
-                total_items
-            );
-            children.push(ViewNode::text_styled(
-                scroll_info,
-                Style::default().fg(Color::GRAY),
-            ));
-        }
+            // Add scroll indicator if needed
+            let total_items = items.len();
+            if total_items > self.visible_height {
+                let scroll_info = format!(
+                    "  [{} {}-{} of {}]",
+                    ctx.glyphs().scroll,
+                    offset + 1,
+                    end,
+                    total_items
+                );
+                children.push(ViewNode::text_styled(
+                    scroll_info,
+                    Style::default().fg(Color::GRAY),
+                ));
+            }
 
-        ViewNode::container(children)
+            ViewNode::container(children)
+        })
     }
 
     fn handle_event(&mut self, event: &Event, _ctx: &mut EventContext) -> EventResult {
+        if self.reorderable {
+            let result = self.handle_reorder(event);
+            if result != EventResult::Ignored {
+                return result;
+            }
+        }
+
         match event {
             Event::Key(key) => match key.code {
                 KeyCode::Up => {
@@ -296,6 +481,18 @@ impl<T: Clone + Send + Sync + 'static> Component for List<T> {
                 }
                 _ => EventResult::Ignored,
             },
+            // The wheel moves the selection like Up/Down, over the list only
+            Event::Mouse(mouse) if self.is_over(mouse.x, mouse.y) => match mouse.kind {
+                MouseEventKind::ScrollUp => {
+                    self.select_prev();
+                    EventResult::Handled
+                }
+                MouseEventKind::ScrollDown => {
+                    self.select_next();
+                    EventResult::Handled
+                }
+                _ => EventResult::Ignored,
+            },
             _ => EventResult::Ignored,
         }
     }
@@ -314,7 +511,7 @@ mod tests {
         let selected = Signal::new(Some(0));
         let list = List::new(items, selected);
 
-        assert_eq!(list.scroll_offset, 0);
+        assert_eq!(list.scroll_offset.get(), 0);
         assert_eq!(list.visible_height, 10);
     }
 
@@ -352,6 +549,92 @@ mod tests {
         assert_eq!(selected.get(), Some(0));
     }
 
+    #[test]
+    fn test_key_follows_reordered_item() {
+        let items = Signal::new(vec!["a", "b", "c"]);
+        let selected = Signal::new(Some(1));
+        let _list = List::new(items.clone(), selected.clone()).key(|s: &&str| s.to_string());
+
+        items.set(vec!["c", "a", "b"]);
+        assert_eq!(selected.get(), Some(2));
+
+        // Selection changed by the user is tracked too
+        selected.set(Some(0));
+        items.set(vec!["b", "c"]);
+        assert_eq!(selected.get(), Some(1));
+    }
+
+    #[test]
+    fn test_key_removed_item_selects_nearest() {
+        let items = Signal::new(vec![1, 2, 3, 4]);
+        let selected = Signal::new(Some(3));
+        let _list = List::new(items.clone(), selected.clone()).key(|n: &i32| *n);
+
+        items.set(vec![1, 2]);
+        assert_eq!(selected.get(), Some(1));
+
+        items.set(vec![5, 6, 7]);
+        assert_eq!(selected.get(), Some(1));
+
+        items.set(Vec::new());
+        assert_eq!(selected.get(), None);
+    }
+
+    #[test]
+    fn test_wrap_at_both_ends() {
+        let items = Signal::new(vec!["a", "b", "c"]);
+        let selected = Signal::new(Some(2));
+        let mut list = List::new(items, selected.clone()).wrap(true);
+
+        list.select_next();
+        assert_eq!(selected.get(), Some(0));
+
+        list.select_prev();
+        assert_eq!(selected.get(), Some(2));
+    }
+
+    #[test]
+    fn test_wrap_paging() {
+        let items = Signal::new((0..20).collect::<Vec<_>>());
+        let selected = Signal::new(Some(15));
+        let mut list = List::new(items, selected.clone())
+            .visible_height(10)
+            .wrap(true);
+
+        // Clamp to the end first...
+        list.page_down();
+        assert_eq!(selected.get(), Some(19));
+        // ...then wrap
+        list.page_down();
+        assert_eq!(selected.get(), Some(0));
+        assert_eq!(list.scroll_offset.get(), 0);
+
+        list.page_up();
+        assert_eq!(selected.get(), Some(19));
+    }
+
+    #[test]
+    fn test_wrap_single_item() {
+        let items = Signal::new(vec!["only"]);
+        let selected = Signal::new(Some(0));
+        let mut list = List::new(items, selected.clone()).wrap(true);
+
+        list.select_next();
+        assert_eq!(selected.get(), Some(0));
+        list.select_prev();
+        assert_eq!(selected.get(), Some(0));
+    }
+
+    #[test]
+    fn test_no_wrap_by_default() {
+        let items = Signal::new(vec!["a", "b"]);
+        let selected = Signal::new(Some(1));
+        let mut list = List::new(items, selected.clone());
+
+        list.select_next();
+        assert_eq!(selected.get(), Some(1));
+    }
+
     #[test]
     fn test_page_navigation() {
         let items = Signal::new((0..20).collect::<Vec<_>>());
@@ -381,7 +664,7 @@ mod tests {
 
         assert_eq!(selected.get(), Some(10));
         // Scroll offset should follow
-        assert!(list.scroll_offset >= 6); // Keep selected in view
+        assert!(list.scroll_offset.get() >= 6); // Keep selected in view
     }
 
     #[test]
@@ -434,4 +717,110 @@ mod tests {
             _ => panic!("Expected container node"),
         }
     }
+
+    #[test]
+    fn test_shrinking_items_clamps_scroll() {
+        let items = Signal::new((0..20).collect::<Vec<_>>());
+        let selected = Signal::new(None);
+        let mut list = List::new(items.clone(), selected.clone())
+            .visible_height(5)
+            .render_item(|n, _| ViewNode::text(n.to_string()));
+
+        list.select_last();
+        assert_eq!(list.scroll_offset.get(), 15);
+
+        // Offset 15 is past the end of the new list
+        items.set((0..8).collect());
+        selected.set(None);
+
+        let mut buffer = Buffer::new(40, 10);
+        let store = Store::new();
+        let ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 40, 10), &store);
+        let node = list.render(&ctx);
+
+        assert_eq!(list.scroll_offset.get(), 3);
+        match node {
+            ViewNode::Container { children, .. } => match &children[4] {
+                ViewNode::Text { content, .. } => assert_eq!(content.trim(), "7"),
+                _ => panic!("Expected text node"),
+            },
+            _ => panic!("Expected container node"),
+        }
+    }
+
+    #[test]
+    fn test_grab_and_move_reorders_items() {
+        use crate::event::KeyEvent;
+
+        let items = Signal::new(vec!["a", "b", "c"]);
+        let selected = Signal::new(Some(0));
+        let mut list = List::new(items.clone(), selected.clone()).reorderable(true);
+
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 20, 5),
+        };
+        let mut press = |list: &mut List<&'static str>, code| {
+            list.handle_event(
+                &Event::Key(KeyEvent::new(code, KeyModifiers::empty())),
+                &mut ctx,
+            )
+        };
+
+        press(&mut list, KeyCode::Char(' '));
+        assert!(list.is_grabbed());
+        press(&mut list, KeyCode::Down);
+        assert_eq!(items.get(), vec!["b", "a", "c"]);
+        assert_eq!(selected.get(), Some(1));
+
+        press(&mut list, KeyCode::Down);
+        press(&mut list, KeyCode::Down); // already last: no-op
+        assert_eq!(items.get(), vec!["b", "c", "a"]);
+        assert_eq!(selected.get(), Some(2));
+
+        // Dropped: Up is plain navigation again
+        press(&mut list, KeyCode::Enter);
+        press(&mut list, KeyCode::Up);
+        assert_eq!(items.get(), vec!["b", "c", "a"]);
+        assert_eq!(selected.get(), Some(1));
+    }
+
+    #[test]
+    fn test_wheel_moves_selection_over_the_list() {
+        use crate::event::MouseEvent;
+        use crate::testing::render_to_buffer;
+
+        let items = Signal::new((0..50).collect::<Vec<i32>>());
+        let selected = Signal::new(Some(0));
+        let mut list = List::new(items, selected.clone()).visible_height(5);
+        render_to_buffer(&list, 20, 5);
+
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 20, 5),
+        };
+        let mut wheel = |list: &mut List<i32>, kind, y| {
+            let event = Event::Mouse(MouseEvent {
+                kind,
+                x: 2,
+                y,
+                modifiers: KeyModifiers::empty(),
+            });
+            list.handle_event(&event, &mut ctx)
+        };
+
+        for _ in 0..7 {
+            wheel(&mut list, MouseEventKind::ScrollDown, 1);
+        }
+        wheel(&mut list, MouseEventKind::ScrollUp, 1);
+        assert_eq!(selected.get(), Some(6));
+        assert_eq!(list.scroll_offset.get(), 3);
+
+        // Rolling the wheel somewhere else leaves the list alone
+        let result = wheel(&mut list, MouseEventKind::ScrollDown, 7);
+        assert_eq!(result, EventResult::Ignored);
+        assert_eq!(selected.get(), Some(6));
+    }
 }
@@ -0,0 +1,489 @@
+//! Log view widget
+//!
+//! Scrolling view over lines of text with in-content search.
+
+use crate::event::{Event, EventResult, KeyCode};
+use crate::state::Signal;
+use crate::theme::{Color, Style};
+use crate::view::{Component, EventContext, RenderContext, Span, ViewNode};
+use std::cell::RefCell;
+
+/// A search hit, as char offsets into one line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SearchMatch {
+    pub line: usize,
+    pub start: usize,
+    pub end: usize,
+}
+
+/// Find every non-overlapping occurrence of `query` in `lines`
+///
+/// The search is literal (no regex). Offsets are in chars, not bytes.
+pub fn find_matches(lines: &[String], query: &str, case_sensitive: bool) -> Vec<SearchMatch> {
+    let needle: Vec<char> = query.chars().collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+
+    let same =
+        |a: char, b: char| a == b || (!case_sensitive && a.to_lowercase().eq(b.to_lowercase()));
+
+    let mut matches = Vec::new();
+    for (line_no, line) in lines.iter().enumerate() {
+        let hay: Vec<char> = line.chars().collect();
+        let mut i = 0;
+        while i + needle.len() <= hay.len() {
+            if hay[i..i + needle.len()]
+                .iter()
+                .zip(&needle)
+                .all(|(&a, &b)| same(a, b))
+            {
+                matches.push(SearchMatch {
+                    line: line_no,
+                    start: i,
+                    end: i + needle.len(),
+                });
+                i += needle.len();
+            } else {
+                i += 1;
+            }
+        }
+    }
+    matches
+}
+
+/// Scrollable log with search highlighting
+///
+/// Every match of the query signal is highlighted; `n`/`N` jump to the
+/// next/previous match and scroll it into view. While a query is set, the
+/// last row shows a match counter like `3/17`.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let lines = Signal::new(vec!["started".to_string(), "error: disk full".to_string()]);
+/// let query = Signal::new("error".to_string());
+///
+/// let log = LogView::new(lines).search(query).visible_height(20);
+/// ```
+pub struct LogView {
+    lines: Signal<Vec<String>>,
+    query: Signal<String>,
+    case_sensitive: bool,
+    scroll_offset: usize,
+    visible_height: usize,
+    /// Index into the match list, and the query it belongs to
+    current: Option<(usize, String)>,
+    // Filled lazily in render, hence the RefCell
+    cache: RefCell<MatchCache>,
+    style: LogStyle,
+}
+
+/// Matches for the last (query, case sensitivity, lines version) seen,
+/// so frames where none of those changed don't rescan every line
+#[derive(Default)]
+struct MatchCache {
+    key: Option<(String, bool, u64)>,
+    matches: Vec<SearchMatch>,
+}
+
+#[derive(Clone)]
+struct LogStyle {
+    normal: Style,
+    highlight: Style,
+    current: Style,
+    status: Style,
+}
+
+impl Default for LogStyle {
+    fn default() -> Self {
+        Self {
+            normal: Style::default(),
+            highlight: Style::fg_bg(Color::BLACK, Color::YELLOW),
+            current: Style::fg_bg(Color::BLACK, Color::rgb(255, 140, 0)).bold(),
+            status: Style::default().fg(Color::GRAY),
+        }
+    }
+}
+
+impl LogView {
+    /// Create a view over a list of lines
+    pub fn new(lines: Signal<Vec<String>>) -> Self {
+        Self {
+            lines,
+            query: Signal::new(String::new()),
+            case_sensitive: false,
+            scroll_offset: 0,
+            visible_height: 20,
+            current: None,
+            cache: RefCell::new(MatchCache::default()),
+            style: LogStyle::default(),
+        }
+    }
+
+    /// Highlight matches of this query (empty = no search)
+    pub fn search(mut self, query: Signal<String>) -> Self {
+        self.query = query;
+        self
+    }
+
+    /// Match case exactly (off by default)
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> Self {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Set visible height (lines shown at once, not counting the counter)
+    pub fn visible_height(mut self, height: usize) -> Self {
+        self.visible_height = height;
+        self
+    }
+
+    /// Flip case sensitivity at runtime
+    pub fn toggle_case(&mut self) {
+        self.case_sensitive = !self.case_sensitive;
+        self.current = None;
+    }
+
+    /// First line currently shown
+    pub fn scroll_offset(&self) -> usize {
+        self.scroll_offset
+    }
+
+    /// All matches of the current query
+    pub fn matches(&self) -> Vec<SearchMatch> {
+        self.with_matches(|matches| matches.to_vec())
+    }
+
+    /// The match `n`/`N` last landed on
+    pub fn current_match(&self) -> Option<SearchMatch> {
+        let index = self.current_index()?;
+        self.with_matches(|matches| matches.get(index).copied())
+    }
+
+    /// Counter text: `"3/17"`, `"0/17"` before jumping, `"no matches"`
+    ///
+    /// `None` when there's no query.
+    pub fn match_label(&self) -> Option<String> {
+        self.with_matches(|matches| self.label_for(matches))
+    }
+
+    /// Run `f` on the matches, rescanning only if the query, case
+    /// sensitivity or lines changed since the last scan
+    fn with_matches<R>(&self, f: impl FnOnce(&[SearchMatch]) -> R) -> R {
+        let key = (self.query.get(), self.case_sensitive, self.lines.version());
+        let mut cache = self.cache.borrow_mut();
+        if cache.key.as_ref() != Some(&key) {
+            cache.matches = self
+                .lines
+                .with(|lines| find_matches(lines, &key.0, self.case_sensitive));
+            cache.key = Some(key);
+        }
+        f(&cache.matches)
+    }
+
+    fn label_for(&self, matches: &[SearchMatch]) -> Option<String> {
+        if self.query.with(|query| query.is_empty()) {
+            return None;
+        }
+        let total = matches.len();
+        if total == 0 {
+            return Some("no matches".to_string());
+        }
+        let position = self.current_index().map_or(0, |i| i + 1);
+        Some(format!("{}/{}", position, total))
+    }
+
+    /// Jump to the next match, wrapping at the end
+    pub fn next_match(&mut self) {
+        self.jump(true);
+    }
+
+    /// Jump to the previous match, wrapping at the start
+    pub fn prev_match(&mut self) {
+        self.jump(false);
+    }
+
+    /// Scroll down by `n` lines, stopping at the end
+    pub fn scroll_down(&mut self, n: usize) {
+        let len = self.lines.with(|lines| lines.len());
+        let max = len.saturating_sub(self.visible_height);
+        self.scroll_offset = self.scroll_offset.saturating_add(n).min(max);
+    }
+
+    /// Scroll up by `n` lines
+    pub fn scroll_up(&mut self, n: usize) {
+        self.scroll_offset = self.scroll_offset.saturating_sub(n);
+    }
+
+    /// Current index, dropped if the query changed since it was set
+    fn current_index(&self) -> Option<usize> {
+        let (index, ref query) = *self.current.as_ref()?;
+        (*query == self.query.get()).then_some(index)
+    }
+
+    fn jump(&mut self, forward: bool) {
+        let matches = self.matches();
+        if matches.is_empty() {
+            self.current = None;
+            return;
+        }
+
+        let len = matches.len();
+        let index = match self.current_index() {
+            Some(i) if forward => (i + 1) % len,
+            Some(i) => (i + len - 1) % len,
+            // First jump starts from what's on screen
+            None if forward => matches
+                .iter()
+                .position(|m| m.line >= self.scroll_offset)
+                .unwrap_or(0),
+            None => matches
+                .iter()
+                .rposition(|m| m.line < self.scroll_offset + self.visible_height)
+                .unwrap_or(len - 1),
+        };
+
+        self.current = Some((index, self.query.get()));
+        self.scroll_to_line(matches[index].line);
+    }
+
+    fn scroll_to_line(&mut self, line: usize) {
+        if line < self.scroll_offset {
+            self.scroll_offset = line;
+        } else if line >= self.scroll_offset + self.visible_height {
+            self.scroll_offset = line + 1 - self.visible_height.max(1);
+        }
+    }
+
+    /// Split a line into plain and highlighted spans, given the matches
+    /// on that line
+    fn line_spans(
+        &self,
+        text: &str,
+        matches: &[SearchMatch],
+        current: Option<SearchMatch>,
+    ) -> Vec<Span> {
+        let chars: Vec<char> = text.chars().collect();
+        let mut spans = Vec::new();
+        let mut pos = 0;
+
+        for m in matches {
+            if m.start > pos {
+                spans.push(Span::styled(
+                    chars[pos..m.start].iter().collect::<String>(),
+                    self.style.normal,
+                ));
+            }
+            let style = if Some(*m) == current {
+                self.style.current
+            } else {
+                self.style.highlight
+            };
+            spans.push(Span::styled(
+                chars[m.start..m.end].iter().collect::<String>(),
+                style,
+            ));
+            pos = m.end;
+        }
+        if pos < chars.len() || spans.is_empty() {
+            spans.push(Span::styled(
+                chars[pos..].iter().collect::<String>(),
+                self.style.normal,
+            ));
+        }
+        spans
+    }
+}
+
+impl Component for LogView {
+    fn render(&self, _ctx: &RenderContext) -> ViewNode {
+        let (mut children, label) = self.with_matches(|matches| {
+            let current = self.current_index().and_then(|i| matches.get(i).copied());
+
+            let rows: Vec<ViewNode> = self.lines.with(|lines| {
+                let start = self.scroll_offset.min(lines.len());
+                let end = (start + self.visible_height).min(lines.len());
+                lines[start..end]
+                    .iter()
+                    .enumerate()
+                    .map(|(i, text)| {
+                        // Matches are in line order, so each row's are a
+                        // contiguous run
+                        let line_no = start + i;
+                        let from = matches.partition_point(|m| m.line < line_no);
+                        let to = matches.partition_point(|m| m.line <= line_no);
+                        ViewNode::spans(self.line_spans(text, &matches[from..to], current))
+                    })
+                    .collect()
+            });
+            (rows, self.label_for(matches))
+        });
+
+        if let Some(label) = label {
+            children.push(ViewNode::text_styled(label, self.style.status));
+        }
+
+        ViewNode::container(children)
+    }
+
+    fn handle_event(&mut self, event: &Event, _ctx: &mut EventContext) -> EventResult {
+        let Event::Key(key) = event else {
+            return EventResult::Ignored;
+        };
+
+        match key.code {
+            KeyCode::Char('n') => self.next_match(),
+            KeyCode::Char('N') => self.prev_match(),
+            KeyCode::Down => self.scroll_down(1),
+            KeyCode::Up => self.scroll_up(1),
+            KeyCode::PageDown => self.scroll_down(self.visible_height),
+            KeyCode::PageUp => self.scroll_up(self.visible_height),
+            KeyCode::Home => self.scroll_offset = 0,
+            KeyCode::End => self.scroll_down(usize::MAX),
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Handled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Rect;
+    use crate::render::Buffer;
+    use crate::state::Store;
+
+    fn log(lines: &[&str]) -> Signal<Vec<String>> {
+        Signal::new(lines.iter().map(|s| s.to_string()).collect())
+    }
+
+    fn render_spans(view: &LogView) -> Vec<Vec<Span>> {
+        let mut buffer = Buffer::new(40, 10);
+        let store = Store::new();
+        let ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 40, 10), &store);
+
+        match view.render(&ctx) {
+            ViewNode::Container { children, .. } => children
+                .into_iter()
+                .filter_map(|c| match c {
+                    ViewNode::Spans { spans } => Some(spans),
+                    _ => None,
+                })
+                .collect(),
+            _ => panic!("Expected container node"),
+        }
+    }
+
+    #[test]
+    fn test_highlights_matches() {
+        let query = Signal::new("err".to_string());
+        let view = LogView::new(log(&["ok", "err: x, err: y"])).search(query);
+
+        let rows = render_spans(&view);
+        assert_eq!(rows[0], vec![Span::styled("ok", view.style.normal)]);
+
+        let texts: Vec<_> = rows[1]
+            .iter()
+            .map(|s| (s.content.as_str(), s.style == view.style.highlight))
+            .collect();
+        assert_eq!(
+            texts,
+            vec![
+                ("err", true),
+                (": x, ", false),
+                ("err", true),
+                (": y", false)
+            ]
+        );
+        assert_eq!(view.match_label().as_deref(), Some("0/2"));
+    }
+
+    #[test]
+    fn test_next_prev_cycle() {
+        let lines: Vec<String> = (0..30)
+            .map(|i| {
+                if i % 10 == 5 {
+                    format!("hit {}", i)
+                } else {
+                    i.to_string()
+                }
+            })
+            .collect();
+        let query = Signal::new("hit".to_string());
+        let mut view = LogView::new(Signal::new(lines))
+            .search(query)
+            .visible_height(4);
+
+        view.next_match();
+        assert_eq!(view.current_match().unwrap().line, 5);
+        assert_eq!(view.match_label().as_deref(), Some("1/3"));
+
+        view.next_match();
+        assert_eq!(view.current_match().unwrap().line, 15);
+        assert!(view.scroll_offset() <= 15 && 15 < view.scroll_offset() + 4);
+
+        view.next_match();
+        view.next_match();
+        assert_eq!(view.current_match().unwrap().line, 5);
+
+        view.prev_match();
+        assert_eq!(view.current_match().unwrap().line, 25);
+        assert_eq!(view.match_label().as_deref(), Some("3/3"));
+
+        // The current match gets its own style
+        let rows = render_spans(&view);
+        let current = rows
+            .iter()
+            .flatten()
+            .find(|s| s.style == view.style.current)
+            .unwrap();
+        assert_eq!(current.content, "hit");
+    }
+
+    #[test]
+    fn test_no_matches() {
+        let query = Signal::new("missing".to_string());
+        let mut view = LogView::new(log(&["a", "b"])).search(query.clone());
+
+        view.next_match();
+        assert_eq!(view.current_match(), None);
+        assert_eq!(view.match_label().as_deref(), Some("no matches"));
+
+        query.set(String::new());
+        assert_eq!(view.match_label(), None);
+    }
+
+    #[test]
+    fn test_matches_follow_new_lines() {
+        let lines = log(&["err a", "ok"]);
+        let query = Signal::new("err".to_string());
+        let view = LogView::new(lines.clone()).search(query);
+
+        render_spans(&view);
+        assert_eq!(view.match_label().as_deref(), Some("0/1"));
+
+        lines.update(|lines| lines.push("err b".to_string()));
+        let rows = render_spans(&view);
+        assert_eq!(rows[2][0], Span::styled("err", view.style.highlight));
+        assert_eq!(view.match_label().as_deref(), Some("0/2"));
+    }
+
+    #[test]
+    fn test_case_sensitivity() {
+        let query = Signal::new("warn".to_string());
+        let mut view = LogView::new(log(&["WARN disk", "warn cpu"])).search(query);
+
+        assert_eq!(view.matches().len(), 2);
+        view.toggle_case();
+        assert_eq!(
+            view.matches(),
+            vec![SearchMatch {
+                line: 1,
+                start: 0,
+                end: 4
+            }]
+        );
+    }
+}
@@ -3,9 +3,10 @@
 //! Displays content in a centered overlay box.
 
 use crate::event::{Event, EventResult, KeyCode};
+use crate::focus::ComponentId;
 use crate::state::Signal;
 use crate::theme::{Color, Modifier, Style};
-use crate::view::{Component, EventContext, RenderContext, ViewNode};
+use crate::view::{BorderSet, Component, EventContext, RenderContext, UpdateContext, ViewNode};
 
 /// Modal dialog widget
 ///
@@ -44,7 +45,7 @@ impl Default for ModalStyle {
             title: Style::default()
                 .fg(Color::YELLOW)
                 .add_modifier(Modifier::BOLD),
-            background: Style::default().bg(Color::rgb(30, 30, 30)),
+            background: Style::default().bg(Color::gray(30)),
         }
     }
 }
@@ -92,24 +93,41 @@ impl Component for Modal {
         }
 
         let mut children = Vec::new();
+        let b = ctx.mode.border(BorderSet::DOUBLE);
+        let rule = |n: usize| b.horizontal.to_string().repeat(n);
 
         // Top border with title
         if let Some(ref title) = self.title {
-            let border_line = format!("╔═══ {} ═══╗", title);
+            let border_line = format!(
+                "{}{} {} {}{}",
+                b.top_left,
+                rule(3),
+                title,
+                rule(3),
+                b.top_right
+            );
             children.push(ViewNode::text_styled(border_line, self.style.border));
         } else {
-            children.push(ViewNode::text_styled("╔═════════╗", self.style.border));
+            let top = format!("{}{}{}", b.top_left, rule(9), b.top_right);
+            children.push(ViewNode::text_styled(top, self.style.border));
         }
 
         // Content
         if let Some(ref child) = self.child {
-            children.push(ViewNode::text_styled("║ ", self.style.border));
-            children.push(child.render(ctx));
-            children.push(ViewNode::text_styled(" ║", self.style.border));
+            children.push(ViewNode::text_styled(
+                format!("{} ", b.vertical),
+                self.style.border,
+            ));
+            children.push(ctx.render_child(child.as_ref()));
+            children.push(ViewNode::text_styled(
+                format!(" {}", b.vertical),
+                self.style.border,
+            ));
         }
 
         // Bottom border
-        children.push(ViewNode::text_styled("╚═════════╝", self.style.border));
+        let bottom = format!("{}{}{}", b.bottom_left, rule(9), b.bottom_right);
+        children.push(ViewNode::text_styled(bottom, self.style.border));
 
         // Close instruction
         if self.closable {
@@ -151,6 +169,27 @@ impl Component for Modal {
             EventResult::Ignored
         }
     }
+    fn focus_changed(
+        &mut self,
+        previous: Option<ComponentId>,
+        current: Option<ComponentId>,
+        ctx: &mut EventContext,
+    ) {
+        if let Some(ref mut child) = self.child {
+            child.focus_changed(previous, current, ctx);
+        }
+    }
+
+    fn update(&mut self, ctx: &mut UpdateContext) -> bool {
+        if let Some(ref mut child) = self.child {
+            child.update(ctx);
+        }
+        true
+    }
+
+    fn children(&self) -> Vec<&dyn Component> {
+        self.child.iter().map(|child| child.as_ref()).collect()
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,416 @@
+//! Numeric stepper (spinbox) widget
+//!
+//! A number field with ▲/▼ buttons, keyboard stepping, and min/max clamping.
+
+use crate::event::{Event, EventResult, KeyCode, KeyModifiers, MouseButton, MouseEventKind};
+use crate::focus::ComponentId;
+use crate::layout::Rect;
+use crate::state::Signal;
+use crate::theme::{Color, Modifier, Style};
+use crate::view::{Component, EventContext, RenderContext, ViewNode};
+use std::cell::Cell;
+
+/// Numeric input with step buttons
+///
+/// Up/Down step by `step`, PageUp/PageDown by `page_step`. Digits can be
+/// typed directly and are committed with Enter (Esc cancels).
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let volume = Signal::new(50);
+/// let input = NumberInput::new(volume)
+///     .range(0, 100)
+///     .step(5)
+///     .page_step(25);
+/// ```
+pub struct NumberInput {
+    value: Signal<i64>,
+    min: i64,
+    max: i64,
+    step: i64,
+    page_step: i64,
+    /// Text being typed, committed on Enter
+    editing: Option<String>,
+    focused: bool,
+    focus_id: Option<ComponentId>,
+    style: NumberInputStyle,
+    /// Last rendered position, for clicking the arrows
+    last_rect: Cell<Option<Rect>>,
+}
+
+#[derive(Clone)]
+struct NumberInputStyle {
+    normal: Style,
+    focused: Style,
+    disabled: Style,
+}
+
+impl Default for NumberInputStyle {
+    fn default() -> Self {
+        Self {
+            normal: Style::default().fg(Color::WHITE).bg(Color::gray(40)),
+            focused: Style::default()
+                .fg(Color::WHITE)
+                .bg(Color::rgb(60, 60, 80))
+                .add_modifier(Modifier::BOLD),
+            disabled: Style::default().fg(Color::GRAY).add_modifier(Modifier::DIM),
+        }
+    }
+}
+
+impl NumberInput {
+    /// Create a stepper bound to a signal (full i64 range, step 1)
+    pub fn new(value: Signal<i64>) -> Self {
+        Self {
+            value,
+            min: i64::MIN,
+            max: i64::MAX,
+            step: 1,
+            page_step: 10,
+            editing: None,
+            focused: false,
+            focus_id: None,
+            style: NumberInputStyle::default(),
+            last_rect: Cell::new(None),
+        }
+    }
+
+    /// Set the allowed range (inclusive)
+    ///
+    /// A bound value outside it is left alone until edited; it's shown
+    /// clamped, and stepping starts from the clamped value.
+    pub fn range(mut self, min: i64, max: i64) -> Self {
+        let (min, max) = if min <= max { (min, max) } else { (max, min) };
+        self.min = min;
+        self.max = max;
+        self
+    }
+
+    /// Set the Up/Down step
+    pub fn step(mut self, step: i64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Set the PageUp/PageDown step
+    pub fn page_step(mut self, step: i64) -> Self {
+        self.page_step = step;
+        self
+    }
+
+    /// Set focused state
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Set the focus id used for focus highlighting
+    pub fn focus_id(mut self, id: ComponentId) -> Self {
+        self.focus_id = Some(id);
+        self
+    }
+
+    /// A range with a single value can't be changed
+    pub fn is_disabled(&self) -> bool {
+        self.min == self.max
+    }
+
+    /// Step the value by `delta`, saturating at the i64 bounds and clamping
+    fn step_by(&mut self, delta: i64) {
+        let (min, max) = (self.min, self.max);
+        self.value
+            .set_with(|v| (*v).clamp(min, max).saturating_add(delta).clamp(min, max));
+    }
+
+    /// Parse and commit whatever was typed
+    fn commit_edit(&mut self) {
+        if let Some(text) = self.editing.take() {
+            if let Ok(parsed) = text.parse::<i64>() {
+                self.value.set(parsed.clamp(self.min, self.max));
+            }
+            // Garbage like a lone "-" just gets dropped
+        }
+    }
+
+    fn display_value(&self) -> String {
+        match self.editing {
+            Some(ref text) => text.clone(),
+            None => self.value.get().clamp(self.min, self.max).to_string(),
+        }
+    }
+
+    fn handle_key(&mut self, code: KeyCode, modifiers: KeyModifiers) -> EventResult {
+        match code {
+            KeyCode::Up => {
+                self.commit_edit();
+                self.step_by(self.step);
+            }
+            KeyCode::Down => {
+                self.commit_edit();
+                self.step_by(self.step.saturating_neg());
+            }
+            KeyCode::PageUp => {
+                self.commit_edit();
+                self.step_by(self.page_step);
+            }
+            KeyCode::PageDown => {
+                self.commit_edit();
+                self.step_by(self.page_step.saturating_neg());
+            }
+            KeyCode::Char(c)
+                if (c.is_ascii_digit() || c == '-')
+                    && !modifiers.contains(KeyModifiers::CONTROL) =>
+            {
+                let text = self.editing.get_or_insert_with(String::new);
+                // Minus only makes sense as the first character
+                if c != '-' || text.is_empty() {
+                    text.push(c);
+                }
+            }
+            KeyCode::Backspace => match self.editing {
+                Some(ref mut text) => {
+                    text.pop();
+                }
+                None => return EventResult::Ignored,
+            },
+            KeyCode::Enter => {
+                if self.editing.is_none() {
+                    return EventResult::Ignored;
+                }
+                self.commit_edit();
+            }
+            KeyCode::Esc => {
+                if self.editing.take().is_none() {
+                    return EventResult::Ignored;
+                }
+            }
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Handled
+    }
+}
+
+impl Component for NumberInput {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        self.last_rect.set(Some(ctx.area));
+
+        let style = if self.is_disabled() {
+            self.style.disabled
+        } else if self.focused {
+            self.style.focused
+        } else {
+            self.style.normal
+        };
+
+        let glyphs = ctx.glyphs();
+        ViewNode::text_styled(
+            format!(
+                "{} {}{}",
+                self.display_value(),
+                glyphs.arrow_up,
+                glyphs.arrow_down
+            ),
+            style,
+        )
+    }
+
+    fn handle_event(&mut self, event: &Event, _ctx: &mut EventContext) -> EventResult {
+        if self.is_disabled() {
+            return EventResult::Ignored;
+        }
+
+        match event {
+            Event::Key(key) if self.focused => self.handle_key(key.code, key.modifiers),
+
+            Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                let Some(rect) = self.last_rect.get() else {
+                    return EventResult::Ignored;
+                };
+                if mouse.y != rect.y {
+                    return EventResult::Ignored;
+                }
+
+                // Arrows sit right after "<value> "
+                let up_x = rect.x + self.display_value().chars().count() as u16 + 1;
+                if mouse.x == up_x {
+                    self.commit_edit();
+                    self.step_by(self.step);
+                    EventResult::Handled
+                } else if mouse.x == up_x + 1 {
+                    self.commit_edit();
+                    self.step_by(self.step.saturating_neg());
+                    EventResult::Handled
+                } else {
+                    EventResult::Ignored
+                }
+            }
+
+            _ => EventResult::Ignored,
+        }
+    }
+
+    fn focus_id(&self) -> Option<ComponentId> {
+        self.focus_id
+    }
+
+    fn on_focus(&mut self, _ctx: &mut EventContext) {
+        self.focused = true;
+    }
+
+    fn on_blur(&mut self, _ctx: &mut EventContext) {
+        self.focused = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{KeyEvent, MouseEvent};
+    use crate::render::Buffer;
+    use crate::state::Store;
+
+    fn press(input: &mut NumberInput, code: KeyCode) -> EventResult {
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 20, 1),
+        };
+        let event = Event::Key(KeyEvent::new(code, KeyModifiers::empty()));
+        input.handle_event(&event, &mut ctx)
+    }
+
+    #[test]
+    fn test_stepping() {
+        let value = Signal::new(10);
+        let mut input = NumberInput::new(value.clone())
+            .step(2)
+            .page_step(5)
+            .focused(true);
+
+        press(&mut input, KeyCode::Up);
+        assert_eq!(value.get(), 12);
+        press(&mut input, KeyCode::Down);
+        press(&mut input, KeyCode::Down);
+        assert_eq!(value.get(), 8);
+        press(&mut input, KeyCode::PageUp);
+        assert_eq!(value.get(), 13);
+        press(&mut input, KeyCode::PageDown);
+        assert_eq!(value.get(), 8);
+    }
+
+    #[test]
+    fn test_clamping() {
+        let value = Signal::new(150);
+        let mut input = NumberInput::new(value.clone())
+            .range(0, 100)
+            .page_step(30)
+            .focused(true);
+
+        // Shown in range, but the signal isn't touched until an edit
+        assert_eq!(value.get(), 150);
+        assert_eq!(input.display_value(), "100");
+
+        press(&mut input, KeyCode::Up);
+        assert_eq!(value.get(), 100);
+
+        for _ in 0..5 {
+            press(&mut input, KeyCode::PageDown);
+        }
+        assert_eq!(value.get(), 0);
+    }
+
+    #[test]
+    fn test_overflow_at_bounds() {
+        let value = Signal::new(i64::MAX - 1);
+        let mut input = NumberInput::new(value.clone()).step(10).focused(true);
+
+        press(&mut input, KeyCode::Up);
+        assert_eq!(value.get(), i64::MAX);
+
+        value.set(i64::MIN);
+        input = input.step(i64::MIN);
+        press(&mut input, KeyCode::Up);
+        assert_eq!(value.get(), i64::MIN);
+        // Negating i64::MIN would overflow - saturates instead
+        press(&mut input, KeyCode::Down);
+        assert_eq!(value.get(), -1);
+    }
+
+    #[test]
+    fn test_disabled_when_min_equals_max() {
+        let value = Signal::new(5);
+        let mut input = NumberInput::new(value.clone()).range(5, 5).focused(true);
+
+        assert!(input.is_disabled());
+        assert_eq!(press(&mut input, KeyCode::Up), EventResult::Ignored);
+        assert_eq!(value.get(), 5);
+    }
+
+    #[test]
+    fn test_typing() {
+        let value = Signal::new(0);
+        let mut input = NumberInput::new(value.clone()).range(-50, 50).focused(true);
+
+        press(&mut input, KeyCode::Char('-'));
+        press(&mut input, KeyCode::Char('4'));
+        press(&mut input, KeyCode::Char('2'));
+        assert_eq!(input.display_value(), "-42");
+        assert_eq!(value.get(), 0); // Not committed yet
+
+        press(&mut input, KeyCode::Enter);
+        assert_eq!(value.get(), -42);
+
+        // Out of range gets clamped on commit
+        press(&mut input, KeyCode::Char('9'));
+        press(&mut input, KeyCode::Char('9'));
+        press(&mut input, KeyCode::Enter);
+        assert_eq!(value.get(), 50);
+
+        // Esc throws the edit away
+        press(&mut input, KeyCode::Char('1'));
+        press(&mut input, KeyCode::Esc);
+        assert_eq!(value.get(), 50);
+        assert_eq!(input.display_value(), "50");
+    }
+
+    #[test]
+    fn test_click_arrows() {
+        let value = Signal::new(7);
+        let mut input = NumberInput::new(value.clone());
+
+        let mut buffer = Buffer::new(20, 1);
+        let store = Store::new();
+        let ctx = RenderContext::new(&mut buffer, Rect::new(2, 0, 10, 1), &store);
+        match input.render(&ctx) {
+            ViewNode::Text { content, .. } => assert_eq!(content, "7 ▲▼"),
+            _ => panic!("Expected text node"),
+        }
+
+        let click = |x| {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                x,
+                y: 0,
+                modifiers: KeyModifiers::empty(),
+            })
+        };
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(2, 0, 10, 1),
+        };
+
+        // "7 ▲▼" starting at x=2: ▲ at 4, ▼ at 5
+        input.handle_event(&click(4), &mut ctx);
+        assert_eq!(value.get(), 8);
+        input.handle_event(&click(5), &mut ctx);
+        input.handle_event(&click(5), &mut ctx);
+        assert_eq!(value.get(), 6);
+        assert_eq!(
+            input.handle_event(&click(2), &mut ctx),
+            EventResult::Ignored
+        );
+    }
+}
@@ -5,7 +5,18 @@
 use crate::event::{Event, EventResult};
 use crate::state::Signal;
 use crate::theme::{Color, Modifier, Style};
-use crate::view::{Component, EventContext, RenderContext, ViewNode};
+use crate::view::{Component, EventContext, Glyphs, RenderContext, ViewNode};
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// Weight of the newest sample in the rate's moving average
+const RATE_SMOOTHING: f64 = 0.3;
+
+/// Rates below this (progress per second) count as stalled - no estimate
+const MIN_RATE: f64 = 1e-6;
+
+/// Longest ETA shown; anything beyond is displayed as this
+const MAX_ETA: Duration = Duration::from_secs(99 * 3600 + 59 * 60 + 59);
 
 /// Progress bar widget
 ///
@@ -20,16 +31,102 @@ use crate::view::{Component, EventContext, RenderContext, ViewNode};
 /// let bar = ProgressBar::new(progress)
 ///     .label("Loading...")
 ///     .width(40)
-///     .show_percentage(true);
+///     .show_percentage(true)
+///     .total_bytes(50_000_000)
+///     .show_eta(true);
 /// ```
 pub struct ProgressBar {
     progress: Signal<f32>, // 0.0 to 1.0
     label: Option<String>,
     width: usize,
     show_percentage: bool,
+    show_eta: bool,
+    /// Size of the whole job, for showing throughput
+    total_bytes: Option<u64>,
+    started: Instant,
+    // Sampled during render, hence the RefCell
+    estimator: RefCell<EtaEstimator>,
     style: ProgressStyle,
 }
 
+/// Estimates speed and time remaining from (progress, time) samples
+#[derive(Debug, Default)]
+struct EtaEstimator {
+    last: Option<(f32, Duration)>,
+    /// Smoothed progress per second
+    rate: Option<f64>,
+}
+
+impl EtaEstimator {
+    /// Feed the progress at a point in time
+    fn sample(&mut self, value: f32, at: Duration) {
+        let Some((last_value, last_at)) = self.last else {
+            self.last = Some((value, at));
+            return;
+        };
+
+        if value < last_value {
+            // Went backwards (restarted?) - old samples are meaningless
+            self.rate = None;
+            self.last = Some((value, at));
+            return;
+        }
+
+        let dt = at.saturating_sub(last_at).as_secs_f64();
+        if dt <= 0.0 {
+            return;
+        }
+
+        let instant = (value - last_value) as f64 / dt;
+        self.rate = Some(match self.rate {
+            Some(rate) => RATE_SMOOTHING * instant + (1.0 - RATE_SMOOTHING) * rate,
+            None => instant,
+        });
+        self.last = Some((value, at));
+    }
+
+    /// Progress per second, if known
+    fn rate(&self) -> Option<f64> {
+        self.rate
+    }
+
+    /// Time left to reach 100%, if there's enough to go on
+    fn eta(&self, value: f32) -> Option<Duration> {
+        let rate = self.rate.filter(|r| *r >= MIN_RATE)?;
+        if value <= 0.0 || value >= 1.0 {
+            return None;
+        }
+        let eta = Duration::try_from_secs_f64((1.0 - value) as f64 / rate).ok()?;
+        Some(eta.min(MAX_ETA))
+    }
+}
+
+/// Human-readable byte rate, e.g. "1.2MB/s"
+fn format_rate(bytes_per_sec: f64) -> String {
+    const UNITS: [&str; 4] = ["B", "KB", "MB", "GB"];
+    let mut value = bytes_per_sec;
+    let mut unit = 0;
+    while value >= 1000.0 && unit < UNITS.len() - 1 {
+        value /= 1000.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{:.0}{}/s", value, UNITS[unit])
+    } else {
+        format!("{:.1}{}/s", value, UNITS[unit])
+    }
+}
+
+/// mm:ss, or h:mm:ss for long waits
+fn format_eta(eta: Duration) -> String {
+    let secs = eta.as_secs();
+    if secs >= 3600 {
+        format!("{}:{:02}:{:02}", secs / 3600, (secs % 3600) / 60, secs % 60)
+    } else {
+        format!("{:02}:{:02}", secs / 60, secs % 60)
+    }
+}
+
 #[derive(Clone)]
 struct ProgressStyle {
     filled: Style,
@@ -41,7 +138,7 @@ impl Default for ProgressStyle {
     fn default() -> Self {
         Self {
             filled: Style::default().bg(Color::GREEN).fg(Color::BLACK),
-            empty: Style::default().bg(Color::rgb(40, 40, 40)).fg(Color::GRAY),
+            empty: Style::default().bg(Color::gray(40)).fg(Color::GRAY),
             label: Style::default().fg(Color::WHITE),
         }
     }
@@ -55,6 +152,10 @@ impl ProgressBar {
             label: None,
             width: 30,
             show_percentage: true,
+            show_eta: false,
+            total_bytes: None,
+            started: Instant::now(),
+            estimator: RefCell::new(EtaEstimator::default()),
             style: ProgressStyle::default(),
         }
     }
@@ -77,6 +178,18 @@ impl ProgressBar {
         self
     }
 
+    /// Show estimated time remaining (and throughput if `total_bytes` is set)
+    pub fn show_eta(mut self, show: bool) -> Self {
+        self.show_eta = show;
+        self
+    }
+
+    /// Total size of the job, used to show a transfer rate
+    pub fn total_bytes(mut self, total: u64) -> Self {
+        self.total_bytes = Some(total);
+        self
+    }
+
     /// Set filled bar color
     pub fn filled_color(mut self, color: Color) -> Self {
         self.style.filled = self.style.filled.bg(color);
@@ -84,21 +197,46 @@ impl ProgressBar {
     }
 
     /// Render the progress bar
-    fn render_bar(&self, progress: f32) -> String {
+    fn render_bar(&self, progress: f32, glyphs: &Glyphs) -> String {
         let filled_width = ((self.width as f32) * progress.clamp(0.0, 1.0)) as usize;
         let empty_width = self.width.saturating_sub(filled_width);
 
-        let filled = "█".repeat(filled_width);
-        let empty = "░".repeat(empty_width);
+        let filled = glyphs.bar_filled.to_string().repeat(filled_width);
+        let empty = glyphs.bar_empty.to_string().repeat(empty_width);
 
         format!("{}{}", filled, empty)
     }
+
+    /// The " • 1.2MB/s • ETA 00:15" part
+    fn eta_text(&self, progress: f32, glyphs: &Glyphs) -> String {
+        let estimator = self.estimator.borrow();
+        let mut text = String::new();
+
+        if let (Some(total), Some(rate)) = (self.total_bytes, estimator.rate()) {
+            text.push_str(&format!(
+                " {} {}",
+                glyphs.bullet,
+                format_rate(rate * total as f64)
+            ));
+        }
+
+        match estimator.eta(progress) {
+            Some(eta) => text.push_str(&format!(" {} ETA {}", glyphs.bullet, format_eta(eta))),
+            None => text.push_str(&format!(" {} ETA --:--", glyphs.bullet)),
+        }
+        text
+    }
 }
 
 impl Component for ProgressBar {
-    fn render(&self, _ctx: &RenderContext) -> ViewNode {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
         let progress = self.progress.get().clamp(0.0, 1.0);
-        let bar = self.render_bar(progress);
+        if self.show_eta {
+            self.estimator
+                .borrow_mut()
+                .sample(progress, self.started.elapsed());
+        }
+        let bar = self.render_bar(progress, ctx.glyphs());
 
         let mut parts = Vec::new();
 
@@ -129,6 +267,13 @@ impl Component for ProgressBar {
             ));
         }
 
+        if self.show_eta {
+            parts.push(ViewNode::text_styled(
+                self.eta_text(progress, ctx.glyphs()),
+                self.style.label,
+            ));
+        }
+
         // Combine horizontally
         let combined = parts
             .into_iter()
@@ -164,7 +309,7 @@ mod tests {
         let progress = Signal::new(0.5);
         let bar = ProgressBar::new(progress).width(10);
 
-        let rendered = bar.render_bar(0.5);
+        let rendered = bar.render_bar(0.5, &Glyphs::UNICODE);
         assert_eq!(rendered.chars().count(), 10);
     }
 
@@ -173,7 +318,7 @@ mod tests {
         let progress = Signal::new(1.5); // Over 100%
         let bar = ProgressBar::new(progress).width(10);
 
-        let rendered = bar.render_bar(1.5);
+        let rendered = bar.render_bar(1.5, &Glyphs::UNICODE);
         assert_eq!(rendered.chars().filter(|&c| c == '█').count(), 10); // All filled
     }
 
@@ -190,7 +335,7 @@ mod tests {
         let progress = Signal::new(0.0);
         let bar = ProgressBar::new(progress).width(10);
 
-        let rendered = bar.render_bar(0.0);
+        let rendered = bar.render_bar(0.0, &Glyphs::UNICODE);
         assert_eq!(rendered.chars().filter(|&c| c == '░').count(), 10); // All empty
     }
 
@@ -199,7 +344,109 @@ mod tests {
         let progress = Signal::new(1.0);
         let bar = ProgressBar::new(progress).width(10);
 
-        let rendered = bar.render_bar(1.0);
+        let rendered = bar.render_bar(1.0, &Glyphs::UNICODE);
         assert_eq!(rendered.chars().filter(|&c| c == '█').count(), 10); // All filled
     }
+
+    #[test]
+    fn test_eta_from_timed_samples() {
+        let mut est = EtaEstimator::default();
+
+        // 10% per second, steady
+        for i in 0..5 {
+            est.sample(i as f32 * 0.1, Duration::from_secs(i));
+        }
+
+        let eta = est.eta(0.4).unwrap();
+        assert!((eta.as_secs_f64() - 6.0).abs() < 0.1, "eta was {:?}", eta);
+    }
+
+    #[test]
+    fn test_rate_is_smoothed() {
+        let mut est = EtaEstimator::default();
+        est.sample(0.0, Duration::from_secs(0));
+        est.sample(0.1, Duration::from_secs(1));
+        // One burst shouldn't swing the estimate all the way
+        est.sample(0.6, Duration::from_secs(2));
+
+        let rate = est.rate().unwrap();
+        assert!(rate > 0.1 && rate < 0.5, "rate was {}", rate);
+    }
+
+    #[test]
+    fn test_eta_edge_cases() {
+        let mut est = EtaEstimator::default();
+
+        // Nothing done yet - no estimate
+        est.sample(0.0, Duration::from_secs(0));
+        est.sample(0.0, Duration::from_secs(5));
+        assert_eq!(est.eta(0.0), None);
+
+        // Going backwards resets
+        est.sample(0.5, Duration::from_secs(6));
+        assert!(est.rate().is_some());
+        est.sample(0.2, Duration::from_secs(7));
+        assert_eq!(est.rate(), None);
+        assert_eq!(est.eta(0.2), None);
+    }
+
+    #[test]
+    fn test_stalled_progress_has_no_eta() {
+        let mut est = EtaEstimator::default();
+        est.sample(0.1, Duration::from_secs(0));
+        est.sample(0.2, Duration::from_secs(1));
+
+        // The smoothed rate decays toward zero while nothing moves
+        for i in 0..500 {
+            est.sample(
+                0.2,
+                Duration::from_secs(1) + Duration::from_millis(16 * (i + 1)),
+            );
+        }
+        assert_eq!(est.eta(0.2), None);
+
+        // Slow but moving is capped rather than absurd
+        let mut est = EtaEstimator::default();
+        est.sample(0.1, Duration::from_secs(0));
+        est.sample(0.102, Duration::from_secs(1_000));
+        assert_eq!(est.eta(0.102), Some(MAX_ETA));
+    }
+
+    #[test]
+    fn test_stalled_bar_renders() {
+        use crate::layout::Rect;
+        use crate::render::Buffer;
+        use crate::state::Store;
+
+        let progress = Signal::new(0.3);
+        let bar = ProgressBar::new(progress.clone()).show_eta(true);
+        let mut buffer = Buffer::new(80, 1);
+        let store = Store::new();
+        let ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 80, 1), &store);
+
+        bar.render(&ctx);
+        progress.set(0.4);
+        for _ in 0..500 {
+            bar.render(&ctx);
+        }
+    }
+
+    #[test]
+    fn test_eta_text_format() {
+        let bar = ProgressBar::new(Signal::new(0.42))
+            .total_bytes(10_000_000)
+            .show_eta(true);
+        {
+            let mut est = bar.estimator.borrow_mut();
+            est.sample(0.30, Duration::from_secs(0));
+            est.sample(0.42, Duration::from_secs(1));
+        }
+
+        assert_eq!(
+            bar.eta_text(0.42, &Glyphs::UNICODE),
+            " • 1.2MB/s • ETA 00:04"
+        );
+        assert_eq!(format_eta(Duration::from_secs(3725)), "1:02:05");
+        assert_eq!(format_rate(512.0), "512B/s");
+    }
 }
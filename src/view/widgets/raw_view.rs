@@ -0,0 +1,152 @@
+//! Raw terminal output widget
+//!
+//! Shows ANSI-encoded output from another program (a pager, git, etc.)
+//! inside a normal widget area.
+
+use crate::render::AnsiParser;
+use crate::state::Signal;
+use crate::view::{Component, RenderContext, ViewNode};
+use std::cell::RefCell;
+
+/// Renders ANSI terminal output from a signal
+///
+/// The signal is treated as an append-only log: new bytes are parsed
+/// incrementally, so an escape sequence split between two updates is
+/// handled fine. If the data shrinks (or the area changes size) the whole
+/// thing is parsed again from scratch.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let output = Signal::new(Vec::new());
+/// let view = RawView::new(output.clone());
+///
+/// // e.g. from a child process's stdout
+/// output.update(|buf| buf.extend_from_slice(b"\x1b[32mok\x1b[0m\n"));
+/// ```
+pub struct RawView {
+    data: Signal<Vec<u8>>,
+    // Parsing happens lazily in render
+    state: RefCell<RawState>,
+}
+
+struct RawState {
+    parser: AnsiParser,
+    size: (u16, u16),
+    /// How much of the signal's data has been fed to the parser
+    consumed: usize,
+}
+
+impl RawView {
+    /// Create a view over a byte signal
+    pub fn new(data: Signal<Vec<u8>>) -> Self {
+        Self {
+            data,
+            state: RefCell::new(RawState {
+                parser: AnsiParser::new(0, 0),
+                size: (0, 0),
+                consumed: 0,
+            }),
+        }
+    }
+
+    /// Bring the parser up to date with the signal for a given size
+    ///
+    /// Only bytes appended since the last sync are parsed, read in place
+    /// rather than cloning the whole log.
+    fn sync(&self, width: u16, height: u16) {
+        let mut state = self.state.borrow_mut();
+
+        self.data.with(|data| {
+            if state.size != (width, height) || data.len() < state.consumed {
+                state.parser = AnsiParser::new(width, height);
+                state.size = (width, height);
+                state.consumed = 0;
+            }
+
+            let start = state.consumed;
+            state.parser.feed(&data[start..]);
+            state.consumed = data.len();
+        });
+    }
+}
+
+impl Component for RawView {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        self.sync(ctx.area.width, ctx.area.height);
+
+        let state = self.state.borrow();
+        let buffer = state.parser.buffer();
+        let lines = (0..buffer.height)
+            .map(|y| buffer.line(y).to_vec())
+            .collect();
+
+        ViewNode::cells(lines)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Rect;
+    use crate::render::Buffer;
+    use crate::state::Store;
+    use crate::theme::Color;
+
+    fn render(view: &RawView, area: Rect) -> Buffer {
+        let mut buffer = Buffer::new(area.width, area.height);
+        let store = Store::new();
+        let node = {
+            let ctx = RenderContext::new(&mut buffer, area, &store);
+            view.render(&ctx)
+        };
+        node.render(&mut RenderContext::new(&mut buffer, area, &store));
+        buffer
+    }
+
+    #[test]
+    fn test_renders_colored_output() {
+        let data = Signal::new(b"\x1b[31mred\x1b[0m ok".to_vec());
+        let view = RawView::new(data);
+
+        let buffer = render(&view, Rect::new(0, 0, 10, 2));
+        let r = buffer.get(0, 0).unwrap();
+        assert_eq!(r.ch, 'r');
+        assert_eq!(r.style.fg, Some(Color::rgb(205, 0, 0)));
+        assert_eq!(buffer.get(4, 0).unwrap().ch, 'o');
+        assert_eq!(buffer.get(4, 0).unwrap().style.fg, None);
+    }
+
+    #[test]
+    fn test_appended_data_across_updates() {
+        let data = Signal::new(b"\x1b[3".to_vec());
+        let view = RawView::new(data.clone());
+        let area = Rect::new(0, 0, 10, 2);
+
+        // Half a sequence - nothing to show yet
+        let buffer = render(&view, area);
+        assert_eq!(buffer.get(0, 0).unwrap().ch, '\0');
+
+        data.update(|d| d.extend_from_slice(b"4mblue"));
+        let buffer = render(&view, area);
+        assert_eq!(buffer.get(0, 0).unwrap().ch, 'b');
+        assert_eq!(
+            buffer.get(0, 0).unwrap().style.fg,
+            Some(Color::rgb(0, 0, 238))
+        );
+    }
+
+    #[test]
+    fn test_shrinking_data_reparses() {
+        let data = Signal::new(b"first".to_vec());
+        let view = RawView::new(data.clone());
+        let area = Rect::new(0, 0, 10, 1);
+        render(&view, area);
+
+        data.set(b"2nd".to_vec());
+        let buffer = render(&view, area);
+        assert_eq!(buffer.get(0, 0).unwrap().ch, '2');
+        assert_eq!(buffer.get(3, 0).unwrap().ch, '\0');
+    }
+}
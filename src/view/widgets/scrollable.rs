@@ -2,14 +2,26 @@
 //!
 //! Wraps content in a scrollable viewport with scrollbar indicators.
 
-use crate::event::{Event, EventResult, KeyCode};
+use crate::animation::{EasingFunction, Tween};
+use crate::event::{Event, EventResult, KeyCode, MouseEventKind};
+use crate::focus::ComponentId;
+use crate::layout::{Length, Rect};
+use crate::render::Buffer;
 use crate::state::Signal;
 use crate::theme::{Color, Style};
-use crate::view::{Component, EventContext, RenderContext, ViewNode};
+use crate::view::{
+    Component, ContainerDirection, EventContext, RenderContext, UpdateContext, ViewNode,
+};
+use std::cell::Cell;
+use std::time::Duration;
 
 /// Scrollable container that handles content overflow
 ///
 /// Provides vertical scrolling for content that exceeds the visible area.
+/// The child is drawn at its full height off screen and only the
+/// `height` lines from the scroll offset down are shown. A vertical
+/// container child gets one row per line of each of its children, so a
+/// `VStack` of 100 `Text`s is 100 lines tall.
 ///
 /// ## Example
 /// ```no_run
@@ -28,15 +40,24 @@ use crate::view::{Component, EventContext, RenderContext, ViewNode};
 ///
 /// ## Features
 /// - Vertical scrolling with Up/Down/PageUp/PageDown
+/// - Configurable lines per step and optional smooth scrolling
 /// - Optional scrollbar with position indicator
-/// - Mouse wheel support (future)
+/// - Mouse wheel scrolling over the viewport
 /// - Auto-scroll to keep focused content visible
 pub struct Scrollable {
     child: Box<dyn Component>,
     scroll_offset: Signal<usize>,
     viewport_height: usize,
-    content_height: usize,
+    /// Measured from the child on each render
+    content_height: Cell<usize>,
     show_scrollbar: bool,
+    scroll_step: usize,
+    smooth: bool,
+    smooth_duration: Duration,
+    /// Running smooth scroll and the offset it ends at
+    animation: Option<(Tween<f64>, usize)>,
+    /// Where we last rendered, for mouse events
+    last_rect: Cell<Option<Rect>>,
     style: ScrollStyle,
 }
 
@@ -49,7 +70,7 @@ struct ScrollStyle {
 impl Default for ScrollStyle {
     fn default() -> Self {
         Self {
-            scrollbar: Style::default().fg(Color::rgb(60, 60, 60)),
+            scrollbar: Style::default().fg(Color::gray(60)),
             indicator: Style::default().fg(Color::CYAN),
         }
     }
@@ -62,8 +83,13 @@ impl Scrollable {
             child: Box::new(child),
             scroll_offset: Signal::new(0),
             viewport_height: 10,
-            content_height: 0, // Will be calculated
+            content_height: Cell::new(0),
             show_scrollbar: true,
+            scroll_step: 1,
+            smooth: false,
+            smooth_duration: Duration::from_millis(150),
+            animation: None,
+            last_rect: Cell::new(None),
             style: ScrollStyle::default(),
         }
     }
@@ -80,63 +106,108 @@ impl Scrollable {
         self
     }
 
-    /// Scroll down by one line
-    pub fn scroll_down(&mut self) {
-        let current = self.scroll_offset.get();
-        let max_offset = self.content_height.saturating_sub(self.viewport_height);
+    /// Set how many lines Up/Down scroll (default 1)
+    pub fn scroll_step(mut self, lines: usize) -> Self {
+        self.scroll_step = lines.max(1);
+        self
+    }
 
-        if current < max_offset {
-            self.scroll_offset.set(current + 1);
-        }
+    /// Animate the offset toward where a scroll ends up instead of
+    /// jumping there (off by default)
+    ///
+    /// The offset moves a whole line at a time over `smooth_duration`.
+    /// Scrolling again mid-animation adds to where the running one was
+    /// headed, so quick repeated input still covers the full distance.
+    pub fn smooth(mut self, smooth: bool) -> Self {
+        self.smooth = smooth;
+        self
+    }
+
+    /// Set how long a smooth scroll takes (default 150ms)
+    pub fn smooth_duration(mut self, duration: Duration) -> Self {
+        self.smooth_duration = duration;
+        self
+    }
+
+    /// Scroll down by one step
+    pub fn scroll_down(&mut self) {
+        let next = (self.target_offset() + self.scroll_step).min(self.max_offset());
+        self.scroll_to(next);
     }
 
-    /// Scroll up by one line
+    /// Scroll up by one step
     pub fn scroll_up(&mut self) {
-        let current = self.scroll_offset.get();
-        if current > 0 {
-            self.scroll_offset.set(current - 1);
-        }
+        let prev = self.target_offset().saturating_sub(self.scroll_step);
+        self.scroll_to(prev);
     }
 
     /// Page down
     pub fn page_down(&mut self) {
-        let current = self.scroll_offset.get();
-        let max_offset = self.content_height.saturating_sub(self.viewport_height);
-        let next = (current + self.viewport_height).min(max_offset);
-
-        self.scroll_offset.set(next);
+        let next = (self.target_offset() + self.viewport_height).min(self.max_offset());
+        self.scroll_to(next);
     }
 
     /// Page up
     pub fn page_up(&mut self) {
-        let current = self.scroll_offset.get();
-        let prev = current.saturating_sub(self.viewport_height);
-
-        self.scroll_offset.set(prev);
+        let prev = self.target_offset().saturating_sub(self.viewport_height);
+        self.scroll_to(prev);
     }
 
     /// Scroll to top
     pub fn scroll_to_top(&mut self) {
-        self.scroll_offset.set(0);
+        self.scroll_to(0);
     }
 
     /// Scroll to bottom
     pub fn scroll_to_bottom(&mut self) {
-        let max_offset = self.content_height.saturating_sub(self.viewport_height);
-        self.scroll_offset.set(max_offset);
+        self.scroll_to(self.max_offset());
+    }
+
+    fn max_offset(&self) -> usize {
+        self.content_height
+            .get()
+            .saturating_sub(self.viewport_height)
+    }
+
+    /// Where scrolling is headed: the end of a running smooth scroll, or
+    /// the current offset
+    fn target_offset(&self) -> usize {
+        match self.animation {
+            Some((_, target)) => target,
+            None => self.scroll_offset.get(),
+        }
+    }
+
+    /// Move to `offset`, animated in smooth mode
+    fn scroll_to(&mut self, offset: usize) {
+        if !self.smooth {
+            self.animation = None;
+            self.scroll_offset.set(offset);
+            return;
+        }
+        if offset == self.target_offset() {
+            return;
+        }
+
+        // Carry on from wherever a running animation has got to
+        let from = match self.animation {
+            Some((ref tween, _)) => tween.value(),
+            None => self.scroll_offset.get() as f64,
+        };
+        let tween = Tween::new(from, offset as f64, self.smooth_duration)
+            .easing(EasingFunction::EaseOutCubic);
+        self.animation = Some((tween, offset));
     }
 
     /// Render scrollbar indicator
     fn render_scrollbar(&self) -> ViewNode {
-        if !self.show_scrollbar || self.content_height <= self.viewport_height {
+        if !self.show_scrollbar || self.content_height.get() <= self.viewport_height {
             return ViewNode::text("");
         }
 
-        let scroll_position = if self.content_height > 0 {
-            (self.scroll_offset.get() as f32 / self.content_height as f32 * 100.0) as u32
-        } else {
-            0
-        };
+        // 0% at the top, 100% once the last line is in view
+        let offset = self.scroll_offset.get().min(self.max_offset());
+        let scroll_position = (offset as f32 / self.max_offset() as f32 * 100.0) as u32;
 
         let indicator = format!(" [{}%]", scroll_position);
         ViewNode::text_styled(indicator, self.style.indicator)
@@ -145,21 +216,45 @@ impl Scrollable {
 
 impl Component for Scrollable {
     fn render(&self, ctx: &RenderContext) -> ViewNode {
-        // Render child content
-        let content = self.child.render(ctx);
-
-        // For now, we render all content with a note about scrolling
-        // In a full implementation, we'd clip to viewport
-        let mut children = Vec::new();
-
-        children.push(content);
+        self.last_rect.set(Some(ctx.area));
+        let content = natural_layout(ctx.render_child(self.child.as_ref()));
+        let content_height = natural_height(&content);
+        self.content_height.set(content_height as usize);
+
+        // Draw everything off screen, then keep the rows in view
+        let width = ctx.area.width;
+        let mut buffer = Buffer::new(width, content_height);
+        {
+            let mut offscreen = RenderContext::new(
+                &mut buffer,
+                Rect::new(0, 0, width, content_height),
+                ctx.store,
+            );
+            offscreen.style = ctx.style;
+            offscreen.focused = ctx.focused;
+            offscreen.theme = ctx.theme;
+            offscreen.mode = ctx.mode;
+            content.render(&mut offscreen);
+        }
 
-        // Add scroll indicator
+        let offset = self.scroll_offset.get().min(self.max_offset());
+        let visible = self.viewport_height.min(content_height as usize - offset);
+        let lines = (offset..offset + visible)
+            .map(|y| {
+                (0..width)
+                    .map(|x| buffer.get(x, y as u16).cloned().unwrap_or_default())
+                    .collect()
+            })
+            .collect();
+
+        let mut items = vec![(
+            Length::Fixed(self.viewport_height as u16),
+            ViewNode::cells(lines),
+        )];
         if self.show_scrollbar {
-            children.push(self.render_scrollbar());
+            items.push((Length::Fixed(1), self.render_scrollbar()));
         }
-
-        ViewNode::container(children)
+        ViewNode::sized(ContainerDirection::Vertical, items)
     }
 
     fn handle_event(&mut self, event: &Event, ctx: &mut EventContext) -> EventResult {
@@ -198,9 +293,125 @@ impl Component for Scrollable {
                 }
                 _ => EventResult::Ignored,
             },
+            // The wheel scrolls a step, like Up/Down, over the viewport only
+            Event::Mouse(mouse)
+                if self
+                    .last_rect
+                    .get()
+                    .is_some_and(|rect| rect.contains(mouse.x, mouse.y)) =>
+            {
+                match mouse.kind {
+                    MouseEventKind::ScrollUp => {
+                        self.scroll_up();
+                        EventResult::Handled
+                    }
+                    MouseEventKind::ScrollDown => {
+                        self.scroll_down();
+                        EventResult::Handled
+                    }
+                    _ => EventResult::Ignored,
+                }
+            }
             _ => EventResult::Ignored,
         }
     }
+    fn focus_changed(
+        &mut self,
+        previous: Option<ComponentId>,
+        current: Option<ComponentId>,
+        ctx: &mut EventContext,
+    ) {
+        self.child.focus_changed(previous, current, ctx);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateContext) -> bool {
+        self.child.update(ctx);
+
+        if let Some((ref mut tween, target)) = self.animation {
+            tween.update(ctx.delta);
+            if tween.is_complete() {
+                self.scroll_offset.set(target);
+                self.animation = None;
+            } else {
+                self.scroll_offset.set(tween.value().round() as usize);
+            }
+        }
+        true
+    }
+
+    fn children(&self) -> Vec<&dyn Component> {
+        vec![self.child.as_ref()]
+    }
+}
+
+/// Give each child of a vertical container as many rows as it needs,
+/// rather than an equal share of the area
+fn natural_layout(node: ViewNode) -> ViewNode {
+    match node {
+        ViewNode::Container {
+            children,
+            direction: ContainerDirection::Vertical,
+            style,
+            ..
+        } if style == Style::default() => ViewNode::sized(
+            ContainerDirection::Vertical,
+            children
+                .into_iter()
+                .map(|child| (Length::Fixed(natural_height(&child)), child))
+                .collect(),
+        ),
+        other => other,
+    }
+}
+
+/// How many rows a node takes when it isn't squeezed
+fn natural_height(node: &ViewNode) -> u16 {
+    match node {
+        ViewNode::Text { content, .. } => content.split('\n').count() as u16,
+        ViewNode::Spans { .. } => 1,
+        ViewNode::Cells { lines } => lines.len() as u16,
+        ViewNode::Border { child, .. } => natural_height(child).saturating_add(2),
+        ViewNode::Focusable { child, .. } | ViewNode::LowPriority { child } => {
+            natural_height(child)
+        }
+        ViewNode::Container {
+            children,
+            direction,
+            ..
+        } => stacked_height(children.iter().map(natural_height), *direction, 0),
+        ViewNode::Layout {
+            children,
+            sizes,
+            direction,
+            gap,
+        } => {
+            let heights = children.iter().zip(sizes).map(|(child, size)| match size {
+                Length::Fixed(n) if *direction == ContainerDirection::Vertical => *n,
+                _ => natural_height(child),
+            });
+            stacked_height(heights, *direction, *gap)
+        }
+        ViewNode::Empty => 0,
+    }
+}
+
+/// Rows taken by children laid out in `direction`
+fn stacked_height(
+    heights: impl Iterator<Item = u16>,
+    direction: ContainerDirection,
+    gap: u16,
+) -> u16 {
+    match direction {
+        ContainerDirection::Vertical => {
+            let mut count = 0u16;
+            let total = heights.fold(0u16, |sum, h| {
+                count += 1;
+                sum.saturating_add(h)
+            });
+            total.saturating_add(gap.saturating_mul(count.saturating_sub(1)))
+        }
+        ContainerDirection::Horizontal | ContainerDirection::Stacked => heights.max().unwrap_or(0),
+    }
 }
 
 #[cfg(test)]
@@ -222,7 +433,7 @@ mod tests {
         let content = Text::new("Test");
         let mut scrollable = Scrollable::new(content).height(10);
 
-        scrollable.content_height = 50; // Simulate content
+        scrollable.content_height.set(50); // Simulate content
         scrollable.scroll_down();
 
         assert_eq!(scrollable.scroll_offset.get(), 1);
@@ -244,7 +455,7 @@ mod tests {
         let content = Text::new("Test");
         let mut scrollable = Scrollable::new(content).height(10);
 
-        scrollable.content_height = 100;
+        scrollable.content_height.set(100);
         scrollable.page_down();
 
         assert_eq!(scrollable.scroll_offset.get(), 10);
@@ -258,7 +469,7 @@ mod tests {
         let content = Text::new("Test");
         let mut scrollable = Scrollable::new(content).height(10);
 
-        scrollable.content_height = 100;
+        scrollable.content_height.set(100);
         scrollable.scroll_to_bottom();
 
         assert_eq!(scrollable.scroll_offset.get(), 90);
@@ -266,4 +477,117 @@ mod tests {
         scrollable.scroll_to_top();
         assert_eq!(scrollable.scroll_offset.get(), 0);
     }
+
+    #[test]
+    fn test_scroll_step() {
+        let mut scrollable = Scrollable::new(Text::new("Test")).height(10).scroll_step(3);
+        scrollable.content_height.set(14);
+
+        scrollable.scroll_down();
+        assert_eq!(scrollable.scroll_offset.get(), 3);
+        scrollable.scroll_down();
+        assert_eq!(scrollable.scroll_offset.get(), 4);
+        scrollable.scroll_up();
+        assert_eq!(scrollable.scroll_offset.get(), 1);
+    }
+
+    #[test]
+    fn test_smooth_scroll_animates_across_ticks() {
+        use crate::state::Store;
+
+        let mut scrollable = Scrollable::new(Text::new("Test"))
+            .height(10)
+            .scroll_step(3)
+            .smooth(true)
+            .smooth_duration(Duration::from_millis(100));
+        scrollable.content_height.set(100);
+        let store = Store::new();
+        let tick = |scrollable: &mut Scrollable| {
+            let mut ctx = UpdateContext {
+                store: &store,
+                delta: Duration::from_millis(25),
+            };
+            scrollable.update(&mut ctx);
+            scrollable.scroll_offset.get()
+        };
+
+        // Rapid presses add up before the first frame moves anything
+        scrollable.scroll_down();
+        scrollable.scroll_down();
+        assert_eq!(scrollable.scroll_offset.get(), 0);
+
+        let steps: Vec<usize> = (0..4).map(|_| tick(&mut scrollable)).collect();
+        assert!(steps.windows(2).all(|w| w[0] <= w[1]));
+        assert!(steps[0] > 0 && steps[0] < 6);
+        assert_eq!(steps[3], 6);
+        assert!(scrollable.animation.is_none());
+
+        // Reversing mid-animation heads back from where it got to
+        scrollable.page_down();
+        let partway = tick(&mut scrollable);
+        scrollable.scroll_up();
+        assert_eq!(scrollable.target_offset(), 13);
+        let after = (0..4).map(|_| tick(&mut scrollable)).last().unwrap();
+        assert!(partway > 6);
+        assert_eq!(after, 13);
+    }
+
+    #[test]
+    fn test_shows_lines_from_the_offset() {
+        use crate::testing::{buffer_lines, render_to_buffer};
+        use crate::view::VStack;
+
+        let mut content = VStack::new();
+        for i in 0..100 {
+            content = content.push(Text::new(format!("Line {}", i)));
+        }
+        let mut scrollable = Scrollable::new(content).height(3);
+
+        let lines = buffer_lines(&render_to_buffer(&scrollable, 20, 4));
+        assert_eq!(lines, ["Line 0", "Line 1", "Line 2", " [0%]"]);
+        assert_eq!(scrollable.content_height.get(), 100);
+
+        scrollable.scroll_offset.set(42);
+        let lines = buffer_lines(&render_to_buffer(&scrollable, 20, 4));
+        assert_eq!(lines, ["Line 42", "Line 43", "Line 44", " [43%]"]);
+
+        scrollable.scroll_to_bottom();
+        scrollable.scroll_down();
+        let lines = buffer_lines(&render_to_buffer(&scrollable, 20, 4));
+        assert_eq!(lines, ["Line 97", "Line 98", "Line 99", " [100%]"]);
+    }
+
+    #[test]
+    fn test_wheel_scrolls_the_viewport() {
+        use crate::event::{KeyModifiers, MouseEvent};
+        use crate::state::Store;
+        use crate::testing::render_to_buffer;
+        use crate::view::VStack;
+
+        let mut content = VStack::new();
+        for i in 0..20 {
+            content = content.push(Text::new(format!("Line {}", i)));
+        }
+        let mut scrollable = Scrollable::new(content).height(3).scroll_step(2);
+        render_to_buffer(&scrollable, 20, 4);
+
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 20, 4),
+        };
+        let wheel = |kind| {
+            Event::Mouse(MouseEvent {
+                kind,
+                x: 0,
+                y: 1,
+                modifiers: KeyModifiers::empty(),
+            })
+        };
+
+        scrollable.handle_event(&wheel(MouseEventKind::ScrollDown), &mut ctx);
+        scrollable.handle_event(&wheel(MouseEventKind::ScrollDown), &mut ctx);
+        scrollable.handle_event(&wheel(MouseEventKind::ScrollUp), &mut ctx);
+        assert_eq!(scrollable.scroll_offset.get(), 2);
+    }
 }
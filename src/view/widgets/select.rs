@@ -0,0 +1,319 @@
+//! Dropdown select widget
+//!
+//! Shows the current choice on one line and expands into a `List` below
+//! it for picking another.
+
+use super::List;
+use crate::event::{Event, EventResult, KeyCode, MouseButton, MouseEventKind};
+use crate::focus::ComponentId;
+use crate::layout::{Length, Rect};
+use crate::state::Signal;
+use crate::theme::{Color, Style};
+use crate::view::{Component, ContainerDirection, EventContext, RenderContext, ViewNode};
+use std::cell::Cell;
+use std::fmt::Display;
+
+/// Single-choice selector that opens a popup list, like HTML's `<select>`
+///
+/// Collapsed, it shows the selected option followed by `▾`. Enter, Space
+/// or a click opens the list; Up/Down move through it and Enter, Space or
+/// a click on an option picks it, which writes the bound selection and
+/// collapses. Esc (or clicking the header again) closes the list without
+/// changing anything.
+///
+/// The open list draws over whatever is below the header, within the
+/// select's own area - give it room for `max_visible` rows plus one.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let sizes = Signal::new(vec!["Small", "Medium", "Large"]);
+/// let size = Signal::new(Some(1));
+/// let select = Select::new(sizes, size).max_visible(5);
+/// ```
+pub struct Select<T> {
+    options: Signal<Vec<T>>,
+    selected: Signal<Option<usize>>,
+    /// The list's cursor while open, only copied to `selected` on a pick
+    highlight: Signal<Option<usize>>,
+    list: List<T>,
+    open: bool,
+    max_visible: usize,
+    placeholder: String,
+    focus_id: Option<ComponentId>,
+    /// Where we last rendered, for mapping clicks
+    last_rect: Cell<Option<Rect>>,
+    style: SelectStyle,
+}
+
+#[derive(Clone)]
+struct SelectStyle {
+    header: Style,
+    popup_bg: Color,
+}
+
+impl Default for SelectStyle {
+    fn default() -> Self {
+        Self {
+            header: Style::default().fg(Color::WHITE).bg(Color::gray(40)),
+            popup_bg: Color::gray(25),
+        }
+    }
+}
+
+impl<T: Clone + Display + Send + Sync + 'static> Select<T> {
+    /// Create a select over `options`, with the chosen index in `selected`
+    pub fn new(options: Signal<Vec<T>>, selected: Signal<Option<usize>>) -> Self {
+        let highlight = Signal::new(None);
+        let max_visible = 8;
+        let list = List::new(options.clone(), highlight.clone())
+            .visible_height(max_visible)
+            .render_item(|item: &T, _| ViewNode::text(item.to_string()));
+        Self {
+            options,
+            selected,
+            highlight,
+            list,
+            open: false,
+            max_visible,
+            placeholder: "(none)".to_string(),
+            focus_id: None,
+            last_rect: Cell::new(None),
+            style: SelectStyle::default(),
+        }
+    }
+
+    /// Set how many options the open list shows at once (default 8)
+    pub fn max_visible(mut self, rows: usize) -> Self {
+        self.max_visible = rows.max(1);
+        self.list = self.list.visible_height(self.max_visible);
+        self
+    }
+
+    /// Set the text shown when nothing is selected (default "(none)")
+    pub fn placeholder(mut self, text: impl Into<String>) -> Self {
+        self.placeholder = text.into();
+        self
+    }
+
+    /// Set the focus id used for focus highlighting
+    pub fn focus_id(mut self, id: ComponentId) -> Self {
+        self.focus_id = Some(id);
+        self
+    }
+
+    /// Check if the list is expanded
+    pub fn is_open(&self) -> bool {
+        self.open
+    }
+
+    /// Expand the list, starting on the current choice
+    fn open(&mut self) {
+        let len = self.options.with(|options| options.len());
+        let start = self.selected.get().filter(|&i| i < len);
+        self.highlight
+            .set(start.or(if len > 0 { Some(0) } else { None }));
+        self.open = true;
+    }
+
+    /// Take the highlighted option and collapse
+    fn pick(&mut self, index: Option<usize>) {
+        if index.is_some() {
+            self.selected.set(index);
+        }
+        self.open = false;
+    }
+
+    /// Rows the open list takes, scroll indicator included
+    fn popup_height(&self) -> usize {
+        let len = self.options.with(|options| options.len());
+        if len > self.max_visible {
+            self.max_visible + 1
+        } else {
+            len.max(1)
+        }
+    }
+
+    fn handle_click(&mut self, x: u16, y: u16) -> EventResult {
+        let Some(rect) = self.last_rect.get() else {
+            return EventResult::Ignored;
+        };
+        if !rect.contains(x, y) {
+            return EventResult::Ignored;
+        }
+        if y == rect.y {
+            if self.open {
+                self.open = false;
+            } else {
+                self.open();
+            }
+            return EventResult::Handled;
+        }
+        if !self.open {
+            return EventResult::Ignored;
+        }
+        // The list rendered in our area, so its rows start one below ours
+        match self.list.index_at(y - 1) {
+            Some(index) => {
+                self.pick(Some(index));
+                EventResult::Handled
+            }
+            None => EventResult::Ignored,
+        }
+    }
+}
+
+impl<T: Clone + Display + Send + Sync + 'static> Component for Select<T> {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        self.last_rect.set(Some(ctx.area));
+
+        let label = self.options.with(|options| {
+            self.selected
+                .get()
+                .and_then(|i| options.get(i))
+                .map_or_else(|| self.placeholder.clone(), |option| option.to_string())
+        });
+        let header = ViewNode::text_styled(
+            format!("{} {}", label, ctx.glyphs().dropdown),
+            self.style.header,
+        );
+        if !self.open {
+            return header;
+        }
+
+        let list = ctx
+            .render_child(&self.list)
+            .with_background(self.style.popup_bg);
+        ViewNode::sized(
+            ContainerDirection::Vertical,
+            vec![
+                (Length::Fixed(1), header),
+                (Length::Fixed(self.popup_height() as u16), list),
+            ],
+        )
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut EventContext) -> EventResult {
+        match event {
+            Event::Key(key) if !self.open => match key.code {
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    self.open();
+                    EventResult::Handled
+                }
+                _ => EventResult::Ignored,
+            },
+            Event::Key(key) => match key.code {
+                KeyCode::Enter | KeyCode::Char(' ') => {
+                    self.pick(self.highlight.get());
+                    EventResult::Handled
+                }
+                KeyCode::Esc => {
+                    self.open = false;
+                    EventResult::Handled
+                }
+                // Up/Down and paging move the highlight; other keys are
+                // kept from leaking out while open
+                _ => {
+                    self.list.handle_event(event, ctx);
+                    EventResult::Handled
+                }
+            },
+            Event::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                self.handle_click(mouse.x, mouse.y)
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+
+    fn focus_id(&self) -> Option<ComponentId> {
+        self.focus_id
+    }
+
+    fn on_blur(&mut self, _ctx: &mut EventContext) {
+        self.open = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{KeyEvent, KeyModifiers, MouseEvent};
+    use crate::state::Store;
+    use crate::testing::{buffer_lines, render_to_buffer};
+
+    fn fruit() -> (Select<&'static str>, Signal<Option<usize>>) {
+        let selected = Signal::new(Some(1));
+        let options = Signal::new(vec!["Apple", "Banana", "Cherry"]);
+        (Select::new(options, selected.clone()), selected)
+    }
+
+    fn send(select: &mut Select<&'static str>, event: Event) -> EventResult {
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 20, 5),
+        };
+        select.handle_event(&event, &mut ctx)
+    }
+
+    fn key(code: KeyCode) -> Event {
+        Event::Key(KeyEvent::new(code, KeyModifiers::empty()))
+    }
+
+    #[test]
+    fn test_pick_with_keys() {
+        let (mut select, selected) = fruit();
+        assert_eq!(
+            buffer_lines(&render_to_buffer(&select, 20, 5)),
+            ["Banana ▾", "", "", "", ""]
+        );
+
+        send(&mut select, key(KeyCode::Enter));
+        assert!(select.is_open());
+        assert_eq!(
+            buffer_lines(&render_to_buffer(&select, 20, 5)),
+            ["Banana ▾", "  Apple", "> Banana", "  Cherry", ""]
+        );
+
+        send(&mut select, key(KeyCode::Down));
+        send(&mut select, key(KeyCode::Enter));
+        assert!(!select.is_open());
+        assert_eq!(selected.get(), Some(2));
+    }
+
+    #[test]
+    fn test_escape_keeps_the_value() {
+        let (mut select, selected) = fruit();
+        send(&mut select, key(KeyCode::Char(' ')));
+        send(&mut select, key(KeyCode::Up));
+        assert_eq!(send(&mut select, key(KeyCode::Esc)), EventResult::Handled);
+
+        assert!(!select.is_open());
+        assert_eq!(selected.get(), Some(1));
+        assert_eq!(send(&mut select, key(KeyCode::Esc)), EventResult::Ignored);
+    }
+
+    #[test]
+    fn test_pick_with_mouse() {
+        let (mut select, selected) = fruit();
+        let click = |y| {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                x: 3,
+                y,
+                modifiers: KeyModifiers::empty(),
+            })
+        };
+
+        render_to_buffer(&select, 20, 5);
+        assert_eq!(send(&mut select, click(2)), EventResult::Ignored);
+        send(&mut select, click(0));
+        assert!(select.is_open());
+
+        render_to_buffer(&select, 20, 5);
+        send(&mut select, click(1));
+        assert!(!select.is_open());
+        assert_eq!(selected.get(), Some(0));
+    }
+}
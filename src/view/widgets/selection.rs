@@ -0,0 +1,70 @@
+//! Keeping a selection on the same item when the item list changes
+
+use crate::state::{Signal, Subscription};
+use std::any::Any;
+use std::sync::{Arc, Mutex};
+
+/// Subscriptions that keep `selected` pointing at the same logical item
+///
+/// Items are identified by `key`. When the list changes, the previously
+/// selected key is looked up again; if it's gone, the old index is clamped
+/// so the selection lands on the nearest remaining item.
+///
+/// The returned handle owns the subscriptions - drop it to stop tracking.
+pub(crate) fn track_selection<T, K, F>(
+    items: &Signal<Vec<T>>,
+    selected: &Signal<Option<usize>>,
+    key: F,
+) -> Box<dyn Any + Send + Sync>
+where
+    T: Clone + Send + Sync + 'static,
+    K: PartialEq + Send + Sync + 'static,
+    F: Fn(&T) -> K + Send + Sync + 'static,
+{
+    let key = Arc::new(key);
+
+    // Key and index of the selected item, as of the last change
+    let current = {
+        let items = items.get();
+        selected
+            .get()
+            .and_then(|i| items.get(i).map(|item| (key(item), i)))
+    };
+    let current = Arc::new(Mutex::new(current));
+
+    let on_select: Subscription<Option<usize>> = {
+        let (items, key, current) = (items.clone(), key.clone(), current.clone());
+        selected.subscribe(move |sel| {
+            let items = items.get();
+            *current.lock().unwrap() = sel.and_then(|i| items.get(i).map(|item| (key(item), i)));
+        })
+    };
+
+    let on_items: Subscription<Vec<T>> = {
+        let (selected, key, current) = (selected.clone(), key.clone(), current.clone());
+        items.subscribe(move |items| {
+            let previous = current.lock().unwrap().take();
+
+            let relocated = match previous {
+                Some((k, old_index)) => items
+                    .iter()
+                    .position(|item| key(item) == k)
+                    .or_else(|| items.len().checked_sub(1).map(|last| old_index.min(last))),
+                // Nothing was selected (or the index was already stale)
+                None => selected
+                    .get()
+                    .and_then(|i| items.len().checked_sub(1).map(|last| i.min(last))),
+            };
+
+            if relocated != selected.get() {
+                // The selection subscription records the new key
+                selected.set(relocated);
+            } else {
+                *current.lock().unwrap() =
+                    relocated.and_then(|i| items.get(i).map(|item| (key(item), i)));
+            }
+        })
+    };
+
+    Box::new((on_select, on_items))
+}
@@ -0,0 +1,427 @@
+//! Split pane widget
+//!
+//! Two children separated by a movable divider.
+
+use crate::event::{Event, EventResult, KeyCode, MouseButton, MouseEventKind};
+use crate::focus::ComponentId;
+use crate::layout::{Length, Rect};
+use crate::theme::{Color, Modifier, Style};
+use crate::view::component::notify_focus;
+use crate::view::{
+    Component, ContainerDirection, EventContext, RenderContext, UpdateContext, ViewNode,
+};
+use std::cell::Cell;
+
+/// Which way the panes are split
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SplitDirection {
+    /// Side by side, with a vertical divider
+    Horizontal,
+    /// Stacked top and bottom, with a horizontal divider
+    Vertical,
+}
+
+/// Two panes with a resizable divider between them
+///
+/// The divider can be dragged with the mouse, or moved with the arrow keys
+/// when focused (`[`/`]` for bigger jumps). Neither pane shrinks below its
+/// minimum size.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let split = SplitPane::new(Text::new("Left"), Text::new("Right"))
+///     .ratio(0.3)
+///     .min_sizes(10, 20);
+/// ```
+pub struct SplitPane {
+    first: Box<dyn Component>,
+    second: Box<dyn Component>,
+    direction: SplitDirection,
+    /// Share of the space (minus the divider) given to the first pane
+    ratio: f32,
+    step: f32,
+    big_step: f32,
+    min_first: u16,
+    min_second: u16,
+    focused: bool,
+    focus_id: Option<ComponentId>,
+    dragging: bool,
+    /// Last rendered area, needed to turn cells into ratios
+    last_area: Cell<Option<Rect>>,
+    divider_style: Style,
+    divider_focused_style: Style,
+}
+
+impl SplitPane {
+    /// Create a side-by-side split, divided evenly
+    pub fn new(first: impl Component + 'static, second: impl Component + 'static) -> Self {
+        Self {
+            first: Box::new(first),
+            second: Box::new(second),
+            direction: SplitDirection::Horizontal,
+            ratio: 0.5,
+            step: 0.05,
+            big_step: 0.2,
+            min_first: 1,
+            min_second: 1,
+            focused: false,
+            focus_id: None,
+            dragging: false,
+            last_area: Cell::new(None),
+            divider_style: Style::default().fg(Color::GRAY),
+            divider_focused_style: Style::default()
+                .fg(Color::CYAN)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+
+    /// Set the split direction
+    pub fn direction(mut self, direction: SplitDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Set the initial share of the first pane (0.0 - 1.0)
+    pub fn ratio(mut self, ratio: f32) -> Self {
+        self.ratio = ratio.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Set the keyboard step and the `[`/`]` step (as ratios)
+    pub fn steps(mut self, step: f32, big_step: f32) -> Self {
+        self.step = step;
+        self.big_step = big_step;
+        self
+    }
+
+    /// Minimum size in cells for each pane
+    pub fn min_sizes(mut self, first: u16, second: u16) -> Self {
+        self.min_first = first;
+        self.min_second = second;
+        self
+    }
+
+    /// Give the divider keyboard focus
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Set the focus id used for focus highlighting
+    pub fn focus_id(mut self, id: ComponentId) -> Self {
+        self.focus_id = Some(id);
+        self
+    }
+
+    /// Current share of the first pane
+    pub fn current_ratio(&self) -> f32 {
+        self.ratio
+    }
+
+    /// Length along the split axis
+    fn extent(&self, area: Rect) -> u16 {
+        match self.direction {
+            SplitDirection::Horizontal => area.width,
+            SplitDirection::Vertical => area.height,
+        }
+    }
+
+    /// Size of the first pane for a given amount of space (divider excluded)
+    fn first_size(&self, total: u16) -> u16 {
+        let min_sum = self.min_first as u32 + self.min_second as u32;
+
+        // Can't honor both minimums - split what we have in their proportion
+        if min_sum > total as u32 {
+            if min_sum == 0 {
+                return 0;
+            }
+            return (total as u32 * self.min_first as u32 / min_sum) as u16;
+        }
+
+        let wanted = (total as f32 * self.ratio).round() as u16;
+        wanted.clamp(self.min_first, total - self.min_second)
+    }
+
+    /// Compute (first, divider, second) rects for an area
+    pub fn layout(&self, area: Rect) -> (Rect, Rect, Rect) {
+        let extent = self.extent(area);
+        let total = extent.saturating_sub(1);
+        let first = self.first_size(total);
+
+        match self.direction {
+            SplitDirection::Horizontal => {
+                let (left, rest) = area.split_h(first);
+                let (divider, right) = rest.split_h(1.min(rest.width));
+                (left, divider, right)
+            }
+            SplitDirection::Vertical => {
+                let (top, rest) = area.split_v(first);
+                let (divider, bottom) = rest.split_v(1.min(rest.height));
+                (top, divider, bottom)
+            }
+        }
+    }
+
+    /// Move the divider by a ratio delta, respecting minimum sizes
+    pub fn adjust(&mut self, delta: f32) {
+        self.ratio = (self.ratio + delta).clamp(0.0, 1.0);
+
+        // Snap the ratio back to what actually gets rendered, so bumping into
+        // a minimum doesn't leave "invisible" steps to undo later
+        if let Some(area) = self.last_area.get() {
+            let total = self.extent(area).saturating_sub(1);
+            if total > 0 {
+                self.ratio = self.first_size(total) as f32 / total as f32;
+            }
+        }
+    }
+
+    fn handle_key(&mut self, code: KeyCode) -> EventResult {
+        let (back, forward) = match self.direction {
+            SplitDirection::Horizontal => (KeyCode::Left, KeyCode::Right),
+            SplitDirection::Vertical => (KeyCode::Up, KeyCode::Down),
+        };
+
+        match code {
+            c if c == back => self.adjust(-self.step),
+            c if c == forward => self.adjust(self.step),
+            KeyCode::Char('[') => self.adjust(-self.big_step),
+            KeyCode::Char(']') => self.adjust(self.big_step),
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Handled
+    }
+
+    fn handle_mouse(&mut self, kind: MouseEventKind, x: u16, y: u16) -> EventResult {
+        let Some(area) = self.last_area.get() else {
+            return EventResult::Ignored;
+        };
+        let (_, divider, _) = self.layout(area);
+
+        match kind {
+            MouseEventKind::Down(MouseButton::Left) if divider.contains(x, y) => {
+                self.dragging = true;
+                EventResult::Handled
+            }
+            MouseEventKind::Drag(MouseButton::Left) if self.dragging => {
+                let (pos, start) = match self.direction {
+                    SplitDirection::Horizontal => (x, area.x),
+                    SplitDirection::Vertical => (y, area.y),
+                };
+                let total = self.extent(area).saturating_sub(1);
+                if total > 0 {
+                    let wanted = pos.saturating_sub(start) as f32 / total as f32;
+                    self.adjust(wanted - self.ratio);
+                }
+                EventResult::Handled
+            }
+            MouseEventKind::Up(MouseButton::Left) if self.dragging => {
+                self.dragging = false;
+                EventResult::Handled
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+}
+
+impl Component for SplitPane {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        self.last_area.set(Some(ctx.area));
+
+        let total = self.extent(ctx.area).saturating_sub(1);
+        let first = self.first_size(total);
+
+        let style = if self.focused || ctx.is_focused(self.focus_id) {
+            self.divider_focused_style
+        } else {
+            self.divider_style
+        };
+
+        let (divider, direction) = match self.direction {
+            SplitDirection::Horizontal => {
+                let vertical = ctx.glyphs().vertical.to_string();
+                let line = vec![vertical; ctx.area.height as usize].join("\n");
+                (line, ContainerDirection::Horizontal)
+            }
+            SplitDirection::Vertical => (
+                ctx.glyphs()
+                    .horizontal
+                    .to_string()
+                    .repeat(ctx.area.width as usize),
+                ContainerDirection::Vertical,
+            ),
+        };
+
+        ViewNode::sized(
+            direction,
+            vec![
+                (Length::Fixed(first), ctx.render_child(self.first.as_ref())),
+                (Length::Fixed(1), ViewNode::text_styled(divider, style)),
+                (Length::Fill(1), ctx.render_child(self.second.as_ref())),
+            ],
+        )
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut EventContext) -> EventResult {
+        match event {
+            Event::Key(key) if self.focused => {
+                let result = self.handle_key(key.code);
+                if result != EventResult::Ignored {
+                    return result;
+                }
+            }
+            Event::Mouse(mouse) => {
+                let result = self.handle_mouse(mouse.kind, mouse.x, mouse.y);
+                if result != EventResult::Ignored {
+                    return result;
+                }
+            }
+            _ => {}
+        }
+
+        match self.first.handle_event(event, ctx) {
+            EventResult::Ignored => self.second.handle_event(event, ctx),
+            result => result,
+        }
+    }
+
+    fn focus_id(&self) -> Option<ComponentId> {
+        self.focus_id
+    }
+
+    fn on_focus(&mut self, _ctx: &mut EventContext) {
+        self.focused = true;
+    }
+
+    fn on_blur(&mut self, _ctx: &mut EventContext) {
+        self.focused = false;
+    }
+
+    fn focus_changed(
+        &mut self,
+        previous: Option<ComponentId>,
+        current: Option<ComponentId>,
+        ctx: &mut EventContext,
+    ) {
+        notify_focus(self, previous, current, ctx);
+        self.first.focus_changed(previous, current, ctx);
+        self.second.focus_changed(previous, current, ctx);
+    }
+
+    fn update(&mut self, ctx: &mut UpdateContext) -> bool {
+        self.first.update(ctx);
+        self.second.update(ctx);
+        true
+    }
+
+    fn children(&self) -> Vec<&dyn Component> {
+        vec![self.first.as_ref(), self.second.as_ref()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{KeyEvent, KeyModifiers};
+    use crate::render::Buffer;
+    use crate::state::Store;
+    use crate::view::Text;
+
+    fn render(split: &SplitPane, area: Rect) {
+        let mut buffer = Buffer::new(area.width, area.height);
+        let store = Store::new();
+        let ctx = RenderContext::new(&mut buffer, area, &store);
+        split.render(&ctx);
+    }
+
+    fn press(split: &mut SplitPane, code: KeyCode) {
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 0, 0),
+        };
+        split.handle_event(
+            &Event::Key(KeyEvent::new(code, KeyModifiers::empty())),
+            &mut ctx,
+        );
+    }
+
+    #[test]
+    fn test_layout_rects() {
+        let split = SplitPane::new(Text::new("a"), Text::new("b"));
+        let (left, divider, right) = split.layout(Rect::new(0, 0, 21, 5));
+
+        assert_eq!(left, Rect::new(0, 0, 10, 5));
+        assert_eq!(divider, Rect::new(10, 0, 1, 5));
+        assert_eq!(right, Rect::new(11, 0, 10, 5));
+    }
+
+    #[test]
+    fn test_keyboard_resize_updates_rects() {
+        let area = Rect::new(0, 0, 21, 5);
+        let mut split = SplitPane::new(Text::new("a"), Text::new("b"))
+            .steps(0.1, 0.3)
+            .focused(true);
+        render(&split, area);
+
+        press(&mut split, KeyCode::Right);
+        assert_eq!(split.layout(area).0.width, 12);
+
+        press(&mut split, KeyCode::Char('['));
+        assert_eq!(split.layout(area).0.width, 6);
+
+        // Wrong axis for a horizontal split
+        press(&mut split, KeyCode::Down);
+        assert_eq!(split.layout(area).0.width, 6);
+    }
+
+    #[test]
+    fn test_stepping_respects_minimums() {
+        let area = Rect::new(0, 0, 21, 5);
+        let mut split = SplitPane::new(Text::new("a"), Text::new("b"))
+            .min_sizes(4, 6)
+            .focused(true);
+        render(&split, area);
+
+        for _ in 0..10 {
+            press(&mut split, KeyCode::Char('['));
+        }
+        assert_eq!(split.layout(area).0.width, 4);
+
+        // One step back is visible immediately - no wasted presses
+        press(&mut split, KeyCode::Right);
+        assert_eq!(split.layout(area).0.width, 5);
+
+        for _ in 0..10 {
+            press(&mut split, KeyCode::Char(']'));
+        }
+        let (left, _, right) = split.layout(area);
+        assert_eq!(right.width, 6);
+        assert_eq!(left.width, 14);
+    }
+
+    #[test]
+    fn test_too_small_shrinks_proportionally() {
+        let split = SplitPane::new(Text::new("a"), Text::new("b"))
+            .direction(SplitDirection::Vertical)
+            .min_sizes(10, 30);
+
+        // 9 rows of content + 1 divider, minimums want 40
+        let (top, divider, bottom) = split.layout(Rect::new(0, 0, 20, 10));
+        assert_eq!(top.height, 2);
+        assert_eq!(divider.y, 2);
+        assert_eq!(bottom.height, 7);
+    }
+
+    #[test]
+    fn test_unfocused_ignores_keys() {
+        let area = Rect::new(0, 0, 21, 5);
+        let mut split = SplitPane::new(Text::new("a"), Text::new("b"));
+        render(&split, area);
+
+        press(&mut split, KeyCode::Right);
+        assert_eq!(split.current_ratio(), 0.5);
+    }
+}
@@ -0,0 +1,210 @@
+//! Frame statistics overlay for profiling
+//!
+//! Shows what the last frame cost, as published by the App under
+//! `FRAME_STATS_KEY`, in the top-right corner of its area.
+
+use crate::event::{Event, EventResult, KeyCode};
+use crate::layout::Length;
+use crate::render::FrameStats;
+use crate::theme::{Color, Style};
+use crate::view::{
+    Component, ContainerDirection, EventContext, RenderContext, ViewNode, FRAME_STATS_KEY,
+};
+
+/// Width of the box, label and value included
+const WIDTH: u16 = 16;
+
+/// FPS, frame time, dirty regions and cells redrawn, toggled by a key
+///
+/// Meant to sit on top of the rest of the UI, e.g. as the last child of
+/// a stacked container. Until the first frame has rendered the values
+/// show as `--`.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let overlay = StatsOverlay::new().toggle_key(KeyCode::F(2)).visible(true);
+/// ```
+pub struct StatsOverlay {
+    visible: bool,
+    toggle_key: KeyCode,
+    style: Style,
+}
+
+impl StatsOverlay {
+    /// Create a hidden overlay toggled by F12
+    pub fn new() -> Self {
+        Self {
+            visible: false,
+            toggle_key: KeyCode::F(12),
+            style: Style::fg_bg(Color::WHITE, Color::gray(30)),
+        }
+    }
+
+    /// Set the key that shows and hides the overlay
+    pub fn toggle_key(mut self, key: KeyCode) -> Self {
+        self.toggle_key = key;
+        self
+    }
+
+    /// Set whether the overlay starts out shown
+    pub fn visible(mut self, visible: bool) -> Self {
+        self.visible = visible;
+        self
+    }
+
+    /// Set the style of the box
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Check if the overlay is shown
+    pub fn is_visible(&self) -> bool {
+        self.visible
+    }
+
+    fn lines(stats: Option<FrameStats>) -> [(&'static str, String); 4] {
+        match stats {
+            Some(stats) => [
+                ("FPS", format!("{:.1}", stats.fps())),
+                (
+                    "frame",
+                    format!("{:.2}ms", stats.frame_time.as_secs_f64() * 1000.0),
+                ),
+                ("dirty", stats.dirty_regions.to_string()),
+                ("cells", stats.cells_redrawn.to_string()),
+            ],
+            None => [
+                ("FPS", "--".to_string()),
+                ("frame", "--".to_string()),
+                ("dirty", "--".to_string()),
+                ("cells", "--".to_string()),
+            ],
+        }
+    }
+}
+
+impl Default for StatsOverlay {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Component for StatsOverlay {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        if !self.visible {
+            return ViewNode::Empty;
+        }
+
+        let stats = ctx
+            .store
+            .get::<FrameStats>(FRAME_STATS_KEY)
+            .map(|s| s.get());
+        let rows: Vec<ViewNode> = Self::lines(stats)
+            .into_iter()
+            .map(|(label, value)| {
+                ViewNode::text_styled(format!(" {:<6}{:>8} ", label, value), self.style)
+            })
+            .collect();
+        let height = rows.len() as u16;
+
+        let panel = ViewNode::sized(
+            ContainerDirection::Vertical,
+            vec![
+                (Length::Fixed(height), ViewNode::container(rows)),
+                (Length::Fill(1), ViewNode::Empty),
+            ],
+        );
+        ViewNode::sized(
+            ContainerDirection::Horizontal,
+            vec![
+                (Length::Fill(1), ViewNode::Empty),
+                (Length::Fixed(WIDTH), panel),
+            ],
+        )
+    }
+
+    fn handle_event(&mut self, event: &Event, ctx: &mut EventContext) -> EventResult {
+        match event {
+            Event::Key(key) if key.code == self.toggle_key => {
+                self.visible = !self.visible;
+                ctx.request_redraw();
+                EventResult::Handled
+            }
+            _ => EventResult::Ignored,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::{KeyEvent, KeyModifiers};
+    use crate::layout::Rect;
+    use crate::render::Buffer;
+    use crate::state::Store;
+    use std::time::Duration;
+
+    fn draw(overlay: &StatsOverlay, store: &Store) -> Vec<String> {
+        let area = Rect::new(0, 0, 30, 6);
+        let mut buffer = Buffer::new(30, 6);
+        let node = overlay.render(&RenderContext::new(&mut buffer, area, store));
+        node.render(&mut RenderContext::new(&mut buffer, area, store));
+        (0..6)
+            .map(|y| {
+                (0..30)
+                    .map(|x| match buffer.get(x, y).unwrap().ch {
+                        '\0' => ' ',
+                        ch => ch,
+                    })
+                    .collect::<String>()
+                    .trim_end()
+                    .to_string()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_renders_current_stats() {
+        let store = Store::new();
+        let overlay = StatsOverlay::new().visible(true);
+
+        // Nothing published yet
+        let rows = draw(&overlay, &store);
+        assert_eq!(rows[0], "               FPS         --");
+
+        store.set(
+            FRAME_STATS_KEY,
+            FrameStats {
+                dirty_regions: 3,
+                cells_redrawn: 120,
+                frame_time: Duration::from_micros(2500),
+                interval: Duration::from_millis(20),
+            },
+        );
+        let rows = draw(&overlay, &store);
+        assert_eq!(rows[0], "               FPS       50.0");
+        assert_eq!(rows[1], "               frame   2.50ms");
+        assert_eq!(rows[2], "               dirty        3");
+        assert_eq!(rows[3], "               cells      120");
+        assert_eq!(rows[4], "");
+    }
+
+    #[test]
+    fn test_toggle_key() {
+        let mut overlay = StatsOverlay::new().toggle_key(KeyCode::F(2));
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 30, 6),
+        };
+        let key = Event::Key(KeyEvent::new(KeyCode::F(2), KeyModifiers::empty()));
+
+        assert_eq!(overlay.handle_event(&key, &mut ctx), EventResult::Handled);
+        assert!(overlay.is_visible());
+        overlay.handle_event(&key, &mut ctx);
+        assert!(!overlay.is_visible());
+    }
+}
@@ -2,11 +2,17 @@
 //!
 //! A table with columns, headers, sorting, and row selection.
 
-use crate::event::{Event, EventResult, KeyCode};
+use super::selection::track_selection;
+use crate::event::{Event, EventResult, KeyCode, MouseButton, MouseEventKind};
+use crate::layout::Rect;
 use crate::state::Signal;
 use crate::theme::{Color, Modifier, Style};
-use crate::view::{Component, EventContext, RenderContext, ViewNode};
+use crate::view::{Component, EventContext, Glyphs, RenderContext, Span, ViewNode};
+use std::any::Any;
+use std::cell::Cell;
+use std::cmp::Ordering;
 use std::sync::Arc;
+use unicode_width::UnicodeWidthStr;
 
 /// Sort order for table columns
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -31,8 +37,12 @@ pub struct Column<T> {
     width: usize,
     render: Arc<dyn Fn(&T) -> String + Send + Sync>,
     sortable: bool,
-    /// Sort comparison function (optional, required if sortable)
+    /// String key to sort by (optional, required if sortable)
     sort_key: Option<Arc<dyn Fn(&T) -> String + Send + Sync>>,
+    /// Typed comparison, used instead of `sort_key` when set
+    sort_cmp: Option<Arc<dyn Fn(&T, &T) -> Ordering + Send + Sync>>,
+    /// Style for each cell, layered over the row's
+    style: Option<Arc<dyn Fn(&T) -> Style + Send + Sync>>,
 }
 
 impl<T> Column<T> {
@@ -44,6 +54,8 @@ impl<T> Column<T> {
             render: Arc::new(|_| String::from("?")),
             sortable: false,
             sort_key: None,
+            sort_cmp: None,
+            style: None,
         }
     }
 
@@ -56,6 +68,18 @@ impl<T> Column<T> {
         self
     }
 
+    /// Style each cell from its row, e.g. negative numbers in red
+    ///
+    /// Layered over the row's style: colors set here win, modifiers add up,
+    /// and anything unset (like the selection background) shows through.
+    pub fn style<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&T) -> Style + Send + Sync + 'static,
+    {
+        self.style = Some(Arc::new(f));
+        self
+    }
+
     /// Make this column sortable with a sort key function
     pub fn sortable(mut self) -> Self {
         self.sortable = true;
@@ -75,6 +99,19 @@ impl<T> Column<T> {
         self.sort_key = Some(Arc::new(f));
         self
     }
+
+    /// Sort by comparing rows directly (automatically makes column sortable)
+    ///
+    /// For numbers, dates and other values whose text doesn't sort the
+    /// same way they do; takes precedence over `sort_by`.
+    pub fn sort_cmp<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&T, &T) -> Ordering + Send + Sync + 'static,
+    {
+        self.sortable = true;
+        self.sort_cmp = Some(Arc::new(f));
+        self
+    }
 }
 
 /// Table widget for displaying structured data
@@ -108,9 +145,18 @@ pub struct Table<T> {
     columns: Vec<Column<T>>,
     selected: Signal<Option<usize>>,
     sort: Signal<Option<(usize, SortOrder)>>,
-    scroll_offset: usize,
+    /// Column that `s` sorts by, moved with Left/Right
+    sort_cursor: usize,
+    /// Clamped during render, hence the Cell
+    scroll_offset: Cell<usize>,
     visible_height: usize,
     style: TableStyle,
+    wrap: bool,
+    zebra: bool,
+    /// Keeps the selection on the same item across updates (see `key`)
+    selection_tracker: Option<Box<dyn Any + Send + Sync>>,
+    /// Where we last rendered, for mouse events
+    last_rect: Cell<Option<Rect>>,
 }
 
 #[derive(Clone)]
@@ -118,7 +164,8 @@ struct TableStyle {
     header: Style,
     normal: Style,
     selected: Style,
-    alternating: Style,
+    /// Stripe for even rows; None follows the theme
+    alternating: Option<Style>,
 }
 
 impl Default for TableStyle {
@@ -129,7 +176,7 @@ impl Default for TableStyle {
                 .add_modifier(Modifier::BOLD),
             normal: Style::default(),
             selected: Style::default().bg(Color::BLUE).fg(Color::WHITE),
-            alternating: Style::default().bg(Color::rgb(30, 30, 30)),
+            alternating: None,
         }
     }
 }
@@ -142,14 +189,24 @@ impl<T: Clone + Send + Sync + 'static> Table<T> {
             columns: Vec::new(),
             selected,
             sort: Signal::new(None),
-            scroll_offset: 0,
+            sort_cursor: 0,
+            scroll_offset: Cell::new(0),
             visible_height: 10,
             style: TableStyle::default(),
+            wrap: false,
+            zebra: true,
+            selection_tracker: None,
+            last_rect: Cell::new(None),
         }
     }
 
     /// Add a column to the table
+    ///
+    /// `s` starts out sorting by the first sortable column.
     pub fn column(mut self, column: Column<T>) -> Self {
+        if column.sortable && !self.columns.iter().any(|c| c.sortable) {
+            self.sort_cursor = self.columns.len();
+        }
         self.columns.push(column);
         self
     }
@@ -160,47 +217,102 @@ impl<T: Clone + Send + Sync + 'static> Table<T> {
         self
     }
 
+    /// Wrap selection around at either end (default: stop at the ends)
+    pub fn wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    /// Stripe every other row (default: on)
+    ///
+    /// The selected row always uses the selection style.
+    pub fn zebra(mut self, zebra: bool) -> Self {
+        self.zebra = zebra;
+        self
+    }
+
+    /// Style for the striped rows (default: the theme's `stripe`)
+    pub fn alternating_style(mut self, style: Style) -> Self {
+        self.style.alternating = Some(style);
+        self
+    }
+
+    /// Identify items by key so the selection follows the same item when
+    /// the rows get replaced or reordered
+    ///
+    /// If the selected item disappears, the nearest remaining one is picked.
+    pub fn key<K, F>(mut self, key: F) -> Self
+    where
+        K: PartialEq + Send + Sync + 'static,
+        F: Fn(&T) -> K + Send + Sync + 'static,
+    {
+        self.selection_tracker = Some(track_selection(&self.rows, &self.selected, key));
+        self
+    }
+
     /// Format a row into a string with column alignment
-    fn format_row(&self, row: &T, is_header: bool) -> String {
-        let mut result = String::new();
+    fn format_row(&self, row: &T, is_header: bool, glyphs: &Glyphs) -> String {
+        let separator = format!(" {} ", glyphs.vertical);
+        self.row_cells(row, is_header, glyphs).join(&separator)
+    }
 
-        for (i, col) in self.columns.iter().enumerate() {
-            if i > 0 {
-                result.push_str(" │ ");
-            }
+    /// Each column's text for a row, cut or padded to the column width
+    fn row_cells(&self, row: &T, is_header: bool, glyphs: &Glyphs) -> Vec<String> {
+        self.columns
+            .iter()
+            .map(|col| {
+                let content = if is_header {
+                    col.title.clone()
+                } else {
+                    (col.render)(row)
+                };
 
-            let content = if is_header {
-                col.title.clone()
-            } else {
-                (col.render)(row)
-            };
+                // Truncate or pad to column width, in display columns
+                let mut cell = glyphs.truncate(&content, col.width);
+                let padding = col.width.saturating_sub(cell.width());
+                cell.push_str(&" ".repeat(padding));
+                cell
+            })
+            .collect()
+    }
 
-            // Truncate or pad to column width
-            let formatted = if content.len() > col.width {
-                format!("{:.width$}", content, width = col.width - 1) + "…"
-            } else {
-                format!("{:<width$}", content, width = col.width)
+    /// A body row as spans, each cell styled over the row's style
+    fn render_row(&self, row: &T, marker: &str, style: Style, glyphs: &Glyphs) -> ViewNode {
+        let separator = format!(" {} ", glyphs.vertical);
+        let mut spans = vec![Span::styled(marker, style)];
+
+        for (i, (col, cell)) in self
+            .columns
+            .iter()
+            .zip(self.row_cells(row, false, glyphs))
+            .enumerate()
+        {
+            if i > 0 {
+                spans.push(Span::styled(separator.as_str(), style));
+            }
+            let cell_style = match &col.style {
+                Some(f) => style.patch(f(row)),
+                None => style,
             };
-
-            result.push_str(&formatted);
+            spans.push(Span::styled(cell, cell_style));
         }
 
-        result
+        ViewNode::spans(spans)
     }
 
     /// Render the header row
-    fn render_header(&self) -> ViewNode {
-        let header_text = self.format_row(&self.rows.get()[0], true);
+    fn render_header(&self, first: &T, glyphs: &Glyphs) -> ViewNode {
+        let header_text = self.format_row(first, true, glyphs);
 
         // Add sort indicator if column is sorted
         let sort_info = self.sort.get();
         let header_with_sort = if let Some((col_idx, order)) = sort_info {
             if col_idx < self.columns.len() {
                 let indicator = match order {
-                    SortOrder::Ascending => " ▲",
-                    SortOrder::Descending => " ▼",
+                    SortOrder::Ascending => glyphs.arrow_up,
+                    SortOrder::Descending => glyphs.arrow_down,
                 };
-                format!("{}{}", header_text, indicator)
+                format!("{} {}", header_text, indicator)
             } else {
                 header_text
             }
@@ -212,28 +324,30 @@ impl<T: Clone + Send + Sync + 'static> Table<T> {
     }
 
     /// Render separator line
-    fn render_separator(&self) -> ViewNode {
+    fn render_separator(&self, glyphs: &Glyphs) -> ViewNode {
+        let rule = glyphs.horizontal.to_string();
         let mut sep = String::new();
         for (i, col) in self.columns.iter().enumerate() {
             if i > 0 {
-                sep.push_str("─┼─");
+                sep.push_str(&format!("{}{}{}", rule, glyphs.cross, rule));
             }
-            sep.push_str(&"─".repeat(col.width));
+            sep.push_str(&rule.repeat(col.width));
         }
         ViewNode::text_styled(sep, Style::default().fg(Color::GRAY))
     }
 
     /// Select next row
     fn select_next(&mut self) {
-        let rows = self.rows.get();
-        if rows.is_empty() {
+        let len = self.rows.with(|rows| rows.len());
+        if len == 0 {
             return;
         }
 
         let current = self.selected.get();
         let next = match current {
             None => Some(0),
-            Some(idx) if idx + 1 < rows.len() => Some(idx + 1),
+            Some(idx) if idx + 1 < len => Some(idx + 1),
+            Some(_) if self.wrap => Some(0),
             Some(idx) => Some(idx),
         };
 
@@ -243,14 +357,15 @@ impl<T: Clone + Send + Sync + 'static> Table<T> {
 
     /// Select previous row
     fn select_prev(&mut self) {
-        let rows = self.rows.get();
-        if rows.is_empty() {
+        let len = self.rows.with(|rows| rows.len());
+        if len == 0 {
             return;
         }
 
         let current = self.selected.get();
         let prev = match current {
-            None => Some(rows.len() - 1),
+            None => Some(len - 1),
+            Some(0) if self.wrap => Some(len - 1),
             Some(0) => Some(0),
             Some(idx) => Some(idx - 1),
         };
@@ -259,13 +374,98 @@ impl<T: Clone + Send + Sync + 'static> Table<T> {
         self.ensure_visible(prev.unwrap());
     }
 
+    /// Move down a page (wraps only once already at the last row)
+    fn page_down(&mut self) {
+        let len = self.rows.with(|rows| rows.len());
+        if len == 0 {
+            return;
+        }
+
+        let last = len - 1;
+        let current = self.selected.get().unwrap_or(0);
+        let next = if self.wrap && current == last {
+            0
+        } else {
+            (current + self.visible_height).min(last)
+        };
+
+        self.selected.set(Some(next));
+        self.ensure_visible(next);
+    }
+
+    /// Move up a page (wraps only once already at the first row)
+    fn page_up(&mut self) {
+        let len = self.rows.with(|rows| rows.len());
+        if len == 0 {
+            return;
+        }
+
+        let current = self.selected.get().unwrap_or(0);
+        let prev = if self.wrap && current == 0 {
+            len - 1
+        } else {
+            current.saturating_sub(self.visible_height)
+        };
+
+        self.selected.set(Some(prev));
+        self.ensure_visible(prev);
+    }
+
     /// Ensure row is visible
-    fn ensure_visible(&mut self, index: usize) {
-        if index >= self.scroll_offset + self.visible_height {
-            self.scroll_offset = index - self.visible_height + 1;
-        } else if index < self.scroll_offset {
-            self.scroll_offset = index;
+    fn ensure_visible(&self, index: usize) {
+        if index >= self.scroll_offset.get() + self.visible_height {
+            self.scroll_offset.set(index - self.visible_height + 1);
+        } else if index < self.scroll_offset.get() {
+            self.scroll_offset.set(index);
+        }
+    }
+
+    /// Bring the scroll offset back in range for `len` items
+    ///
+    /// `visible_height` or the item count may have changed since the
+    /// offset was set, which would leave blank rows (or an offset past the
+    /// end). Also keeps the selection on screen.
+    fn clamp_scroll(&self, len: usize) -> usize {
+        let max = len.saturating_sub(self.visible_height);
+        self.scroll_offset.set(self.scroll_offset.get().min(max));
+        if let Some(index) = self.selected.get().filter(|&i| i < len) {
+            self.ensure_visible(index);
+        }
+        self.scroll_offset.get()
+    }
+
+    /// Move the column `s` sorts by to the next sortable one to the left
+    /// or right, staying put if there isn't one. Returns whether it moved.
+    fn move_sort_cursor(&mut self, right: bool) -> bool {
+        let sortable = |i: &usize| self.columns[*i].sortable;
+        let next = if right {
+            (self.sort_cursor + 1..self.columns.len()).find(sortable)
+        } else {
+            (0..self.sort_cursor.min(self.columns.len()))
+                .rev()
+                .find(sortable)
+        };
+        match next {
+            Some(col_idx) => {
+                self.sort_cursor = col_idx;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// The column under a header x offset (relative to the table)
+    fn column_at(&self, x: u16) -> Option<usize> {
+        let mut start = 0;
+        for (i, col) in self.columns.iter().enumerate() {
+            let end = start + col.width;
+            if (start..end).contains(&(x as usize)) {
+                return Some(i);
+            }
+            // Skip the " | " between columns
+            start = end + 3;
         }
+        None
     }
 
     /// Toggle sort on column
@@ -303,84 +503,80 @@ impl<T: Clone + Send + Sync + 'static> Table<T> {
     fn apply_sort(&mut self, col_idx: usize, order: SortOrder) {
         let column = &self.columns[col_idx];
 
-        // Get the sort key function
-        let Some(sort_key) = &column.sort_key else {
-            return;
-        };
-
-        let sort_key = sort_key.clone();
+        // Prefer the typed comparison, then the string key
+        let compare: Arc<dyn Fn(&T, &T) -> Ordering + Send + Sync> =
+            match (&column.sort_cmp, &column.sort_key) {
+                (Some(sort_cmp), _) => sort_cmp.clone(),
+                (None, Some(sort_key)) => {
+                    let sort_key = sort_key.clone();
+                    Arc::new(move |a, b| sort_key(a).cmp(&sort_key(b)))
+                }
+                (None, None) => return,
+            };
 
         // Sort the rows
         self.rows.update(|rows| {
-            rows.sort_by(|a, b| {
-                let key_a = sort_key(a);
-                let key_b = sort_key(b);
-
-                match order {
-                    SortOrder::Ascending => key_a.cmp(&key_b),
-                    SortOrder::Descending => key_b.cmp(&key_a),
-                }
+            rows.sort_by(|a, b| match order {
+                SortOrder::Ascending => compare(a, b),
+                SortOrder::Descending => compare(b, a),
             });
         });
 
         // Reset scroll position after sort
-        self.scroll_offset = 0;
+        self.scroll_offset.set(0);
     }
 }
 
 impl<T: Clone + Send + Sync + 'static> Component for Table<T> {
-    fn render(&self, _ctx: &RenderContext) -> ViewNode {
-        let rows = self.rows.get();
-
-        if rows.is_empty() {
-            return ViewNode::text_styled("(empty table)", Style::default().fg(Color::GRAY));
-        }
-
-        let mut children = Vec::new();
-
-        // Header
-        children.push(self.render_header());
-        children.push(self.render_separator());
-
-        // Visible rows
-        let selected_idx = self.selected.get();
-        let end = (self.scroll_offset + self.visible_height).min(rows.len());
-        let visible_rows = &rows[self.scroll_offset..end];
-
-        for (offset, row) in visible_rows.iter().enumerate() {
-            let absolute_idx = self.scroll_offset + offset;
-            let is_selected = selected_idx == Some(absolute_idx);
-            let is_even = absolute_idx % 2 == 0;
-
-            let row_text = self.format_row(row, false);
-
-            let style = if is_selected {
-                self.style.selected
-            } else if is_even {
-                self.style.alternating
-            } else {
-                self.style.normal
-            };
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        self.last_rect.set(Some(ctx.area));
+        let glyphs = ctx.glyphs();
+        // Borrow the rows - cloning a big table every frame adds up
+        self.rows.with(|rows| {
+            if rows.is_empty() {
+                return ViewNode::text_styled("(empty table)", Style::default().fg(Color::GRAY));
+            }
 
-            let formatted = if is_selected {
-                format!("> {}", row_text)
-            } else {
-                format!("  {}", row_text)
-            };
+            let mut children = Vec::new();
+
+            // Header
+            children.push(self.render_header(&rows[0], glyphs));
+            children.push(self.render_separator(glyphs));
+
+            // Visible rows
+            let selected_idx = self.selected.get();
+            let offset = self.clamp_scroll(rows.len());
+            let end = (offset + self.visible_height).min(rows.len());
+            let visible_rows = &rows[offset..end];
+
+            for (i, row) in visible_rows.iter().enumerate() {
+                let absolute_idx = offset + i;
+                let is_selected = selected_idx == Some(absolute_idx);
+                let is_even = absolute_idx % 2 == 0;
+
+                let style = if is_selected {
+                    self.style.selected
+                } else if self.zebra && is_even {
+                    self.style.alternating.unwrap_or(ctx.theme.stripe())
+                } else {
+                    self.style.normal
+                };
 
-            children.push(ViewNode::text_styled(formatted, style));
-        }
+                let marker = if is_selected { "> " } else { "  " };
+                children.push(self.render_row(row, marker, style, glyphs));
+            }
 
-        // Scroll indicator
-        if rows.len() > self.visible_height {
-            let info = format!("  [{}-{} of {}]", self.scroll_offset + 1, end, rows.len());
-            children.push(ViewNode::text_styled(
-                info,
-                Style::default().fg(Color::GRAY),
-            ));
-        }
+            // Scroll indicator
+            if rows.len() > self.visible_height {
+                let info = format!("  [{}-{} of {}]", offset + 1, end, rows.len());
+                children.push(ViewNode::text_styled(
+                    info,
+                    Style::default().fg(Color::GRAY),
+                ));
+            }
 
-        ViewNode::container(children)
+            ViewNode::container(children)
+        })
     }
 
     fn handle_event(&mut self, event: &Event, _ctx: &mut EventContext) -> EventResult {
@@ -394,13 +590,56 @@ impl<T: Clone + Send + Sync + 'static> Component for Table<T> {
                     self.select_next();
                     EventResult::Handled
                 }
+                KeyCode::PageUp => {
+                    self.page_up();
+                    EventResult::Handled
+                }
+                KeyCode::PageDown => {
+                    self.page_down();
+                    EventResult::Handled
+                }
+                // Left/Right at the edge (or with nothing sortable) are left
+                // for the parent, e.g. Tabs switching tabs
+                KeyCode::Left if self.move_sort_cursor(false) => EventResult::Handled,
+                KeyCode::Right if self.move_sort_cursor(true) => EventResult::Handled,
                 KeyCode::Char('s') | KeyCode::Char('S') => {
-                    // Toggle sort (on first column for now)
-                    self.toggle_sort(0);
+                    self.toggle_sort(self.sort_cursor);
                     EventResult::Handled
                 }
                 _ => EventResult::Ignored,
             },
+            // The wheel moves the selection like Up/Down, over the table only;
+            // clicking a sortable header sorts by that column
+            Event::Mouse(mouse) => {
+                let Some(rect) = self
+                    .last_rect
+                    .get()
+                    .filter(|rect| rect.contains(mouse.x, mouse.y))
+                else {
+                    return EventResult::Ignored;
+                };
+                match mouse.kind {
+                    MouseEventKind::Down(MouseButton::Left) if mouse.y == rect.y => {
+                        match self.column_at(mouse.x - rect.x) {
+                            Some(col_idx) if self.columns[col_idx].sortable => {
+                                self.sort_cursor = col_idx;
+                                self.toggle_sort(col_idx);
+                                EventResult::Handled
+                            }
+                            _ => EventResult::Ignored,
+                        }
+                    }
+                    MouseEventKind::ScrollUp => {
+                        self.select_prev();
+                        EventResult::Handled
+                    }
+                    MouseEventKind::ScrollDown => {
+                        self.select_next();
+                        EventResult::Handled
+                    }
+                    _ => EventResult::Ignored,
+                }
+            }
             _ => EventResult::Ignored,
         }
     }
@@ -409,7 +648,9 @@ impl<T: Clone + Send + Sync + 'static> Component for Table<T> {
 #[cfg(test)]
 mod tests {
     use super::{Column, SortOrder, Table};
+    use crate::event::KeyCode;
     use crate::state::Signal;
+    use crate::view::Glyphs;
 
     #[derive(Clone, Debug)]
     struct TestRow {
@@ -468,6 +709,76 @@ mod tests {
         assert_eq!(selected.get(), Some(1));
     }
 
+    #[test]
+    fn test_wheel_moves_selection() {
+        use crate::event::{Event, KeyModifiers, MouseEvent, MouseEventKind};
+        use crate::layout::Rect;
+        use crate::state::Store;
+        use crate::testing::render_to_buffer;
+        use crate::view::{Component, EventContext};
+
+        let data = Signal::new(
+            (0..5)
+                .map(|value| TestRow {
+                    name: value.to_string(),
+                    value,
+                })
+                .collect(),
+        );
+        let selected = Signal::new(Some(0));
+        let mut table = Table::new(data, selected.clone())
+            .column(Column::new("Name", 10).render(|r: &TestRow| r.name.clone()));
+        render_to_buffer(&table, 20, 8);
+
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 20, 8),
+        };
+        let wheel = |kind, y| {
+            Event::Mouse(MouseEvent {
+                kind,
+                x: 1,
+                y,
+                modifiers: KeyModifiers::empty(),
+            })
+        };
+
+        table.handle_event(&wheel(MouseEventKind::ScrollDown, 3), &mut ctx);
+        table.handle_event(&wheel(MouseEventKind::ScrollDown, 3), &mut ctx);
+        table.handle_event(&wheel(MouseEventKind::ScrollUp, 3), &mut ctx);
+        table.handle_event(&wheel(MouseEventKind::ScrollDown, 9), &mut ctx);
+        assert_eq!(selected.get(), Some(1));
+    }
+
+    #[test]
+    fn test_table_wrap() {
+        let data = Signal::new(
+            (0..5)
+                .map(|i| TestRow {
+                    name: i.to_string(),
+                    value: i,
+                })
+                .collect::<Vec<_>>(),
+        );
+        let selected = Signal::new(Some(4));
+        let mut table = Table::new(data, selected.clone())
+            .column(Column::new("Name", 10).render(|r: &TestRow| r.name.clone()))
+            .visible_height(2)
+            .wrap(true);
+
+        table.select_next();
+        assert_eq!(selected.get(), Some(0));
+        table.select_prev();
+        assert_eq!(selected.get(), Some(4));
+
+        table.page_up();
+        assert_eq!(selected.get(), Some(2));
+        table.page_up();
+        table.page_up();
+        assert_eq!(selected.get(), Some(4));
+    }
+
     #[test]
     fn test_column_render() {
         let data = Signal::new(vec![TestRow {
@@ -481,11 +792,265 @@ mod tests {
             .column(Column::new("Value", 5).render(|r: &TestRow| r.value.to_string()));
 
         let rows = data.get();
-        let formatted = table.format_row(&rows[0], false);
+        let formatted = table.format_row(&rows[0], false, &Glyphs::UNICODE);
         assert!(formatted.contains("Test"));
         assert!(formatted.contains("42"));
     }
 
+    #[test]
+    fn test_columns_measured_in_display_width() {
+        use unicode_width::UnicodeWidthStr;
+
+        let data = Signal::new(vec![
+            TestRow {
+                name: "Müller".into(),
+                value: 1,
+            },
+            TestRow {
+                name: "太郎".into(),
+                value: 2,
+            },
+            TestRow {
+                name: "山田太郎さん".into(),
+                value: 3,
+            },
+        ]);
+        let table = Table::new(data.clone(), Signal::new(None))
+            .column(Column::new("Name", 6).render(|r: &TestRow| r.name.clone()))
+            .column(Column::new("Value", 5).render(|r: &TestRow| r.value.to_string()));
+
+        let rows = data.get();
+        let formatted: Vec<_> = rows
+            .iter()
+            .map(|row| table.format_row(row, false, &Glyphs::UNICODE))
+            .collect();
+        assert_eq!(formatted[0], "Müller │ 1    ");
+        assert_eq!(formatted[1], "太郎   │ 2    ");
+        // Cut before a wide char that won't fit, then padded
+        assert_eq!(formatted[2], "山田…  │ 3    ");
+        for line in &formatted {
+            assert_eq!(line.width(), 14);
+        }
+    }
+
+    #[test]
+    fn test_sort_cmp_sorts_numerically() {
+        let data = Signal::new(
+            [95000, 105000, 110000, 68000]
+                .into_iter()
+                .map(|value| TestRow {
+                    name: String::new(),
+                    value,
+                })
+                .collect::<Vec<_>>(),
+        );
+        let mut table = Table::new(data.clone(), Signal::new(None)).column(
+            Column::new("Salary", 10)
+                .render(|r: &TestRow| r.value.to_string())
+                .sort_by(|r: &TestRow| r.value.to_string())
+                .sort_cmp(|a: &TestRow, b: &TestRow| a.value.cmp(&b.value)),
+        );
+
+        let values = || data.get().iter().map(|r| r.value).collect::<Vec<_>>();
+        table.toggle_sort(0);
+        assert_eq!(values(), [68000, 95000, 105000, 110000]);
+        table.toggle_sort(0);
+        assert_eq!(values(), [110000, 105000, 95000, 68000]);
+    }
+
+    fn three_columns(data: &Signal<Vec<TestRow>>) -> Table<TestRow> {
+        Table::new(data.clone(), Signal::new(None))
+            .column(
+                Column::new("Name", 5)
+                    .render(|r: &TestRow| r.name.clone())
+                    .sortable(),
+            )
+            .column(Column::new("Note", 5).render(|_: &TestRow| String::new()))
+            .column(
+                Column::new("Value", 5)
+                    .render(|r: &TestRow| r.value.to_string())
+                    .sort_cmp(|a: &TestRow, b: &TestRow| a.value.cmp(&b.value)),
+            )
+    }
+
+    fn unsorted() -> Signal<Vec<TestRow>> {
+        Signal::new(
+            [("a", 3), ("b", 1), ("c", 2)]
+                .into_iter()
+                .map(|(name, value)| TestRow {
+                    name: name.into(),
+                    value,
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_sort_cursor_picks_the_column() {
+        use crate::event::{Event, KeyEvent, KeyModifiers};
+        use crate::layout::Rect;
+        use crate::state::Store;
+        use crate::view::{Component, EventContext};
+
+        let data = unsorted();
+        let mut table = three_columns(&data);
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 30, 8),
+        };
+        let mut press = |table: &mut Table<TestRow>, code| {
+            table.handle_event(
+                &Event::Key(KeyEvent::new(code, KeyModifiers::empty())),
+                &mut ctx,
+            );
+        };
+
+        // Skips the unsortable middle column, and stops at the end
+        press(&mut table, KeyCode::Right);
+        press(&mut table, KeyCode::Right);
+        assert_eq!(table.sort_cursor, 2);
+
+        press(&mut table, KeyCode::Char('s'));
+        assert_eq!(table.sort.get(), Some((2, SortOrder::Ascending)));
+        let values: Vec<_> = data.get().iter().map(|r| r.value).collect();
+        assert_eq!(values, [1, 2, 3]);
+
+        press(&mut table, KeyCode::Left);
+        press(&mut table, KeyCode::Char('s'));
+        assert_eq!(table.sort.get(), Some((0, SortOrder::Ascending)));
+    }
+
+    #[test]
+    fn test_edge_arrows_reach_tabs() {
+        use crate::event::{Event, KeyEvent, KeyModifiers};
+        use crate::layout::Rect;
+        use crate::state::Store;
+        use crate::view::{Component, EventContext, Tabs, Text};
+
+        let data = unsorted();
+        let tab = Signal::new(0);
+        let mut tabs = Tabs::new(tab.clone())
+            .tab("Table", three_columns(&data))
+            .tab("Other", Text::new("other"));
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 30, 8),
+        };
+        let mut press = |tabs: &mut Tabs, code| {
+            tabs.handle_event(
+                &Event::Key(KeyEvent::new(code, KeyModifiers::empty())),
+                &mut ctx,
+            )
+        };
+
+        // Already on the leftmost sortable column: Tabs gets the key
+        press(&mut tabs, KeyCode::Left);
+        assert_eq!(tab.get(), 1);
+        press(&mut tabs, KeyCode::Left);
+        assert_eq!(tab.get(), 0);
+
+        // Moving the sort cursor keeps the tab; the edge switches it
+        press(&mut tabs, KeyCode::Right);
+        assert_eq!(tab.get(), 0);
+        press(&mut tabs, KeyCode::Right);
+        assert_eq!(tab.get(), 1);
+    }
+
+    #[test]
+    fn test_header_click_sorts() {
+        use crate::event::{Event, KeyModifiers, MouseButton, MouseEvent, MouseEventKind};
+        use crate::layout::Rect;
+        use crate::state::Store;
+        use crate::testing::render_to_buffer;
+        use crate::view::{Component, EventContext};
+
+        let data = unsorted();
+        let mut table = three_columns(&data);
+        render_to_buffer(&table, 30, 8);
+
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 30, 8),
+        };
+        let click = |x, y| {
+            Event::Mouse(MouseEvent {
+                kind: MouseEventKind::Down(MouseButton::Left),
+                x,
+                y,
+                modifiers: KeyModifiers::empty(),
+            })
+        };
+
+        // "Value" starts after two 5-wide columns and their separators
+        table.handle_event(&click(17, 0), &mut ctx);
+        assert_eq!(table.sort.get(), Some((2, SortOrder::Ascending)));
+        table.handle_event(&click(17, 0), &mut ctx);
+        assert_eq!(table.sort.get(), Some((2, SortOrder::Descending)));
+        assert_eq!(table.sort_cursor, 2);
+
+        // Unsortable header and body rows don't sort
+        table.handle_event(&click(9, 0), &mut ctx);
+        table.handle_event(&click(1, 3), &mut ctx);
+        assert_eq!(table.sort.get(), Some((2, SortOrder::Descending)));
+    }
+
+    #[test]
+    fn test_cell_style_layers_over_row_style() {
+        use crate::layout::Rect;
+        use crate::render::Buffer;
+        use crate::state::Store;
+        use crate::theme::{Color, Style};
+        use crate::view::{Component, RenderContext, ViewNode};
+
+        let data = Signal::new(
+            [("Ann", 5), ("Bob", -3)]
+                .into_iter()
+                .map(|(name, value)| TestRow {
+                    name: name.into(),
+                    value,
+                })
+                .collect(),
+        );
+        let table = Table::new(data, Signal::new(Some(1)))
+            .zebra(false)
+            .column(Column::new("Name", 5).render(|r: &TestRow| r.name.clone()))
+            .column(
+                Column::new("Value", 5)
+                    .render(|r: &TestRow| r.value.to_string())
+                    .style(|r: &TestRow| match r.value {
+                        v if v < 0 => Style::default().fg(Color::RED),
+                        _ => Style::default(),
+                    }),
+            );
+
+        let mut buffer = Buffer::new(30, 8);
+        let store = Store::new();
+        let ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 30, 8), &store);
+        let ViewNode::Container { children, .. } = table.render(&ctx) else {
+            panic!("Expected container node");
+        };
+
+        // Header and separator, then the rows as marker, cell, separator, cell
+        let spans = |i: usize| match &children[i] {
+            ViewNode::Spans { spans } => spans.clone(),
+            _ => panic!("Expected spans"),
+        };
+        let plain = spans(2);
+        assert_eq!(plain[3].content, "5    ");
+        assert_eq!(plain[3].style, Style::default());
+
+        // Red text on the selection background
+        let selected = spans(3);
+        assert_eq!(selected[0].content, "> ");
+        assert_eq!(selected[1].style, table.style.selected);
+        assert_eq!(selected[3].content, "-3   ");
+        assert_eq!(selected[3].style.fg, Some(Color::RED));
+        assert_eq!(selected[3].style.bg, table.style.selected.bg);
+    }
+
     #[test]
     fn test_sort_toggle() {
         let data = Signal::new(vec![
@@ -523,4 +1088,78 @@ mod tests {
         table.toggle_sort(0);
         assert_eq!(table.sort.get(), None);
     }
+
+    #[test]
+    fn test_sort_keeps_selected_row() {
+        let data = Signal::new(vec![
+            TestRow {
+                name: "B".into(),
+                value: 2,
+            },
+            TestRow {
+                name: "A".into(),
+                value: 1,
+            },
+        ]);
+        let selected = Signal::new(Some(0));
+        let mut table = Table::new(data.clone(), selected.clone())
+            .column(
+                Column::new("Name", 10)
+                    .render(|r: &TestRow| r.name.clone())
+                    .sortable(),
+            )
+            .key(|r: &TestRow| r.value);
+
+        // "B" moves to the bottom, and the selection moves with it
+        table.toggle_sort(0);
+        assert_eq!(selected.get(), Some(1));
+        assert_eq!(data.get()[1].name, "B");
+    }
+
+    #[test]
+    fn test_zebra_toggle() {
+        use crate::layout::Rect;
+        use crate::render::Buffer;
+        use crate::state::Store;
+        use crate::theme::Style;
+        use crate::view::{Component, RenderContext, ViewNode};
+
+        let row_styles = |table: &Table<TestRow>| -> Vec<Style> {
+            let mut buffer = Buffer::new(40, 10);
+            let store = Store::new();
+            let ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 40, 10), &store);
+            let ViewNode::Container { children, .. } = table.render(&ctx) else {
+                panic!("Expected container node");
+            };
+            // Skip the header and separator; rows start with the marker
+            children[2..]
+                .iter()
+                .map(|child| match child {
+                    ViewNode::Spans { spans } => spans[0].style,
+                    _ => panic!("Expected spans"),
+                })
+                .collect()
+        };
+
+        let rows: Vec<TestRow> = (0..3)
+            .map(|value| TestRow {
+                name: value.to_string(),
+                value,
+            })
+            .collect();
+        let table = |zebra| {
+            Table::new(Signal::new(rows.clone()), Signal::new(Some(2)))
+                .column(Column::new("Name", 10).render(|r: &TestRow| r.name.clone()))
+                .zebra(zebra)
+        };
+
+        let striped = row_styles(&table(true));
+        assert_ne!(striped[0], striped[1]);
+
+        let plain = row_styles(&table(false));
+        assert_eq!(plain[0], plain[1]);
+        // Selection still wins
+        assert_ne!(plain[2], plain[1]);
+        assert_eq!(plain[2], striped[2]);
+    }
 }
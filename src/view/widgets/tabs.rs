@@ -3,9 +3,13 @@
 //! Displays multiple views with tab navigation.
 
 use crate::event::{Event, EventResult, KeyCode};
-use crate::state::Signal;
+use crate::focus::ComponentId;
+use crate::state::{Signal, Store};
 use crate::theme::{Color, Modifier, Style};
-use crate::view::{Component, EventContext, RenderContext, ViewNode};
+use crate::view::{
+    Component, EventContext, Glyphs, MountContext, RenderContext, RenderMode, UpdateContext,
+    ViewNode,
+};
 
 /// Tabs widget for switching between multiple views
 ///
@@ -23,15 +27,26 @@ use crate::view::{Component, EventContext, RenderContext, ViewNode};
 ///     .tab("Details", details)
 ///     .tab("Settings", settings);
 /// ```
+///
+/// ## Lifecycle
+/// A tab's content is mounted the first time it's shown, not when it's
+/// added, so tabs that load data on mount only do so when opened. With
+/// `keep_alive(false)` the content is also unmounted when another tab is
+/// selected (and mounted again on return). A `selected` change made from
+/// outside is picked up on the next event.
 pub struct Tabs {
     tabs: Vec<Tab>,
     selected: Signal<usize>,
     style: TabStyle,
+    keep_alive: bool,
+    /// Tab whose content was last shown (and mounted)
+    shown: Option<usize>,
 }
 
 struct Tab {
     title: String,
     content: Box<dyn Component>,
+    mounted: bool,
 }
 
 #[derive(Clone)]
@@ -49,7 +64,7 @@ impl Default for TabStyle {
                 .fg(Color::WHITE)
                 .add_modifier(Modifier::BOLD),
             inactive: Style::default().fg(Color::GRAY),
-            separator: Style::default().fg(Color::rgb(60, 60, 60)),
+            separator: Style::default().fg(Color::gray(60)),
         }
     }
 }
@@ -61,14 +76,25 @@ impl Tabs {
             tabs: Vec::new(),
             selected,
             style: TabStyle::default(),
+            keep_alive: true,
+            shown: None,
         }
     }
 
+    /// Keep hidden tabs mounted (default: true)
+    ///
+    /// `false` unmounts a tab's content when switching away from it.
+    pub fn keep_alive(mut self, keep_alive: bool) -> Self {
+        self.keep_alive = keep_alive;
+        self
+    }
+
     /// Add a tab with title and content
     pub fn tab(mut self, title: impl Into<String>, content: impl Component + 'static) -> Self {
         self.tabs.push(Tab {
             title: title.into(),
             content: Box::new(content),
+            mounted: false,
         });
         self
     }
@@ -99,8 +125,35 @@ impl Tabs {
         self.selected.set(prev);
     }
 
+    /// Mount the selected tab's content, unmounting the previous one
+    /// unless kept alive
+    fn sync_mounted(&mut self, store: &mut Store) {
+        if self.tabs.is_empty() {
+            return;
+        }
+        let selected = self.selected.get().min(self.tabs.len() - 1);
+        if self.shown == Some(selected) {
+            return;
+        }
+
+        let mut ctx = MountContext { store };
+        if let Some(prev) = self.shown.and_then(|i| self.tabs.get_mut(i)) {
+            if !self.keep_alive && prev.mounted {
+                prev.content.unmount(&mut ctx);
+                prev.mounted = false;
+            }
+        }
+
+        let tab = &mut self.tabs[selected];
+        if !tab.mounted {
+            tab.content.mount(&mut ctx);
+            tab.mounted = true;
+        }
+        self.shown = Some(selected);
+    }
+
     /// Render tab bar
-    fn render_tab_bar(&self, selected: usize) -> ViewNode {
+    fn render_tab_bar(&self, selected: usize, glyphs: &Glyphs) -> ViewNode {
         let mut parts = Vec::new();
 
         for (i, tab) in self.tabs.iter().enumerate() {
@@ -118,7 +171,10 @@ impl Tabs {
 
             // Add separator between tabs
             if i < self.tabs.len() - 1 {
-                parts.push(ViewNode::text_styled("│", self.style.separator));
+                parts.push(ViewNode::text_styled(
+                    glyphs.vertical.to_string(),
+                    self.style.separator,
+                ));
             }
         }
 
@@ -137,26 +193,48 @@ impl Component for Tabs {
         let mut children = Vec::new();
 
         // Render tab bar
-        children.push(self.render_tab_bar(selected));
+        let glyphs = ctx.glyphs();
+        children.push(self.render_tab_bar(selected, glyphs));
 
         // Separator line
-        children.push(ViewNode::text_styled("─".repeat(60), self.style.separator));
+        children.push(ViewNode::text_styled(
+            glyphs.horizontal.to_string().repeat(60),
+            self.style.separator,
+        ));
 
         // Render selected tab content
         if let Some(tab) = self.tabs.get(selected) {
-            children.push(tab.content.render(ctx));
+            children.push(ctx.render_child(tab.content.as_ref()));
         }
 
         // Help text
+        let help = match ctx.mode {
+            RenderMode::Unicode => "  [Tab/→] Next  [Shift+Tab/←] Previous",
+            RenderMode::Ascii => "  [Tab/Right] Next  [Shift+Tab/Left] Previous",
+        };
         children.push(ViewNode::text_styled(
-            "  [Tab/→] Next  [Shift+Tab/←] Previous",
+            help,
             Style::default().fg(Color::GRAY),
         ));
 
         ViewNode::container(children)
     }
 
+    fn mount(&mut self, ctx: &mut MountContext) {
+        self.sync_mounted(ctx.store);
+    }
+
+    fn unmount(&mut self, ctx: &mut MountContext) {
+        for tab in self.tabs.iter_mut().filter(|tab| tab.mounted) {
+            tab.content.unmount(ctx);
+            tab.mounted = false;
+        }
+        self.shown = None;
+    }
+
     fn handle_event(&mut self, event: &Event, ctx: &mut EventContext) -> EventResult {
+        self.sync_mounted(ctx.store);
+
         // Pass event to active tab first
         let selected = self.selected.get();
         if let Some(tab) = self.tabs.get_mut(selected) {
@@ -167,7 +245,7 @@ impl Component for Tabs {
         }
 
         // Handle tab navigation
-        match event {
+        let result = match event {
             Event::Key(key) => match key.code {
                 KeyCode::Tab => {
                     if key.modifiers.contains(crate::event::KeyModifiers::SHIFT) {
@@ -188,14 +266,42 @@ impl Component for Tabs {
                 _ => EventResult::Ignored,
             },
             _ => EventResult::Ignored,
+        };
+
+        self.sync_mounted(ctx.store);
+        result
+    }
+
+    fn focus_changed(
+        &mut self,
+        previous: Option<ComponentId>,
+        current: Option<ComponentId>,
+        ctx: &mut EventContext,
+    ) {
+        for tab in &mut self.tabs {
+            tab.content.focus_changed(previous, current, ctx);
         }
     }
+
+    /// Every tab's content, not just the selected one
+    fn update(&mut self, ctx: &mut UpdateContext) -> bool {
+        for tab in self.tabs.iter_mut().filter(|tab| tab.mounted) {
+            tab.content.update(ctx);
+        }
+        true
+    }
+
+    fn children(&self) -> Vec<&dyn Component> {
+        self.tabs.iter().map(|tab| tab.content.as_ref()).collect()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::view::Text;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
 
     #[test]
     fn test_tabs_creation() {
@@ -250,4 +356,72 @@ mod tests {
         tabs.select_prev();
         assert_eq!(selected.get(), 2);
     }
+
+    /// Counts its mount/unmount calls
+    struct Probe {
+        mounts: Arc<AtomicUsize>,
+        unmounts: Arc<AtomicUsize>,
+    }
+
+    impl Component for Probe {
+        fn render(&self, _ctx: &RenderContext) -> ViewNode {
+            ViewNode::text("probe")
+        }
+
+        fn mount(&mut self, _ctx: &mut MountContext) {
+            self.mounts.fetch_add(1, Ordering::SeqCst);
+        }
+
+        fn unmount(&mut self, _ctx: &mut MountContext) {
+            self.unmounts.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    fn probe() -> (Probe, Arc<AtomicUsize>, Arc<AtomicUsize>) {
+        let (mounts, unmounts) = (Arc::new(AtomicUsize::new(0)), Arc::new(AtomicUsize::new(0)));
+        let probe = Probe {
+            mounts: mounts.clone(),
+            unmounts: unmounts.clone(),
+        };
+        (probe, mounts, unmounts)
+    }
+
+    #[test]
+    fn test_tabs_mount_lazily() {
+        use crate::event::{KeyEvent, KeyModifiers};
+        use crate::layout::Rect;
+
+        let right = Event::Key(KeyEvent::new(KeyCode::Right, KeyModifiers::empty()));
+        let count = |n: &Arc<AtomicUsize>| n.load(Ordering::SeqCst);
+
+        for keep_alive in [true, false] {
+            let (first, first_mounts, first_unmounts) = probe();
+            let (second, second_mounts, _) = probe();
+            let mut tabs = Tabs::new(Signal::new(0))
+                .keep_alive(keep_alive)
+                .tab("One", first)
+                .tab("Two", second);
+
+            let mut store = Store::new();
+            tabs.mount(&mut MountContext { store: &mut store });
+            assert_eq!((count(&first_mounts), count(&second_mounts)), (1, 0));
+
+            let mut ctx = EventContext {
+                store: &mut store,
+                area: Rect::new(0, 0, 40, 5),
+            };
+            tabs.handle_event(&right, &mut ctx); // -> Two
+            assert_eq!(count(&second_mounts), 1);
+            tabs.handle_event(&right, &mut ctx); // -> One (wraps)
+            tabs.handle_event(&right, &mut ctx); // -> Two
+
+            if keep_alive {
+                assert_eq!((count(&first_mounts), count(&second_mounts)), (1, 1));
+                assert_eq!(count(&first_unmounts), 0);
+            } else {
+                assert_eq!((count(&first_mounts), count(&second_mounts)), (2, 2));
+                assert_eq!(count(&first_unmounts), 2);
+            }
+        }
+    }
 }
@@ -0,0 +1,355 @@
+//! Multi-line text editor widget
+//!
+//! Like `Input`, but Enter starts a new line and the cursor moves in two
+//! dimensions.
+
+use crate::event::{Event, EventResult, KeyCode, KeyModifiers};
+use crate::focus::ComponentId;
+use crate::layout::Length;
+use crate::state::Signal;
+use crate::theme::{Color, Modifier, Style};
+use crate::view::{Component, ContainerDirection, EventContext, RenderContext, ViewNode};
+
+/// Multi-line text entry bound to a `Signal<String>`
+///
+/// Lines are separated by `\n` in the signal's value, so newlines typed
+/// here show up there and vice versa. Up/Down keep the column you were
+/// aiming for when they pass through shorter lines, and the view scrolls
+/// to keep the cursor in sight.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let comment = Signal::new(String::new());
+/// let editor = TextArea::new(comment)
+///     .height(6)
+///     .placeholder("Leave a comment");
+/// ```
+pub struct TextArea {
+    value: Signal<String>,
+    /// Cursor line and char offset within it
+    row: usize,
+    col: usize,
+    /// Column Up/Down try to return to
+    desired_col: usize,
+    /// First visible line
+    scroll: usize,
+    height: usize,
+    placeholder: Option<String>,
+    focused: bool,
+    style: TextAreaStyle,
+    focus_id: Option<ComponentId>,
+}
+
+#[derive(Clone)]
+struct TextAreaStyle {
+    normal: Style,
+    focused: Style,
+    placeholder: Style,
+}
+
+impl Default for TextAreaStyle {
+    fn default() -> Self {
+        Self {
+            normal: Style::default().fg(Color::WHITE).bg(Color::gray(40)),
+            focused: Style::default()
+                .fg(Color::WHITE)
+                .bg(Color::rgb(60, 60, 80))
+                .add_modifier(Modifier::BOLD),
+            placeholder: Style::default().fg(Color::GRAY).bg(Color::gray(40)),
+        }
+    }
+}
+
+impl TextArea {
+    /// Create a text area bound to a signal
+    pub fn new(value: Signal<String>) -> Self {
+        Self {
+            value,
+            row: 0,
+            col: 0,
+            desired_col: 0,
+            scroll: 0,
+            height: 5,
+            placeholder: None,
+            focused: false,
+            style: TextAreaStyle::default(),
+            focus_id: None,
+        }
+    }
+
+    /// Set how many lines are visible (default 5)
+    pub fn height(mut self, lines: usize) -> Self {
+        self.height = lines.max(1);
+        self
+    }
+
+    /// Set placeholder text shown when empty
+    pub fn placeholder(mut self, text: impl Into<String>) -> Self {
+        self.placeholder = Some(text.into());
+        self
+    }
+
+    /// Set focused state
+    pub fn focused(mut self, focused: bool) -> Self {
+        self.focused = focused;
+        self
+    }
+
+    /// Set the focus id used for focus highlighting
+    pub fn focus_id(mut self, id: ComponentId) -> Self {
+        self.focus_id = Some(id);
+        self
+    }
+
+    /// Cursor position as (line, column), both counted from zero
+    pub fn cursor(&self) -> (usize, usize) {
+        (self.row, self.col)
+    }
+
+    fn lines(&self) -> Vec<Vec<char>> {
+        self.value
+            .with(|v| v.split('\n').map(|l| l.chars().collect()).collect())
+    }
+
+    fn set_lines(&mut self, lines: &[Vec<char>]) {
+        let text: Vec<String> = lines.iter().map(|l| l.iter().collect()).collect();
+        self.value.set(text.join("\n"));
+    }
+
+    /// Put the cursor at (row, col), clamped to the text
+    fn move_to(&mut self, lines: &[Vec<char>], row: usize, col: usize) {
+        self.row = row.min(lines.len() - 1);
+        self.col = col.min(lines[self.row].len());
+        self.desired_col = self.col;
+        self.scroll_to_cursor();
+    }
+
+    /// Move up or down a line, aiming for `desired_col`
+    fn move_vertical(&mut self, lines: &[Vec<char>], row: usize) {
+        let desired = self.desired_col;
+        self.move_to(lines, row, desired);
+        self.desired_col = desired;
+    }
+
+    fn scroll_to_cursor(&mut self) {
+        if self.row < self.scroll {
+            self.scroll = self.row;
+        } else if self.row >= self.scroll + self.height {
+            self.scroll = self.row + 1 - self.height;
+        }
+    }
+
+    fn insert_char(&mut self, lines: &mut [Vec<char>], c: char) {
+        lines[self.row].insert(self.col, c);
+        self.set_lines(lines);
+        self.move_to(lines, self.row, self.col + 1);
+    }
+
+    /// Split the line at the cursor
+    fn insert_newline(&mut self, lines: &mut Vec<Vec<char>>) {
+        let rest = lines[self.row].split_off(self.col);
+        lines.insert(self.row + 1, rest);
+        self.set_lines(lines);
+        self.move_to(lines, self.row + 1, 0);
+    }
+
+    /// Delete before the cursor, joining with the line above at column 0
+    fn backspace(&mut self, lines: &mut Vec<Vec<char>>) {
+        if self.col > 0 {
+            lines[self.row].remove(self.col - 1);
+            self.set_lines(lines);
+            self.move_to(lines, self.row, self.col - 1);
+        } else if self.row > 0 {
+            let line = lines.remove(self.row);
+            let col = lines[self.row - 1].len();
+            lines[self.row - 1].extend(line);
+            self.set_lines(lines);
+            self.move_to(lines, self.row - 1, col);
+        }
+    }
+
+    /// Delete at the cursor, joining the next line at the end of one
+    fn delete(&mut self, lines: &mut Vec<Vec<char>>) {
+        if self.col < lines[self.row].len() {
+            lines[self.row].remove(self.col);
+        } else if self.row + 1 < lines.len() {
+            let next = lines.remove(self.row + 1);
+            lines[self.row].extend(next);
+        } else {
+            return;
+        }
+        self.set_lines(lines);
+    }
+}
+
+impl Component for TextArea {
+    fn render(&self, _ctx: &RenderContext) -> ViewNode {
+        let style = if self.focused {
+            self.style.focused
+        } else {
+            self.style.normal
+        };
+
+        // If empty, show placeholder
+        if let Some(ref placeholder) = self.placeholder {
+            if self.value.with(|v| v.is_empty()) {
+                return ViewNode::text_styled(format!("{} ", placeholder), self.style.placeholder);
+            }
+        }
+
+        let lines: Vec<String> = self
+            .value
+            .with(|v| v.split('\n').map(String::from).collect());
+        let rows = lines
+            .iter()
+            .enumerate()
+            .skip(self.scroll)
+            .take(self.height)
+            .map(|(row, line)| {
+                let text = if self.focused && row == self.row {
+                    let chars: Vec<char> = line.chars().collect();
+                    let col = self.col.min(chars.len());
+                    let before: String = chars[..col].iter().collect();
+                    let after: String = chars[col..].iter().collect();
+                    format!("{}|{}", before, after)
+                } else {
+                    format!("{} ", line)
+                };
+                (Length::Fixed(1), ViewNode::text_styled(text, style))
+            })
+            .collect();
+        ViewNode::sized(ContainerDirection::Vertical, rows)
+    }
+
+    fn handle_event(&mut self, event: &Event, _ctx: &mut EventContext) -> EventResult {
+        if !self.focused {
+            return EventResult::Ignored;
+        }
+        let Event::Key(key) = event else {
+            return EventResult::Ignored;
+        };
+
+        let mut lines = self.lines();
+        // The value may have changed under us
+        let (row, col) = (self.row, self.col);
+        self.row = row.min(lines.len() - 1);
+        self.col = col.min(lines[self.row].len());
+
+        match key.code {
+            KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.insert_char(&mut lines, c)
+            }
+            KeyCode::Enter => self.insert_newline(&mut lines),
+            KeyCode::Backspace => self.backspace(&mut lines),
+            KeyCode::Delete => self.delete(&mut lines),
+            KeyCode::Left if self.col > 0 => self.move_to(&lines, self.row, self.col - 1),
+            KeyCode::Left if self.row > 0 => {
+                let end = lines[self.row - 1].len();
+                self.move_to(&lines, self.row - 1, end)
+            }
+            KeyCode::Right if self.col < lines[self.row].len() => {
+                self.move_to(&lines, self.row, self.col + 1)
+            }
+            KeyCode::Right if self.row + 1 < lines.len() => self.move_to(&lines, self.row + 1, 0),
+            KeyCode::Left | KeyCode::Right => {}
+            KeyCode::Up if self.row > 0 => self.move_vertical(&lines, self.row - 1),
+            KeyCode::Down if self.row + 1 < lines.len() => self.move_vertical(&lines, self.row + 1),
+            KeyCode::Up | KeyCode::Down => {}
+            KeyCode::Home => self.move_to(&lines, self.row, 0),
+            KeyCode::End => self.move_to(&lines, self.row, usize::MAX),
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Handled
+    }
+
+    fn focus_id(&self) -> Option<ComponentId> {
+        self.focus_id
+    }
+
+    fn on_focus(&mut self, _ctx: &mut EventContext) {
+        self.focused = true;
+    }
+
+    fn on_blur(&mut self, _ctx: &mut EventContext) {
+        self.focused = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::event::KeyEvent;
+    use crate::layout::Rect;
+    use crate::state::Store;
+    use crate::testing::{buffer_lines, render_to_buffer};
+
+    fn type_keys(area: &mut TextArea, keys: &[KeyCode]) {
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 20, 5),
+        };
+        for &code in keys {
+            area.handle_event(
+                &Event::Key(KeyEvent::new(code, KeyModifiers::empty())),
+                &mut ctx,
+            );
+        }
+    }
+
+    fn text(s: &str) -> Vec<KeyCode> {
+        s.chars()
+            .map(|c| match c {
+                '\n' => KeyCode::Enter,
+                c => KeyCode::Char(c),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_newlines_round_trip() {
+        let value = Signal::new(String::new());
+        let mut area = TextArea::new(value.clone()).focused(true);
+
+        type_keys(&mut area, &text("hello\nworld"));
+        assert_eq!(value.get(), "hello\nworld");
+        assert_eq!(area.cursor(), (1, 5));
+
+        // Backspace at column 0 joins with the line above
+        type_keys(&mut area, &[KeyCode::Home, KeyCode::Backspace]);
+        assert_eq!(value.get(), "helloworld");
+        assert_eq!(area.cursor(), (0, 5));
+
+        type_keys(&mut area, &[KeyCode::Enter]);
+        assert_eq!(value.get(), "hello\nworld");
+    }
+
+    #[test]
+    fn test_up_down_keep_the_column() {
+        let value = Signal::new("a long line\nab\nanother line".to_string());
+        let mut area = TextArea::new(value).focused(true);
+
+        type_keys(&mut area, &[KeyCode::End]);
+        assert_eq!(area.cursor(), (0, 11));
+        type_keys(&mut area, &[KeyCode::Down]);
+        assert_eq!(area.cursor(), (1, 2));
+        type_keys(&mut area, &[KeyCode::Down]);
+        assert_eq!(area.cursor(), (2, 11));
+
+        // Moving sideways picks a new column
+        type_keys(&mut area, &[KeyCode::Up, KeyCode::Left, KeyCode::Down]);
+        assert_eq!(area.cursor(), (2, 1));
+    }
+
+    #[test]
+    fn test_renders_visible_lines() {
+        let value = Signal::new("one\ntwo\nthree\nfour".to_string());
+        let mut area = TextArea::new(value).height(2).focused(true);
+        type_keys(&mut area, &[KeyCode::Down, KeyCode::Down, KeyCode::End]);
+
+        let lines = buffer_lines(&render_to_buffer(&area, 10, 3));
+        assert_eq!(lines, ["two", "three|", ""]);
+    }
+}
@@ -0,0 +1,241 @@
+//! Virtualized list for very large datasets
+//!
+//! Like `List`, but rows come from a closure instead of a `Vec`, so only
+//! the visible ones are ever built.
+
+use crate::event::{Event, EventResult, KeyCode};
+use crate::state::Signal;
+use crate::theme::{Color, Modifier, Style};
+use crate::view::{Component, EventContext, RenderContext, ViewNode};
+use std::cell::Cell;
+use std::sync::Arc;
+
+/// Scrollable list over `len` rows produced on demand
+///
+/// The row closure gets an index and is only called for rows on screen,
+/// never for an index `>= len`. Use it for millions of rows, or rows that
+/// live in a file or database rather than in memory.
+///
+/// ## Example
+/// ```no_run
+/// use rsdrav::prelude::*;
+///
+/// let len = Signal::new(1_000_000);
+/// let selected = Signal::new(Some(0));
+///
+/// let list = VirtualList::new(len, selected)
+///     .render_item(|i| ViewNode::text(format!("Row {}", i)));
+/// ```
+#[allow(clippy::type_complexity)]
+pub struct VirtualList {
+    len: Signal<usize>,
+    selected: Signal<Option<usize>>,
+    /// Clamped during render, hence the Cell
+    scroll_offset: Cell<usize>,
+    visible_height: usize,
+    render_item: Arc<dyn Fn(usize) -> ViewNode + Send + Sync>,
+    style: VirtualListStyle,
+}
+
+#[derive(Clone)]
+struct VirtualListStyle {
+    selected: Style,
+}
+
+impl Default for VirtualListStyle {
+    fn default() -> Self {
+        Self {
+            selected: Style::default()
+                .bg(Color::BLUE)
+                .add_modifier(Modifier::BOLD),
+        }
+    }
+}
+
+impl VirtualList {
+    /// Create a virtual list
+    ///
+    /// - `len`: Signal containing the number of rows
+    /// - `selected`: Signal containing the selected index (None = no selection)
+    pub fn new(len: Signal<usize>, selected: Signal<Option<usize>>) -> Self {
+        Self {
+            len,
+            selected,
+            scroll_offset: Cell::new(0),
+            visible_height: 10,
+            render_item: Arc::new(|i| ViewNode::text(i.to_string())),
+            style: VirtualListStyle::default(),
+        }
+    }
+
+    /// Set the row renderer, called with the row index
+    pub fn render_item<F>(mut self, f: F) -> Self
+    where
+        F: Fn(usize) -> ViewNode + Send + Sync + 'static,
+    {
+        self.render_item = Arc::new(f);
+        self
+    }
+
+    /// Set visible height (number of rows shown at once)
+    pub fn visible_height(mut self, height: usize) -> Self {
+        self.visible_height = height;
+        self
+    }
+
+    /// Move the selection by `delta` rows, stopping at the ends
+    fn select_by(&mut self, delta: isize) {
+        let next = match self.selected.get() {
+            None => 0,
+            Some(current) => current.saturating_add_signed(delta),
+        };
+        self.select(next);
+    }
+
+    /// Select a row, clamped to the last one
+    fn select(&mut self, index: usize) {
+        let len = self.len.get();
+        if len == 0 {
+            return;
+        }
+        let index = index.min(len - 1);
+        self.selected.set(Some(index));
+        self.ensure_visible(index);
+    }
+
+    /// Ensure selected row is visible (adjust scroll offset)
+    fn ensure_visible(&self, index: usize) {
+        if index >= self.scroll_offset.get() + self.visible_height {
+            self.scroll_offset.set(index + 1 - self.visible_height);
+        } else if index < self.scroll_offset.get() {
+            self.scroll_offset.set(index);
+        }
+    }
+
+    /// Bring the scroll offset back in range for `len` rows
+    fn clamp_scroll(&self, len: usize) -> usize {
+        let max = len.saturating_sub(self.visible_height);
+        self.scroll_offset.set(self.scroll_offset.get().min(max));
+        if let Some(index) = self.selected.get().filter(|&i| i < len) {
+            self.ensure_visible(index);
+        }
+        self.scroll_offset.get()
+    }
+}
+
+impl Component for VirtualList {
+    fn render(&self, ctx: &RenderContext) -> ViewNode {
+        let len = self.len.get();
+        if len == 0 {
+            return ViewNode::text_styled("(empty list)", Style::default().fg(Color::GRAY));
+        }
+
+        let offset = self.clamp_scroll(len);
+        let end = (offset + self.visible_height).min(len);
+        let selected = self.selected.get();
+
+        let mut children: Vec<ViewNode> = (offset..end)
+            .map(|index| {
+                let is_selected = selected == Some(index);
+                match (self.render_item)(index) {
+                    ViewNode::Text { content, style } if is_selected => ViewNode::Text {
+                        content: format!("> {}", content),
+                        style: style.patch(self.style.selected),
+                    },
+                    ViewNode::Text { content, style } => ViewNode::Text {
+                        content: format!("  {}", content),
+                        style,
+                    },
+                    other => other,
+                }
+            })
+            .collect();
+
+        if len > self.visible_height {
+            children.push(ViewNode::text_styled(
+                format!(
+                    "  [{} {}-{} of {}]",
+                    ctx.glyphs().scroll,
+                    offset + 1,
+                    end,
+                    len
+                ),
+                Style::default().fg(Color::GRAY),
+            ));
+        }
+
+        ViewNode::container(children)
+    }
+
+    fn handle_event(&mut self, event: &Event, _ctx: &mut EventContext) -> EventResult {
+        let Event::Key(key) = event else {
+            return EventResult::Ignored;
+        };
+        let page = self.visible_height as isize;
+        match key.code {
+            KeyCode::Up => self.select_by(-1),
+            KeyCode::Down => self.select_by(1),
+            KeyCode::PageUp => self.select_by(-page),
+            KeyCode::PageDown => self.select_by(page),
+            KeyCode::Home => self.select(0),
+            KeyCode::End => self.select(usize::MAX),
+            _ => return EventResult::Ignored,
+        }
+        EventResult::Handled
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::layout::Rect;
+    use crate::render::Buffer;
+    use crate::state::Store;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    fn render(list: &VirtualList) -> ViewNode {
+        let mut buffer = Buffer::new(40, 20);
+        let store = Store::new();
+        let ctx = RenderContext::new(&mut buffer, Rect::new(0, 0, 40, 20), &store);
+        list.render(&ctx)
+    }
+
+    #[test]
+    fn test_only_visible_rows_are_built() {
+        let calls = Arc::new(AtomicUsize::new(0));
+        let highest = Arc::new(AtomicUsize::new(0));
+        let len = Signal::new(5_000_000);
+
+        let (c, h) = (calls.clone(), highest.clone());
+        let mut list = VirtualList::new(len.clone(), Signal::new(None))
+            .visible_height(10)
+            .render_item(move |i| {
+                c.fetch_add(1, Ordering::SeqCst);
+                h.fetch_max(i, Ordering::SeqCst);
+                ViewNode::text(i.to_string())
+            });
+
+        render(&list);
+        assert_eq!(calls.load(Ordering::SeqCst), 10);
+
+        let mut store = Store::new();
+        let mut ctx = EventContext {
+            store: &mut store,
+            area: Rect::new(0, 0, 40, 20),
+        };
+        let end = Event::Key(crate::event::KeyEvent::new(
+            KeyCode::End,
+            crate::event::KeyModifiers::empty(),
+        ));
+        list.handle_event(&end, &mut ctx);
+        render(&list);
+        assert_eq!(calls.load(Ordering::SeqCst), 20);
+        assert_eq!(highest.load(Ordering::SeqCst), 4_999_999);
+
+        // Shrinking below the scroll position never asks for missing rows
+        len.set(3);
+        render(&list);
+        assert_eq!(calls.load(Ordering::SeqCst), 23);
+        assert_eq!(list.scroll_offset.get(), 0);
+    }
+}
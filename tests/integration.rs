@@ -224,19 +224,25 @@ fn test_command_parsing_edge_cases() {
     assert!(parse("   ").is_err());
 
     // Single command
-    let cmd = parse("quit").unwrap();
+    let cmd = parse("quit").unwrap().commands.remove(0);
     assert_eq!(cmd.name, "quit");
     assert_eq!(cmd.args.len(), 0);
 
     // Multiple spaces
-    let cmd = parse("echo    hello    world").unwrap();
+    let cmd = parse("echo    hello    world").unwrap().commands.remove(0);
     assert_eq!(cmd.args, vec!["hello", "world"]);
 
     // Quotes with spaces
-    let cmd = parse(r#"set "my var" "some value""#).unwrap();
+    let cmd = parse(r#"set "my var" "some value""#)
+        .unwrap()
+        .commands
+        .remove(0);
     assert_eq!(cmd.args, vec!["my var", "some value"]);
 
     // Escaped characters
-    let cmd = parse(r"path /home/user\ name/file").unwrap();
+    let cmd = parse(r"path /home/user\ name/file")
+        .unwrap()
+        .commands
+        .remove(0);
     assert_eq!(cmd.args, vec!["/home/user name/file"]);
 }
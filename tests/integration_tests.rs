@@ -272,7 +272,7 @@ fn test_command_parsing() {
     let result = parse("test arg1 arg2");
     assert!(result.is_ok());
 
-    let cmd = result.unwrap();
+    let cmd = result.unwrap().commands.remove(0);
     assert_eq!(cmd.name, "test");
     assert_eq!(cmd.args, vec!["arg1", "arg2"]);
 }